@@ -174,6 +174,10 @@ impl Estate<AccountId32> for EstateHandler {
 	fn is_estate_leased(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(false)
 	}
+
+	fn check_estate_lease(_leasor: AccountId32, _estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
 }
 
 pub struct Handler;
@@ -0,0 +1,56 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition of the economy pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+pub use economy::{PowerPurchaseQuote, RewardLedgerEntry};
+use primitives::{DomainId, PowerAmount};
+
+sp_api::decl_runtime_apis! {
+	/// Queries of the economy pallet state for the dApp
+	pub trait EconomyApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// BIT cost, commission and expected execution block of buying `power_amount` in
+		/// `domain_id`
+		fn quote_power_purchase(
+			power_amount: PowerAmount,
+			domain_id: DomainId,
+		) -> Result<PowerPurchaseQuote<Balance, BlockNumber>, DispatchError>;
+
+		/// Economic weight of `who`: its self-stake, weighted estate stake and innovation shares
+		fn total_economic_weight(who: AccountId) -> Balance;
+
+		/// Label or content id `who` attached to its innovation stake position, if any
+		fn position_memo(who: AccountId) -> Option<Vec<u8>>;
+
+		/// Leaderboard of the accounts with the most innovation shares, ordered by descending share
+		fn top_stakers() -> Vec<(AccountId, Balance)>;
+
+		/// Page of at most `limit` reward ledger entries of `who` starting at `start`, oldest first
+		fn reward_ledger_page(who: AccountId, start: u32, limit: u32) -> Vec<RewardLedgerEntry<Balance, BlockNumber>>;
+	}
+}
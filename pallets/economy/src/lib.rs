@@ -22,29 +22,129 @@ use codec::{Encode, HasCompact};
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, LockableCurrency, ReservableCurrency},
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, Imbalance, LockIdentifier, LockableCurrency, OnUnbalanced,
+		Randomness, ReservableCurrency, WithdrawReasons,
+	},
 	transactional, PalletId,
 };
-use frame_system::{ensure_signed, pallet_prelude::*};
+use frame_system::{ensure_signed, pallet_prelude::*, RawOrigin};
 use orml_traits::{DataProvider, MultiCurrency, MultiReservableCurrency};
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::traits::{
-	BlockNumberProvider, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating, UniqueSaturatedInto,
+	BlockNumberProvider, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, IntegerSquareRoot, Saturating,
+	UniqueSaturatedInto, Verify,
 };
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, Perbill, SaturatedConversion,
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, PerThing, Perbill, Rounding, SaturatedConversion,
 };
-use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec::Vec};
+use sp_std::{
+	borrow::Borrow,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	ops::Bound,
+	prelude::*,
+	vec::Vec,
+};
+use xcm::v3::{Junction, MultiLocation};
 
 use core_primitives::NFTTrait;
 use core_primitives::*;
 pub use pallet::*;
 
-use primitives::{estate::Estate, EraIndex, EstateId};
-use primitives::{Balance, DomainId, FungibleTokenId, PowerAmount, RoundIndex};
+pub use migrations::{SteppedMigration, SteppedMigrations};
+use primitives::{
+	estate::Estate,
+	staking::{Bond, RoundInfo},
+	EraIndex, EstateId,
+};
+use primitives::{Balance, DomainId, FungibleTokenId, PowerAmount, ProposalId, RoundIndex};
+pub use types::*;
 pub use weights::WeightInfo;
 
+/// Precision of the accumulated power per staked native token
+pub const POWER_PER_STAKE_PRECISION: u128 = 1_000_000_000_000_000_000_000_000;
+
+/// Number of eras for which staker set snapshots are kept
+pub const STAKER_SNAPSHOT_HISTORY_DEPTH: EraIndex = 84;
+
+/// Number of rounds innovation stake stays in the exit queue without conviction
+pub const INNOVATION_UNBOND_ROUNDS: RoundIndex = 28;
+
+/// Maximum number of voter records removed at once when a voting points snapshot is cleared
+pub const MAX_VOTING_POINTS_SNAPSHOT_STAKERS: u32 = 1000;
+
+/// Maximum number of voting points checkpoints kept per account, the oldest is dropped first
+pub const MAX_VOTING_POINTS_CHECKPOINTS: u32 = 32;
+
+/// Maximum number of BIT to power exchange rate updates kept in the history
+pub const MAX_EXCHANGE_RATE_HISTORY: usize = 100;
+
+/// Maximum length of an innovation project metadata content id
+pub const MAX_PROJECT_METADATA_CID_LENGTH: u32 = 128;
+
+/// Maximum number of payouts kept in the reward ledger of an account
+pub const MAX_REWARD_LEDGER_ENTRIES: u32 = 1000;
+
+/// Maximum number of entries over the retention trimmed from a reward ledger per recorded payout
+pub const MAX_REWARD_LEDGER_TRIM: u32 = 2;
+
+/// Maximum number of maturing exit rounds and entries looked up by the round change hook
+pub const MAX_MATURED_EXIT_NOTIFICATIONS: u32 = 100;
+
+/// Maximum number of entries in a force unstake batch
+pub const MAX_FORCE_UNSTAKE_BATCH: u32 = 500;
+
+/// Lock identifier of economy 101 self-staking backed by a balance lock
+pub const ECONOMY_STAKE_LOCK_ID: LockIdentifier = *b"ecostake";
+
+/// Maximum length of the memo attached to an innovation stake position
+pub const MAX_POSITION_MEMO_LENGTH: u32 = 128;
+
+/// Maximum number of accounts waiting for an innovation staking slot
+pub const MAX_INNOVATION_WAITLIST: u32 = 100;
+
+/// Maximum number of keeper maintenance calls accepted per block
+pub const MAX_KEEPER_CALLS_PER_BLOCK: u32 = 5;
+
+/// Maximum number of accounts or campaigns handled by a single keeper maintenance call
+pub const MAX_KEEPER_BATCH: u32 = 50;
+
+/// Maximum number of entries kept in the forced actions log
+pub const MAX_FORCED_ACTIONS: u32 = 500;
+
+/// Maximum number of innovation stakers considered by stake-weighted staker selection
+pub const MAX_WEIGHTED_SELECTION_CANDIDATES: usize = 1000;
+
+/// Maximum length of the proof of an offence report
+pub const MAX_OFFENCE_PROOF_LENGTH: u32 = 4096;
+
+/// Maximum number of NFT classes granting an innovation staking reward boost
+pub const MAX_NFT_REWARD_BOOSTS: usize = 20;
+
+/// Maximum number of accounts kept in the top stakers leaderboard
+pub const MAX_TOP_STAKERS: usize = 100;
+
+/// Maximum number of reward campaigns drawn by the era distributor at once
+pub const MAX_ACTIVE_REWARD_CAMPAIGNS: u32 = 50;
+
+/// Maximum number of staking positions merged in a single call
+pub const MAX_MERGE_POSITIONS: u32 = 20;
+/// Maximum number of accounts flushed in a single call
+pub const MAX_FLUSH_CLAIMS_BATCH: u32 = 50;
+/// Maximum number of reward currencies initialized in a single call
+pub const MAX_REWARD_POOL_CURRENCIES: u32 = 20;
+
+/// Number of consecutive eras staked required for each loyalty bonus step
+pub const LOYALTY_ERAS_PER_BONUS_STEP: EraIndex = 10;
+/// Reward bonus percentage granted per loyalty step
+pub const LOYALTY_BONUS_PERCENT_PER_STEP: u32 = 1;
+/// Maximum loyalty reward bonus percentage
+pub const MAX_LOYALTY_BONUS_PERCENT: u32 = 20;
+
 /// The Reward Pool Info.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct InnovationStakingPoolInfo<Share: HasCompact, Balance: HasCompact, CurrencyId: Ord> {
@@ -74,25 +174,32 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+mod types;
 pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use sp_runtime::traits::{CheckedAdd, CheckedSub, Saturating};
+	use sp_runtime::traits::{CheckedAdd, CheckedSub, IdentifyAccount, Saturating, Verify};
 	use sp_runtime::ArithmeticError;
 
 	use primitives::{staking::Bond, ClassId, NftId};
 
 	use super::*;
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(trait Store)]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 	pub type TokenId = NftId;
 	pub type WithdrawnRewards<T> = BTreeMap<FungibleTokenId, BalanceOf<T>>;
+	pub type QueuedExitOf<T> = QueuedExit<BalanceOf<T>, BlockNumberFor<T>>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -142,6 +249,128 @@ pub mod pallet {
 		// Reward payout account
 		#[pallet::constant]
 		type RewardPayoutAccount: Get<PalletId>;
+
+		/// The maximum number of estates that can be staked on in a single batch
+		#[pallet::constant]
+		type MaxEstateStakeBatch: Get<u32>;
+
+		/// Reward swapper converting claimed rewards into another currency
+		type RewardSwapper: RewardSwapper<Self::AccountId, BalanceOf<Self>>;
+
+		/// Insurance fund account funded by penalties and slashes
+		#[pallet::constant]
+		type InsuranceFundAccount: Get<PalletId>;
+
+		/// Pallet ID deriving the bonded pool accounts
+		#[pallet::constant]
+		type BondedPoolPalletId: Get<PalletId>;
+
+		/// Deposit reserved for permissionlessly created reward campaigns
+		#[pallet::constant]
+		type RewardCampaignDeposit: Get<BalanceOf<Self>>;
+
+		/// Origin authorized to distribute mission rewards, e.g. the game backend or an oracle
+		type MissionRewardOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallet ID of the mission reward pot
+		#[pallet::constant]
+		type MissionPotPalletId: Get<PalletId>;
+
+		/// Off-chain signature type of claim authorizations
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Off-chain public key of claim authorization signers
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Origin approving economy treasury spending proposals
+		type EconomySpendOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deposit reserved from economy treasury spending proposers, returned on approval and
+		/// forfeited as a penalty on rejection
+		#[pallet::constant]
+		type EconomySpendDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of pending economy treasury spending proposals
+		#[pallet::constant]
+		type MaxOpenEconomySpends: Get<u32>;
+
+		/// Multi-block migrations stepped through in `on_idle`, one after the other
+		type SteppedMigration: SteppedMigrations;
+
+		/// The minimum income accumulated before it is automatically staked
+		#[pallet::constant]
+		type MinimumIncomeStake: Get<BalanceOf<Self>>;
+
+		/// Innovation positions below this are dust and are folded into the exit queue
+		#[pallet::constant]
+		type ExistentialStake: Get<BalanceOf<Self>>;
+
+		/// Free balance kept on top of the existential deposit when staking, to pay transaction
+		/// fees
+		#[pallet::constant]
+		type StakeFeeHeadroom: Get<BalanceOf<Self>>;
+
+		/// Origin judging offence reports
+		type OffenceJudge: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deposit reserved from offence reporters, forfeited to the insurance fund if the report
+		/// is rejected
+		#[pallet::constant]
+		type OffenceReportDeposit: Get<BalanceOf<Self>>;
+
+		/// Share of a confirmed offence slash paid to the reporter
+		#[pallet::constant]
+		type OffenceReporterReward: Get<Perbill>;
+
+		/// The minimum deposit reserved to register an innovation project
+		#[pallet::constant]
+		type MinimumProjectDeposit: Get<BalanceOf<Self>>;
+
+		/// Number of innovation projects selected by directed stake at every era rollover
+		#[pallet::constant]
+		type TopInnovationProjectsCount: Get<u32>;
+
+		/// Handler notified of the innovation projects selected at every era rollover, e.g. grants
+		type OnInnovationProjectsSelected: OnInnovationProjectsSelected<InnovationProjectId, BalanceOf<Self>>;
+
+		/// Sybil resistance check on the stakers counted for project reward matching
+		type MatchingEligibility: MatchingEligibility<Self::AccountId>;
+
+		/// Pallet ID of the lending pot funding credit lines against innovation stake
+		#[pallet::constant]
+		type LendingPotPalletId: Get<PalletId>;
+
+		/// Sends claimed rewards to accounts on other chains
+		type CrossChainTransfer: CrossChainTransfer<Self::AccountId, BalanceOf<Self>, MultiLocation>;
+
+		/// The maximum number of reward currencies paid out in a single claim
+		#[pallet::constant]
+		type MaxCurrenciesPerClaim: Get<u32>;
+
+		/// Currency id of power tokens
+		#[pallet::constant]
+		type PowerCurrencyId: Get<FungibleTokenId>;
+
+		/// The maximum number of distinct rounds an account can have entries for in each exit queue
+		#[pallet::constant]
+		type MaxQueuedExitsPerAccount: Get<u32>;
+
+		/// Randomness source of stake-weighted staker selection
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Number of blocks a frozen reward pool keeps its undistributed funds before they can be
+		/// drained to the economy treasury
+		#[pallet::constant]
+		type RewardPoolDrainGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Handlers notified after every innovation staking era update with the new era and the
+		/// rewards distributed during the ended era
+		type OnEraChange: OnEraChange<BalanceOf<Self>>;
+
+		/// The maximum number of innovation stake tiers and of estate boost tiers
+		#[pallet::constant]
+		type MaxStakeTiers: Get<u32>;
+
 		/// Weight info
 		type WeightInfo: WeightInfo;
 	}
@@ -149,13 +378,30 @@ pub mod pallet {
 	/// BIT to power exchange rate
 	#[pallet::storage]
 	#[pallet::getter(fn get_bit_power_exchange_rate)]
-	pub(super) type BitPowerExchangeRate<T: Config> = StorageValue<_, Balance, ValueQuery>;
+	pub(super) type BitPowerExchangeRate<T: Config> = StorageValue<_, FixedU128, ValueQuery>;
 
-	/// Power balance of user
+	/// Rounding applied when converting power to BIT
+	#[pallet::storage]
+	#[pallet::getter(fn bit_power_conversion_rounding)]
+	pub(super) type BitPowerConversionRounding<T: Config> = StorageValue<_, ConversionRounding, ValueQuery>;
+
+	/// Recent BIT to power exchange rate updates, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn bit_power_exchange_rate_history)]
+	pub type BitPowerExchangeRateHistory<T: Config> =
+		StorageValue<_, Vec<ExchangeRateUpdate<BlockNumberFor<T>>>, ValueQuery>;
+
+	/// Legacy power balance of user, moved to `PowerCurrencyId` tokens on first use or by the
+	/// `MigratePowerBalanceToTokens` stepped migration
 	#[pallet::storage]
-	#[pallet::getter(fn get_power_balance)]
 	pub type PowerBalance<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, PowerAmount, ValueQuery>;
 
+	/// Power an account allows a spender to transfer on its behalf
+	#[pallet::storage]
+	#[pallet::getter(fn power_allowance)]
+	pub type PowerAllowances<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, PowerAmount, ValueQuery>;
+
 	/// TBD Accept domain
 	#[pallet::storage]
 	#[pallet::getter(fn get_accepted_domain)]
@@ -172,12 +418,94 @@ pub mod pallet {
 	pub type EstateStakingInfo<T: Config> =
 		StorageMap<_, Twox64Concat, EstateId, Bond<T::AccountId, BalanceOf<T>>, OptionQuery>;
 
+	/// Conviction of each innovation staker, extending its unbond period for more voting points
+	#[pallet::storage]
+	#[pallet::getter(fn innovation_conviction)]
+	pub type InnovationConvictions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, InnovationConviction, ValueQuery>;
+
+	/// Innovation staking voting points of each voter frozen at the snapshot of a governance
+	/// proposal, recorded when the voter first votes
+	#[pallet::storage]
+	#[pallet::getter(fn voting_points_snapshot)]
+	pub type VotingPointsSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ProposalId, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// Voting points snapshot taken when each governance proposal was created
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_voting_points_snapshot)]
+	pub type ProposalVotingPointsSnapshot<T: Config> = StorageMap<_, Twox64Concat, ProposalId, u32, OptionQuery>;
+
+	/// Number of voting points snapshots taken
+	#[pallet::storage]
+	#[pallet::getter(fn voting_points_snapshot_count)]
+	pub type VotingPointsSnapshotCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Voting points held by each account when a snapshot was taken, recorded before the first
+	/// change of its voting points after the snapshot
+	#[pallet::storage]
+	#[pallet::getter(fn voting_points_checkpoints)]
+	pub type VotingPointsCheckpoints<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(u32, BalanceOf<T>), ConstU32<MAX_VOTING_POINTS_CHECKPOINTS>>,
+		ValueQuery,
+	>;
+
+	/// Number of eras in a voting season, zero if seasons are disabled
+	#[pallet::storage]
+	#[pallet::getter(fn voting_season_length)]
+	pub type VotingSeasonLength<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+	/// Index of the current voting season
+	#[pallet::storage]
+	#[pallet::getter(fn current_voting_season)]
+	pub type CurrentVotingSeason<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Era the current voting season started
+	#[pallet::storage]
+	#[pallet::getter(fn voting_season_start_era)]
+	pub type VotingSeasonStartEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+	/// Voting points snapshot taken at the end of each voting season
+	#[pallet::storage]
+	#[pallet::getter(fn season_voting_points_snapshot)]
+	pub type SeasonVotingPointsSnapshot<T: Config> = StorageMap<_, Twox64Concat, u32, u32, OptionQuery>;
+
+	/// Total estate stake of each account
+	#[pallet::storage]
+	#[pallet::getter(fn get_account_estate_stake)]
+	pub type AccountEstateStake<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Estates each account holds the owner stake bond of
+	#[pallet::storage]
+	#[pallet::getter(fn staked_estate)]
+	pub type StakedEstates<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, EstateId, (), OptionQuery>;
+
+	/// Weights of each staking category in the stake weight provided to governance
+	#[pallet::storage]
+	#[pallet::getter(fn stake_weights)]
+	pub type StakeWeights<T: Config> = StorageValue<_, StakeWeight, ValueQuery>;
+
+	/// The role under which the estate stake bond is held
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_staker_role)]
+	pub type EstateStakerRoles<T: Config> = StorageMap<_, Twox64Concat, EstateId, EstateStakerRole, ValueQuery>;
+
+	/// Estate staking operator authorized by the estate owner: (owner, operator)
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_staking_operator)]
+	pub type EstateStakingOperators<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, (T::AccountId, T::AccountId), OptionQuery>;
+
 	/// Self-staking exit queue info
 	/// This will keep track of stake exits queue, unstake only allows after 1 round
 	#[pallet::storage]
 	#[pallet::getter(fn staking_exit_queue)]
 	pub type ExitQueue<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoundIndex, BalanceOf<T>, OptionQuery>;
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoundIndex, QueuedExitOf<T>, OptionQuery>;
 
 	/// Estate self-staking exit estate queue info
 	/// This will keep track of staked estate exits queue, unstake only allows after 1 round
@@ -190,7 +518,7 @@ pub mod pallet {
 			NMapKey<Blake2_128Concat, RoundIndex>,
 			NMapKey<Blake2_128Concat, EstateId>,
 		),
-		BalanceOf<T>,
+		QueuedExitOf<T>,
 		OptionQuery,
 	>;
 
@@ -204,1033 +532,7852 @@ pub mod pallet {
 	#[pallet::getter(fn total_estate_stake)]
 	type TotalEstateStake<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-	/// Innovation staking info
+	/// Rewards added to the innovation staking reward pool since the last era rollover
 	#[pallet::storage]
-	#[pallet::getter(fn get_innovation_staking_info)]
-	pub type InnovationStakingInfo<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+	pub type EraRewardsAdded<T: Config> = StorageValue<_, BTreeMap<FungibleTokenId, BalanceOf<T>>, ValueQuery>;
 
-	/// Total innovation staking locked in this pallet
+	/// Income of opted-in accounts not staked yet as it is below `MinimumIncomeStake`
 	#[pallet::storage]
-	#[pallet::getter(fn total_innovation_staking)]
-	type TotalInnovationStaking<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+	#[pallet::getter(fn pending_income_stake)]
+	pub type PendingIncomeStake<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-	/// Record share amount, reward currency and withdrawn reward amount for
-	/// specific `AccountId`
-	///
-	/// storage_map AccountId => (Share, BTreeMap<CurrencyId, Balance>)
+	/// Reward payouts of accounts opted in to reward ledger recording, keyed by their index in
+	/// the ledger of the account
 	#[pallet::storage]
-	#[pallet::getter(fn shares_and_withdrawn_rewards)]
-	pub type SharesAndWithdrawnRewards<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, (BalanceOf<T>, WithdrawnRewards<T>), ValueQuery>;
+	#[pallet::getter(fn account_reward_ledger_entry)]
+	pub type AccountRewardLedger<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		RewardLedgerEntry<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
 
-	/// Record reward pool info.
-	///
-	/// StakingRewardPoolInfo
+	/// Index of the oldest kept and of the next reward ledger entry of each account
 	#[pallet::storage]
-	#[pallet::getter(fn staking_reward_pool_info)]
-	pub type StakingRewardPoolInfo<T: Config> =
-		StorageValue<_, InnovationStakingPoolInfo<BalanceOf<T>, BalanceOf<T>, FungibleTokenId>, ValueQuery>;
+	#[pallet::getter(fn account_reward_ledger_bounds)]
+	pub type AccountRewardLedgerBounds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), ValueQuery>;
 
-	/// Self-staking exit queue info
-	/// This will keep track of stake exits queue, unstake only allows after 1 round
+	/// The next innovation project id
 	#[pallet::storage]
-	#[pallet::getter(fn innovation_staking_exit_queue)]
-	pub type InnovationStakingExitQueue<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoundIndex, BalanceOf<T>, OptionQuery>;
+	#[pallet::getter(fn next_innovation_project_id)]
+	pub type NextInnovationProjectId<T: Config> = StorageValue<_, InnovationProjectId, ValueQuery>;
 
-	/// The pending rewards amount accumulated from staking on innovation, pending reward added when
-	/// user claim reward or remove shares
-	///
-	/// PendingRewards: map AccountId => BTreeMap<FungibleTokenId, Balance>
+	/// Innovation projects registered for curation
 	#[pallet::storage]
-	#[pallet::getter(fn pending_multi_rewards)]
-	pub type PendingRewardsOfStakingInnovation<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, BTreeMap<FungibleTokenId, BalanceOf<T>>, ValueQuery>;
+	#[pallet::getter(fn innovation_project)]
+	pub type InnovationProjects<T: Config> =
+		StorageMap<_, Twox64Concat, InnovationProjectId, InnovationProject<T::AccountId, BalanceOf<T>>, OptionQuery>;
 
-	/// The current era index
+	/// Innovation stake each account directed at each project
 	#[pallet::storage]
-	#[pallet::getter(fn current_era)]
-	pub type CurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+	#[pallet::getter(fn directed_project_stake)]
+	pub type DirectedProjectStakes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		InnovationProjectId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
 
-	/// The block number of last era updated
+	/// Total innovation stake each account directed at projects, bounded by its innovation stake
 	#[pallet::storage]
-	#[pallet::getter(fn last_era_updated_block)]
-	pub type LastEraUpdatedBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+	#[pallet::getter(fn account_directed_stake)]
+	pub type AccountDirectedStake<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-	/// The internal of block number between era.
+	/// Total innovation stake directed at each registered project
 	#[pallet::storage]
-	#[pallet::getter(fn update_era_frequency)]
-	pub type UpdateEraFrequency<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+	#[pallet::getter(fn innovation_project_stake)]
+	pub type InnovationProjectStake<T: Config> =
+		StorageMap<_, Twox64Concat, InnovationProjectId, BalanceOf<T>, ValueQuery>;
 
-	/// The estimated staking reward rate per era on innovation staking.
-	///
-	/// EstimatedStakingRewardRatePerEra: value: Rate
+	/// Innovation projects selected at the last era rollover, ranked by directed stake
 	#[pallet::storage]
-	pub type EstimatedStakingRewardPerEra<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
-	#[pallet::event]
-	#[pallet::generate_deposit(pub (super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Mining resource burned [amount]
-		MiningResourceBurned(Balance),
-		/// Self staking to economy 101 [staker, amount]
-		SelfStakedToEconomy101(T::AccountId, BalanceOf<T>),
-		/// Estate staking to economy 101 [staker, estate_id, amount]
-		EstateStakedToEconomy101(T::AccountId, EstateId, BalanceOf<T>),
-		/// Self staking removed from economy 101 [staker, amount]
-		SelfStakingRemovedFromEconomy101(T::AccountId, BalanceOf<T>),
-		/// Estate staking remoed from economy 101 [staker, estate_id, amount]
-		EstateStakingRemovedFromEconomy101(T::AccountId, EstateId, BalanceOf<T>),
-		/// New BIT to Power exchange rate has updated [amount]
-		BitPowerExchangeRateUpdated(Balance),
-		/// Unstaked amount has been withdrew after it's expired [account, rate]
-		UnstakedAmountWithdrew(T::AccountId, BalanceOf<T>),
-		/// Set power balance by sudo [account, power_amount]
-		SetPowerBalance(T::AccountId, PowerAmount),
-		/// Power conversion request has cancelled [(class_id, token_id), account]
-		CancelPowerConversionRequest((ClassId, TokenId), T::AccountId),
-		/// Innovation Staking [staker, amount]
-		StakedInnovation(T::AccountId, BalanceOf<T>),
-		/// Unstaked from Innovation [staker, amount]
-		UnstakedInnovation(T::AccountId, BalanceOf<T>),
-		/// Claim rewards
-		ClaimRewards(T::AccountId, FungibleTokenId, BalanceOf<T>),
-		/// Current innovation staking era updated
-		CurrentInnovationStakingEraUpdated(EraIndex),
-		/// Innovation Staking Era frequency updated
-		UpdatedInnovationStakingEraFrequency(BlockNumberFor<T>),
-		/// Last innovation staking era updated
-		LastInnovationStakingEraUpdated(BlockNumberFor<T>),
-		/// Estimated reward per era
-		EstimatedRewardPerEraUpdated(BalanceOf<T>),
-	}
+	#[pallet::getter(fn top_innovation_projects)]
+	pub type TopInnovationProjects<T: Config> = StorageValue<_, Vec<(InnovationProjectId, BalanceOf<T>)>, ValueQuery>;
 
-	#[pallet::error]
-	pub enum Error<T> {
-		/// NFT asset does not exist
-		NFTAssetDoesNotExist,
-		/// NFT class does not exist
-		NFTClassDoesNotExist,
-		/// NFT collection does not exist
-		NFTCollectionDoesNotExist,
-		/// No permission
-		NoPermission,
-		/// No authorization
-		NoAuthorization,
-		/// Insufficient power balance
-		AccountHasNoPowerBalance,
-		/// Power amount is zero
-		PowerAmountIsZero,
-		/// Not enough free balance for staking
-		InsufficientBalanceForStaking,
-		/// Unstake amount greater than staked amount
-		UnstakeAmountExceedStakedAmount,
-		/// Has scheduled exit staking, only stake after queue exit
-		ExitQueueAlreadyScheduled,
-		/// Stake amount below minimum staking required
-		StakeBelowMinimum,
-		/// Withdraw future round
-		WithdrawFutureRound,
-		/// Exit queue does not exist
-		ExitQueueDoesNotExit,
-		/// Unstaked amount is zero
-		UnstakeAmountIsZero,
-		/// Request already exists
-		RequestAlreadyExist,
-		/// Order has not reach target
-		NotReadyToExecute,
-		/// Staker is not estate owner
-		StakerNotEstateOwner,
-		/// Staking estate does not exist
-		StakeEstateDoesNotExist,
-		/// Stake is not previous owner
-		StakerNotPreviousOwner,
-		/// No funds staked at estate
-		NoFundsStakedAtEstate,
-		/// Previous owner still stakes at estate
-		PreviousOwnerStillStakesAtEstate,
-		/// Has scheduled exit estate staking, only stake after queue exit
-		EstateExitQueueAlreadyScheduled,
-		/// Estate exit queue does not exist
-		EstateExitQueueDoesNotExit,
-		/// Stake amount exceed estate max amount
-		StakeAmountExceedMaximumAmount,
-		/// Invalid era set up config
-		InvalidLastEraUpdatedBlock,
-		/// Unexpected error
-		Unexpected,
-		/// Reward pool does not exist
-		RewardPoolDoesNotExist,
-		/// Invalid reward set up
-		InvalidEstimatedRewardSetup,
-	}
+	/// Innovation project ranking in progress: the era, the project stake cursor and the best
+	/// ranked projects so far
+	#[pallet::storage]
+	#[pallet::getter(fn top_innovation_projects_scan)]
+	pub type TopInnovationProjectsScan<T: Config> =
+		StorageValue<_, (EraIndex, Option<Vec<u8>>, Vec<(InnovationProjectId, BalanceOf<T>)>), OptionQuery>;
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			let era_number = Self::get_era_index(<frame_system::Pallet<T>>::block_number());
+	/// Treasury matching of innovation project reward pools, disabled if not set
+	#[pallet::storage]
+	#[pallet::getter(fn project_matching)]
+	pub type ProjectMatching<T: Config> = StorageValue<_, ProjectMatchingConfig<BalanceOf<T>>, OptionQuery>;
 
-			if !era_number.is_zero() {
-				let _ = Self::update_current_era(era_number).map_err(|err| err).ok();
-			}
+	/// Project matching in progress: the era, the directed stake cursor and the staker breadth
+	/// of each project so far
+	#[pallet::storage]
+	#[pallet::getter(fn project_matching_scan)]
+	pub type ProjectMatchingScan<T: Config> =
+		StorageValue<_, (EraIndex, Option<Vec<u8>>, BTreeMap<InnovationProjectId, u128>), OptionQuery>;
 
-			T::WeightInfo::stake_b()
-		}
-	}
+	/// Treasury matching accrued to each innovation project and not claimed yet
+	#[pallet::storage]
+	#[pallet::getter(fn project_matching_pool)]
+	pub type ProjectMatchingPools<T: Config> =
+		StorageMap<_, Twox64Concat, InnovationProjectId, BalanceOf<T>, ValueQuery>;
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Stake native token to staking ledger to receive build material every round
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// `amount`: the stake amount
-		///
-		/// Emit `SelfStakedToEconomy101` event or `EstateStakedToEconomy101` event if successful
-		#[pallet::weight(
-			if estate.is_some() {
-				T::WeightInfo::stake_b()
-			} else {
-				T::WeightInfo::stake_a()
-			}
-		)]
-		#[transactional]
-		pub fn stake(
-			origin: OriginFor<T>,
-			amount: BalanceOf<T>,
-			estate: Option<EstateId>,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+	/// Estate stake weight boost tiers of contiguous estates, by ascending land units
+	#[pallet::storage]
+	#[pallet::getter(fn estate_boost_tiers)]
+	pub type EstateBoostTiers<T: Config> = StorageValue<_, BoundedVec<EstateBoostTier, T::MaxStakeTiers>, ValueQuery>;
 
-			// Check if user has enough balance for staking
-			ensure!(
-				T::Currency::free_balance(&who) >= amount,
-				Error::<T>::InsufficientBalanceForStaking
-			);
+	/// Terms of credit lines against innovation stake, borrowing is disabled if not set
+	#[pallet::storage]
+	#[pallet::getter(fn credit_line_config)]
+	pub type CreditLineTerms<T: Config> = StorageValue<_, CreditLineConfig, OptionQuery>;
 
-			let current_round = T::RoundHandler::get_current_round_info();
-			match estate {
-				None => {
-					// Check if user already in exit queue
-					ensure!(
-						!ExitQueue::<T>::contains_key(&who, current_round.current),
-						Error::<T>::ExitQueueAlreadyScheduled
-					);
+	/// Open credit lines against innovation stake
+	#[pallet::storage]
+	#[pallet::getter(fn credit_line)]
+	pub type CreditLines<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, CreditLine<BalanceOf<T>>, OptionQuery>;
 
-					let staked_balance = StakingInfo::<T>::get(&who);
-					let total = staked_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+	/// Credit line sweep in progress: the era and the credit line cursor
+	#[pallet::storage]
+	#[pallet::getter(fn credit_line_scan)]
+	pub type CreditLineScan<T: Config> = StorageValue<_, (EraIndex, Option<Vec<u8>>), OptionQuery>;
 
-					ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+	/// Terms of power advances, advances are disabled if not set
+	#[pallet::storage]
+	#[pallet::getter(fn power_advance_config)]
+	pub type PowerAdvanceTerms<T: Config> = StorageValue<_, PowerAdvanceConfig, OptionQuery>;
 
-					T::Currency::reserve(&who, amount)?;
+	/// Open power advances and their escrowed BIT deposit
+	#[pallet::storage]
+	#[pallet::getter(fn power_advance)]
+	pub type PowerAdvances<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PowerAdvance<BalanceOf<T>>, OptionQuery>;
 
-					StakingInfo::<T>::insert(&who, total);
+	/// Chains rewards can be sent to
+	#[pallet::storage]
+	#[pallet::getter(fn cross_chain_reward_chain)]
+	pub type CrossChainRewardChains<T: Config> = StorageMap<_, Blake2_128Concat, MultiLocation, (), OptionQuery>;
 
-					let new_total_staked = TotalStake::<T>::get().saturating_add(amount);
-					<TotalStake<T>>::put(new_total_staked);
+	/// Accounts receiving their claimed rewards on another chain
+	#[pallet::storage]
+	#[pallet::getter(fn cross_chain_reward_destination)]
+	pub type CrossChainRewardDestinations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, CrossChainRewardDestination, OptionQuery>;
 
-					Self::deposit_event(Event::SelfStakedToEconomy101(who, amount));
-				}
-				Some(estate_id) => {
-					// Check if user already in exit queue
-					ensure!(
-						!EstateExitQueue::<T>::contains_key((&who, current_round.current, estate_id)),
-						Error::<T>::EstateExitQueueAlreadyScheduled
-					);
+	/// Remaining number of first-time stakes each approved sponsor pays the fees for
+	#[pallet::storage]
+	#[pallet::getter(fn stake_sponsor_budget)]
+	pub type StakeSponsors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
 
-					ensure!(
-						T::EstateHandler::check_estate(estate_id.clone())?,
-						Error::<T>::StakeEstateDoesNotExist
-					);
-					ensure!(
-						T::EstateHandler::check_estate_ownership(who.clone(), estate_id.clone())?,
-						Error::<T>::StakerNotEstateOwner
-					);
+	/// Sponsor of the first innovation stake of an account
+	#[pallet::storage]
+	#[pallet::getter(fn stake_sponsor_of)]
+	pub type SponsoredStakers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
 
-					let mut staked_balance: BalanceOf<T> = Zero::zero();
-					let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
-					match staking_bond_value {
-						Some(staking_bond) => {
-							ensure!(
-								staking_bond.staker == who.clone(),
-								Error::<T>::PreviousOwnerStillStakesAtEstate
-							);
-							staked_balance = staking_bond.amount;
-						}
-						_ => {}
-					}
+	/// Pending reward below which claims of a currency stay pending instead of paying out
+	#[pallet::storage]
+	#[pallet::getter(fn minimum_claim_amount)]
+	pub type MinimumClaimAmount<T: Config> = StorageMap<_, Twox64Concat, FungibleTokenId, BalanceOf<T>, ValueQuery>;
 
-					let total = staked_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+	/// Reward currency the next claim of an account continues from, once a claim reached
+	/// `MaxCurrenciesPerClaim`
+	#[pallet::storage]
+	#[pallet::getter(fn claim_cursor)]
+	pub type ClaimCursors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, FungibleTokenId, OptionQuery>;
 
-					ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+	/// Blocks an account has to wait between unstakes from the same pool, zero if disabled
+	#[pallet::storage]
+	#[pallet::getter(fn unstake_cooldown)]
+	pub type UnstakeCooldown<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
-					// Ensure stake amount less than maximum
-					let total_land_units = T::EstateHandler::get_total_land_units(Some(estate_id));
-					ensure!(total_land_units > 0, Error::<T>::StakeEstateDoesNotExist);
+	/// Block of the last unstake of an account from each pool
+	#[pallet::storage]
+	#[pallet::getter(fn last_unstake_block)]
+	pub type LastUnstakeBlocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ExitQueueKind,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
 
-					let stake_allowance = T::MaximumEstateStake::get()
-						.saturating_mul(TryInto::<BalanceOf<T>>::try_into(total_land_units).unwrap_or_default());
-					ensure!(total <= stake_allowance, Error::<T>::StakeAmountExceedMaximumAmount);
+	/// Innovation staking reward bonus granted to holders of each NFT class
+	#[pallet::storage]
+	#[pallet::getter(fn nft_reward_boosts)]
+	pub type NftRewardBoosts<T: Config> = StorageValue<_, BTreeMap<ClassId, Perbill>, ValueQuery>;
 
-					T::Currency::reserve(&who, amount)?;
+	/// Number of entries kept in the reward ledger of each account, `MAX_REWARD_LEDGER_ENTRIES`
+	/// if not set
+	#[pallet::storage]
+	#[pallet::getter(fn reward_ledger_retention)]
+	pub type RewardLedgerRetention<T: Config> = StorageValue<_, u32, OptionQuery>;
 
-					let new_staking_bond = Bond {
-						staker: who.clone(),
-						amount: total,
-					};
+	/// Exit queue entries becoming withdrawable at a round, used to notify their stakers when
+	/// the round starts
+	///
+	/// MaturingExits: double_map RoundIndex, (AccountId, ExitQueueKind) => ()
+	#[pallet::storage]
+	#[pallet::getter(fn maturing_exits)]
+	pub type MaturingExits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, RoundIndex, Blake2_128Concat, (T::AccountId, ExitQueueKind), (), OptionQuery>;
 
-					EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
+	/// First round whose maturing exits are not all notified yet and the last round started
+	#[pallet::storage]
+	#[pallet::getter(fn matured_exits_cursor)]
+	pub type MaturedExitsCursor<T: Config> = StorageValue<_, (RoundIndex, RoundIndex), OptionQuery>;
 
-					let new_total_staked = TotalEstateStake::<T>::get().saturating_add(amount);
-					<TotalEstateStake<T>>::put(new_total_staked);
+	/// Reward payout account set by governance, replacing the account of `RewardPayoutAccount`
+	#[pallet::storage]
+	#[pallet::getter(fn rotated_reward_payout_account)]
+	pub type RotatedRewardPayoutAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
-					Self::deposit_event(Event::EstateStakedToEconomy101(who, estate_id, amount));
-				}
-			}
+	/// Native balance of the reward payout account below which `RewardPotLow` is emitted, zero
+	/// if disabled
+	#[pallet::storage]
+	#[pallet::getter(fn reward_pot_low_watermark)]
+	pub type RewardPotLowWatermark<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-			Ok(().into())
-		}
+	/// Ring log of the most recent force operations, keyed by slot. The entry recorded as the
+	/// n-th force operation is kept in slot `n % MAX_FORCED_ACTIONS`
+	#[pallet::storage]
+	#[pallet::getter(fn forced_action)]
+	pub type ForcedActions<T: Config> =
+		StorageMap<_, Twox64Concat, u32, ForcedAction<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>, OptionQuery>;
 
-		/// Stake native token to innovation staking ledger to receive reward and voting points
-		/// every round
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// `amount`: the stake amount
-		///
-		/// Emit `SelfStakedToEconomy101` event or `EstateStakedToEconomy101` event if successful
-		#[pallet::weight(T::WeightInfo::stake_on_innovation())]
-		#[transactional]
-		pub fn stake_on_innovation(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	/// Number of force operations recorded in the forced actions log
+	#[pallet::storage]
+	#[pallet::getter(fn forced_action_count)]
+	pub type ForcedActionCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
-			// Check if user has enough balance for staking
-			ensure!(
-				T::Currency::free_balance(&who) >= amount,
-				Error::<T>::InsufficientBalanceForStaking
-			);
+	/// Part of the economy 101 self-stake, including its exit queue, backed by a balance lock
+	/// instead of a reserve
+	#[pallet::storage]
+	#[pallet::getter(fn locked_self_stake)]
+	pub type LockedSelfStake<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-			ensure!(
-				!amount.is_zero() || amount >= T::MinimumStake::get(),
-				Error::<T>::StakeBelowMinimum
-			);
+	/// Accounts allowed to call the keeper maintenance extrinsics without fees
+	#[pallet::storage]
+	#[pallet::getter(fn authorized_keepers)]
+	pub type AuthorizedKeepers<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
 
-			let current_round = T::RoundHandler::get_current_round_info();
+	/// Block of the last keeper maintenance call and the number of keeper calls in that block
+	#[pallet::storage]
+	pub type KeeperCallsInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
 
-			// Check if user already in exit queue
-			ensure!(
-				!InnovationStakingExitQueue::<T>::contains_key(&who, current_round.current),
-				Error::<T>::ExitQueueAlreadyScheduled
-			);
+	/// Label or content id attached by stakers to their innovation stake position
+	#[pallet::storage]
+	#[pallet::getter(fn position_memo)]
+	pub type PositionMemos<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<u8, ConstU32<MAX_POSITION_MEMO_LENGTH>>, OptionQuery>;
 
-			let staked_balance = InnovationStakingInfo::<T>::get(&who);
-			let total = staked_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+	/// Commission charged on power purchases of each domain
+	#[pallet::storage]
+	#[pallet::getter(fn domain_power_commission)]
+	pub type DomainPowerCommission<T: Config> = StorageMap<_, Twox64Concat, DomainId, Perbill, ValueQuery>;
 
-			ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+	/// Power each distributor NFT of a class may issue per round, classes without a quota cannot
+	/// distribute power
+	#[pallet::storage]
+	#[pallet::getter(fn distributor_power_quota)]
+	pub type DistributorPowerQuotas<T: Config> = StorageMap<_, Twox64Concat, ClassId, PowerAmount, OptionQuery>;
 
-			T::Currency::reserve(&who, amount)?;
+	/// Round and power issued in that round by each distributor NFT. The issued power resets when
+	/// the round changes
+	#[pallet::storage]
+	#[pallet::getter(fn distributor_power_issuance)]
+	pub type DistributorPowerIssued<T: Config> =
+		StorageMap<_, Twox64Concat, (ClassId, TokenId), (RoundIndex, PowerAmount), ValueQuery>;
 
-			InnovationStakingInfo::<T>::insert(&who, total);
+	/// Claimed rewards whose payout failed, per beneficiary and currency, kept for retry
+	#[pallet::storage]
+	#[pallet::getter(fn failed_payouts)]
+	pub type FailedPayouts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, FungibleTokenId, BalanceOf<T>, ValueQuery>;
 
-			let new_total_staked = TotalInnovationStaking::<T>::get().saturating_add(amount);
-			<TotalInnovationStaking<T>>::put(new_total_staked);
+	/// Era configuration change waiting for its activation block
+	#[pallet::storage]
+	#[pallet::getter(fn pending_era_config)]
+	pub type PendingEraConfig<T: Config> =
+		StorageValue<_, ScheduledEraConfig<BlockNumberFor<T>, BalanceOf<T>>, OptionQuery>;
 
-			Self::add_share(&who, amount);
+	/// Block since which the innovation staking reward pool is frozen
+	#[pallet::storage]
+	#[pallet::getter(fn reward_pool_frozen_since)]
+	pub type RewardPoolFrozenSince<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
-			Self::deposit_event(Event::StakedInnovation(who, amount));
+	/// Block at which the current owner or lessee stake bond of each estate was created
+	#[pallet::storage]
+	#[pallet::getter(fn estate_staking_since)]
+	pub type EstateStakingSince<T: Config> = StorageMap<_, Twox64Concat, EstateId, BlockNumberFor<T>, OptionQuery>;
 
-			Ok(())
-		}
+	/// Maximum number of active innovation stakers, uncapped if not set
+	#[pallet::storage]
+	#[pallet::getter(fn max_innovation_stakers)]
+	pub type MaxInnovationStakers<T: Config> = StorageValue<_, u32, OptionQuery>;
 
-		/// Unstake native token to innovation staking ledger to receive reward and voting points
-		/// every round
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// `amount`: the unstake amount
-		///
-		/// Emit `UnstakedInnovation` event if successful
-		#[pallet::weight(T::WeightInfo::unstake_on_innovation())]
-		#[transactional]
-		pub fn unstake_on_innovation(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	/// Accounts waiting for an innovation staking slot with their reserved stake, in admission
+	/// order
+	#[pallet::storage]
+	#[pallet::getter(fn innovation_staker_waitlist)]
+	pub type InnovationStakerWaitlist<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, BalanceOf<T>), ConstU32<MAX_INNOVATION_WAITLIST>>, ValueQuery>;
 
-			let staked_balance = InnovationStakingInfo::<T>::get(&who);
-			ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+	/// Economy 101 self-stake switched out of innovation staking, one tranche per round it
+	/// unlocks at. A tranche cannot be unstaked before the innovation unbond period of its switch
+	/// ends
+	#[pallet::storage]
+	#[pallet::getter(fn switched_stake_locks)]
+	pub type SwitchedStakeLocks<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(BalanceOf<T>, RoundIndex), ConstU32<INNOVATION_UNBOND_ROUNDS>>,
+		ValueQuery,
+	>;
 
-			let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+	/// Highest round seen from the round handler
+	#[pallet::storage]
+	#[pallet::getter(fn last_seen_round)]
+	pub type LastSeenRound<T: Config> = StorageValue<_, RoundIndex, ValueQuery>;
 
-			let amount_to_unstake = if remaining < T::MinimumStake::get() {
-				// Remaining amount below minimum, remove all staked amount
-				staked_balance
-			} else {
-				amount
-			};
+	/// Cursor of the `on_idle` sweep restaking matured exits of opted-in accounts
+	#[pallet::storage]
+	pub type RestakeSweepCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
 
-			let current_round = T::RoundHandler::get_current_round_info();
-			let next_round = current_round.current.saturating_add(28u32);
+	/// Id and cursor of the ongoing multi-block migration
+	#[pallet::storage]
+	#[pallet::getter(fn stepped_migration_cursor)]
+	pub type SteppedMigrationCursor<T: Config> = StorageValue<_, (Vec<u8>, Vec<u8>), OptionQuery>;
 
-			// Check if user already in exit queue of the current
-			ensure!(
-				!InnovationStakingExitQueue::<T>::contains_key(&who, next_round),
-				Error::<T>::ExitQueueAlreadyScheduled
-			);
+	/// Multi-block migrations completed
+	#[pallet::storage]
+	#[pallet::getter(fn completed_stepped_migrations)]
+	pub type CompletedSteppedMigrations<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, (), OptionQuery>;
 
-			// This exit queue will be executed by exit_staking extrinsics to unreserved token
-			InnovationStakingExitQueue::<T>::insert(&who, next_round.clone(), amount_to_unstake);
+	/// Reward weight of each estate trait value, traits without a weight are weighted one
+	#[pallet::storage]
+	#[pallet::getter(fn estate_trait_reward_weight)]
+	pub type EstateTraitRewardWeights<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EstateTraitKind, Twox64Concat, u8, FixedU128, OptionQuery>;
 
-			// Update staking info of user immediately
-			// Remove staking info
-			if amount_to_unstake == staked_balance {
-				InnovationStakingInfo::<T>::remove(&who);
-			} else {
-				InnovationStakingInfo::<T>::insert(&who, remaining);
-			}
+	/// Estate stake of each staker on an estate weighted by the estate reward weight
+	#[pallet::storage]
+	#[pallet::getter(fn weighted_estate_stake)]
+	pub type WeightedEstateStakes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EstateId, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-			let new_total_staked = TotalInnovationStaking::<T>::get().saturating_sub(amount_to_unstake);
-			<TotalInnovationStaking<T>>::put(new_total_staked);
+	/// Weighted estate stake of an account summed across all estates
+	#[pallet::storage]
+	#[pallet::getter(fn account_weighted_estate_stake)]
+	pub type AccountWeightedEstateStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-			Self::remove_share(&who, amount_to_unstake);
+	/// Weighted estate stake summed across all estates and accounts
+	#[pallet::storage]
+	#[pallet::getter(fn total_weighted_estate_stake)]
+	pub type TotalWeightedEstateStake<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-			Self::deposit_event(Event::UnstakedInnovation(who, amount));
-			Ok(())
-		}
+	/// Estate rewards funded per unit of weighted estate stake since genesis
+	#[pallet::storage]
+	#[pallet::getter(fn estate_reward_per_weight)]
+	pub type EstateRewardPerWeight<T: Config> = StorageValue<_, FixedU128, ValueQuery>;
 
-		/// Claim reward from innovation staking ledger to receive reward and voting points
-		/// every round
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		///
-		/// Emit `ClaimRewards` event if successful
-		#[pallet::weight(T::WeightInfo::claim_reward())]
-		#[transactional]
-		pub fn claim_reward(origin: OriginFor<T>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	/// Estate rewards per unit of weighted estate stake at the start of rounds ending a reward
+	/// rights lease
+	#[pallet::storage]
+	#[pallet::getter(fn estate_reward_per_weight_at)]
+	pub type EstateRewardPerWeightAt<T: Config> = StorageMap<_, Twox64Concat, RoundIndex, FixedU128, OptionQuery>;
 
-			Self::claim_rewards(&who);
+	/// Number of reward rights leases ending at each round
+	#[pallet::storage]
+	#[pallet::getter(fn estate_reward_lease_ends)]
+	pub type EstateRewardLeaseEnds<T: Config> = StorageMap<_, Twox64Concat, RoundIndex, u32, ValueQuery>;
 
-			PendingRewardsOfStakingInnovation::<T>::mutate_exists(&who, |maybe_pending_multi_rewards| {
-				if let Some(pending_multi_rewards) = maybe_pending_multi_rewards {
-					for (currency_id, pending_reward) in pending_multi_rewards.iter_mut() {
-						if pending_reward.is_zero() {
-							continue;
-						}
+	/// Estate rewards per unit of weighted estate stake last settled for each estate stake
+	#[pallet::storage]
+	#[pallet::getter(fn estate_reward_checkpoint)]
+	pub type EstateRewardCheckpoints<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EstateId, Blake2_128Concat, T::AccountId, FixedU128, ValueQuery>;
 
-						let payout_amount = pending_reward.clone();
-
-						match Self::distribute_reward(&who, *currency_id, payout_amount) {
-							Ok(_) => {
-								// update state
-								*pending_reward = Zero::zero();
-
-								Self::deposit_event(Event::ClaimRewards(
-									who.clone(),
-									FungibleTokenId::NativeToken(0),
-									payout_amount,
-								));
-							}
-							Err(e) => {
-								log::error!(
-									target: "economy",
-									"staking_payout_reward: failed to payout {:?} to {:?} to {:?}",
-									pending_reward, who, e
-								);
-							}
-						}
-					}
-				}
-			});
+	/// Settled estate rewards not claimed yet
+	#[pallet::storage]
+	#[pallet::getter(fn pending_estate_reward)]
+	pub type PendingEstateRewards<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-			Ok(())
-		}
+	/// Offers of estate stakers to lease the estate rewards of their stake
+	#[pallet::storage]
+	#[pallet::getter(fn estate_reward_lease_offer)]
+	pub type EstateRewardLeaseOffers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EstateId,
+		Blake2_128Concat,
+		T::AccountId,
+		EstateRewardLeaseOffer<BalanceOf<T>>,
+		OptionQuery,
+	>;
 
-		/// Unstake native token from staking ledger. The unstaked amount able to redeem from the
-		/// next round
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// `amount`: the stake amount
-		///
-		/// Emit `SelfStakingRemovedFromEconomy101` event or `EstateStakingRemovedFromEconomy101`
-		/// event if successful
-		#[pallet::weight(
-			if estate.is_some() {
-				T::WeightInfo::unstake_b()
-			} else {
-				T::WeightInfo::unstake_a()
-			}
-		)]
-		pub fn unstake(
-			origin: OriginFor<T>,
-			amount: BalanceOf<T>,
-			estate: Option<EstateId>,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+	/// Active leases of the estate rewards of estate stakes
+	#[pallet::storage]
+	#[pallet::getter(fn estate_reward_lease)]
+	pub type EstateRewardLeases<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EstateId,
+		Blake2_128Concat,
+		T::AccountId,
+		EstateRewardLease<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
 
-			// Ensure amount is greater than zero
-			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+	/// Estate co-stakers approved by the estate owner and their stake cap
+	#[pallet::storage]
+	#[pallet::getter(fn estate_co_staker_cap)]
+	pub type EstateCoStakerCaps<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EstateId, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
 
-			match estate {
-				None => {
-					let staked_balance = StakingInfo::<T>::get(&who);
-					ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+	/// Stake of each co-staker on an estate
+	#[pallet::storage]
+	#[pallet::getter(fn estate_co_stake)]
+	pub type EstateCoStakes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EstateId, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-					let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+	/// Total co-stake of each estate
+	#[pallet::storage]
+	#[pallet::getter(fn estate_co_stake_total)]
+	pub type EstateCoStakeTotal<T: Config> = StorageMap<_, Twox64Concat, EstateId, BalanceOf<T>, ValueQuery>;
 
-					let amount_to_unstake = if remaining < T::MinimumStake::get() {
-						// Remaining amount below minimum, remove all staked amount
-						staked_balance
-					} else {
-						amount
-					};
+	/// Maximum estate stake of an account summed across all estates, unlimited if not set
+	#[pallet::storage]
+	#[pallet::getter(fn max_estate_stake_per_account)]
+	pub type MaxEstateStakePerAccount<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
 
-					let current_round = T::RoundHandler::get_current_round_info();
-					let next_round = current_round.current.saturating_add(One::one());
+	/// Maximum estate stake across all accounts, unlimited if not set
+	#[pallet::storage]
+	#[pallet::getter(fn max_total_estate_stake)]
+	pub type MaxTotalEstateStake<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
 
-					// Check if user already in exit queue of the current
-					ensure!(
-						!ExitQueue::<T>::contains_key(&who, next_round),
-						Error::<T>::ExitQueueAlreadyScheduled
-					);
+	/// Innovation staking info
+	#[pallet::storage]
+	#[pallet::getter(fn get_innovation_staking_info)]
+	pub type InnovationStakingInfo<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
-					// This exit queue will be executed by exit_staking extrinsics to unreserved token
-					ExitQueue::<T>::insert(&who, next_round.clone(), amount_to_unstake);
+	/// Total innovation staking locked in this pallet
+	#[pallet::storage]
+	#[pallet::getter(fn total_innovation_staking)]
+	type TotalInnovationStaking<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-					// Update staking info of user immediately
-					// Remove staking info
-					if amount_to_unstake == staked_balance {
-						StakingInfo::<T>::remove(&who);
-					} else {
-						StakingInfo::<T>::insert(&who, remaining);
-					}
+	/// Record share amount, reward currency and withdrawn reward amount for
+	/// specific `AccountId`
+	///
+	/// storage_map AccountId => (Share, BTreeMap<CurrencyId, Balance>)
+	#[pallet::storage]
+	#[pallet::getter(fn shares_and_withdrawn_rewards)]
+	pub type SharesAndWithdrawnRewards<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (BalanceOf<T>, WithdrawnRewards<T>), ValueQuery>;
 
-					let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_unstake);
-					<TotalStake<T>>::put(new_total_staked);
+	/// Record reward pool info.
+	///
+	/// StakingRewardPoolInfo
+	#[pallet::storage]
+	#[pallet::getter(fn staking_reward_pool_info)]
+	pub type StakingRewardPoolInfo<T: Config> =
+		StorageValue<_, InnovationStakingPoolInfo<BalanceOf<T>, BalanceOf<T>, FungibleTokenId>, ValueQuery>;
 
-					Self::deposit_event(Event::SelfStakingRemovedFromEconomy101(who, amount));
-				}
-				Some(estate_id) => {
-					ensure!(
-						T::EstateHandler::check_estate(estate_id.clone())?,
-						Error::<T>::StakeEstateDoesNotExist
-					);
+	/// Self-staking exit queue info
+	/// This will keep track of stake exits queue, unstake only allows after 1 round
+	#[pallet::storage]
+	#[pallet::getter(fn innovation_staking_exit_queue)]
+	pub type InnovationStakingExitQueue<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoundIndex, QueuedExitOf<T>, OptionQuery>;
 
-					let mut staked_balance = Zero::zero();
-					let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
-					match staking_bond_value {
-						Some(staking_bond) => {
-							ensure!(staking_bond.staker == who.clone(), Error::<T>::NoFundsStakedAtEstate);
-							staked_balance = staking_bond.amount;
-						}
-						_ => {}
-					}
-					ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+	/// The pending rewards amount accumulated from staking on innovation, pending reward added when
+	/// user claim reward or remove shares
+	///
+	/// PendingRewards: map AccountId => BTreeMap<FungibleTokenId, Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_multi_rewards)]
+	pub type PendingRewardsOfStakingInnovation<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BTreeMap<FungibleTokenId, BalanceOf<T>>, ValueQuery>;
 
-					let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+	/// Nonce of the next off-chain signed claim authorization of each account
+	#[pallet::storage]
+	#[pallet::getter(fn claim_nonce)]
+	pub type ClaimNonces<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u64, ValueQuery>;
 
-					let amount_to_unstake = if remaining < T::MinimumStake::get() {
-						// Remaining amount below minimum, remove all staked amount
-						staked_balance
-					} else {
-						amount
-					};
+	/// The current era index
+	#[pallet::storage]
+	#[pallet::getter(fn current_era)]
+	pub type CurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
 
-					let current_round = T::RoundHandler::get_current_round_info();
-					let next_round = current_round.current.saturating_add(One::one());
+	/// The block number of last era updated
+	#[pallet::storage]
+	#[pallet::getter(fn last_era_updated_block)]
+	pub type LastEraUpdatedBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
-					// Check if user already in estate exit queue of the current estate
-					ensure!(
-						!EstateExitQueue::<T>::contains_key((&who, next_round, estate_id)),
-						Error::<T>::ExitQueueAlreadyScheduled
-					);
+	/// The internal of block number between era.
+	#[pallet::storage]
+	#[pallet::getter(fn update_era_frequency)]
+	pub type UpdateEraFrequency<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
-					// This estate exit queue will be executed by exit_staking extrinsics to unreserved token
-					EstateExitQueue::<T>::insert((&who, next_round.clone(), estate_id), amount_to_unstake);
+	/// The estimated staking reward per era on innovation staking, either a rate of the total
+	/// innovation stake or a fixed amount.
+	///
+	/// EstimatedStakingRewardPerEra: value: EraRewardBudget
+	#[pallet::storage]
+	pub type EstimatedStakingRewardPerEra<T: Config> = StorageValue<_, EraRewardBudget<BalanceOf<T>>, ValueQuery>;
 
-					// Update estate staking info of user immediately
-					// Remove estate staking info
-					if amount_to_unstake == staked_balance {
-						EstateStakingInfo::<T>::remove(&estate_id);
-					} else {
-						let new_staking_bond = Bond {
-							staker: who.clone(),
-							amount: remaining,
-						};
-						EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
-					}
+	/// The era since which an account has continuously held innovation staking shares
+	#[pallet::storage]
+	#[pallet::getter(fn innovation_staking_since)]
+	pub type InnovationStakingSince<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, EraIndex, OptionQuery>;
 
-					let new_total_staked = TotalEstateStake::<T>::get().saturating_sub(amount_to_unstake);
-					<TotalEstateStake<T>>::put(new_total_staked);
+	/// Innovation staking tiers ordered by ascending threshold
+	#[pallet::storage]
+	#[pallet::getter(fn stake_tiers)]
+	pub type StakeTiers<T: Config> = StorageValue<_, BoundedVec<StakeTier<BalanceOf<T>>, T::MaxStakeTiers>, ValueQuery>;
 
-					Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(who, estate_id, amount));
-				}
-			}
+	/// The index of the stake tier an account currently qualifies for
+	#[pallet::storage]
+	#[pallet::getter(fn account_stake_tier)]
+	pub type AccountStakeTier<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
 
-			Ok(().into())
-		}
+	/// Whether stake tiers changed and all stakers need re-evaluation at the next era
+	#[pallet::storage]
+	pub type StakeTiersChanged<T: Config> = StorageValue<_, bool, ValueQuery>;
 
-		/// Unstake native token (staked by previous owner) from staking ledger.
-		///
-		/// The dispatch origin for this call must be _Signed_. Works if the origin is the estate
-		/// owner and the previous owner got staked funds
-		///
-		/// `estate_id`: the estate ID which funds are going to be unstaked
-		///
-		/// Emit `EstateStakingRemovedFromEconomy101` event if successful
-		#[pallet::weight(T::WeightInfo::unstake_new_estate_owner())]
-		pub fn unstake_new_estate_owner(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+	/// Version of the stake tiers, bumped at the era following a stake tier change
+	#[pallet::storage]
+	#[pallet::getter(fn stake_tiers_version)]
+	pub type StakeTiersVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
 
-			ensure!(
-				T::EstateHandler::check_estate(estate_id.clone())?,
-				Error::<T>::StakeEstateDoesNotExist
-			);
+	/// The stake tiers version an account's stake tier was last evaluated against
+	#[pallet::storage]
+	#[pallet::getter(fn account_stake_tier_version)]
+	pub type AccountStakeTierVersion<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
-			ensure!(
-				T::EstateHandler::check_estate_ownership(who.clone(), estate_id.clone())?,
-				Error::<T>::StakerNotEstateOwner
-			);
+	/// The distribution mode of the per-era reward budget
+	#[pallet::storage]
+	#[pallet::getter(fn reward_distribution_mode)]
+	pub type DistributionMode<T: Config> = StorageValue<_, RewardDistributionMode, ValueQuery>;
 
-			let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
-			match staking_bond_value {
-				Some(staking_info) => {
-					ensure!(
-						staking_info.staker.clone() != who.clone(),
-						Error::<T>::StakerNotPreviousOwner
-					);
-					let staked_balance = staking_info.amount;
+	/// The next bonded pool ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_bonded_pool_id)]
+	pub type NextBondedPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
 
-					let current_round = T::RoundHandler::get_current_round_info();
-					let next_round = current_round.current.saturating_add(One::one());
+	/// Bonded pools staking on behalf of their members
+	#[pallet::storage]
+	#[pallet::getter(fn bonded_pools)]
+	pub type BondedPools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, BondedPool<T::AccountId, BalanceOf<T>>, OptionQuery>;
 
-					// This exit queue will be executed by exit_staking extrinsics to unreserved token
-					EstateExitQueue::<T>::insert((&staking_info.staker, next_round.clone(), estate_id), staked_balance);
-					EstateStakingInfo::<T>::remove(&estate_id);
+	/// The maximum commission a bonded pool operator can set
+	#[pallet::storage]
+	#[pallet::getter(fn max_pool_commission)]
+	pub type MaxPoolCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
-					let new_total_staked = TotalEstateStake::<T>::get().saturating_sub(staked_balance);
-					<TotalEstateStake<T>>::put(new_total_staked);
+	/// Share of transaction fees and tips routed into the innovation staking reward pool
+	#[pallet::storage]
+	#[pallet::getter(fn fee_share_ratio)]
+	pub type FeeShareRatio<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
-					Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(
-						who,
-						estate_id,
-						staked_balance,
-					));
-					Ok(().into())
-				}
-				None => Err(Error::<T>::StakeEstateDoesNotExist.into()),
-			}
-		}
+	/// Bonded pool membership of each account
+	#[pallet::storage]
+	#[pallet::getter(fn pool_members)]
+	pub type PoolMembers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PoolMember<BalanceOf<T>>, OptionQuery>;
 
-		/// Withdraw unstaked token from unstaking queue. The unstaked amount will be unreserved and
-		/// become transferrable
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// `round_index`: the round index that user can unstake.
-		///
-		/// Emit `UnstakedAmountWithdrew` event if successful
-		#[pallet::weight(T::WeightInfo::withdraw_unreserved())]
-		pub fn withdraw_unreserved(origin: OriginFor<T>, round_index: RoundIndex) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+	/// The next reward campaign ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_campaign_id)]
+	pub type NextCampaignId<T: Config> = StorageValue<_, CampaignId, ValueQuery>;
 
-			// Get user exit queue
-			let exit_balance = ExitQueue::<T>::get(&who, round_index).ok_or(Error::<T>::ExitQueueDoesNotExit)?;
+	/// Reward campaigns drawn by the era distributor while active
+	#[pallet::storage]
+	#[pallet::getter(fn reward_campaigns)]
+	pub type RewardCampaigns<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CampaignId,
+		RewardCampaign<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
 
-			ExitQueue::<T>::remove(&who, round_index);
-			T::Currency::unreserve(&who, exit_balance);
+	/// Reward campaigns not yet ended or drained, drawn by the era distributor
+	#[pallet::storage]
+	#[pallet::getter(fn active_reward_campaigns)]
+	pub type ActiveRewardCampaigns<T: Config> =
+		StorageValue<_, BoundedVec<CampaignId, ConstU32<MAX_ACTIVE_REWARD_CAMPAIGNS>>, ValueQuery>;
 
-			Self::deposit_event(Event::<T>::UnstakedAmountWithdrew(who, exit_balance));
+	/// Share of bonded pool commissions accrued to the economy treasury as protocol income
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_commission_share)]
+	pub type ProtocolCommissionShare<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
-			Ok(().into())
-		}
+	/// Split of penalties and slashes between the reward pool, the treasury and the insurance fund
+	#[pallet::storage]
+	#[pallet::getter(fn penalty_split)]
+	pub type PenaltySplitConfig<T: Config> = StorageValue<_, PenaltySplit, ValueQuery>;
 
-		/// Withdraw unstaked token from estate unstaking queue. The unstaked amount will be
-		/// unreserved and become transferrable
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// `round_index`: the round index that user can redeem.
-		/// `estate_id`: the estate id that user can redeem.
-		///
-		/// Emit `UnstakedAmountWithdrew` event if successful
-		#[pallet::weight(T::WeightInfo::withdraw_unreserved())]
-		pub fn withdraw_estate_unreserved(
-			origin: OriginFor<T>,
-			round_index: RoundIndex,
-			estate_id: EstateId,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+	/// Next economy treasury spending proposal id
+	#[pallet::storage]
+	#[pallet::getter(fn next_economy_spend_id)]
+	pub type NextEconomySpendId<T: Config> = StorageValue<_, EconomySpendId, ValueQuery>;
 
-			// Get user exit queue
-			let exit_balance = EstateExitQueue::<T>::get((&who, round_index, estate_id))
-				.ok_or(Error::<T>::EstateExitQueueDoesNotExit)?;
+	/// Pending economy treasury spending proposals
+	#[pallet::storage]
+	#[pallet::getter(fn economy_spend_proposals)]
+	pub type EconomySpendProposals<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		EconomySpendId,
+		EconomySpendProposal<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
 
-			EstateExitQueue::<T>::remove((&who, round_index, estate_id));
-			T::Currency::unreserve(&who, exit_balance);
+	/// Number of pending economy treasury spending proposals
+	#[pallet::storage]
+	#[pallet::getter(fn open_economy_spends)]
+	pub type OpenEconomySpends<T: Config> = StorageValue<_, u32, ValueQuery>;
 
-			Self::deposit_event(Event::<T>::UnstakedAmountWithdrew(who, exit_balance));
+	/// Next offence report id
+	#[pallet::storage]
+	#[pallet::getter(fn next_offence_report_id)]
+	pub type NextOffenceReportId<T: Config> = StorageValue<_, OffenceReportId, ValueQuery>;
 
-			Ok(().into())
-		}
+	/// Offence reports awaiting judgement
+	#[pallet::storage]
+	#[pallet::getter(fn offence_reports)]
+	pub type OffenceReports<T: Config> =
+		StorageMap<_, Twox64Concat, OffenceReportId, OffenceReport<T::AccountId, BalanceOf<T>>, OptionQuery>;
 
-		/// Force unstake native token from staking ledger. The unstaked amount able to redeem
-		/// immediately
-		///
-		///
-		/// The dispatch origin for this call must be _Root_.
-		///
-		/// `amount`: the stake amount
-		/// `who`: the address of staker
-		///
-		/// Emit `SelfStakingRemovedFromEconomy101` event or `EstateStakingRemovedFromEconomy101`
-		/// event if successful
-		#[pallet::weight(
-			if estate.is_some() {
-				T::WeightInfo::unstake_b()
-			} else {
-				T::WeightInfo::unstake_a()
-			}
-		)]
-		pub fn force_unstake(
-			origin: OriginFor<T>,
-			amount: BalanceOf<T>,
-			who: T::AccountId,
-			estate: Option<EstateId>,
-		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+	/// Total reward distributed by each mission per currency
+	#[pallet::storage]
+	#[pallet::getter(fn mission_rewards)]
+	pub type MissionRewards<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MissionId, Twox64Concat, FungibleTokenId, BalanceOf<T>, ValueQuery>;
 
-			// Ensure amount is greater than zero
-			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+	/// NFT class of innovation staking position receipts
+	#[pallet::storage]
+	#[pallet::getter(fn position_receipt_class)]
+	pub type PositionReceiptClass<T: Config> = StorageValue<_, ClassId, OptionQuery>;
 
-			match estate {
-				None => {
-					let staked_balance = StakingInfo::<T>::get(&who);
-					ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+	/// Innovation staking positions represented by NFT receipts
+	#[pallet::storage]
+	#[pallet::getter(fn staking_positions)]
+	pub type StakingPositions<T: Config> = StorageMap<_, Twox64Concat, (ClassId, TokenId), BalanceOf<T>, OptionQuery>;
 
-					let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+	/// Staking preferences of each account
+	#[pallet::storage]
+	#[pallet::getter(fn account_preferences)]
+	pub type AccountPreferences<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AccountPreference<T::AccountId>, ValueQuery>;
 
-					let amount_to_unstake = if remaining < T::MinimumStake::get() {
-						// Remaining amount below minimum, remove all staked amount
-						staked_balance
-					} else {
-						amount
-					};
+	/// Leaderboard of the accounts with the most innovation shares, ordered by descending share
+	#[pallet::storage]
+	#[pallet::getter(fn top_stakers)]
+	pub type TopStakers<T: Config> = StorageValue<_, Vec<(T::AccountId, BalanceOf<T>)>, ValueQuery>;
+
+	/// Number of accounts holding innovation staking shares
+	#[pallet::storage]
+	#[pallet::getter(fn innovation_staker_count)]
+	pub type InnovationStakerCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Hash chain over every innovation share change, used to commit to the staker set
+	#[pallet::storage]
+	#[pallet::getter(fn shares_commitment)]
+	pub type SharesCommitment<T: Config> = StorageValue<_, H256, ValueQuery>;
+
+	/// Staker set snapshot taken at the start of each era
+	#[pallet::storage]
+	#[pallet::getter(fn era_staker_snapshot)]
+	pub type EraStakerSnapshots<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, StakerSetSnapshot<BalanceOf<T>>, OptionQuery>;
+
+	/// Innovation shares added by each account during an era, not eligible for the era reward
+	#[pallet::storage]
+	#[pallet::getter(fn era_joined_shares)]
+	pub type EraJoinedShares<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Reward increment and pool shares of ended eras whose joined shares still have to forgo
+	/// their part of the increment
+	#[pallet::storage]
+	#[pallet::getter(fn era_joined_shares_settlement)]
+	pub type EraJoinedSharesSettlements<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, (BalanceOf<T>, BalanceOf<T>), OptionQuery>;
+
+	/// Total innovation shares added during the current era
+	#[pallet::storage]
+	#[pallet::getter(fn total_era_joined_shares)]
+	pub type TotalEraJoinedShares<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The power amount distributed to all economy 101 self-stakers every round
+	#[pallet::storage]
+	#[pallet::getter(fn self_staking_power_per_round)]
+	pub type SelfStakingPowerPerRound<T: Config> = StorageValue<_, PowerAmount, ValueQuery>;
+
+	/// Accumulated power per staked native token, scaled by `POWER_PER_STAKE_PRECISION`
+	#[pallet::storage]
+	#[pallet::getter(fn accumulated_power_per_stake)]
+	pub type AccumulatedPowerPerStake<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	/// The accumulated power per stake at which the self-staking power of an account was last
+	/// settled
+	#[pallet::storage]
+	#[pallet::getter(fn self_staking_power_checkpoint)]
+	pub type SelfStakingPowerCheckpoint<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Mining resource burned [amount]
+		MiningResourceBurned(Balance),
+		/// Self staking to economy 101 [staker, amount]
+		SelfStakedToEconomy101(T::AccountId, BalanceOf<T>),
+		/// Estate staking to economy 101 [staker, estate_id, amount]
+		EstateStakedToEconomy101(T::AccountId, EstateId, BalanceOf<T>),
+		/// Self staking removed from economy 101 [staker, amount]
+		SelfStakingRemovedFromEconomy101(T::AccountId, BalanceOf<T>),
+		/// Estate staking remoed from economy 101 [staker, estate_id, amount]
+		EstateStakingRemovedFromEconomy101(T::AccountId, EstateId, BalanceOf<T>),
+		/// Estate stake bond of an account after staking or unstaking [staker, estate_id,
+		/// bond_total, remaining_estate_allowance]
+		EstateBondUpdated(T::AccountId, EstateId, BalanceOf<T>, BalanceOf<T>),
+		/// New BIT to Power exchange rate has updated [rate, rounding]
+		BitPowerExchangeRateUpdated(FixedU128, ConversionRounding),
+		/// Unstaked amount has been withdrew after it's expired [account, rate]
+		UnstakedAmountWithdrew(T::AccountId, BalanceOf<T>),
+		/// Set power balance by sudo [account, power_amount]
+		SetPowerBalance(T::AccountId, PowerAmount),
+		/// Power conversion request has cancelled [(class_id, token_id), account]
+		CancelPowerConversionRequest((ClassId, TokenId), T::AccountId),
+		/// Innovation Staking [staker, amount]
+		StakedInnovation(T::AccountId, BalanceOf<T>),
+		/// Unstaked from Innovation [staker, amount]
+		UnstakedInnovation(T::AccountId, BalanceOf<T>),
+		/// Claim rewards
+		ClaimRewards(T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Current innovation staking era updated
+		CurrentInnovationStakingEraUpdated(EraIndex),
+		/// Innovation Staking Era frequency updated
+		UpdatedInnovationStakingEraFrequency(BlockNumberFor<T>),
+		/// Last innovation staking era updated
+		LastInnovationStakingEraUpdated(BlockNumberFor<T>),
+		/// Estimated reward per era
+		EstimatedRewardPerEraUpdated(EraRewardBudget<BalanceOf<T>>),
+		/// Stake switched between pools [staker, from, to, amount]
+		StakeSwitched(T::AccountId, PoolKind, PoolKind, BalanceOf<T>),
+		/// Batch estate staking to economy 101 [staker, number_of_estates, total_amount]
+		EstateBatchStakedToEconomy101(T::AccountId, u32, BalanceOf<T>),
+		/// Estate staking operator updated [owner, estate_id, operator]
+		EstateStakingOperatorUpdated(T::AccountId, EstateId, Option<T::AccountId>),
+		/// Estate stake above the land unit allowance queued for exit [staker, estate_id,
+		/// excess_amount]
+		EstateStakeRebalanced(T::AccountId, EstateId, BalanceOf<T>),
+		/// Self-staking power per round updated [power_amount]
+		SelfStakingPowerPerRoundUpdated(PowerAmount),
+		/// Self-staking power distributed to economy 101 stakers [round, power_amount]
+		SelfStakingPowerDistributed(RoundIndex, PowerAmount),
+		/// Self-staking power credited to staker [staker, power_amount]
+		SelfStakingPowerCredited(T::AccountId, PowerAmount),
+		/// Power spent [who, amount]
+		PowerSpent(T::AccountId, PowerAmount),
+		/// Power transferred [from, to, amount]
+		PowerTransferred(T::AccountId, T::AccountId, PowerAmount),
+		/// Power allowance approved [owner, spender, amount]
+		PowerApproved(T::AccountId, T::AccountId, PowerAmount),
+		/// Stake weights of governance updated [weights]
+		StakeWeightsUpdated(StakeWeight),
+		/// Estate trait reward weight updated [trait_kind, trait_value, weight]
+		EstateTraitRewardWeightUpdated(EstateTraitKind, u8, Option<FixedU128>),
+		/// Innovation staking conviction updated [who, conviction]
+		InnovationConvictionUpdated(T::AccountId, InnovationConviction),
+		/// Voting points snapshot taken for a proposal [proposal_id, snapshot]
+		VotingPointsSnapshotTaken(ProposalId, u32),
+		/// Stake tiers updated by governance [number_of_tiers]
+		StakeTiersUpdated(u32),
+		/// Stake tier of staker changed [staker, tier_index]
+		StakeTierChanged(T::AccountId, Option<u32>),
+		/// Account staking preferences updated [account]
+		AccountPreferencesUpdated(T::AccountId),
+		/// Claim rewards paid out to beneficiary [staker, beneficiary, currency_id, amount]
+		ClaimRewardsTo(T::AccountId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Claim rewards converted into another currency [staker, currency_in, currency_out,
+		/// amount_in, amount_out]
+		ClaimRewardsSwapped(
+			T::AccountId,
+			FungibleTokenId,
+			FungibleTokenId,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// Reward distribution mode updated [mode]
+		RewardDistributionModeUpdated(RewardDistributionMode),
+		/// All rewards paid out by a claim [staker, beneficiary, rewards]
+		ClaimRewardsSummary(T::AccountId, T::AccountId, Vec<(FungibleTokenId, BalanceOf<T>)>),
+		/// Insurance fund deposited [from, amount]
+		InsuranceFundDeposited(T::AccountId, BalanceOf<T>),
+		/// Staker compensated from insurance fund [staker, amount]
+		InsuranceCompensationPaid(T::AccountId, BalanceOf<T>),
+		/// Bonded pool created [pool_id, operator]
+		BondedPoolCreated(PoolId, T::AccountId),
+		/// Joined bonded pool [member, pool_id, amount, points]
+		BondedPoolJoined(T::AccountId, PoolId, BalanceOf<T>, BalanceOf<T>),
+		/// Unbonded from bonded pool [member, pool_id, points, amount]
+		BondedPoolUnbonded(T::AccountId, PoolId, BalanceOf<T>, BalanceOf<T>),
+		/// Bonded pool reward compounded [pool_id, restaked_amount, commission]
+		BondedPoolRewardCompounded(PoolId, BalanceOf<T>, BalanceOf<T>),
+		/// Maximum bonded pool commission updated [commission]
+		MaxPoolCommissionUpdated(Perbill),
+		/// Fee share ratio updated [ratio]
+		FeeShareRatioUpdated(Perbill),
+		/// Transaction fees shared into the innovation staking reward pool [amount]
+		FeesSharedToRewardPool(BalanceOf<T>),
+		/// Bonded pool commission updated [pool_id, commission]
+		BondedPoolCommissionUpdated(PoolId, Perbill),
+		/// Bonded pool operator nominated [pool_id, operator]
+		BondedPoolOperatorNominated(PoolId, T::AccountId),
+		/// Bonded pool state updated [pool_id, state]
+		BondedPoolStateUpdated(PoolId, PoolState),
+		/// Empty destroying bonded pool removed [pool_id]
+		BondedPoolDestroyed(PoolId),
+		/// Reward campaign created [campaign_id, creator, currency_id, budget]
+		RewardCampaignCreated(CampaignId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Reward campaign budget distributed to the reward pool [campaign_id, amount]
+		RewardCampaignDistributed(CampaignId, BalanceOf<T>),
+		/// Expired reward campaign refunded [campaign_id, creator, leftover_budget]
+		RewardCampaignRefunded(CampaignId, T::AccountId, BalanceOf<T>),
+		/// Protocol commission share updated [share]
+		ProtocolCommissionShareUpdated(Perbill),
+		/// Penalty split updated [split]
+		PenaltySplitUpdated(PenaltySplit),
+		/// Penalty redistributed [from, reward_pool, insurance_fund, treasury]
+		PenaltyRedistributed(T::AccountId, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// Protocol income accrued to the economy treasury [amount]
+		ProtocolIncomeDeposited(BalanceOf<T>),
+		/// Economy treasury spend proposed [spend_id, proposer, currency_id, amount]
+		EconomySpendProposed(EconomySpendId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Economy treasury spend approved and executed [spend_id]
+		EconomySpendApproved(EconomySpendId),
+		/// Economy treasury spend rejected [spend_id]
+		EconomySpendRejected(EconomySpendId),
+		/// Offence reported [report_id, reporter, offender]
+		OffenceReported(OffenceReportId, T::AccountId, T::AccountId),
+		/// Offence confirmed and the offender slashed [report_id, offender, slashed,
+		/// reporter_reward]
+		OffenceConfirmed(OffenceReportId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Offence report rejected and the reporter deposit forfeited [report_id]
+		OffenceRejected(OffenceReportId),
+		/// Mission reward paid from the mission pot [mission_id, account, currency_id, amount]
+		MissionRewardDistributed(MissionId, T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Position receipt NFT class updated [class_id]
+		PositionReceiptClassUpdated(ClassId),
+		/// Innovation staking position receipt minted [staker, (class_id, token_id), amount]
+		PositionReceiptMinted(T::AccountId, (ClassId, TokenId), BalanceOf<T>),
+		/// Innovation staking position unstaked by the receipt owner [owner, (class_id,
+		/// token_id), amount]
+		PositionReceiptUnstaked(T::AccountId, (ClassId, TokenId), BalanceOf<T>),
+		/// Part of an innovation staking position moved to a new position receipt [owner,
+		/// position, new_position, amount]
+		PositionSplit(T::AccountId, (ClassId, TokenId), (ClassId, TokenId), BalanceOf<T>),
+		/// Innovation staking positions merged into the first position [owner, position,
+		/// merged_positions]
+		PositionsMerged(T::AccountId, (ClassId, TokenId), Vec<(ClassId, TokenId)>),
+		/// Estate stake limits updated [max_per_account, max_total]
+		EstateStakeLimitsUpdated(Option<BalanceOf<T>>, Option<BalanceOf<T>>),
+		/// Account reached the maximum estate stake per account [staker, amount]
+		AccountEstateStakeSaturated(T::AccountId, BalanceOf<T>),
+		/// Total estate stake reached the maximum [amount]
+		TotalEstateStakeSaturated(BalanceOf<T>),
+		/// Staking position force transferred to a replacement account [from, to, pool_kind,
+		/// staked_amount, exit_queue_amount]
+		StakeForceTransferred(T::AccountId, T::AccountId, PoolKind, BalanceOf<T>, BalanceOf<T>),
+		/// Multi-block migration completed [migration_id]
+		SteppedMigrationCompleted(Vec<u8>),
+		/// Share accounting invariant violated, accounting may have drifted [account, kind]
+		AccountingAnomaly(T::AccountId, AccountingAnomalyKind),
+		/// Staking metrics at era rollover [era, metrics]
+		EraSummary(EraIndex, EraMetrics<BalanceOf<T>>),
+		/// Matured exit queue entry restaked [staker, pool_kind, round, amount]
+		ExitRestaked(T::AccountId, PoolKind, RoundIndex, BalanceOf<T>),
+		/// Income received from another pallet staked on innovation staking [staker, amount]
+		IncomeStaked(T::AccountId, BalanceOf<T>),
+		/// Innovation position below the existential stake folded into the exit queue [staker,
+		/// amount]
+		DustPositionFolded(T::AccountId, BalanceOf<T>),
+		/// Estate owner approved a co-staker [estate_id, co_staker, cap]
+		EstateCoStakerApproved(EstateId, T::AccountId, BalanceOf<T>),
+		/// Estate owner revoked a co-staker approval [estate_id, co_staker]
+		EstateCoStakerRevoked(EstateId, T::AccountId),
+		/// Innovation project registered [project_id, owner, deposit]
+		InnovationProjectRegistered(InnovationProjectId, T::AccountId, BalanceOf<T>),
+		/// Innovation project deregistered and its deposit returned [project_id]
+		InnovationProjectDeregistered(InnovationProjectId),
+		/// Innovation stake directed at a project [staker, project_id, amount]
+		InnovationStakeDirected(T::AccountId, InnovationProjectId, BalanceOf<T>),
+		/// Directed innovation stake withdrawn from a project [staker, project_id, amount]
+		DirectedStakeWithdrawn(T::AccountId, InnovationProjectId, BalanceOf<T>),
+		/// Directed innovation stake released as the innovation stake fell below it [staker]
+		DirectedStakeReleased(T::AccountId),
+		/// Innovation projects selected by directed stake [era, projects]
+		TopInnovationProjectsSelected(EraIndex, Vec<(InnovationProjectId, BalanceOf<T>)>),
+		/// Project matching config updated [config]
+		ProjectMatchingUpdated(Option<ProjectMatchingConfig<BalanceOf<T>>>),
+		/// Treasury matched project reward pools [era, matches]
+		ProjectRewardsMatched(EraIndex, Vec<(InnovationProjectId, BalanceOf<T>)>),
+		/// Project owner claimed the matched reward pool [project_id, owner, amount]
+		ProjectMatchingClaimed(InnovationProjectId, T::AccountId, BalanceOf<T>),
+		/// Contiguous estate boost tiers updated [number_of_tiers]
+		EstateBoostTiersUpdated(u32),
+		/// Credit line terms updated [config]
+		CreditLineConfigUpdated(Option<CreditLineConfig>),
+		/// BIT borrowed against innovation stake [borrower, amount, debt]
+		CreditBorrowed(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Credit line repaid [borrower, amount, remaining_debt]
+		CreditRepaid(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Credit line liquidated from the innovation stake [borrower, debt, liquidated_stake]
+		CreditLineLiquidated(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Power advance terms updated [config]
+		PowerAdvanceConfigUpdated(Option<PowerAdvanceConfig>),
+		/// Power advanced against escrowed BIT [account, power_amount, debt]
+		PowerAdvanced(T::AccountId, PowerAmount, BalanceOf<T>),
+		/// Power advance installment withheld from BIT staking rewards [account, amount,
+		/// remaining_debt]
+		PowerAdvanceInstallmentPaid(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Power advance fully repaid and the escrow released [account]
+		PowerAdvanceRepaid(T::AccountId),
+		/// Unpaid power advance covered from the escrow [account, covered_debt]
+		PowerAdvanceDefaulted(T::AccountId, BalanceOf<T>),
+		/// Cross-chain reward chain whitelisted or removed [chain, allowed]
+		CrossChainRewardChainUpdated(MultiLocation, bool),
+		/// Cross-chain reward destination of an account updated [account, destination]
+		CrossChainRewardDestinationUpdated(T::AccountId, Option<CrossChainRewardDestination>),
+		/// Claimed reward sent to another chain [account, currency_id, amount, destination]
+		CrossChainRewardSent(T::AccountId, FungibleTokenId, BalanceOf<T>, MultiLocation),
+		/// Claimed reward kept on the local account as sending it to another chain failed
+		/// [account, currency_id, amount]
+		CrossChainRewardRefunded(T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Stake sponsor approved or removed [sponsor, budget]
+		StakeSponsorUpdated(T::AccountId, Option<u32>),
+		/// First innovation stake of an account paid for by a sponsor [sponsor, staker, amount]
+		SponsoredStakedInnovation(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// Minimum claim amount of a currency updated [currency_id, amount]
+		MinimumClaimAmountUpdated(FungibleTokenId, BalanceOf<T>),
+		/// Claim reached the maximum number of currencies, the next claim continues from the
+		/// currency [account, currency_id]
+		ClaimRewardsContinued(T::AccountId, FungibleTokenId),
+		/// Innovation staking reward pool initialized with the reward currencies [currencies]
+		RewardPoolInitialized(Vec<FungibleTokenId>),
+		/// Cooldown between unstakes from the same pool updated [blocks]
+		UnstakeCooldownUpdated(BlockNumberFor<T>),
+		/// Estate stake of a subdivided estate moved to its children, or queued for exit if it
+		/// could not be split [parent_estate_id, child_estate_ids, exited_amount]
+		EstateStakeSubdivided(EstateId, Vec<EstateId>, BalanceOf<T>),
+		/// Reward bonus of NFT class holders updated [class_id, bonus]
+		NftRewardBoostUpdated(ClassId, Option<Perbill>),
+		/// Reward ledger retention updated [entries]
+		RewardLedgerRetentionUpdated(Option<u32>),
+		/// Oldest reward ledger entries of an account pruned [account, removed_entries]
+		RewardLedgerPruned(T::AccountId, u32),
+		/// Exit queue entry became withdrawable [account, round, amount]
+		ExitMatured(T::AccountId, RoundIndex, BalanceOf<T>),
+		/// Legacy power balance moved to power tokens [account, power_amount]
+		PowerBalanceMigrated(T::AccountId, PowerAmount),
+		/// Reward payout account funded from the economy treasury [amount]
+		RewardPotFunded(BalanceOf<T>),
+		/// Reward payout account surplus swept to the economy treasury [amount]
+		RewardPotSwept(BalanceOf<T>),
+		/// Reward payout account rotated [old_account, new_account]
+		RewardPotRotated(T::AccountId, T::AccountId),
+		/// Reward payout account low watermark updated [watermark]
+		RewardPotLowWatermarkUpdated(BalanceOf<T>),
+		/// Reward payout account balance below the low watermark [balance]
+		RewardPotLow(BalanceOf<T>),
+		/// Force operation recorded in the forced actions log [kind, target, amount]
+		ForcedActionRecorded(ForcedActionKind<T::AccountId>, T::AccountId, BalanceOf<T>),
+		/// Voting season length updated [eras]
+		VotingSeasonLengthUpdated(EraIndex),
+		/// Voting season ended and the voting points of its stakers snapshotted [season,
+		/// staker_count]
+		VotingSeasonEnded(u32, u32),
+		/// Unstaked amount queued for exit [account, exit_queue, round, amount,
+		/// estimated_unlock_block]
+		ExitQueued(T::AccountId, ExitQueueKind, RoundIndex, BalanceOf<T>, BlockNumberFor<T>),
+		/// Self staked to economy 101 with a balance lock [staker, amount]
+		SelfStakedWithLockToEconomy101(T::AccountId, BalanceOf<T>),
+		/// Keeper authorization updated [account, authorized]
+		KeeperUpdated(T::AccountId, bool),
+		/// Innovation stake position memo updated [staker, memo]
+		PositionMemoUpdated(T::AccountId, Option<BoundedVec<u8, ConstU32<MAX_POSITION_MEMO_LENGTH>>>),
+		/// Power purchase commission of a domain updated [domain_id, commission]
+		DomainPowerCommissionUpdated(DomainId, Perbill),
+		/// Reward payout failed and kept for retry [beneficiary, currency_id, amount]
+		PayoutFailed(T::AccountId, FungibleTokenId, BalanceOf<T>),
+		/// Reserved balance of an account is below its staking ledgers and queued exits
+		/// [account, reserved_balance, ledger_balance]
+		ReserveLedgerDiscrepancy(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Era configuration change scheduled [activation_block]
+		EraConfigUpdateScheduled(BlockNumberFor<T>),
+		/// Innovation staking reward pool frozen [block]
+		RewardPoolFrozen(BlockNumberFor<T>),
+		/// Undistributed funds of the frozen reward pool drained to the economy treasury [amount]
+		RewardPoolDrained(BalanceOf<T>),
+		/// Maximum number of active innovation stakers updated [max_stakers]
+		MaxInnovationStakersUpdated(Option<u32>),
+		/// Innovation stake reserved and queued until a staker slot frees up [account, amount,
+		/// position]
+		InnovationStakerWaitlisted(T::AccountId, BalanceOf<T>, u32),
+		/// Waitlisted innovation staker admitted to innovation staking [account, amount]
+		InnovationStakerAdmitted(T::AccountId, BalanceOf<T>),
+		/// Innovation staker left the waitlist and got its stake unreserved [account, amount]
+		InnovationWaitlistLeft(T::AccountId, BalanceOf<T>),
+		/// Estate rewards funded from the economy treasury [amount]
+		EstateRewardsFunded(BalanceOf<T>),
+		/// Estate rewards claimed [account, amount]
+		EstateRewardClaimed(T::AccountId, BalanceOf<T>),
+		/// Estate staker offered to lease the estate rewards of its stake [estate_id, staker,
+		/// rounds, price]
+		EstateRewardLeaseOffered(EstateId, T::AccountId, RoundIndex, BalanceOf<T>),
+		/// Estate reward lease offer cancelled [estate_id, staker]
+		EstateRewardLeaseOfferCancelled(EstateId, T::AccountId),
+		/// Estate rewards of an estate stake leased [estate_id, staker, lessee, end_round, price]
+		EstateRewardLeaseAccepted(EstateId, T::AccountId, T::AccountId, RoundIndex, BalanceOf<T>),
+		/// Estate reward lease ended and its price paid to the staker [estate_id, staker, lessee,
+		/// paid]
+		EstateRewardLeaseEnded(EstateId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// Per round power issuance quota of distributors of a class updated [class_id, quota]
+		DistributorPowerQuotaUpdated(ClassId, Option<PowerAmount>),
+		/// Power issued by a distributor NFT [distributor, beneficiary, power_amount, bit_cost]
+		PowerDistributed((ClassId, TokenId), T::AccountId, PowerAmount, BalanceOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// NFT asset does not exist
+		NFTAssetDoesNotExist,
+		/// NFT class does not exist
+		NFTClassDoesNotExist,
+		/// NFT collection does not exist
+		NFTCollectionDoesNotExist,
+		/// No permission
+		NoPermission,
+		/// No authorization
+		NoAuthorization,
+		/// Insufficient power balance
+		AccountHasNoPowerBalance,
+		/// Power amount is zero
+		PowerAmountIsZero,
+		/// Not enough free balance for staking
+		InsufficientBalanceForStaking,
+		/// Unstake amount greater than staked amount
+		UnstakeAmountExceedStakedAmount,
+		/// Has scheduled exit staking, only stake after queue exit
+		ExitQueueAlreadyScheduled,
+		/// Stake amount below minimum staking required
+		StakeBelowMinimum,
+		/// Withdraw future round
+		WithdrawFutureRound,
+		/// Exit queue does not exist
+		ExitQueueDoesNotExit,
+		/// Unstaked amount is zero
+		UnstakeAmountIsZero,
+		/// Request already exists
+		RequestAlreadyExist,
+		/// Order has not reach target
+		NotReadyToExecute,
+		/// Staker is not estate owner
+		StakerNotEstateOwner,
+		/// Staking estate does not exist
+		StakeEstateDoesNotExist,
+		/// Stake is not previous owner
+		StakerNotPreviousOwner,
+		/// No funds staked at estate
+		NoFundsStakedAtEstate,
+		/// Previous owner still stakes at estate
+		PreviousOwnerStillStakesAtEstate,
+		/// Has scheduled exit estate staking, only stake after queue exit
+		EstateExitQueueAlreadyScheduled,
+		/// Estate exit queue does not exist
+		EstateExitQueueDoesNotExit,
+		/// Stake amount exceed estate max amount
+		StakeAmountExceedMaximumAmount,
+		/// Invalid era set up config
+		InvalidLastEraUpdatedBlock,
+		/// Unexpected error
+		Unexpected,
+		/// Reward pool does not exist
+		RewardPoolDoesNotExist,
+		/// Invalid reward set up
+		InvalidEstimatedRewardSetup,
+		/// Source and destination pool are the same
+		SwitchToSamePool,
+		/// Batch is empty or exceeds the maximum batch size
+		InvalidBatchSize,
+		/// Estate stake is held by an active lessee
+		LesseeStakeStillActive,
+		/// Caller is not the staking operator of the estate
+		NotEstateStakingOperator,
+		/// Estate stake does not exceed the land unit allowance
+		EstateStakeWithinAllowance,
+		/// Stake tier thresholds are not strictly ascending
+		InvalidStakeTiers,
+		/// No pending reward to claim
+		NoPendingReward,
+		/// Swap input and output currency are the same
+		InvalidSwapCurrency,
+		/// Insurance fund balance is not enough for the compensation
+		InsufficientInsuranceFund,
+		/// Bonded pool does not exist
+		BondedPoolDoesNotExist,
+		/// Account is a member of another bonded pool
+		AlreadyPoolMember,
+		/// Account is not a member of the bonded pool
+		NotPoolMember,
+		/// Unbond points exceed the member points
+		InsufficientPoolPoints,
+		/// Commission exceeds the maximum pool commission
+		PoolCommissionExceedsMaximum,
+		/// Caller is not the bonded pool operator
+		NotPoolOperator,
+		/// Bonded pool is not open for new members
+		BondedPoolNotOpen,
+		/// Bonded pool state transition is not allowed
+		InvalidPoolStateTransition,
+		/// Reward campaign does not exist
+		RewardCampaignDoesNotExist,
+		/// Reward campaign period or budget is invalid
+		InvalidRewardCampaign,
+		/// Reward campaign has not expired yet
+		RewardCampaignNotExpired,
+		/// Mission reward amount is zero
+		MissionRewardIsZero,
+		/// Economy treasury spending proposal does not exist
+		EconomySpendDoesNotExist,
+		/// Offence report does not exist
+		OffenceReportDoesNotExist,
+		/// Accounts cannot report themselves
+		SelfOffenceReport,
+		/// Penalty split shares exceed the whole penalty
+		PenaltySplitExceedsTotal,
+		/// Round handler returned a round behind the last seen round
+		RoundDataUnavailable,
+		/// Economy treasury spend amount is zero
+		EconomySpendAmountIsZero,
+		/// Too many economy treasury spending proposals are pending
+		TooManyOpenEconomySpends,
+		/// Staking position does not exist
+		StakingPositionDoesNotExist,
+		/// Power allowance is not enough for the transfer
+		InsufficientPowerAllowance,
+		/// Claim authorization nonce does not match the account nonce
+		InvalidClaimNonce,
+		/// Account has no innovation stake
+		NotInnovationStaker,
+		/// Voting points snapshot of the proposal is already taken
+		VotingPointsSnapshotAlreadyTaken,
+		/// Innovation staking conviction can only be extended while staked
+		ConvictionCannotBeReduced,
+		/// Innovation stake is locked by conviction and must unbond through the exit queue
+		InnovationStakeLockedByConviction,
+		/// Claim authorization signature is invalid
+		WrongSignature,
+		/// Caller does not own the position receipt
+		NotPositionReceiptOwner,
+		/// Both parts of a split position must be at least the minimum stake
+		InvalidPositionSplitAmount,
+		/// Merging needs at least two distinct positions
+		InvalidPositionsToMerge,
+		/// Estate stake exceeds the maximum estate stake per account
+		EstateStakeExceedsAccountLimit,
+		/// Estate stake exceeds the maximum total estate stake
+		EstateStakeExceedsTotalLimit,
+		/// Account has no stake to transfer
+		NoStakeToTransfer,
+		/// Stake transfer target already holds a position in the pool
+		StakeTransferTargetHasStake,
+		/// Estate co-stake exceeds the cap approved by the estate owner
+		EstateCoStakeExceedsCap,
+		/// Innovation position is not below the existential stake
+		NotDustPosition,
+		/// Staking would leave too little free balance to keep the account alive and pay fees
+		WouldKillAccount,
+		/// Innovation project does not exist
+		InnovationProjectDoesNotExist,
+		/// Innovation project deposit is below the minimum
+		ProjectDepositBelowMinimum,
+		/// Only the project owner can perform this action
+		NotInnovationProjectOwner,
+		/// Directed stake exceeds the innovation stake of the account
+		DirectedStakeExceedsInnovationStake,
+		/// Withdraw amount exceeds the stake directed at the project
+		WithdrawAmountExceedsDirectedStake,
+		/// Innovation project has no matched reward to claim
+		NoProjectMatchingToClaim,
+		/// Estate boost tiers must have strictly ascending land units
+		InvalidEstateBoostTiers,
+		/// Borrowing against innovation stake is disabled
+		CreditLinesDisabled,
+		/// Debt would exceed the credit limit of the innovation stake
+		BorrowExceedsCreditLimit,
+		/// Account has no credit line
+		NoCreditLine,
+		/// Innovation stake is needed as collateral of the credit line
+		CollateralLockedByCreditLine,
+		/// Power advances are disabled
+		PowerAdvancesDisabled,
+		/// Account already has an open power advance
+		PowerAdvanceAlreadyOpen,
+		/// Account has no power advance
+		NoPowerAdvance,
+		/// Power advance repayment period has not ended yet
+		PowerAdvanceNotDue,
+		/// Destination chain is not whitelisted for cross-chain rewards
+		CrossChainRewardChainNotAllowed,
+		/// Paying rewards to foreign beneficiaries is not enabled
+		ForeignBeneficiaryDisabled,
+		/// Account is not an approved stake sponsor
+		NotStakeSponsor,
+		/// Stake sponsor has no budget left
+		StakeSponsorBudgetExhausted,
+		/// Only first-time stakers can be sponsored
+		NotFirstTimeStaker,
+		/// Unstaking from the pool again is on cooldown
+		OperationOnCooldown,
+		/// Too many NFT classes grant a reward boost
+		TooManyNftRewardBoosts,
+		/// Reward ledger retention exceeds `MAX_REWARD_LEDGER_ENTRIES`
+		InvalidRewardLedgerRetention,
+		/// Account reached the maximum number of rounds queued in the exit queue
+		TooManyQueuedExits,
+		/// Sweep amount exceeds the reward payout account balance not owed to the reward pool
+		RewardPotSurplusExceeded,
+		/// Account is already the reward payout account
+		RewardPotAlreadyInUse,
+		/// Stake backed by a balance lock cannot be moved to another account
+		LockedStakeNotTransferable,
+		/// Account is not an authorized keeper
+		NotAuthorizedKeeper,
+		/// Keeper maintenance calls of this block reached `MAX_KEEPER_CALLS_PER_BLOCK`
+		KeeperRateLimitExceeded,
+		/// No era update is due
+		NoEraUpdateDue,
+		/// No failed payout to retry
+		NoFailedPayout,
+		/// Era configuration activation block is not in the future
+		InvalidEraConfigActivationBlock,
+		/// Reward pool is already frozen
+		RewardPoolAlreadyFrozen,
+		/// Reward pool is not frozen
+		RewardPoolNotFrozen,
+		/// Reward pool drain grace period has not elapsed
+		RewardPoolDrainGracePeriodNotElapsed,
+		/// Innovation staker waitlist is full
+		InnovationWaitlistFull,
+		/// Account is already in the innovation staker waitlist
+		AlreadyInInnovationWaitlist,
+		/// Account is not in the innovation staker waitlist
+		NotInInnovationWaitlist,
+		/// Innovation staker cap is reached
+		InnovationStakerCapReached,
+		/// Claimed reward is below the minimum claim amount of its currency
+		ClaimBelowMinimum,
+		/// Innovation exit queue does not exist
+		InnovationExitQueueDoesNotExist,
+		/// Innovation exit queue round is not reached yet
+		InnovationExitQueueNotMatured,
+		/// Stake switched out of innovation staking is locked until its innovation unbond ends
+		SwitchedStakeLocked,
+		/// Maximum number of active reward campaigns is reached
+		TooManyRewardCampaigns,
+		/// Estate reward amount is zero per unit of weighted estate stake
+		EstateRewardAmountIsZero,
+		/// There is no weighted estate stake to reward
+		NoWeightedEstateStake,
+		/// There are no estate rewards to claim
+		NoEstateRewardToClaim,
+		/// Estate rewards of the estate stake are already leased
+		EstateRewardsAlreadyLeased,
+		/// Estate reward lease offer does not exist
+		EstateRewardLeaseOfferDoesNotExist,
+		/// Estate reward lease price is above the maximum price
+		EstateRewardLeasePriceTooHigh,
+		/// Estate reward lease is invalid
+		InvalidEstateRewardLease,
+		/// NFT class has no power distributor quota
+		NotPowerDistributor,
+		/// Caller does not own the distributor NFT
+		NotPowerDistributorOwner,
+		/// Power issued by the distributor this round would exceed its quota
+		DistributorPowerQuotaExceeded,
+		/// Switched stake is locked in too many tranches
+		TooManySwitchedStakeLocks,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed = Self::step_migration(remaining_weight);
+			consumed = consumed.saturating_add(Self::step_matured_exits(remaining_weight.saturating_sub(consumed)));
+			consumed = consumed.saturating_add(Self::sweep_matured_exits(remaining_weight.saturating_sub(consumed)));
+			consumed = consumed.saturating_add(Self::settle_ended_era_joined_shares(
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed = consumed.saturating_add(Self::step_top_innovation_projects(
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed = consumed.saturating_add(Self::step_project_matching(remaining_weight.saturating_sub(consumed)));
+			consumed.saturating_add(Self::step_credit_lines(remaining_weight.saturating_sub(consumed)))
+		}
+
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			// PendingEraConfig, LastEraUpdatedBlock, UpdateEraFrequency and RewardDistributionMode
+			let mut weight = T::DbWeight::get().reads(4);
+			if let Some(scheduled) = PendingEraConfig::<T>::get() {
+				if n >= scheduled.activation_block {
+					PendingEraConfig::<T>::kill();
+					Self::apply_era_config(scheduled.frequency, scheduled.reward_per_era);
+					weight = weight.saturating_add(T::DbWeight::get().writes(3));
+				}
+			}
+
+			let era_number = Self::get_era_index(<frame_system::Pallet<T>>::block_number());
+
+			if !era_number.is_zero() {
+				// ActiveRewardCampaigns and every active campaign drawn by the era distributor
+				let campaigns = ActiveRewardCampaigns::<T>::decode_len().unwrap_or_default() as u64;
+				let _ = Self::update_current_era(era_number).map_err(|err| err).ok();
+				weight = weight
+					.saturating_add(T::WeightInfo::update_current_era())
+					.saturating_add(T::DbWeight::get().reads_writes(1 + 4 * campaigns, 1 + 3 * campaigns));
+			}
+
+			if Self::reward_distribution_mode() == RewardDistributionMode::PerBlock {
+				let _ = Self::drip_reward_to_reward_pool().map_err(|err| err).ok();
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 2));
+			}
+
+			// InnovationStakerWaitlist
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			let admitted = Self::admit_waitlisted_innovation_stakers();
+			if !admitted.is_zero() {
+				weight = weight
+					.saturating_add(T::DbWeight::get().reads_writes(1 + 2 * admitted as u64, 1 + 3 * admitted as u64));
+			}
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			let stakers: BTreeSet<T::AccountId> = StakingInfo::<T>::iter_keys()
+				.chain(InnovationStakingInfo::<T>::iter_keys())
+				.chain(AccountEstateStake::<T>::iter_keys())
+				.collect();
+			for who in stakers.iter() {
+				ensure!(
+					T::Currency::reserved_balance(who) >= Self::reserved_stake_ledger(who),
+					"Reserved balance is below the staking ledgers and queued exits"
+				);
+			}
+
+			Ok(())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Stake native token to staking ledger to receive build material every round
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the stake amount
+		///
+		/// Emit `SelfStakedToEconomy101` event or `EstateStakedToEconomy101` event if successful
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			if estate.is_some() {
+				T::WeightInfo::stake_b()
+			} else {
+				T::WeightInfo::stake_a()
+			}
+		)]
+		#[transactional]
+		pub fn stake(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			estate: Option<EstateId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::restake_matured_exits(&who);
+
+			// Check if user has enough balance for staking
+			ensure!(
+				T::Currency::free_balance(&who) >= amount,
+				Error::<T>::InsufficientBalanceForStaking
+			);
+			Self::ensure_keep_alive(&who, amount)?;
+
+			match estate {
+				None => {
+					Self::accrue_self_staking_power(&who);
+
+					let staked_balance = StakingInfo::<T>::get(&who);
+					let total = staked_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+					ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+
+					T::Currency::reserve(&who, amount)?;
+
+					StakingInfo::<T>::insert(&who, total);
+
+					let new_total_staked = TotalStake::<T>::get().saturating_add(amount);
+					<TotalStake<T>>::put(new_total_staked);
+
+					Self::deposit_event(Event::SelfStakedToEconomy101(who, amount));
+				}
+				Some(estate_id) => {
+					Self::do_stake_on_estate(&who, estate_id, amount)?;
+				}
+			}
+
+			Ok(().into())
+		}
+
+		/// Stake native token to staking ledger with a balance lock instead of a reserve. The lock
+		/// can overlap with other locks such as vesting, so locked funds can be staked
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the stake amount
+		///
+		/// Emit `SelfStakedWithLockToEconomy101` event if successful
+		#[pallet::call_index(95)]
+		#[pallet::weight(T::WeightInfo::stake_with_lock())]
+		#[transactional]
+		pub fn stake_with_lock(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::restake_matured_exits(&who);
+
+			// Locked funds stay in the free balance, so vesting locked funds count towards the stake
+			let locked_stake = LockedSelfStake::<T>::get(&who)
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				T::Currency::free_balance(&who) >= locked_stake,
+				Error::<T>::InsufficientBalanceForStaking
+			);
+
+			Self::accrue_self_staking_power(&who);
+
+			let staked_balance = StakingInfo::<T>::get(&who);
+			let total = staked_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+
+			T::Currency::set_lock(ECONOMY_STAKE_LOCK_ID, &who, locked_stake, WithdrawReasons::all());
+			LockedSelfStake::<T>::insert(&who, locked_stake);
+
+			StakingInfo::<T>::insert(&who, total);
+
+			let new_total_staked = TotalStake::<T>::get().saturating_add(amount);
+			<TotalStake<T>>::put(new_total_staked);
+
+			Self::deposit_event(Event::SelfStakedWithLockToEconomy101(who, amount));
+
+			Ok(().into())
+		}
+
+		/// Stake native token on multiple estates in a single transaction. Either all estates are
+		/// staked or none of them are
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `stakes`: list of estate IDs and the amount to stake on each of them
+		///
+		/// Emit `EstateStakedToEconomy101` event for each estate and
+		/// `EstateBatchStakedToEconomy101` event if successful
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::stake_batch(stakes.len() as u32))]
+		#[transactional]
+		pub fn stake_batch(
+			origin: OriginFor<T>,
+			stakes: BoundedVec<(EstateId, BalanceOf<T>), T::MaxEstateStakeBatch>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!stakes.is_empty(), Error::<T>::InvalidBatchSize);
+
+			let mut total_amount: BalanceOf<T> = Zero::zero();
+			for (_, amount) in stakes.iter() {
+				total_amount = total_amount.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			}
+
+			// Check if user has enough balance for staking
+			ensure!(
+				T::Currency::free_balance(&who) >= total_amount,
+				Error::<T>::InsufficientBalanceForStaking
+			);
+			Self::ensure_keep_alive(&who, total_amount)?;
+
+			for (estate_id, amount) in stakes.iter() {
+				Self::do_stake_on_estate(&who, *estate_id, *amount)?;
+			}
+
+			Self::deposit_event(Event::EstateBatchStakedToEconomy101(
+				who,
+				stakes.len() as u32,
+				total_amount,
+			));
+
+			Ok(())
+		}
+
+		/// Stake native token to innovation staking ledger to receive reward and voting points
+		/// every round
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the stake amount
+		///
+		/// Emit `SelfStakedToEconomy101` event or `EstateStakedToEconomy101` event if successful
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::stake_on_innovation())]
+		#[transactional]
+		pub fn stake_on_innovation(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_stake_on_innovation(&who, amount)
+		}
+
+		/// Stake native token of a first-time staker to innovation staking ledger, with an
+		/// approved sponsor paying the fees
+		///
+		/// The dispatch origin for this call must be _Signed_ by the sponsor.
+		///
+		/// `staker`: the first-time staker whose balance is staked
+		/// `amount`: the stake amount
+		/// `signature`: the staker signature of `sponsored_stake_payload(staker, sponsor, amount)`
+		///
+		/// Emit `SponsoredStakedInnovation` event if successful
+		#[pallet::call_index(79)]
+		#[pallet::weight(T::WeightInfo::sponsored_stake_on_innovation())]
+		#[transactional]
+		pub fn sponsored_stake_on_innovation(
+			origin: OriginFor<T>,
+			staker: T::AccountId,
+			amount: BalanceOf<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			let budget = StakeSponsors::<T>::get(&sponsor).ok_or(Error::<T>::NotStakeSponsor)?;
+			ensure!(budget > 0, Error::<T>::StakeSponsorBudgetExhausted);
+			ensure!(
+				!SponsoredStakers::<T>::contains_key(&staker) && InnovationStakingInfo::<T>::get(&staker).is_zero(),
+				Error::<T>::NotFirstTimeStaker
+			);
+			Self::validate_signature(
+				&Self::sponsored_stake_payload(&staker, &sponsor, amount),
+				&signature,
+				&staker,
+			)?;
+
+			StakeSponsors::<T>::insert(&sponsor, budget - 1);
+			SponsoredStakers::<T>::insert(&staker, &sponsor);
+
+			Self::do_stake_on_innovation(&staker, amount)?;
+
+			Self::deposit_event(Event::SponsoredStakedInnovation(sponsor, staker, amount));
+
+			Ok(())
+		}
+
+		/// Unstake native token to innovation staking ledger to receive reward and voting points
+		/// every round
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the unstake amount
+		///
+		/// Emit `UnstakedInnovation` event if successful
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::unstake_on_innovation())]
+		#[transactional]
+		pub fn unstake_on_innovation(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::checkpoint_voting_points(&who);
+			Self::restake_matured_exits(&who);
+			Self::ensure_unstake_cooldown(&who, ExitQueueKind::Innovation)?;
+
+			let staked_balance = InnovationStakingInfo::<T>::get(&who);
+			ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+			let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+
+			let amount_to_unstake = if remaining < T::MinimumStake::get() {
+				// Remaining amount below minimum, remove all staked amount
+				staked_balance
+			} else {
+				amount
+			};
+			Self::ensure_credit_collateral(&who, staked_balance.saturating_sub(amount_to_unstake))?;
+
+			let current_round = Self::current_round_info()?;
+			let unbond_rounds =
+				INNOVATION_UNBOND_ROUNDS.saturating_mul(InnovationConvictions::<T>::get(&who).multiplier());
+			let next_round = current_round.current.saturating_add(unbond_rounds);
+
+			// Check if user already in exit queue of the current
+			ensure!(
+				!InnovationStakingExitQueue::<T>::contains_key(&who, next_round),
+				Error::<T>::ExitQueueAlreadyScheduled
+			);
+
+			// This exit queue will be executed by exit_staking extrinsics to unreserved token
+			Self::ensure_exit_queue_capacity(&who, ExitQueueKind::Innovation, next_round)?;
+			Self::queue_exit(&who, ExitQueueKind::Innovation, next_round, amount_to_unstake);
+
+			// Update staking info of user immediately
+			// Remove staking info
+			if amount_to_unstake == staked_balance {
+				InnovationStakingInfo::<T>::remove(&who);
+				InnovationConvictions::<T>::remove(&who);
+			} else {
+				InnovationStakingInfo::<T>::insert(&who, remaining);
+			}
+
+			let new_total_staked = TotalInnovationStaking::<T>::get().saturating_sub(amount_to_unstake);
+			<TotalInnovationStaking<T>>::put(new_total_staked);
+
+			Self::remove_share(&who, amount_to_unstake);
+
+			Self::deposit_event(Event::UnstakedInnovation(who, amount));
+			Ok(())
+		}
+
+		/// Extend the unbond period of the caller's innovation stake to 2x, 4x or 8x of
+		/// `INNOVATION_UNBOND_ROUNDS` in exchange for proportionally more voting points. The
+		/// conviction cannot be reduced until the stake is fully unstaked
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `conviction`: the new conviction
+		///
+		/// Emit `InnovationConvictionUpdated` event if successful
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_innovation_conviction())]
+		pub fn set_innovation_conviction(origin: OriginFor<T>, conviction: InnovationConviction) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::checkpoint_voting_points(&who);
+
+			ensure!(
+				!InnovationStakingInfo::<T>::get(&who).is_zero(),
+				Error::<T>::NotInnovationStaker
+			);
+			ensure!(
+				conviction > InnovationConvictions::<T>::get(&who),
+				Error::<T>::ConvictionCannotBeReduced
+			);
+
+			InnovationConvictions::<T>::insert(&who, conviction);
+			Self::deposit_event(Event::<T>::InnovationConvictionUpdated(who, conviction));
+
+			Ok(())
+		}
+
+		/// Claim reward from innovation staking ledger to receive reward and voting points
+		/// every round
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		///
+		/// Emit `ClaimRewards` event for each currency paid and `ClaimRewardsSummary` event if
+		/// successful
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::claim_reward())]
+		#[transactional]
+		pub fn claim_reward(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::restake_matured_exits(&who);
+
+			Self::do_claim_reward(&who, &who);
+			Self::fold_dust_position(&who);
+
+			Ok(())
+		}
+
+		/// Fold an innovation position below `ExistentialStake` into the exit queue
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `account`: the owner of the dust position
+		///
+		/// Emit `DustPositionFolded` event if successful
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::reap_dust_position())]
+		#[transactional]
+		pub fn reap_dust_position(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::fold_dust_position(&account).ok_or(Error::<T>::NotDustPosition)?;
+
+			Ok(())
+		}
+
+		/// Claim reward from innovation staking ledger on behalf of an owner who signed the claim
+		/// authorization off-chain, so relayers can pay the fees for users
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `owner`: the staker the rewards are claimed for and paid to
+		/// `nonce`: the current claim nonce of the owner
+		/// `signature`: the owner signature of `claim_authorization_payload(owner, nonce)`
+		///
+		/// Emit `ClaimRewards` event for each currency paid and `ClaimRewardsSummary` event if
+		/// successful
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::claim_with_signature())]
+		#[transactional]
+		pub fn claim_with_signature(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			nonce: u64,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(nonce == ClaimNonces::<T>::get(&owner), Error::<T>::InvalidClaimNonce);
+			Self::validate_signature(&Self::claim_authorization_payload(&owner, nonce), &signature, &owner)?;
+
+			ClaimNonces::<T>::insert(&owner, nonce.saturating_add(1));
+			Self::do_claim_reward(&owner, &owner);
+
+			Ok(())
+		}
+
+		/// Claim reward from innovation staking ledger and pay it out to another account
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `beneficiary`: the account receiving the rewards
+		///
+		/// Emit `ClaimRewardsTo` event for each currency paid and `ClaimRewardsSummary` event if
+		/// successful
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::claim_reward_to())]
+		#[transactional]
+		pub fn claim_reward_to(origin: OriginFor<T>, beneficiary: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_claim_reward(&who, &beneficiary);
+
+			Ok(())
+		}
+
+		/// Claim reward from innovation staking ledger and pay it out to a foreign beneficiary on a
+		/// whitelisted chain. Requires the `foreign-beneficiary` feature
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `beneficiary`: the location of the account receiving the rewards
+		///
+		/// Emit `CrossChainRewardSent` or `CrossChainRewardRefunded` event for each currency paid
+		/// if successful
+		#[pallet::call_index(78)]
+		#[pallet::weight(T::WeightInfo::claim_reward_to_location())]
+		#[transactional]
+		pub fn claim_reward_to_location(origin: OriginFor<T>, beneficiary: MultiLocation) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_claim_reward_to_location(&who, beneficiary)
+		}
+
+		/// Claim part of the pending reward of a currency from innovation staking ledger. The
+		/// remainder stays pending
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `currency_id`: the reward currency to claim
+		/// `max_amount`: the maximum amount to claim
+		///
+		/// Emit `ClaimRewards` event if successful
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::claim_reward_partial())]
+		#[transactional]
+		pub fn claim_reward_partial(
+			origin: OriginFor<T>,
+			currency_id: FungibleTokenId,
+			max_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_claimable_reward(&who, currency_id, Some(max_amount))?;
+			Self::claim_reward_with_location(&who, &who, None, true, Some((currency_id, max_amount)));
+
+			Ok(())
+		}
+
+		/// Claim the pending reward of a currency from innovation staking ledger converted into
+		/// another currency through the runtime reward swapper
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `currency_in`: the pending reward currency to claim
+		/// `currency_out`: the currency to receive
+		/// `min_amount_out`: the minimum amount of `currency_out` to receive
+		///
+		/// Emit `ClaimRewardsSwapped` event if successful
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::claim_reward_swapped())]
+		#[transactional]
+		pub fn claim_reward_swapped(
+			origin: OriginFor<T>,
+			currency_in: FungibleTokenId,
+			currency_out: FungibleTokenId,
+			min_amount_out: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(currency_in != currency_out, Error::<T>::InvalidSwapCurrency);
+
+			let pending_reward = Self::ensure_claimable_reward(&who, currency_in, None)?;
+
+			let paid = PendingRewardsOfStakingInnovation::<T>::mutate_exists(&who, |maybe_pending_multi_rewards| {
+				let pending_multi_rewards = maybe_pending_multi_rewards.as_mut()?;
+				let paid = pending_multi_rewards
+					.get_mut(&currency_in)
+					.and_then(|pending| Self::pay_pending_reward(&who, &who, currency_in, pending, pending_reward));
+				pending_multi_rewards.retain(|_, pending| !pending.is_zero());
+				if pending_multi_rewards.is_empty() {
+					*maybe_pending_multi_rewards = None;
+				}
+				paid
+			});
+			// Nothing left to swap once the reward repaid a power advance or the payout failed
+			let amount_in = match paid {
+				Some(amount_in) => amount_in,
+				None => return Ok(()),
+			};
+
+			let amount_out = T::RewardSwapper::swap_reward(&who, currency_in, currency_out, amount_in, min_amount_out)?;
+
+			Self::deposit_event(Event::ClaimRewardsSwapped(
+				who,
+				currency_in,
+				currency_out,
+				amount_in,
+				amount_out,
+			));
+
+			Ok(())
+		}
+
+		/// Switch staked native token between economy 101 self-staking and innovation staking.
+		/// The reserved amount stays reserved and does not pass through the exit queue
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the amount to switch
+		/// `from`: the pool the amount is currently staked in
+		/// `to`: the pool the amount will be staked in
+		///
+		/// Emit `StakeSwitched` event if successful
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::switch_stake())]
+		#[transactional]
+		pub fn switch_stake(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			from: PoolKind,
+			to: PoolKind,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::checkpoint_voting_points(&who);
+
+			ensure!(from != to, Error::<T>::SwitchToSamePool);
+			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+			ensure!(
+				from != PoolKind::Innovation || InnovationConvictions::<T>::get(&who) == InnovationConviction::None,
+				Error::<T>::InnovationStakeLockedByConviction
+			);
+			ensure!(
+				from != PoolKind::Economy101 || LockedSelfStake::<T>::get(&who).is_zero(),
+				Error::<T>::LockedStakeNotTransferable
+			);
+
+			Self::accrue_self_staking_power(&who);
+
+			let staked_balance = match from {
+				PoolKind::Economy101 => StakingInfo::<T>::get(&who),
+				PoolKind::Innovation => InnovationStakingInfo::<T>::get(&who),
+			};
+			ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+			let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+
+			let amount_to_switch = if remaining < T::MinimumStake::get() {
+				// Remaining amount below minimum, switch all staked amount
+				staked_balance
+			} else {
+				amount
+			};
+			if from == PoolKind::Innovation {
+				Self::ensure_credit_collateral(&who, staked_balance.saturating_sub(amount_to_switch))?;
+			}
+			let current_round = Self::current_round_info()?.current;
+
+			// Remove from source pool
+			match from {
+				PoolKind::Economy101 => {
+					if amount_to_switch == staked_balance {
+						StakingInfo::<T>::remove(&who);
+					} else {
+						StakingInfo::<T>::insert(&who, remaining);
+					}
+
+					let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_switch);
+					<TotalStake<T>>::put(new_total_staked);
+
+					// Stake moving back into innovation staking no longer needs the switch lock
+					Self::release_switched_stake(&who, amount_to_switch, current_round);
+				}
+				PoolKind::Innovation => {
+					if amount_to_switch == staked_balance {
+						InnovationStakingInfo::<T>::remove(&who);
+					} else {
+						InnovationStakingInfo::<T>::insert(&who, remaining);
+					}
+
+					let new_total_staked = TotalInnovationStaking::<T>::get().saturating_sub(amount_to_switch);
+					<TotalInnovationStaking<T>>::put(new_total_staked);
+
+					Self::remove_share(&who, amount_to_switch);
+				}
+			}
+
+			// Add to destination pool
+			match to {
+				PoolKind::Economy101 => {
+					let total = StakingInfo::<T>::get(&who)
+						.checked_add(&amount_to_switch)
+						.ok_or(ArithmeticError::Overflow)?;
+					ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+
+					StakingInfo::<T>::insert(&who, total);
+
+					let new_total_staked = TotalStake::<T>::get().saturating_add(amount_to_switch);
+					<TotalStake<T>>::put(new_total_staked);
+
+					// The switched stake keeps the innovation unbond period before it can be unstaked
+					Self::lock_switched_stake(&who, amount_to_switch, current_round)?;
+				}
+				PoolKind::Innovation => {
+					Self::ensure_innovation_staker_slot(&who)?;
+					let total = Self::ensure_innovation_stake_total(&who, amount_to_switch)?;
+
+					InnovationStakingInfo::<T>::insert(&who, total);
+
+					let new_total_staked = TotalInnovationStaking::<T>::get().saturating_add(amount_to_switch);
+					<TotalInnovationStaking<T>>::put(new_total_staked);
+
+					Self::add_share(&who, amount_to_switch);
+				}
+			}
+
+			Self::deposit_event(Event::StakeSwitched(who, from, to, amount_to_switch));
+
+			Ok(())
+		}
+
+		/// Unstake native token from staking ledger. The unstaked amount able to redeem from the
+		/// next round
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the stake amount
+		///
+		/// Emit `SelfStakingRemovedFromEconomy101` event or `EstateStakingRemovedFromEconomy101`
+		/// event if successful
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			if estate.is_some() {
+				T::WeightInfo::unstake_b()
+			} else {
+				T::WeightInfo::unstake_a()
+			}
+		)]
+		pub fn unstake(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			estate: Option<EstateId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::restake_matured_exits(&who);
+
+			// Ensure amount is greater than zero
+			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+
+			let exit_queue_kind = match estate {
+				None => ExitQueueKind::Economy101,
+				Some(estate_id) => ExitQueueKind::Estate(estate_id),
+			};
+			Self::ensure_unstake_cooldown(&who, exit_queue_kind)?;
+
+			match estate {
+				None => {
+					let staked_balance = StakingInfo::<T>::get(&who);
+					ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+					Self::accrue_self_staking_power(&who);
+
+					let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+
+					let amount_to_unstake = if remaining < T::MinimumStake::get() {
+						// Remaining amount below minimum, remove all staked amount
+						staked_balance
+					} else {
+						amount
+					};
+
+					let current_round = Self::current_round_info()?;
+					let next_round = current_round.current.saturating_add(One::one());
+					ensure!(
+						staked_balance.saturating_sub(amount_to_unstake)
+							>= Self::locked_switched_stake(&who, current_round.current),
+						Error::<T>::SwitchedStakeLocked
+					);
+
+					// Check if user already in exit queue of the current
+					ensure!(
+						!ExitQueue::<T>::contains_key(&who, next_round),
+						Error::<T>::ExitQueueAlreadyScheduled
+					);
+
+					// This exit queue will be executed by exit_staking extrinsics to unreserved token
+					Self::ensure_exit_queue_capacity(&who, ExitQueueKind::Economy101, next_round)?;
+					Self::queue_exit(&who, ExitQueueKind::Economy101, next_round, amount_to_unstake);
+
+					// Update staking info of user immediately
+					// Remove staking info
+					if amount_to_unstake == staked_balance {
+						StakingInfo::<T>::remove(&who);
+					} else {
+						StakingInfo::<T>::insert(&who, remaining);
+					}
+
+					let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_unstake);
+					<TotalStake<T>>::put(new_total_staked);
+
+					Self::deposit_event(Event::SelfStakingRemovedFromEconomy101(who, amount));
+				}
+				Some(estate_id) => {
+					Self::do_unstake_on_estate(&who, estate_id, amount)?;
+				}
+			}
+
+			Ok(().into())
+		}
+
+		/// Authorize or revoke an operator that can stake and unstake on behalf of the estate
+		/// owner. Staked funds and exit queue entries always belong to the estate owner
+		///
+		/// The dispatch origin for this call must be _Signed_ and the estate owner.
+		///
+		/// `estate_id`: the estate ID
+		/// `operator`: the operator account, `None` to revoke
+		///
+		/// Emit `EstateStakingOperatorUpdated` event if successful
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_estate_staking_operator())]
+		pub fn set_estate_staking_operator(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			operator: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::EstateHandler::check_estate_ownership(who.clone(), estate_id)?,
+				Error::<T>::StakerNotEstateOwner
+			);
+
+			match operator.clone() {
+				Some(operator_account) => {
+					EstateStakingOperators::<T>::insert(estate_id, (who.clone(), operator_account));
+				}
+				None => {
+					EstateStakingOperators::<T>::remove(estate_id);
+				}
+			}
+
+			Self::deposit_event(Event::EstateStakingOperatorUpdated(who, estate_id, operator));
+
+			Ok(())
+		}
+
+		/// Stake native token of the estate owner on the estate as its authorized operator
+		///
+		/// The dispatch origin for this call must be _Signed_ and the estate staking operator.
+		///
+		/// `estate_id`: the estate ID
+		/// `amount`: the stake amount
+		///
+		/// Emit `EstateStakedToEconomy101` event if successful
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::operator_stake())]
+		#[transactional]
+		pub fn operator_stake(origin: OriginFor<T>, estate_id: EstateId, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::ensure_estate_staking_operator(&who, estate_id)?;
+
+			ensure!(
+				T::Currency::free_balance(&owner) >= amount,
+				Error::<T>::InsufficientBalanceForStaking
+			);
+			Self::ensure_keep_alive(&owner, amount)?;
+
+			Self::do_stake_on_estate(&owner, estate_id, amount)
+		}
+
+		/// Unstake native token of the estate owner from the estate as its authorized operator. The
+		/// unstaked amount is queued for the estate owner
+		///
+		/// The dispatch origin for this call must be _Signed_ and the estate staking operator.
+		///
+		/// `estate_id`: the estate ID
+		/// `amount`: the unstake amount
+		///
+		/// Emit `EstateStakingRemovedFromEconomy101` event if successful
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::operator_unstake())]
+		#[transactional]
+		pub fn operator_unstake(origin: OriginFor<T>, estate_id: EstateId, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::ensure_estate_staking_operator(&who, estate_id)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+
+			Self::do_unstake_on_estate(&owner, estate_id, amount)
+		}
+
+		/// Queue the estate stake exceeding the current land unit allowance for exit. This can
+		/// happen when land units are removed from a staked estate
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `estate_id`: the estate ID to rebalance
+		///
+		/// Emit `EstateStakeRebalanced` event if successful
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::rebalance_estate_stake())]
+		#[transactional]
+		pub fn rebalance_estate_stake(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let excess = Self::do_rebalance_estate_stake(estate_id)?;
+			ensure!(!excess.is_zero(), Error::<T>::EstateStakeWithinAllowance);
+
+			Ok(())
+		}
+
+		/// Unstake native token (staked by previous owner) from staking ledger.
+		///
+		/// The dispatch origin for this call must be _Signed_. Works if the origin is the estate
+		/// owner and the previous owner got staked funds
+		///
+		/// `estate_id`: the estate ID which funds are going to be unstaked
+		///
+		/// Emit `EstateStakingRemovedFromEconomy101` event if successful
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::unstake_new_estate_owner())]
+		pub fn unstake_new_estate_owner(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::EstateHandler::check_estate(estate_id.clone())?,
+				Error::<T>::StakeEstateDoesNotExist
+			);
+
+			ensure!(
+				T::EstateHandler::check_estate_ownership(who.clone(), estate_id.clone())?,
+				Error::<T>::StakerNotEstateOwner
+			);
+
+			let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
+			match staking_bond_value {
+				Some(staking_info) => {
+					ensure!(
+						staking_info.staker.clone() != who.clone(),
+						Error::<T>::StakerNotPreviousOwner
+					);
+
+					// Lessee stakes can only be evicted once the lease has ended
+					if EstateStakerRoles::<T>::get(estate_id) == EstateStakerRole::Lessee {
+						ensure!(
+							!T::EstateHandler::check_estate_lease(staking_info.staker.clone(), estate_id)?,
+							Error::<T>::LesseeStakeStillActive
+						);
+					}
+					let staked_balance = staking_info.amount;
+
+					let current_round = Self::current_round_info()?;
+					let next_round = current_round.current.saturating_add(One::one());
+
+					// This exit queue will be executed by exit_staking extrinsics to unreserved token
+					Self::queue_exit(
+						&staking_info.staker,
+						ExitQueueKind::Estate(estate_id),
+						next_round,
+						staked_balance,
+					);
+					EstateStakingInfo::<T>::remove(&estate_id);
+					EstateStakerRoles::<T>::remove(&estate_id);
+					EstateStakingSince::<T>::remove(&estate_id);
+
+					let new_total_staked = TotalEstateStake::<T>::get().saturating_sub(staked_balance);
+					<TotalEstateStake<T>>::put(new_total_staked);
+					AccountEstateStake::<T>::mutate(&staking_info.staker, |stake| {
+						*stake = stake.saturating_sub(staked_balance)
+					});
+					Self::sync_weighted_estate_stake(estate_id, &staking_info.staker);
+
+					Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(
+						who,
+						estate_id,
+						staked_balance,
+					));
+					Self::deposit_estate_bond_updated(&staking_info.staker, estate_id);
+					Ok(().into())
+				}
+				None => Err(Error::<T>::StakeEstateDoesNotExist.into()),
+			}
+		}
+
+		/// Withdraw unstaked token from unstaking queue. The unstaked amount will be unreserved and
+		/// become transferrable
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `round_index`: the round index that user can unstake.
+		///
+		/// Emit `UnstakedAmountWithdrew` event if successful
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::withdraw_unreserved())]
+		pub fn withdraw_unreserved(origin: OriginFor<T>, round_index: RoundIndex) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Get user exit queue
+			let exit_balance = ExitQueue::<T>::get(&who, round_index)
+				.ok_or(Error::<T>::ExitQueueDoesNotExit)?
+				.amount;
+
+			ExitQueue::<T>::remove(&who, round_index);
+			Self::release_self_stake(&who, exit_balance);
+
+			Self::deposit_event(Event::<T>::UnstakedAmountWithdrew(who, exit_balance));
+
+			Ok(().into())
+		}
+
+		/// Withdraw unstaked token from the innovation unstaking queue once its round is reached.
+		/// The unstaked amount will be unreserved and become transferrable
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `round_index`: the round index the unstaked amount unlocks at.
+		///
+		/// Emit `UnstakedAmountWithdrew` event if successful
+		#[pallet::call_index(108)]
+		#[pallet::weight(T::WeightInfo::withdraw_innovation_unreserved())]
+		pub fn withdraw_innovation_unreserved(
+			origin: OriginFor<T>,
+			round_index: RoundIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let exit_balance = InnovationStakingExitQueue::<T>::get(&who, round_index)
+				.ok_or(Error::<T>::InnovationExitQueueDoesNotExist)?
+				.amount;
+			ensure!(
+				round_index <= Self::current_round_info()?.current,
+				Error::<T>::InnovationExitQueueNotMatured
+			);
+
+			InnovationStakingExitQueue::<T>::remove(&who, round_index);
+			T::Currency::unreserve(&who, exit_balance);
+
+			Self::deposit_event(Event::<T>::UnstakedAmountWithdrew(who, exit_balance));
+
+			Ok(().into())
+		}
+
+		/// Withdraw unstaked token from estate unstaking queue. The unstaked amount will be
+		/// unreserved and become transferrable
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `round_index`: the round index that user can redeem.
+		/// `estate_id`: the estate id that user can redeem.
+		///
+		/// Emit `UnstakedAmountWithdrew` event if successful
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::withdraw_unreserved())]
+		pub fn withdraw_estate_unreserved(
+			origin: OriginFor<T>,
+			round_index: RoundIndex,
+			estate_id: EstateId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Get user exit queue
+			let exit_balance = EstateExitQueue::<T>::get((&who, round_index, estate_id))
+				.ok_or(Error::<T>::EstateExitQueueDoesNotExit)?
+				.amount;
+
+			EstateExitQueue::<T>::remove((&who, round_index, estate_id));
+			T::Currency::unreserve(&who, exit_balance);
+
+			Self::deposit_event(Event::<T>::UnstakedAmountWithdrew(who, exit_balance));
+
+			Ok(().into())
+		}
+
+		/// Force unstake native token from staking ledger. The unstaked amount able to redeem
+		/// immediately
+		///
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `amount`: the stake amount
+		/// `who`: the address of staker
+		///
+		/// Emit `SelfStakingRemovedFromEconomy101` event or `EstateStakingRemovedFromEconomy101`
+		/// event if successful
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			if estate.is_some() {
+				T::WeightInfo::unstake_b()
+			} else {
+				T::WeightInfo::unstake_a()
+			}
+		)]
+		pub fn force_unstake(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			who: T::AccountId,
+			estate: Option<EstateId>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			Self::do_force_unstake(who, amount, estate)?;
+
+			Ok(().into())
+		}
+
+		/// Move the staking position of a compromised or lost account to a replacement account,
+		/// including its exit queue entries and, for innovation staking, its shares, pending
+		/// rewards and conviction
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `from`: the account holding the position
+		/// `to`: the replacement account, which must not hold a position in the pool
+		/// `pool_kind`: the pool of the position
+		///
+		/// Emit `StakeForceTransferred` event if successful
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::force_transfer_stake())]
+		#[transactional]
+		pub fn force_transfer_stake(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			pool_kind: PoolKind,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(from != to, Error::<T>::StakeTransferTargetHasStake);
+			ensure!(
+				pool_kind != PoolKind::Economy101 || LockedSelfStake::<T>::get(&from).is_zero(),
+				Error::<T>::LockedStakeNotTransferable
+			);
+
+			let (staked_amount, exit_queue_amount) = match pool_kind {
+				PoolKind::Economy101 => Self::do_transfer_self_stake(&from, &to)?,
+				PoolKind::Innovation => Self::do_transfer_innovation_stake(&from, &to)?,
+			};
+
+			let leftover = T::Currency::repatriate_reserved(
+				&from,
+				&to,
+				staked_amount.saturating_add(exit_queue_amount),
+				BalanceStatus::Reserved,
+			)?;
+			ensure!(leftover.is_zero(), Error::<T>::NoStakeToTransfer);
+
+			Self::record_forced_action(
+				ForcedActionKind::TransferStake(pool_kind, to.clone()),
+				&from,
+				staked_amount.saturating_add(exit_queue_amount),
+			);
+			Self::deposit_event(Event::<T>::StakeForceTransferred(
+				from,
+				to,
+				pool_kind,
+				staked_amount,
+				exit_queue_amount,
+			));
+
+			Ok(())
+		}
+
+		/// Force unstake a batch of staking positions, e.g. for incident response or migrations.
+		/// The batch is reverted if any entry fails
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `entries`: the (staker, amount, estate) positions to unstake
+		///
+		/// Emit `SelfStakingRemovedFromEconomy101` event or `EstateStakingRemovedFromEconomy101`
+		/// event for each entry if successful
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::force_unstake_batch(entries.len() as u32))]
+		#[transactional]
+		pub fn force_unstake_batch(
+			origin: OriginFor<T>,
+			entries: BoundedVec<(T::AccountId, BalanceOf<T>, Option<EstateId>), ConstU32<MAX_FORCE_UNSTAKE_BATCH>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			for (who, amount, estate) in entries {
+				Self::do_force_unstake(who, amount, estate)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Force unreserved unstake native token from staking ledger. The unstaked amount able to
+		/// unreserve immediately
+		///
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `amount`: the stake amount
+		/// `who`: the address of staker
+		///
+		/// Emit `SelfStakingRemovedFromEconomy101` event if successful
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::unstake_b())]
+		pub fn force_unreserved_staking(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			// Ensure amount is greater than zero
+			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+
+			// Update staking info
+			let staked_reserved_balance = T::Currency::reserved_balance(&who);
+			ensure!(
+				amount <= staked_reserved_balance,
+				Error::<T>::UnstakeAmountExceedStakedAmount
+			);
+
+			T::Currency::unreserve(&who, amount);
+			Self::record_forced_action(ForcedActionKind::UnreservedStaking, &who, amount);
+
+			Ok(().into())
+		}
+
+		/// Credit the power accrued by economy 101 self-staking to the staker's power balance
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Emit `SelfStakingPowerCredited` event if successful
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::claim_staking_power())]
+		pub fn claim_staking_power(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::accrue_self_staking_power(&who);
+
+			Ok(())
+		}
+
+		/// Transfer power to another account
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `to`: the receiver of the power
+		/// `amount`: the power amount
+		///
+		/// Emit `PowerTransferred` event if successful
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::transfer_power())]
+		pub fn transfer_power(origin: OriginFor<T>, to: T::AccountId, amount: PowerAmount) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transfer_power(&who, &to, amount)
+		}
+
+		/// Allow a spender to transfer up to `amount` of power on behalf of the caller. Replaces
+		/// the current allowance of the spender
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `spender`: the account allowed to transfer power
+		/// `amount`: the power allowance
+		///
+		/// Emit `PowerApproved` event if successful
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::approve_power())]
+		pub fn approve_power(origin: OriginFor<T>, spender: T::AccountId, amount: PowerAmount) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_approve_power(&who, &spender, amount);
+
+			Ok(())
+		}
+
+		/// Transfer power on behalf of an owner within the allowance granted to the caller
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `owner`: the account the power is transferred from
+		/// `to`: the receiver of the power
+		/// `amount`: the power amount
+		///
+		/// Emit `PowerTransferred` event if successful
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::transfer_power_from())]
+		#[transactional]
+		pub fn transfer_power_from(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			to: T::AccountId,
+			amount: PowerAmount,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transfer_power_from(&who, &owner, &to, amount)
+		}
+
+		/// Set the power amount distributed to all economy 101 self-stakers every round
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `power_amount`: the power amount per round
+		///
+		/// Emit `SelfStakingPowerPerRoundUpdated` event if successful
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_self_staking_power_per_round())]
+		pub fn set_self_staking_power_per_round(origin: OriginFor<T>, power_amount: PowerAmount) -> DispatchResult {
+			ensure_root(origin)?;
+
+			SelfStakingPowerPerRound::<T>::put(power_amount);
+			Self::deposit_event(Event::<T>::SelfStakingPowerPerRoundUpdated(power_amount));
+
+			Ok(())
+		}
+
+		/// Set the staking preferences of the caller
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `preferences`: the new staking preferences
+		///
+		/// Emit `AccountPreferencesUpdated` event if successful
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_preferences())]
+		pub fn set_preferences(origin: OriginFor<T>, preferences: AccountPreference<T::AccountId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if preferences == AccountPreference::default() {
+				AccountPreferences::<T>::remove(&who);
+			} else {
+				AccountPreferences::<T>::insert(&who, preferences);
+			}
+
+			Self::deposit_event(Event::<T>::AccountPreferencesUpdated(who));
+
+			Ok(())
+		}
+
+		/// Set the innovation staking tiers. Stakers are re-evaluated at the next era
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `tiers`: the stake tiers ordered by strictly ascending threshold
+		///
+		/// Emit `StakeTiersUpdated` event if successful
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_stake_tiers(tiers.len() as u32))]
+		pub fn set_stake_tiers(
+			origin: OriginFor<T>,
+			tiers: BoundedVec<StakeTier<BalanceOf<T>>, T::MaxStakeTiers>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				tiers.windows(2).all(|pair| pair[0].threshold < pair[1].threshold),
+				Error::<T>::InvalidStakeTiers
+			);
+
+			let number_of_tiers = tiers.len() as u32;
+			StakeTiers::<T>::put(tiers);
+			StakeTiersChanged::<T>::put(true);
+
+			Self::deposit_event(Event::<T>::StakeTiersUpdated(number_of_tiers));
+
+			Ok(())
+		}
+
+		/// Set the stake weight boost tiers of large contiguous estates. Existing weighted estate
+		/// stakes pick up the boost once refreshed
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `tiers`: the boost tiers ordered by strictly ascending land units
+		///
+		/// Emit `EstateBoostTiersUpdated` event if successful
+		#[pallet::call_index(69)]
+		#[pallet::weight(T::WeightInfo::set_estate_boost_tiers(tiers.len() as u32))]
+		pub fn set_estate_boost_tiers(
+			origin: OriginFor<T>,
+			tiers: BoundedVec<EstateBoostTier, T::MaxStakeTiers>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				tiers
+					.windows(2)
+					.all(|pair| pair[0].min_land_units < pair[1].min_land_units),
+				Error::<T>::InvalidEstateBoostTiers
+			);
+
+			let number_of_tiers = tiers.len() as u32;
+			EstateBoostTiers::<T>::put(tiers);
+
+			Self::deposit_event(Event::<T>::EstateBoostTiersUpdated(number_of_tiers));
+
+			Ok(())
+		}
+
+		/// Set how the per-era reward budget is added to the reward pool
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `mode`: the reward distribution mode
+		///
+		/// Emit `RewardDistributionModeUpdated` event if successful
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_reward_distribution_mode())]
+		pub fn set_reward_distribution_mode(origin: OriginFor<T>, mode: RewardDistributionMode) -> DispatchResult {
+			ensure_root(origin)?;
+
+			DistributionMode::<T>::put(mode);
+			Self::deposit_event(Event::<T>::RewardDistributionModeUpdated(mode));
+
+			Ok(())
+		}
+
+		/// Create a bonded pool that stakes on innovation staking on behalf of its members. The
+		/// caller becomes the pool operator
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `commission`: the commission taken from pool rewards, bounded by `MaxPoolCommission`
+		///
+		/// Emit `BondedPoolCreated` event if successful
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::create_pool())]
+		pub fn create_pool(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			ensure!(
+				commission <= MaxPoolCommission::<T>::get(),
+				Error::<T>::PoolCommissionExceedsMaximum
+			);
+
+			let pool_id = NextBondedPoolId::<T>::try_mutate(|id| -> Result<PoolId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			BondedPools::<T>::insert(
+				pool_id,
+				BondedPool {
+					operator: operator.clone(),
+					commission,
+					points: Zero::zero(),
+					state: PoolState::Open,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::BondedPoolCreated(pool_id, operator));
+
+			Ok(())
+		}
+
+		/// Set the commission of a bonded pool
+		///
+		/// The dispatch origin for this call must be _Signed_ and the pool operator.
+		///
+		/// `pool_id`: the pool ID
+		/// `commission`: the new commission, bounded by `MaxPoolCommission`
+		///
+		/// Emit `BondedPoolCommissionUpdated` event if successful
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_pool_commission())]
+		#[transactional]
+		pub fn set_pool_commission(origin: OriginFor<T>, pool_id: PoolId, commission: Perbill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				commission <= MaxPoolCommission::<T>::get(),
+				Error::<T>::PoolCommissionExceedsMaximum
+			);
+
+			// Rewards accrued so far are settled with the previous commission
+			Self::compound_pool_reward(pool_id)?;
+
+			BondedPools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::BondedPoolDoesNotExist)?;
+				ensure!(pool.operator == who, Error::<T>::NotPoolOperator);
+				pool.commission = commission;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::BondedPoolCommissionUpdated(pool_id, commission));
+
+			Ok(())
+		}
+
+		/// Hand the operator role of a bonded pool over to another account
+		///
+		/// The dispatch origin for this call must be _Signed_ and the pool operator.
+		///
+		/// `pool_id`: the pool ID
+		/// `new_operator`: the new pool operator
+		///
+		/// Emit `BondedPoolOperatorNominated` event if successful
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::nominate_pool_operator())]
+		pub fn nominate_pool_operator(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			new_operator: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			BondedPools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::BondedPoolDoesNotExist)?;
+				ensure!(pool.operator == who, Error::<T>::NotPoolOperator);
+				pool.operator = new_operator.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::BondedPoolOperatorNominated(pool_id, new_operator));
+
+			Ok(())
+		}
+
+		/// Update the state of a bonded pool. `Open` and `Blocked` can be switched freely,
+		/// `Destroying` is final
+		///
+		/// The dispatch origin for this call must be _Signed_ and the pool operator.
+		///
+		/// `pool_id`: the pool ID
+		/// `state`: the new pool state
+		///
+		/// Emit `BondedPoolStateUpdated` event if successful
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_pool_state())]
+		pub fn set_pool_state(origin: OriginFor<T>, pool_id: PoolId, state: PoolState) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = BondedPools::<T>::try_mutate(
+				pool_id,
+				|maybe_pool| -> Result<BondedPool<T::AccountId, BalanceOf<T>>, DispatchError> {
+					let pool = maybe_pool.as_mut().ok_or(Error::<T>::BondedPoolDoesNotExist)?;
+					ensure!(pool.operator == who, Error::<T>::NotPoolOperator);
+					ensure!(
+						pool.state != PoolState::Destroying && pool.state != state,
+						Error::<T>::InvalidPoolStateTransition
+					);
+					pool.state = state;
+					Ok(pool.clone())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T>::BondedPoolStateUpdated(pool_id, state));
+			Self::try_remove_destroyed_pool(pool_id, &pool);
+
+			Ok(())
+		}
+
+		/// Set the maximum commission bonded pool operators can set
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `commission`: the maximum pool commission
+		///
+		/// Emit `MaxPoolCommissionUpdated` event if successful
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_max_pool_commission())]
+		pub fn set_max_pool_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			ensure_root(origin)?;
+
+			MaxPoolCommission::<T>::put(commission);
+			Self::deposit_event(Event::<T>::MaxPoolCommissionUpdated(commission));
+
+			Ok(())
+		}
+
+		/// Set the weights of self, estate and innovation stake in the stake weight provided to
+		/// governance
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `weights`: the stake weight of each staking category
+		///
+		/// Emit `StakeWeightsUpdated` event if successful
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::set_stake_weights())]
+		pub fn set_stake_weights(origin: OriginFor<T>, weights: StakeWeight) -> DispatchResult {
+			ensure_root(origin)?;
+
+			StakeWeights::<T>::put(weights.clone());
+			Self::deposit_event(Event::<T>::StakeWeightsUpdated(weights));
+
+			Ok(())
+		}
+
+		/// Set the reward weight of an estate trait value. Estate stake is weighted by the product
+		/// of the weights of the estate traits
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `trait_kind`: the estate trait
+		/// `trait_value`: the trait value
+		/// `weight`: the reward weight, `None` to weight the trait value one
+		///
+		/// Emit `EstateTraitRewardWeightUpdated` event if successful
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::set_estate_trait_reward_weight())]
+		pub fn set_estate_trait_reward_weight(
+			origin: OriginFor<T>,
+			trait_kind: EstateTraitKind,
+			trait_value: u8,
+			weight: Option<FixedU128>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			EstateTraitRewardWeights::<T>::set(trait_kind, trait_value, weight);
+			Self::deposit_event(Event::<T>::EstateTraitRewardWeightUpdated(
+				trait_kind,
+				trait_value,
+				weight,
+			));
+
+			Ok(())
+		}
+
+		/// Recompute the weighted estate stake of a staker after the estate traits or the reward
+		/// weights changed
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `estate_id`: the estate
+		/// `account`: the estate staker
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::refresh_weighted_estate_stake())]
+		pub fn refresh_weighted_estate_stake(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::sync_weighted_estate_stake(estate_id, &account);
+
+			Ok(())
+		}
+
+		/// Approve an account to co-stake on an estate up to `cap`. Approving again updates the cap
+		///
+		/// The dispatch origin for this call must be _Signed_ by the estate owner.
+		///
+		/// `estate_id`: the estate
+		/// `account`: the co-staker
+		/// `cap`: the maximum stake of the co-staker on the estate
+		///
+		/// Emit `EstateCoStakerApproved` event if successful
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::approve_estate_co_staker())]
+		pub fn approve_estate_co_staker(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			account: T::AccountId,
+			cap: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::EstateHandler::check_estate_ownership(who.clone(), estate_id)?,
+				Error::<T>::StakerNotEstateOwner
+			);
+			ensure!(account != who, Error::<T>::StakerNotEstateOwner);
+
+			EstateCoStakerCaps::<T>::insert(estate_id, &account, cap);
+			Self::deposit_event(Event::<T>::EstateCoStakerApproved(estate_id, account, cap));
+
+			Ok(())
+		}
+
+		/// Revoke the co-staking approval of an account. Its existing co-stake can still be
+		/// unstaked
+		///
+		/// The dispatch origin for this call must be _Signed_ by the estate owner.
+		///
+		/// `estate_id`: the estate
+		/// `account`: the co-staker
+		///
+		/// Emit `EstateCoStakerRevoked` event if successful
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::revoke_estate_co_staker())]
+		pub fn revoke_estate_co_staker(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::EstateHandler::check_estate_ownership(who, estate_id)?,
+				Error::<T>::StakerNotEstateOwner
+			);
+
+			EstateCoStakerCaps::<T>::remove(estate_id, &account);
+			Self::deposit_event(Event::<T>::EstateCoStakerRevoked(estate_id, account));
+
+			Ok(())
+		}
+
+		/// Set the maximum estate stake per account, summed across all estates, and the maximum
+		/// total estate stake. `None` removes the limit
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `max_per_account`: the maximum estate stake per account
+		/// `max_total`: the maximum total estate stake
+		///
+		/// Emit `EstateStakeLimitsUpdated` event if successful
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::set_estate_stake_limits())]
+		pub fn set_estate_stake_limits(
+			origin: OriginFor<T>,
+			max_per_account: Option<BalanceOf<T>>,
+			max_total: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			MaxEstateStakePerAccount::<T>::set(max_per_account);
+			MaxTotalEstateStake::<T>::set(max_total);
+			Self::deposit_event(Event::<T>::EstateStakeLimitsUpdated(max_per_account, max_total));
+
+			Ok(())
+		}
+
+		/// Set the BIT to power exchange rate and the rounding applied when converting power to BIT
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `rate`: BIT paid per unit of power
+		/// `rounding`: rounding applied to converted amounts
+		///
+		/// Emit `BitPowerExchangeRateUpdated` event if successful
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::set_bit_power_exchange_rate())]
+		pub fn set_bit_power_exchange_rate(
+			origin: OriginFor<T>,
+			rate: FixedU128,
+			rounding: ConversionRounding,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let old_rate = Self::get_bit_power_exchange_rate();
+			BitPowerExchangeRateHistory::<T>::mutate(|history| {
+				if history.len() >= MAX_EXCHANGE_RATE_HISTORY {
+					history.remove(0);
+				}
+				history.push(ExchangeRateUpdate {
+					block: <frame_system::Pallet<T>>::block_number(),
+					old_rate,
+					new_rate: rate,
+				});
+			});
+			BitPowerExchangeRate::<T>::put(rate);
+			BitPowerConversionRounding::<T>::put(rounding);
+			Self::deposit_event(Event::<T>::BitPowerExchangeRateUpdated(rate, rounding));
+
+			Ok(())
+		}
+
+		/// Set the share of transaction fees and tips routed into the innovation staking reward
+		/// pool by the `FeeSharing` handler
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `ratio`: the fee share ratio
+		///
+		/// Emit `FeeShareRatioUpdated` event if successful
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_fee_share_ratio())]
+		pub fn set_fee_share_ratio(origin: OriginFor<T>, ratio: Perbill) -> DispatchResult {
+			ensure_root(origin)?;
+
+			FeeShareRatio::<T>::put(ratio);
+			Self::deposit_event(Event::<T>::FeeShareRatioUpdated(ratio));
+
+			Ok(())
+		}
+
+		/// Join a bonded pool. The pool stakes the amount on innovation staking and the member
+		/// receives pool points, so amounts below `MinimumStake` can earn rewards
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `pool_id`: the pool to join
+		/// `amount`: the amount to bond
+		///
+		/// Emit `BondedPoolJoined` event if successful
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::join_pool())]
+		#[transactional]
+		pub fn join_pool(origin: OriginFor<T>, pool_id: PoolId, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(amount >= T::ExistentialStake::get(), Error::<T>::StakeBelowMinimum);
+			ensure!(
+				T::Currency::free_balance(&who) >= amount,
+				Error::<T>::InsufficientBalanceForStaking
+			);
+			Self::ensure_keep_alive(&who, amount)?;
+			if let Some(member) = PoolMembers::<T>::get(&who) {
+				ensure!(member.pool_id == pool_id, Error::<T>::AlreadyPoolMember);
+			}
+
+			Self::compound_pool_reward(pool_id)?;
+
+			let mut pool = BondedPools::<T>::get(pool_id).ok_or(Error::<T>::BondedPoolDoesNotExist)?;
+			ensure!(pool.state == PoolState::Open, Error::<T>::BondedPoolNotOpen);
+			let pool_account = Self::bonded_pool_account_id(pool_id);
+			let pool_stake = InnovationStakingInfo::<T>::get(&pool_account);
+
+			let points = if pool.points.is_zero() || pool_stake.is_zero() {
+				amount
+			} else {
+				Self::convert_pool_balance(amount, pool.points, pool_stake)
+			};
+
+			T::Currency::transfer(&who, &pool_account, amount, ExistenceRequirement::AllowDeath)?;
+			Self::bond_innovation_stake(&pool_account, amount)?;
+
+			pool.points = pool.points.saturating_add(points);
+			BondedPools::<T>::insert(pool_id, pool);
+			PoolMembers::<T>::mutate(&who, |maybe_member| {
+				let member = maybe_member.get_or_insert(PoolMember {
+					pool_id,
+					points: Zero::zero(),
+				});
+				member.points = member.points.saturating_add(points);
+			});
+
+			Self::deposit_event(Event::<T>::BondedPoolJoined(who, pool_id, amount, points));
+
+			Ok(())
+		}
+
+		/// Unbond points from the bonded pool of the caller. The unbonded amount is queued in the
+		/// innovation exit queue of the member and can be withdrawn with
+		/// `withdraw_innovation_unreserved` after `INNOVATION_UNBOND_ROUNDS`
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `points`: the pool points to unbond
+		///
+		/// Emit `BondedPoolUnbonded` event if successful
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::unbond_from_pool())]
+		#[transactional]
+		pub fn unbond_from_pool(origin: OriginFor<T>, points: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut member = PoolMembers::<T>::get(&who).ok_or(Error::<T>::NotPoolMember)?;
+			ensure!(!points.is_zero(), Error::<T>::UnstakeAmountIsZero);
+			ensure!(points <= member.points, Error::<T>::InsufficientPoolPoints);
+
+			let pool_id = member.pool_id;
+			Self::compound_pool_reward(pool_id)?;
+
+			let mut pool = BondedPools::<T>::get(pool_id).ok_or(Error::<T>::BondedPoolDoesNotExist)?;
+			let pool_account = Self::bonded_pool_account_id(pool_id);
+			let pool_stake = InnovationStakingInfo::<T>::get(&pool_account);
+
+			let amount = Self::convert_pool_balance(points, pool_stake, pool.points);
+
+			Self::checkpoint_voting_points(&pool_account);
+			// Move the member share of the pool stake out of innovation staking
+			let remaining = pool_stake.saturating_sub(amount);
+			if remaining.is_zero() {
+				InnovationStakingInfo::<T>::remove(&pool_account);
+			} else {
+				InnovationStakingInfo::<T>::insert(&pool_account, remaining);
+			}
+			let new_total_staked = TotalInnovationStaking::<T>::get().saturating_sub(amount);
+			<TotalInnovationStaking<T>>::put(new_total_staked);
+			Self::remove_share(&pool_account, amount);
+
+			T::Currency::unreserve(&pool_account, amount);
+			T::Currency::transfer(&pool_account, &who, amount, ExistenceRequirement::AllowDeath)?;
+			T::Currency::reserve(&who, amount)?;
+
+			// Pooled stake is innovation stake and unbonds over the innovation unbond period
+			let unbond_round = Self::current_round_info()?
+				.current
+				.saturating_add(INNOVATION_UNBOND_ROUNDS);
+			Self::ensure_exit_queue_capacity(&who, ExitQueueKind::Innovation, unbond_round)?;
+			Self::queue_exit(&who, ExitQueueKind::Innovation, unbond_round, amount);
+
+			pool.points = pool.points.saturating_sub(points);
+			BondedPools::<T>::insert(pool_id, pool.clone());
+			member.points = member.points.saturating_sub(points);
+			if member.points.is_zero() {
+				PoolMembers::<T>::remove(&who);
+			} else {
+				PoolMembers::<T>::insert(&who, member);
+			}
+
+			Self::deposit_event(Event::<T>::BondedPoolUnbonded(who, pool_id, points, amount));
+			Self::try_remove_destroyed_pool(pool_id, &pool);
+
+			Ok(())
+		}
+
+		/// Create a reward campaign adding its budget to the innovation staking reward pool
+		/// between `start` and `end`. Governance campaigns are funded by the economy treasury,
+		/// other campaigns are funded by the caller who also reserves `RewardCampaignDeposit`
+		///
+		/// The dispatch origin for this call must be _Signed_ or _Root_.
+		///
+		/// `currency_id`: the reward currency
+		/// `budget`: the total campaign budget
+		/// `start`: the first block the campaign is active
+		/// `end`: the block the campaign expires
+		/// `eligibility`: the stakers eligible for the campaign rewards
+		///
+		/// Emit `RewardCampaignCreated` event if successful
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::create_reward_campaign())]
+		#[transactional]
+		pub fn create_reward_campaign(
+			origin: OriginFor<T>,
+			currency_id: FungibleTokenId,
+			budget: BalanceOf<T>,
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+			eligibility: CampaignEligibility,
+		) -> DispatchResult {
+			let (creator, deposit) = match ensure_signed_or_root(origin)? {
+				Some(who) => (who, T::RewardCampaignDeposit::get()),
+				None => (Self::economy_pallet_account_id(), Zero::zero()),
+			};
+
+			Self::do_create_reward_campaign(creator, deposit, currency_id, budget, start, end, eligibility)?;
+
+			Ok(())
+		}
+
+		/// Refund the leftover budget and deposit of an expired reward campaign to its creator
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `campaign_id`: the expired campaign
+		///
+		/// Emit `RewardCampaignRefunded` event if successful
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::refund_reward_campaign())]
+		#[transactional]
+		pub fn refund_reward_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			Self::do_refund_reward_campaign(campaign_id)
+		}
+
+		/// Set the share of bonded pool commissions accrued to the economy treasury
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `share`: the protocol commission share
+		///
+		/// Emit `ProtocolCommissionShareUpdated` event if successful
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::set_protocol_commission_share())]
+		pub fn set_protocol_commission_share(origin: OriginFor<T>, share: Perbill) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ProtocolCommissionShare::<T>::put(share);
+			Self::deposit_event(Event::<T>::ProtocolCommissionShareUpdated(share));
+
+			Ok(())
+		}
+
+		/// Set the split of penalties and slashes. The insurance fund receives the remainder
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `split`: the reward pool and treasury shares
+		///
+		/// Emit `PenaltySplitUpdated` event if successful
+		#[pallet::call_index(62)]
+		#[pallet::weight(T::WeightInfo::set_penalty_split())]
+		pub fn set_penalty_split(origin: OriginFor<T>, split: PenaltySplit) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				split
+					.reward_pool
+					.deconstruct()
+					.saturating_add(split.treasury.deconstruct())
+					<= Perbill::one().deconstruct(),
+				Error::<T>::PenaltySplitExceedsTotal
+			);
+
+			PenaltySplitConfig::<T>::put(split.clone());
+			Self::deposit_event(Event::<T>::PenaltySplitUpdated(split));
+
+			Ok(())
+		}
+
+		/// Propose spending protocol income held by the economy treasury. The proposal deposit is
+		/// reserved until the proposal is approved or rejected
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `currency_id`: the currency spent
+		/// `amount`: the amount spent
+		/// `target`: the beneficiary or the reward campaign funded
+		///
+		/// Emit `EconomySpendProposed` event if successful
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::propose_economy_spend())]
+		pub fn propose_economy_spend(
+			origin: OriginFor<T>,
+			currency_id: FungibleTokenId,
+			amount: BalanceOf<T>,
+			target: EconomySpendTarget<T::AccountId, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::EconomySpendAmountIsZero);
+			let open_spends = OpenEconomySpends::<T>::get();
+			ensure!(
+				open_spends < T::MaxOpenEconomySpends::get(),
+				Error::<T>::TooManyOpenEconomySpends
+			);
+
+			let deposit = T::EconomySpendDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			let spend_id = NextEconomySpendId::<T>::try_mutate(|id| -> Result<EconomySpendId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			EconomySpendProposals::<T>::insert(
+				spend_id,
+				EconomySpendProposal {
+					proposer: who.clone(),
+					currency_id,
+					amount,
+					target,
+					deposit,
+				},
+			);
+			OpenEconomySpends::<T>::put(open_spends.saturating_add(1));
+			Self::deposit_event(Event::<T>::EconomySpendProposed(spend_id, who, currency_id, amount));
+
+			Ok(())
+		}
+
+		/// Approve and execute an economy treasury spending proposal. The proposal deposit is
+		/// returned to the proposer
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// `spend_id`: the spending proposal
+		///
+		/// Emit `EconomySpendApproved` event if successful
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::approve_economy_spend())]
+		#[transactional]
+		pub fn approve_economy_spend(origin: OriginFor<T>, spend_id: EconomySpendId) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+
+			let proposal = EconomySpendProposals::<T>::take(spend_id).ok_or(Error::<T>::EconomySpendDoesNotExist)?;
+			OpenEconomySpends::<T>::mutate(|open_spends| *open_spends = open_spends.saturating_sub(1));
+			T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+			let economy_treasury = Self::economy_pallet_account_id();
+
+			match proposal.target {
+				EconomySpendTarget::Beneficiary(beneficiary) => {
+					T::FungibleTokenCurrency::transfer(
+						proposal.currency_id,
+						&economy_treasury,
+						&beneficiary,
+						proposal.amount,
+					)?;
+				}
+				EconomySpendTarget::RewardCampaign { start, end } => {
+					Self::do_create_reward_campaign(
+						economy_treasury,
+						Zero::zero(),
+						proposal.currency_id,
+						proposal.amount,
+						start,
+						end,
+						CampaignEligibility::AllInnovationStakers,
+					)?;
+				}
+			}
+
+			Self::deposit_event(Event::<T>::EconomySpendApproved(spend_id));
+
+			Ok(())
+		}
+
+		/// Reject an economy treasury spending proposal. The proposal deposit is forfeited as a
+		/// penalty
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// `spend_id`: the spending proposal
+		///
+		/// Emit `EconomySpendRejected` event if successful
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::reject_economy_spend())]
+		pub fn reject_economy_spend(origin: OriginFor<T>, spend_id: EconomySpendId) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+
+			let proposal = EconomySpendProposals::<T>::take(spend_id).ok_or(Error::<T>::EconomySpendDoesNotExist)?;
+			OpenEconomySpends::<T>::mutate(|open_spends| *open_spends = open_spends.saturating_sub(1));
+			Self::redistribute_penalty(&proposal.proposer, proposal.deposit, true)?;
+			Self::deposit_event(Event::<T>::EconomySpendRejected(spend_id));
+
+			Ok(())
+		}
+
+		/// Report an offence such as reward pool manipulation. The report deposit is reserved
+		/// until the report is judged
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `offender`: the account accused of the offence
+		/// `proof`: the evidence of the offence
+		///
+		/// Emit `OffenceReported` event if successful
+		#[pallet::call_index(59)]
+		#[pallet::weight(T::WeightInfo::report_offence())]
+		pub fn report_offence(
+			origin: OriginFor<T>,
+			offender: T::AccountId,
+			proof: BoundedVec<u8, ConstU32<MAX_OFFENCE_PROOF_LENGTH>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != offender, Error::<T>::SelfOffenceReport);
+
+			let deposit = T::OffenceReportDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			let report_id = NextOffenceReportId::<T>::try_mutate(|id| -> Result<OffenceReportId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			OffenceReports::<T>::insert(
+				report_id,
+				OffenceReport {
+					reporter: who.clone(),
+					offender: offender.clone(),
+					proof: proof.into_inner(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::<T>::OffenceReported(report_id, who, offender));
+
+			Ok(())
+		}
+
+		/// Confirm an offence report. The offender stake is slashed by up to `slash_amount`, the
+		/// reporter receives `OffenceReporterReward` of the slash and the remainder is
+		/// redistributed as a penalty
+		///
+		/// The dispatch origin for this call must be `OffenceJudge`.
+		///
+		/// `report_id`: the offence report
+		/// `slash_amount`: the amount slashed from the offender stake
+		///
+		/// Emit `OffenceConfirmed` event if successful
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::confirm_offence())]
+		#[transactional]
+		pub fn confirm_offence(
+			origin: OriginFor<T>,
+			report_id: OffenceReportId,
+			slash_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::OffenceJudge::ensure_origin(origin)?;
+
+			let report = OffenceReports::<T>::take(report_id).ok_or(Error::<T>::OffenceReportDoesNotExist)?;
+			T::Currency::unreserve(&report.reporter, report.deposit);
+
+			let slashed = Self::slash_stake(&report.offender, slash_amount);
+			let reporter_reward = T::OffenceReporterReward::get() * slashed;
+			T::Currency::repatriate_reserved(&report.offender, &report.reporter, reporter_reward, BalanceStatus::Free)?;
+			Self::redistribute_penalty(&report.offender, slashed.saturating_sub(reporter_reward), true)?;
+
+			Self::deposit_event(Event::<T>::OffenceConfirmed(
+				report_id,
+				report.offender,
+				slashed,
+				reporter_reward,
+			));
+
+			Ok(())
+		}
+
+		/// Reject an offence report. The reporter deposit is forfeited as a penalty
+		///
+		/// The dispatch origin for this call must be `OffenceJudge`.
+		///
+		/// `report_id`: the offence report
+		///
+		/// Emit `OffenceRejected` event if successful
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::reject_offence())]
+		#[transactional]
+		pub fn reject_offence(origin: OriginFor<T>, report_id: OffenceReportId) -> DispatchResult {
+			T::OffenceJudge::ensure_origin(origin)?;
+
+			let report = OffenceReports::<T>::take(report_id).ok_or(Error::<T>::OffenceReportDoesNotExist)?;
+			Self::redistribute_penalty(&report.reporter, report.deposit, true)?;
+
+			Self::deposit_event(Event::<T>::OffenceRejected(report_id));
+
+			Ok(())
+		}
+
+		/// Pay a gameplay mission reward from the mission pot
+		///
+		/// The dispatch origin for this call must be `MissionRewardOrigin`.
+		///
+		/// `account`: the rewarded account
+		/// `currency_id`: the reward currency
+		/// `amount`: the reward amount
+		/// `mission_id`: the mission the reward is accounted to
+		///
+		/// Emit `MissionRewardDistributed` event if successful
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::distribute_mission_reward())]
+		#[transactional]
+		pub fn distribute_mission_reward(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			currency_id: FungibleTokenId,
+			amount: BalanceOf<T>,
+			mission_id: MissionId,
+		) -> DispatchResult {
+			T::MissionRewardOrigin::ensure_origin(origin)?;
+
+			Self::do_distribute_mission_reward(&account, currency_id, amount, mission_id)
+		}
+
+		/// Set the NFT class innovation staking position receipts are minted in
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `class_id`: the receipt NFT class
+		///
+		/// Emit `PositionReceiptClassUpdated` event if successful
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::set_position_receipt_class())]
+		pub fn set_position_receipt_class(origin: OriginFor<T>, class_id: ClassId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			PositionReceiptClass::<T>::put(class_id);
+			Self::deposit_event(Event::<T>::PositionReceiptClassUpdated(class_id));
+
+			Ok(())
+		}
+
+		/// Claim the rewards of an innovation staking position as the receipt owner
+		///
+		/// The dispatch origin for this call must be _Signed_ and the receipt owner.
+		///
+		/// `position`: the position receipt NFT
+		///
+		/// Emit `ClaimRewardsTo` event if successful
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::claim_position_reward())]
+		#[transactional]
+		pub fn claim_position_reward(origin: OriginFor<T>, position: (ClassId, TokenId)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				StakingPositions::<T>::contains_key(position),
+				Error::<T>::StakingPositionDoesNotExist
+			);
+			ensure!(
+				T::NFTHandler::check_ownership(&who, &position)?,
+				Error::<T>::NotPositionReceiptOwner
+			);
+
+			Self::do_claim_reward(&Self::position_account_id(position), &who);
+
+			Ok(())
+		}
+
+		/// Unstake an innovation staking position as the receipt owner. The receipt is burnt and
+		/// the amount is queued in the innovation exit queue of the owner, withdrawable with
+		/// `withdraw_innovation_unreserved` after `INNOVATION_UNBOND_ROUNDS`
+		///
+		/// The dispatch origin for this call must be _Signed_ and the receipt owner.
+		///
+		/// `position`: the position receipt NFT
+		///
+		/// Emit `PositionReceiptUnstaked` event if successful
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::unstake_position())]
+		#[transactional]
+		pub fn unstake_position(origin: OriginFor<T>, position: (ClassId, TokenId)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount = StakingPositions::<T>::get(position).ok_or(Error::<T>::StakingPositionDoesNotExist)?;
+			ensure!(
+				T::NFTHandler::check_ownership(&who, &position)?,
+				Error::<T>::NotPositionReceiptOwner
+			);
+
+			let position_account = Self::position_account_id(position);
+
+			Self::checkpoint_voting_points(&position_account);
+			InnovationStakingInfo::<T>::remove(&position_account);
+			let new_total_staked = TotalInnovationStaking::<T>::get().saturating_sub(amount);
+			<TotalInnovationStaking<T>>::put(new_total_staked);
+			Self::remove_share(&position_account, amount);
+			// Reward rights follow the receipt
+			Self::flush_reward(&position_account, &who);
+
+			T::Currency::unreserve(&position_account, amount);
+			T::Currency::transfer(&position_account, &who, amount, ExistenceRequirement::AllowDeath)?;
+			T::Currency::reserve(&who, amount)?;
+
+			// Position stake is innovation stake and unbonds over the innovation unbond period
+			let unbond_round = Self::current_round_info()?
+				.current
+				.saturating_add(INNOVATION_UNBOND_ROUNDS);
+			Self::ensure_exit_queue_capacity(&who, ExitQueueKind::Innovation, unbond_round)?;
+			Self::queue_exit(&who, ExitQueueKind::Innovation, unbond_round, amount);
+
+			StakingPositions::<T>::remove(position);
+			T::NFTHandler::burn_nft(&who, &position)?;
+
+			Self::deposit_event(Event::<T>::PositionReceiptUnstaked(who, position, amount));
+
+			Ok(())
+		}
+
+		/// Move part of an innovation staking position to a new position receipt
+		///
+		/// The dispatch origin for this call must be _Signed_ and the receipt owner.
+		///
+		/// `position`: the position receipt NFT
+		/// `amount`: the stake moved to the new position
+		///
+		/// Emit `PositionSplit` event if successful
+		#[pallet::call_index(83)]
+		#[pallet::weight(T::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(
+			origin: OriginFor<T>,
+			position: (ClassId, TokenId),
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let staked_amount = StakingPositions::<T>::get(position).ok_or(Error::<T>::StakingPositionDoesNotExist)?;
+			ensure!(
+				T::NFTHandler::check_ownership(&who, &position)?,
+				Error::<T>::NotPositionReceiptOwner
+			);
+			ensure!(
+				amount >= T::MinimumStake::get() && staked_amount.saturating_sub(amount) >= T::MinimumStake::get(),
+				Error::<T>::InvalidPositionSplitAmount
+			);
+
+			let mut attributes: Attributes = BTreeMap::new();
+			attributes.insert(b"staked_amount".to_vec(), amount.encode());
+			attributes.insert(b"staked_round".to_vec(), Self::current_round_info()?.current.encode());
+			let token_id =
+				T::NFTHandler::mint_token(&who, position.0, b"innovation staking position".to_vec(), attributes)?;
+			let new_position = (position.0, token_id);
+
+			Self::move_position_stake(
+				&Self::position_account_id(position),
+				&Self::position_account_id(new_position),
+				amount,
+			)?;
+			StakingPositions::<T>::insert(position, staked_amount.saturating_sub(amount));
+			StakingPositions::<T>::insert(new_position, amount);
+
+			Self::deposit_event(Event::<T>::PositionSplit(who, position, new_position, amount));
+
+			Ok(())
+		}
+
+		/// Merge innovation staking positions into the first one, burning the other receipts
+		///
+		/// The dispatch origin for this call must be _Signed_ and the owner of all receipts.
+		///
+		/// `positions`: the position receipt NFTs, merged into the first one
+		///
+		/// Emit `PositionsMerged` event if successful
+		#[pallet::call_index(84)]
+		#[pallet::weight(T::WeightInfo::merge_positions(positions.len() as u32))]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			positions: BoundedVec<(ClassId, TokenId), ConstU32<MAX_MERGE_POSITIONS>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (target, merged) = positions.split_first().ok_or(Error::<T>::InvalidPositionsToMerge)?;
+			let unique_positions: BTreeSet<(ClassId, TokenId)> = positions.iter().copied().collect();
+			ensure!(
+				!merged.is_empty() && unique_positions.len() == positions.len(),
+				Error::<T>::InvalidPositionsToMerge
+			);
+
+			let mut total = StakingPositions::<T>::get(target).ok_or(Error::<T>::StakingPositionDoesNotExist)?;
+			ensure!(
+				T::NFTHandler::check_ownership(&who, target)?,
+				Error::<T>::NotPositionReceiptOwner
+			);
+			let target_account = Self::position_account_id(*target);
+
+			for position in merged.iter() {
+				let amount = StakingPositions::<T>::take(position).ok_or(Error::<T>::StakingPositionDoesNotExist)?;
+				ensure!(
+					T::NFTHandler::check_ownership(&who, position)?,
+					Error::<T>::NotPositionReceiptOwner
+				);
+
+				Self::move_position_stake(&Self::position_account_id(*position), &target_account, amount)?;
+				T::NFTHandler::burn_nft(&who, position)?;
+				total = total.saturating_add(amount);
+			}
+			StakingPositions::<T>::insert(target, total);
+
+			Self::deposit_event(Event::<T>::PositionsMerged(who, *target, merged.to_vec()));
+
+			Ok(())
+		}
+
+		/// Compensate a staker from the insurance fund after an incident
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `who`: the staker to compensate
+		/// `amount`: the compensation amount
+		///
+		/// Emit `InsuranceCompensationPaid` event if successful
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::compensate_from_insurance_fund())]
+		#[transactional]
+		pub fn compensate_from_insurance_fund(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				Self::insurance_fund_balance() >= amount,
+				Error::<T>::InsufficientInsuranceFund
+			);
+
+			T::Currency::transfer(
+				&Self::insurance_fund_account_id(),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::<T>::InsuranceCompensationPaid(who, amount));
+
+			Ok(())
+		}
+
+		/// This function only for governance origin to execute when starting the protocol or
+		/// changes of era duration. The new frequency and reward rate are applied at
+		/// `activation_block` if given, replacing any change still waiting for its activation.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_config())]
+		pub fn update_era_config(
+			origin: OriginFor<T>,
+			last_era_updated_block: Option<BlockNumberFor<T>>,
+			frequency: Option<BlockNumberFor<T>>,
+			estimated_reward_rate_per_era: Option<EraRewardBudget<BalanceOf<T>>>,
+			activation_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let _ = ensure_root(origin)?;
+
+			match activation_block {
+				Some(activation_block) => {
+					ensure!(
+						activation_block > <frame_system::Pallet<T>>::block_number(),
+						Error::<T>::InvalidEraConfigActivationBlock
+					);
+					PendingEraConfig::<T>::put(ScheduledEraConfig {
+						activation_block,
+						frequency,
+						reward_per_era: estimated_reward_rate_per_era,
+					});
+					Self::deposit_event(Event::<T>::EraConfigUpdateScheduled(activation_block));
+				}
+				None => Self::apply_era_config(frequency, None),
+			}
+
+			if let Some(change) = last_era_updated_block {
+				let update_era_frequency = UpdateEraFrequency::<T>::get();
+				let current_block = <frame_system::Pallet<T>>::block_number();
+				if !update_era_frequency.is_zero() {
+					ensure!(
+						change > current_block.saturating_sub(update_era_frequency) && change <= current_block,
+						Error::<T>::InvalidLastEraUpdatedBlock
+					);
+
+					LastEraUpdatedBlock::<T>::put(change);
+					Self::deposit_event(Event::<T>::LastInnovationStakingEraUpdated(change));
+				}
+			}
+
+			if activation_block.is_none() {
+				Self::apply_era_config(None, estimated_reward_rate_per_era);
+			}
+			Ok(())
+		}
+
+		/// Register an innovation project to be curated by directed innovation stake. The deposit
+		/// is reserved until the project is deregistered
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `metadata_cid`: the content id of the project metadata
+		/// `deposit`: the reserved deposit, at least `MinimumProjectDeposit`
+		///
+		/// Emit `InnovationProjectRegistered` event if successful
+		#[pallet::call_index(63)]
+		#[pallet::weight(T::WeightInfo::register_innovation_project())]
+		pub fn register_innovation_project(
+			origin: OriginFor<T>,
+			metadata_cid: BoundedVec<u8, ConstU32<MAX_PROJECT_METADATA_CID_LENGTH>>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				deposit >= T::MinimumProjectDeposit::get(),
+				Error::<T>::ProjectDepositBelowMinimum
+			);
+
+			T::Currency::reserve(&who, deposit)?;
+
+			let project_id =
+				NextInnovationProjectId::<T>::try_mutate(|id| -> Result<InnovationProjectId, DispatchError> {
+					let current_id = *id;
+					*id = id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+					Ok(current_id)
+				})?;
+
+			InnovationProjects::<T>::insert(
+				project_id,
+				InnovationProject {
+					owner: who.clone(),
+					metadata_cid: metadata_cid.into_inner(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::<T>::InnovationProjectRegistered(project_id, who, deposit));
+
+			Ok(())
+		}
+
+		/// Deregister an innovation project and return its deposit. Stake directed at the project
+		/// stops counting and can be withdrawn by the stakers
+		///
+		/// The dispatch origin for this call must be _Signed_ by the project owner.
+		///
+		/// `project_id`: the innovation project
+		///
+		/// Emit `InnovationProjectDeregistered` event if successful
+		#[pallet::call_index(64)]
+		#[pallet::weight(T::WeightInfo::deregister_innovation_project())]
+		#[transactional]
+		pub fn deregister_innovation_project(origin: OriginFor<T>, project_id: InnovationProjectId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let project = InnovationProjects::<T>::get(project_id).ok_or(Error::<T>::InnovationProjectDoesNotExist)?;
+			ensure!(project.owner == who, Error::<T>::NotInnovationProjectOwner);
+
+			T::Currency::unreserve(&who, project.deposit);
+			InnovationProjects::<T>::remove(project_id);
+			InnovationProjectStake::<T>::remove(project_id);
+
+			// Unclaimed matching returns to the treasury
+			let matched = ProjectMatchingPools::<T>::take(project_id);
+			if !matched.is_zero() {
+				T::Currency::transfer(
+					&Self::project_pool_account_id(project_id),
+					&Self::economy_pallet_account_id(),
+					matched,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+
+			Self::deposit_event(Event::<T>::InnovationProjectDeregistered(project_id));
+
+			Ok(())
+		}
+
+		/// Direct part of the caller's innovation stake at a project to rank it. The stake keeps
+		/// earning rewards, directing only adds curation weight to the project
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `project_id`: the innovation project
+		/// `amount`: the innovation stake directed at the project
+		///
+		/// Emit `InnovationStakeDirected` event if successful
+		#[pallet::call_index(65)]
+		#[pallet::weight(T::WeightInfo::direct_innovation_stake())]
+		pub fn direct_innovation_stake(
+			origin: OriginFor<T>,
+			project_id: InnovationProjectId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				InnovationProjects::<T>::contains_key(project_id),
+				Error::<T>::InnovationProjectDoesNotExist
+			);
+
+			let directed = AccountDirectedStake::<T>::get(&who)
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				directed <= InnovationStakingInfo::<T>::get(&who),
+				Error::<T>::DirectedStakeExceedsInnovationStake
+			);
+
+			AccountDirectedStake::<T>::insert(&who, directed);
+			DirectedProjectStakes::<T>::mutate(&who, project_id, |stake| *stake = stake.saturating_add(amount));
+			InnovationProjectStake::<T>::mutate(project_id, |stake| *stake = stake.saturating_add(amount));
+
+			Self::deposit_event(Event::<T>::InnovationStakeDirected(who, project_id, amount));
+
+			Ok(())
+		}
+
+		/// Withdraw innovation stake directed at a project. Works for deregistered projects too
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `project_id`: the innovation project
+		/// `amount`: the directed stake to withdraw
+		///
+		/// Emit `DirectedStakeWithdrawn` event if successful
+		#[pallet::call_index(66)]
+		#[pallet::weight(T::WeightInfo::withdraw_directed_stake())]
+		pub fn withdraw_directed_stake(
+			origin: OriginFor<T>,
+			project_id: InnovationProjectId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let directed = DirectedProjectStakes::<T>::get(&who, project_id);
+			ensure!(amount <= directed, Error::<T>::WithdrawAmountExceedsDirectedStake);
+
+			let remaining = directed.saturating_sub(amount);
+			if remaining.is_zero() {
+				DirectedProjectStakes::<T>::remove(&who, project_id);
+			} else {
+				DirectedProjectStakes::<T>::insert(&who, project_id, remaining);
+			}
+			AccountDirectedStake::<T>::mutate_exists(&who, |maybe_directed| {
+				let directed = maybe_directed.unwrap_or_default().saturating_sub(amount);
+				*maybe_directed = if directed.is_zero() { None } else { Some(directed) };
+			});
+			if InnovationProjects::<T>::contains_key(project_id) {
+				InnovationProjectStake::<T>::mutate(project_id, |stake| *stake = stake.saturating_sub(amount));
+			}
+
+			Self::deposit_event(Event::<T>::DirectedStakeWithdrawn(who, project_id, amount));
+
+			Ok(())
+		}
+
+		/// Set or disable the terms of credit lines against innovation stake. Open credit lines
+		/// keep their due era
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `config`: the credit line terms, `None` disables new borrowing
+		///
+		/// Emit `CreditLineConfigUpdated` event if successful
+		#[pallet::call_index(70)]
+		#[pallet::weight(T::WeightInfo::set_credit_line_config())]
+		pub fn set_credit_line_config(origin: OriginFor<T>, config: Option<CreditLineConfig>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			CreditLineTerms::<T>::set(config.clone());
+			Self::deposit_event(Event::<T>::CreditLineConfigUpdated(config));
+
+			Ok(())
+		}
+
+		/// Borrow BIT from the lending pot against the caller's innovation stake, up to the
+		/// governance-set LTV of the stake value
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the BIT amount to borrow
+		///
+		/// Emit `CreditBorrowed` event if successful
+		#[pallet::call_index(71)]
+		#[pallet::weight(T::WeightInfo::borrow_against_stake())]
+		#[transactional]
+		pub fn borrow_against_stake(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let config = CreditLineTerms::<T>::get().ok_or(Error::<T>::CreditLinesDisabled)?;
+
+			let mut credit_line = CreditLines::<T>::get(&who).unwrap_or(CreditLine {
+				debt: Zero::zero(),
+				due_era: Self::current_era().saturating_add(config.term_eras),
+				accrued_era: Self::current_era(),
+			});
+			Self::accrue_credit_line(&mut credit_line, Some(&config));
+			credit_line.debt = credit_line.debt.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				credit_line.debt <= Self::credit_limit(&config, InnovationStakingInfo::<T>::get(&who)),
+				Error::<T>::BorrowExceedsCreditLimit
+			);
+
+			T::FungibleTokenCurrency::transfer(
+				T::MiningCurrencyId::get(),
+				&Self::lending_pot_account_id(),
+				&who,
+				amount,
+			)?;
+
+			let debt = credit_line.debt;
+			CreditLines::<T>::insert(&who, credit_line);
+			Self::deposit_event(Event::<T>::CreditBorrowed(who, amount, debt));
+
+			Ok(())
+		}
+
+		/// Repay BIT borrowed against the caller's innovation stake
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `amount`: the BIT amount to repay, capped at the debt
+		///
+		/// Emit `CreditRepaid` event if successful
+		#[pallet::call_index(72)]
+		#[pallet::weight(T::WeightInfo::repay_credit())]
+		#[transactional]
+		pub fn repay_credit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut credit_line = CreditLines::<T>::get(&who).ok_or(Error::<T>::NoCreditLine)?;
+			Self::accrue_credit_line(&mut credit_line, CreditLineTerms::<T>::get().as_ref());
+
+			let repaid = amount.min(credit_line.debt);
+			T::FungibleTokenCurrency::transfer(
+				T::MiningCurrencyId::get(),
+				&who,
+				&Self::lending_pot_account_id(),
+				repaid,
+			)?;
+
+			credit_line.debt = credit_line.debt.saturating_sub(repaid);
+			let remaining_debt = credit_line.debt;
+			if remaining_debt.is_zero() {
+				CreditLines::<T>::remove(&who);
+			} else {
+				CreditLines::<T>::insert(&who, credit_line);
+			}
+			Self::deposit_event(Event::<T>::CreditRepaid(who, repaid, remaining_debt));
+
+			Ok(())
+		}
+
+		/// Set or disable the terms of power advances. Open advances keep their terms
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `config`: the power advance terms, `None` disables new advances
+		///
+		/// Emit `PowerAdvanceConfigUpdated` event if successful
+		#[pallet::call_index(73)]
+		#[pallet::weight(T::WeightInfo::set_power_advance_config())]
+		pub fn set_power_advance_config(origin: OriginFor<T>, config: Option<PowerAdvanceConfig>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			PowerAdvanceTerms::<T>::set(config.clone());
+			Self::deposit_event(Event::<T>::PowerAdvanceConfigUpdated(config));
+
+			Ok(())
+		}
+
+		/// Receive power immediately against a BIT deposit held in escrow. The BIT value of the
+		/// power plus the advance fee is repaid from BIT staking rewards, the escrow covers what is
+		/// left unpaid after the repayment rounds
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `power_amount`: the power to advance
+		///
+		/// Emit `PowerAdvanced` event if successful
+		#[pallet::call_index(74)]
+		#[pallet::weight(T::WeightInfo::request_power_advance())]
+		#[transactional]
+		pub fn request_power_advance(origin: OriginFor<T>, power_amount: PowerAmount) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!power_amount.is_zero(), Error::<T>::PowerAmountIsZero);
+			let config = PowerAdvanceTerms::<T>::get().ok_or(Error::<T>::PowerAdvancesDisabled)?;
+			ensure!(
+				!PowerAdvances::<T>::contains_key(&who),
+				Error::<T>::PowerAdvanceAlreadyOpen
+			);
+
+			let (debt, _) = Self::convert_power_to_bit(power_amount.into(), config.fee)?;
+			let debt: BalanceOf<T> = debt.saturated_into();
+			T::FungibleTokenCurrency::reserve(T::MiningCurrencyId::get(), &who, debt)?;
+
+			let current_round = Self::current_round_info()?;
+			PowerAdvances::<T>::insert(
+				&who,
+				PowerAdvance {
+					escrow: debt,
+					debt,
+					due_round: current_round.current.saturating_add(config.repayment_rounds),
+				},
+			);
+			Self::deposit_power(&who, power_amount);
+
+			Self::deposit_event(Event::<T>::PowerAdvanced(who, power_amount, debt));
+
+			Ok(())
+		}
+
+		/// Cover the unpaid debt of a power advance from its escrow once the repayment rounds
+		/// ended, releasing the rest of the escrow
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `account`: the account holding the power advance
+		///
+		/// Emit `PowerAdvanceDefaulted` event if successful
+		#[pallet::call_index(75)]
+		#[pallet::weight(T::WeightInfo::settle_power_advance())]
+		#[transactional]
+		pub fn settle_power_advance(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let advance = PowerAdvances::<T>::get(&account).ok_or(Error::<T>::NoPowerAdvance)?;
+			let current_round = Self::current_round_info()?;
+			ensure!(
+				current_round.current > advance.due_round,
+				Error::<T>::PowerAdvanceNotDue
+			);
+
+			let currency_id = T::MiningCurrencyId::get();
+			let uncovered = T::FungibleTokenCurrency::repatriate_reserved(
+				currency_id,
+				&account,
+				&Self::economy_pallet_account_id(),
+				advance.debt,
+				BalanceStatus::Free,
+			)?;
+			T::FungibleTokenCurrency::unreserve(currency_id, &account, advance.escrow.saturating_sub(advance.debt));
+			PowerAdvances::<T>::remove(&account);
+
+			Self::deposit_event(Event::<T>::PowerAdvanceDefaulted(
+				account,
+				advance.debt.saturating_sub(uncovered),
+			));
+
+			Ok(())
+		}
+
+		/// Whitelist or remove a chain that claimed rewards can be sent to
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `chain`: the destination chain, e.g. an asset hub parachain
+		/// `allowed`: whether rewards can be sent to the chain
+		///
+		/// Emit `CrossChainRewardChainUpdated` event if successful
+		#[pallet::call_index(76)]
+		#[pallet::weight(T::WeightInfo::set_cross_chain_reward_chain())]
+		pub fn set_cross_chain_reward_chain(
+			origin: OriginFor<T>,
+			chain: MultiLocation,
+			allowed: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if allowed {
+				CrossChainRewardChains::<T>::insert(chain, ());
+			} else {
+				CrossChainRewardChains::<T>::remove(chain);
+			}
+			Self::deposit_event(Event::<T>::CrossChainRewardChainUpdated(chain, allowed));
+
+			Ok(())
+		}
+
+		/// Set or clear the account on another chain receiving the caller's claimed rewards.
+		/// Rewards failing to be sent stay on the caller's account
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `destination`: the whitelisted chain and receiving account, `None` to receive rewards
+		/// locally
+		///
+		/// Emit `CrossChainRewardDestinationUpdated` event if successful
+		#[pallet::call_index(77)]
+		#[pallet::weight(T::WeightInfo::set_cross_chain_reward_destination())]
+		pub fn set_cross_chain_reward_destination(
+			origin: OriginFor<T>,
+			destination: Option<CrossChainRewardDestination>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			match destination.clone() {
+				Some(destination) => {
+					ensure!(
+						CrossChainRewardChains::<T>::contains_key(destination.chain),
+						Error::<T>::CrossChainRewardChainNotAllowed
+					);
+					CrossChainRewardDestinations::<T>::insert(&who, destination);
+				}
+				None => CrossChainRewardDestinations::<T>::remove(&who),
+			}
+			Self::deposit_event(Event::<T>::CrossChainRewardDestinationUpdated(who, destination));
+
+			Ok(())
+		}
+
+		/// Approve a stake sponsor with the number of first-time stakes it pays the fees for, or
+		/// remove it
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `sponsor`: the sponsor account
+		/// `budget`: the number of sponsored stakes, `None` to remove the sponsor
+		///
+		/// Emit `StakeSponsorUpdated` event if successful
+		#[pallet::call_index(80)]
+		#[pallet::weight(T::WeightInfo::set_stake_sponsor())]
+		pub fn set_stake_sponsor(origin: OriginFor<T>, sponsor: T::AccountId, budget: Option<u32>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			StakeSponsors::<T>::set(&sponsor, budget);
+
+			Self::deposit_event(Event::StakeSponsorUpdated(sponsor, budget));
+
+			Ok(())
+		}
+
+		/// Prune the oldest entries of the reward ledger of the caller
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `keep_last_n`: the number of most recent entries kept
+		///
+		/// Emit `RewardLedgerPruned` event if successful
+		#[pallet::call_index(88)]
+		#[pallet::weight(T::WeightInfo::prune_my_history(MAX_REWARD_LEDGER_ENTRIES))]
+		pub fn prune_my_history(origin: OriginFor<T>, keep_last_n: u32) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let (first, next) = AccountRewardLedgerBounds::<T>::get(&who);
+			let removed = next.saturating_sub(first).saturating_sub(keep_last_n);
+			Self::trim_reward_ledger(&who, removed);
+
+			Self::deposit_event(Event::RewardLedgerPruned(who, removed));
+
+			Ok(Some(T::WeightInfo::prune_my_history(removed)).into())
+		}
+
+		/// Set the number of entries kept in the reward ledger of each account. Longer ledgers are
+		/// trimmed gradually on their next recorded payouts
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `retention`: the number of entries, `None` for `MAX_REWARD_LEDGER_ENTRIES`
+		///
+		/// Emit `RewardLedgerRetentionUpdated` event if successful
+		#[pallet::call_index(89)]
+		#[pallet::weight(T::WeightInfo::set_reward_ledger_retention())]
+		pub fn set_reward_ledger_retention(origin: OriginFor<T>, retention: Option<u32>) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				retention.map_or(true, |retention| retention <= MAX_REWARD_LEDGER_ENTRIES),
+				Error::<T>::InvalidRewardLedgerRetention
+			);
+
+			RewardLedgerRetention::<T>::set(retention);
+
+			Self::deposit_event(Event::RewardLedgerRetentionUpdated(retention));
+
+			Ok(())
+		}
+
+		/// Set or remove the innovation staking reward bonus granted to holders of an NFT class.
+		/// Stakers holding several boosted classes get the best bonus
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `class_id`: the NFT class
+		/// `bonus`: the reward bonus, `None` to remove it
+		///
+		/// Emit `NftRewardBoostUpdated` event if successful
+		#[pallet::call_index(87)]
+		#[pallet::weight(T::WeightInfo::set_nft_reward_boost())]
+		pub fn set_nft_reward_boost(origin: OriginFor<T>, class_id: ClassId, bonus: Option<Perbill>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			NftRewardBoosts::<T>::try_mutate(|boosts| -> DispatchResult {
+				match bonus {
+					Some(bonus) => {
+						boosts.insert(class_id, bonus);
+						ensure!(
+							boosts.len() <= MAX_NFT_REWARD_BOOSTS,
+							Error::<T>::TooManyNftRewardBoosts
+						);
+					}
+					None => {
+						boosts.remove(&class_id);
+					}
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::NftRewardBoostUpdated(class_id, bonus));
+
+			Ok(())
+		}
+
+		/// Fund the reward payout account with native tokens of the economy treasury
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// `amount`: the amount moved to the reward payout account
+		///
+		/// Emit `RewardPotFunded` event if successful
+		#[pallet::call_index(90)]
+		#[pallet::weight(T::WeightInfo::fund_reward_pot())]
+		pub fn fund_reward_pot(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+
+			T::Currency::transfer(
+				&Self::economy_pallet_account_id(),
+				&Self::get_reward_payout_account_id(),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::<T>::RewardPotFunded(amount));
+
+			Ok(())
+		}
+
+		/// Freeze the innovation staking reward pool to sunset it. No new rewards are added to the
+		/// pool while pending rewards can still be claimed
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// Emit `RewardPoolFrozen` event if successful
+		#[pallet::call_index(104)]
+		#[pallet::weight(T::WeightInfo::freeze_reward_pool())]
+		pub fn freeze_reward_pool(origin: OriginFor<T>) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+			ensure!(
+				RewardPoolFrozenSince::<T>::get().is_none(),
+				Error::<T>::RewardPoolAlreadyFrozen
+			);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			RewardPoolFrozenSince::<T>::put(now);
+
+			Self::deposit_event(Event::<T>::RewardPoolFrozen(now));
+
+			Ok(())
+		}
+
+		/// Drain the native tokens of the reward payout account not owed to the frozen reward
+		/// pool back to the economy treasury, once `RewardPoolDrainGracePeriod` passed since the
+		/// pool was frozen
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// Emit `RewardPoolDrained` event if successful
+		#[pallet::call_index(105)]
+		#[pallet::weight(T::WeightInfo::drain_reward_pool())]
+		pub fn drain_reward_pool(origin: OriginFor<T>) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+
+			let frozen_since = RewardPoolFrozenSince::<T>::get().ok_or(Error::<T>::RewardPoolNotFrozen)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number()
+					>= frozen_since.saturating_add(T::RewardPoolDrainGracePeriod::get()),
+				Error::<T>::RewardPoolDrainGracePeriodNotElapsed
+			);
+
+			let amount = Self::reward_pot_surplus();
+			T::Currency::transfer(
+				&Self::get_reward_payout_account_id(),
+				&Self::economy_pallet_account_id(),
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::<T>::RewardPoolDrained(amount));
+
+			Ok(())
+		}
+
+		/// Sweep native tokens of the reward payout account not owed to the reward pool back to
+		/// the economy treasury
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// `amount`: the amount moved to the economy treasury
+		///
+		/// Emit `RewardPotSwept` event if successful
+		#[pallet::call_index(91)]
+		#[pallet::weight(T::WeightInfo::sweep_reward_pot())]
+		pub fn sweep_reward_pot(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+			ensure!(
+				amount <= Self::reward_pot_surplus(),
+				Error::<T>::RewardPotSurplusExceeded
+			);
+
+			T::Currency::transfer(
+				&Self::get_reward_payout_account_id(),
+				&Self::economy_pallet_account_id(),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::<T>::RewardPotSwept(amount));
+
+			Ok(())
+		}
+
+		/// Rotate the reward payout account, moving its native and reward currency balances to
+		/// `new_account`
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// `new_account`: the new reward payout account
+		///
+		/// Emit `RewardPotRotated` event if successful
+		#[pallet::call_index(92)]
+		#[pallet::weight(T::WeightInfo::rotate_reward_pot())]
+		#[transactional]
+		pub fn rotate_reward_pot(origin: OriginFor<T>, new_account: T::AccountId) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+
+			let old_account = Self::get_reward_payout_account_id();
+			ensure!(old_account != new_account, Error::<T>::RewardPotAlreadyInUse);
+
+			let native_balance = T::Currency::free_balance(&old_account);
+			T::Currency::transfer(
+				&old_account,
+				&new_account,
+				native_balance,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			for reward_currency in StakingRewardPoolInfo::<T>::get().rewards.keys() {
+				if reward_currency.is_native_token_currency_id() {
+					continue;
+				}
+				let balance = T::FungibleTokenCurrency::free_balance(*reward_currency, &old_account);
+				T::FungibleTokenCurrency::transfer(*reward_currency, &old_account, &new_account, balance)?;
+			}
+			RotatedRewardPayoutAccount::<T>::put(&new_account);
+
+			Self::deposit_event(Event::<T>::RewardPotRotated(old_account, new_account));
+
+			Ok(())
+		}
+
+		/// Set the native balance of the reward payout account below which `RewardPotLow` is
+		/// emitted at every era update
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `watermark`: the balance, zero to disable the notification
+		///
+		/// Emit `RewardPotLowWatermarkUpdated` event if successful
+		#[pallet::call_index(93)]
+		#[pallet::weight(T::WeightInfo::set_reward_pot_low_watermark())]
+		pub fn set_reward_pot_low_watermark(origin: OriginFor<T>, watermark: BalanceOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			RewardPotLowWatermark::<T>::put(watermark);
+
+			Self::deposit_event(Event::<T>::RewardPotLowWatermarkUpdated(watermark));
+
+			Ok(())
+		}
+
+		/// Set the number of eras in a voting season. The current season restarts at the current
+		/// era
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `eras`: the season length, zero to disable seasons
+		///
+		/// Emit `VotingSeasonLengthUpdated` event if successful
+		#[pallet::call_index(94)]
+		#[pallet::weight(T::WeightInfo::set_voting_season_length())]
+		pub fn set_voting_season_length(origin: OriginFor<T>, eras: EraIndex) -> DispatchResult {
+			ensure_root(origin)?;
+
+			VotingSeasonLength::<T>::put(eras);
+			VotingSeasonStartEra::<T>::put(CurrentEra::<T>::get());
+
+			Self::deposit_event(Event::<T>::VotingSeasonLengthUpdated(eras));
+
+			Ok(())
+		}
+
+		/// Check that the reserved balance of an account covers its staking ledgers and queued
+		/// exits
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `account`: the account to check
+		///
+		/// Emit `ReserveLedgerDiscrepancy` event if the reserved balance is short
+		#[pallet::call_index(103)]
+		#[pallet::weight(T::WeightInfo::verify_account())]
+		pub fn verify_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			Self::reconcile_account(&account);
+
+			Ok(())
+		}
+
+		/// Retry the payout of claimed rewards that failed to be paid to the caller
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `currency_id`: the currency of the failed payout
+		///
+		/// Emit `ClaimRewards` event if successful
+		#[pallet::call_index(102)]
+		#[pallet::weight(T::WeightInfo::retry_failed_payout())]
+		#[transactional]
+		pub fn retry_failed_payout(origin: OriginFor<T>, currency_id: FungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount = FailedPayouts::<T>::take(&who, currency_id);
+			ensure!(!amount.is_zero(), Error::<T>::NoFailedPayout);
+
+			Self::distribute_reward(&who, currency_id, amount)?;
+
+			Self::deposit_event(Event::<T>::ClaimRewards(who, currency_id, amount));
+
+			Ok(())
+		}
+
+		/// Set the commission charged on power purchases of a domain
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `domain_id`: the domain
+		/// `commission`: the commission charged on top of the BIT cost of the power
+		///
+		/// Emit `DomainPowerCommissionUpdated` event if successful
+		#[pallet::call_index(101)]
+		#[pallet::weight(T::WeightInfo::set_domain_power_commission())]
+		pub fn set_domain_power_commission(
+			origin: OriginFor<T>,
+			domain_id: DomainId,
+			commission: Perbill,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			DomainPowerCommission::<T>::insert(domain_id, commission);
+			Self::deposit_event(Event::<T>::DomainPowerCommissionUpdated(domain_id, commission));
+
+			Ok(())
+		}
+
+		/// Attach a label or content id to the innovation stake position of the caller
+		///
+		/// The dispatch origin for this call must be _Signed_ by an innovation staker.
+		///
+		/// `memo`: the memo of the position, `None` removes it
+		///
+		/// Emit `PositionMemoUpdated` event if successful
+		#[pallet::call_index(100)]
+		#[pallet::weight(T::WeightInfo::set_position_memo())]
+		pub fn set_position_memo(
+			origin: OriginFor<T>,
+			memo: Option<BoundedVec<u8, ConstU32<MAX_POSITION_MEMO_LENGTH>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			match memo.clone() {
+				Some(memo) => {
+					ensure!(
+						!InnovationStakingInfo::<T>::get(&who).is_zero(),
+						Error::<T>::NotInnovationStaker
+					);
+					PositionMemos::<T>::insert(&who, memo);
+				}
+				None => PositionMemos::<T>::remove(&who),
+			}
+
+			Self::deposit_event(Event::<T>::PositionMemoUpdated(who, memo));
+
+			Ok(())
+		}
+
+		/// Set the maximum number of active innovation stakers, new stakers beyond it join a
+		/// waitlist admitted in order as slots free up
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `max_stakers`: the staker cap, or `None` to remove it
+		///
+		/// Emit `MaxInnovationStakersUpdated` event if successful
+		#[pallet::call_index(106)]
+		#[pallet::weight(T::WeightInfo::set_max_innovation_stakers())]
+		pub fn set_max_innovation_stakers(origin: OriginFor<T>, max_stakers: Option<u32>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			MaxInnovationStakers::<T>::set(max_stakers);
+
+			Self::deposit_event(Event::<T>::MaxInnovationStakersUpdated(max_stakers));
+
+			Self::admit_waitlisted_innovation_stakers();
+
+			Ok(())
+		}
+
+		/// Leave the innovation staker waitlist and unreserve the waiting stake
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Emit `InnovationWaitlistLeft` event if successful
+		#[pallet::call_index(107)]
+		#[pallet::weight(T::WeightInfo::leave_innovation_waitlist())]
+		pub fn leave_innovation_waitlist(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount =
+				InnovationStakerWaitlist::<T>::try_mutate(|waitlist| -> Result<BalanceOf<T>, DispatchError> {
+					let index = waitlist
+						.iter()
+						.position(|(waiting, _)| waiting == &who)
+						.ok_or(Error::<T>::NotInInnovationWaitlist)?;
+					Ok(waitlist.remove(index).1)
+				})?;
+			T::Currency::unreserve(&who, amount);
+
+			Self::deposit_event(Event::<T>::InnovationWaitlistLeft(who, amount));
+
+			Ok(())
+		}
+
+		/// Authorize or revoke an account as keeper of the maintenance extrinsics
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `account`: the keeper account
+		/// `authorized`: whether the account is allowed to call the keeper extrinsics
+		///
+		/// Emit `KeeperUpdated` event if successful
+		#[pallet::call_index(96)]
+		#[pallet::weight(T::WeightInfo::set_keeper())]
+		pub fn set_keeper(origin: OriginFor<T>, account: T::AccountId, authorized: bool) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if authorized {
+				AuthorizedKeepers::<T>::insert(&account, ());
+			} else {
+				AuthorizedKeepers::<T>::remove(&account);
+			}
+
+			Self::deposit_event(Event::<T>::KeeperUpdated(account, authorized));
+
+			Ok(())
+		}
+
+		/// Catch up the innovation staking era if an era update is due. Free of fees for keepers
+		///
+		/// The dispatch origin for this call must be _Signed_ by an authorized keeper.
+		///
+		/// Emit `CurrentInnovationStakingEraUpdated` event if successful
+		#[pallet::call_index(97)]
+		#[pallet::weight(T::WeightInfo::keeper_update_era())]
+		pub fn keeper_update_era(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			Self::ensure_keeper(origin)?;
+
+			let era_number = Self::get_era_index(<frame_system::Pallet<T>>::block_number());
+			ensure!(!era_number.is_zero(), Error::<T>::NoEraUpdateDue);
+			Self::update_current_era(era_number)?;
+
+			Ok(Pays::No.into())
+		}
+
+		/// Restake the matured exit queue entries of accounts that opted in to automatic
+		/// restaking. Free of fees for keepers
+		///
+		/// The dispatch origin for this call must be _Signed_ by an authorized keeper.
+		///
+		/// `accounts`: the accounts whose matured exits are restaked
+		///
+		/// Emit `ExitRestaked` event for each restaked exit if successful
+		#[pallet::call_index(98)]
+		#[pallet::weight(T::WeightInfo::keeper_restake_matured_exits(accounts.len() as u32))]
+		pub fn keeper_restake_matured_exits(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, ConstU32<MAX_KEEPER_BATCH>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_keeper(origin)?;
+
+			for who in accounts.iter() {
+				Self::restake_matured_exits(who);
+			}
+
+			Ok(Pays::No.into())
+		}
+
+		/// Refund expired reward campaigns to their creators. Free of fees for keepers
+		///
+		/// The dispatch origin for this call must be _Signed_ by an authorized keeper.
+		///
+		/// `campaign_ids`: the expired campaigns
+		///
+		/// Emit `RewardCampaignRefunded` event for each campaign if successful
+		#[pallet::call_index(99)]
+		#[pallet::weight(T::WeightInfo::keeper_refund_expired_campaigns(campaign_ids.len() as u32))]
+		#[transactional]
+		pub fn keeper_refund_expired_campaigns(
+			origin: OriginFor<T>,
+			campaign_ids: BoundedVec<CampaignId, ConstU32<MAX_KEEPER_BATCH>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_keeper(origin)?;
+
+			for campaign_id in campaign_ids {
+				Self::do_refund_reward_campaign(campaign_id)?;
+			}
+
+			Ok(Pays::No.into())
+		}
+
+		/// Set the number of blocks an account has to wait between unstakes from the same pool
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `blocks`: the cooldown, zero to disable it
+		///
+		/// Emit `UnstakeCooldownUpdated` event if successful
+		#[pallet::call_index(86)]
+		#[pallet::weight(T::WeightInfo::set_unstake_cooldown())]
+		pub fn set_unstake_cooldown(origin: OriginFor<T>, blocks: BlockNumberFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			UnstakeCooldown::<T>::put(blocks);
+
+			Self::deposit_event(Event::UnstakeCooldownUpdated(blocks));
+
+			Ok(())
+		}
+
+		/// Initialize the innovation staking reward pool with zeroed entries for the reward
+		/// currencies, so rewards can be accumulated before the first stake. Currencies already in
+		/// the pool are kept
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `currencies`: the reward currencies
+		///
+		/// Emit `RewardPoolInitialized` event if successful
+		#[pallet::call_index(85)]
+		#[pallet::weight(T::WeightInfo::initialize_reward_pool(currencies.len() as u32))]
+		pub fn initialize_reward_pool(
+			origin: OriginFor<T>,
+			currencies: BoundedVec<FungibleTokenId, ConstU32<MAX_REWARD_POOL_CURRENCIES>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			StakingRewardPoolInfo::<T>::mutate(|pool_info| {
+				for currency_id in currencies.iter() {
+					pool_info
+						.rewards
+						.entry(*currency_id)
+						.or_insert((Zero::zero(), Zero::zero()));
+				}
+			});
+
+			Self::deposit_event(Event::RewardPoolInitialized(currencies.into_inner()));
+
+			Ok(())
+		}
+
+		/// Set the pending reward below which claims of a currency stay pending
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `currency_id`: the reward currency
+		/// `amount`: the minimum claim amount, zero to pay out any amount
+		///
+		/// Emit `MinimumClaimAmountUpdated` event if successful
+		#[pallet::call_index(81)]
+		#[pallet::weight(T::WeightInfo::set_minimum_claim_amount())]
+		pub fn set_minimum_claim_amount(
+			origin: OriginFor<T>,
+			currency_id: FungibleTokenId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			MinimumClaimAmount::<T>::insert(currency_id, amount);
+
+			Self::deposit_event(Event::MinimumClaimAmountUpdated(currency_id, amount));
+
+			Ok(())
+		}
+
+		/// Pay out all pending innovation staking rewards of accounts, including those below the
+		/// minimum claim amount
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `accounts`: the stakers whose rewards are paid out
+		///
+		/// Emit `ClaimRewards` event for each currency paid and `ClaimRewardsSummary` event if
+		/// successful
+		#[pallet::call_index(82)]
+		#[pallet::weight(T::WeightInfo::force_flush_small_claims(accounts.len() as u32))]
+		#[transactional]
+		pub fn force_flush_small_claims(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, ConstU32<MAX_FLUSH_CLAIMS_BATCH>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			for who in accounts.iter() {
+				Self::flush_reward(who, who);
+			}
+
+			Ok(())
+		}
+
+		/// Set or disable the treasury matching of innovation project reward pools
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `config`: the matching mode and caps, `None` disables matching
+		///
+		/// Emit `ProjectMatchingUpdated` event if successful
+		#[pallet::call_index(67)]
+		#[pallet::weight(T::WeightInfo::set_project_matching())]
+		pub fn set_project_matching(
+			origin: OriginFor<T>,
+			config: Option<ProjectMatchingConfig<BalanceOf<T>>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ProjectMatching::<T>::set(config.clone());
+			Self::deposit_event(Event::<T>::ProjectMatchingUpdated(config));
+
+			Ok(())
+		}
+
+		/// Claim the treasury matching accrued to an innovation project
+		///
+		/// The dispatch origin for this call must be _Signed_ by the project owner.
+		///
+		/// `project_id`: the innovation project
+		///
+		/// Emit `ProjectMatchingClaimed` event if successful
+		#[pallet::call_index(68)]
+		#[pallet::weight(T::WeightInfo::claim_project_matching())]
+		#[transactional]
+		pub fn claim_project_matching(origin: OriginFor<T>, project_id: InnovationProjectId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let project = InnovationProjects::<T>::get(project_id).ok_or(Error::<T>::InnovationProjectDoesNotExist)?;
+			ensure!(project.owner == who, Error::<T>::NotInnovationProjectOwner);
+
+			let matched = ProjectMatchingPools::<T>::take(project_id);
+			ensure!(!matched.is_zero(), Error::<T>::NoProjectMatchingToClaim);
+
+			T::Currency::transfer(
+				&Self::project_pool_account_id(project_id),
+				&who,
+				matched,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Self::deposit_event(Event::<T>::ProjectMatchingClaimed(project_id, who, matched));
+
+			Ok(())
+		}
+
+		/// Fund the estate rewards with native tokens of the economy treasury. The amount is
+		/// shared among estate stakers in proportion to their weighted estate stake
+		///
+		/// The dispatch origin for this call must be `EconomySpendOrigin`.
+		///
+		/// `amount`: the amount of estate rewards
+		///
+		/// Emit `EstateRewardsFunded` event if successful
+		#[pallet::call_index(109)]
+		#[pallet::weight(T::WeightInfo::fund_estate_rewards())]
+		pub fn fund_estate_rewards(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			T::EconomySpendOrigin::ensure_origin(origin)?;
+
+			let total_weighted = TotalWeightedEstateStake::<T>::get();
+			ensure!(!total_weighted.is_zero(), Error::<T>::NoWeightedEstateStake);
+			let reward_per_weight = FixedU128::saturating_from_rational(
+				amount.saturated_into::<u128>(),
+				total_weighted.saturated_into::<u128>(),
+			);
+			ensure!(!reward_per_weight.is_zero(), Error::<T>::EstateRewardAmountIsZero);
+
+			T::Currency::transfer(
+				&Self::economy_pallet_account_id(),
+				&Self::estate_reward_pot_account_id(),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			EstateRewardPerWeight::<T>::mutate(|total| *total = total.saturating_add(reward_per_weight));
+			Self::deposit_event(Event::<T>::EstateRewardsFunded(amount));
+
+			Ok(())
+		}
+
+		/// Settle the estate rewards of an estate stake and claim the estate rewards of the caller.
+		/// Estate rewards accrued during a reward lease are paid to the lessee
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `estate_id`: the estate of the stake to settle
+		/// `staker`: the staker of the stake to settle
+		///
+		/// Emit `EstateRewardClaimed` event if successful
+		#[pallet::call_index(110)]
+		#[pallet::weight(T::WeightInfo::claim_estate_reward())]
+		#[transactional]
+		pub fn claim_estate_reward(origin: OriginFor<T>, estate_id: EstateId, staker: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::settle_estate_reward(estate_id, &staker);
+
+			let pending = PendingEstateRewards::<T>::take(&who);
+			ensure!(!pending.is_zero(), Error::<T>::NoEstateRewardToClaim);
+
+			T::Currency::transfer(
+				&Self::estate_reward_pot_account_id(),
+				&who,
+				pending,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Self::deposit_event(Event::<T>::EstateRewardClaimed(who, pending));
+
+			Ok(())
+		}
+
+		/// Offer to lease the estate rewards of the caller's stake on an estate for a number of
+		/// rounds against an upfront price
+		///
+		/// The dispatch origin for this call must be _Signed_ by the estate staker.
+		///
+		/// `estate_id`: the staked estate
+		/// `rounds`: the number of rounds of the lease
+		/// `price`: the upfront price of the lease
+		///
+		/// Emit `EstateRewardLeaseOffered` event if successful
+		#[pallet::call_index(111)]
+		#[pallet::weight(T::WeightInfo::offer_estate_reward_lease())]
+		pub fn offer_estate_reward_lease(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			rounds: RoundIndex,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!rounds.is_zero(), Error::<T>::InvalidEstateRewardLease);
+			ensure!(
+				!WeightedEstateStakes::<T>::get(estate_id, &who).is_zero(),
+				Error::<T>::NoFundsStakedAtEstate
+			);
+
+			Self::settle_estate_reward(estate_id, &who);
+			ensure!(
+				!EstateRewardLeases::<T>::contains_key(estate_id, &who),
+				Error::<T>::EstateRewardsAlreadyLeased
+			);
+
+			EstateRewardLeaseOffers::<T>::insert(estate_id, &who, EstateRewardLeaseOffer { rounds, price });
+			Self::deposit_event(Event::<T>::EstateRewardLeaseOffered(estate_id, who, rounds, price));
+
+			Ok(())
+		}
+
+		/// Cancel the offer to lease the estate rewards of the caller's stake on an estate
+		///
+		/// The dispatch origin for this call must be _Signed_ by the estate staker.
+		///
+		/// `estate_id`: the staked estate
+		///
+		/// Emit `EstateRewardLeaseOfferCancelled` event if successful
+		#[pallet::call_index(112)]
+		#[pallet::weight(T::WeightInfo::cancel_estate_reward_lease_offer())]
+		pub fn cancel_estate_reward_lease_offer(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			EstateRewardLeaseOffers::<T>::take(estate_id, &who)
+				.ok_or(Error::<T>::EstateRewardLeaseOfferDoesNotExist)?;
+			Self::deposit_event(Event::<T>::EstateRewardLeaseOfferCancelled(estate_id, who));
+
+			Ok(())
+		}
+
+		/// Accept an offer to lease the estate rewards of an estate stake. The price is reserved
+		/// and paid to the staker when the lease ends
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// `estate_id`: the staked estate
+		/// `staker`: the staker offering the lease
+		/// `max_price`: the maximum price accepted
+		///
+		/// Emit `EstateRewardLeaseAccepted` event if successful
+		#[pallet::call_index(113)]
+		#[pallet::weight(T::WeightInfo::accept_estate_reward_lease())]
+		#[transactional]
+		pub fn accept_estate_reward_lease(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			staker: T::AccountId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != staker, Error::<T>::InvalidEstateRewardLease);
+
+			let offer = EstateRewardLeaseOffers::<T>::take(estate_id, &staker)
+				.ok_or(Error::<T>::EstateRewardLeaseOfferDoesNotExist)?;
+			ensure!(offer.price <= max_price, Error::<T>::EstateRewardLeasePriceTooHigh);
+			ensure!(
+				!WeightedEstateStakes::<T>::get(estate_id, &staker).is_zero(),
+				Error::<T>::NoFundsStakedAtEstate
+			);
+
+			let current_round = Self::current_round_info()?.current;
+			Self::settle_estate_reward(estate_id, &staker);
+			ensure!(
+				!EstateRewardLeases::<T>::contains_key(estate_id, &staker),
+				Error::<T>::EstateRewardsAlreadyLeased
+			);
+
+			T::Currency::reserve(&who, offer.price)?;
+			let end_round = current_round.saturating_add(offer.rounds);
+			EstateRewardLeases::<T>::insert(
+				estate_id,
+				&staker,
+				EstateRewardLease {
+					lessee: who.clone(),
+					start_round: current_round,
+					end_round,
+					price: offer.price,
+				},
+			);
+			EstateRewardLeaseEnds::<T>::mutate(end_round, |leases| *leases = leases.saturating_add(1));
+			Self::deposit_event(Event::<T>::EstateRewardLeaseAccepted(
+				estate_id,
+				staker,
+				who,
+				end_round,
+				offer.price,
+			));
+
+			Ok(())
+		}
+
+		/// Set or remove the power each distributor NFT of a class may issue per round
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// `class_id`: the distributor NFT class
+		/// `quota`: the power issued per round, `None` stops the class from distributing power
+		///
+		/// Emit `DistributorPowerQuotaUpdated` event if successful
+		#[pallet::call_index(114)]
+		#[pallet::weight(T::WeightInfo::set_distributor_power_quota())]
+		pub fn set_distributor_power_quota(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			quota: Option<PowerAmount>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			DistributorPowerQuotas::<T>::set(class_id, quota);
+			Self::deposit_event(Event::<T>::DistributorPowerQuotaUpdated(class_id, quota));
+
+			Ok(())
+		}
+
+		/// Issue power to a beneficiary through a distributor NFT. The BIT value of the power is
+		/// paid to the economy treasury and the power counts toward the per round quota of the
+		/// distributor
+		///
+		/// The dispatch origin for this call must be _Signed_ by the distributor NFT owner.
+		///
+		/// `distributor`: the distributor NFT
+		/// `beneficiary`: the account receiving the power
+		/// `power_amount`: the power to issue
+		///
+		/// Emit `PowerDistributed` event if successful
+		#[pallet::call_index(115)]
+		#[pallet::weight(T::WeightInfo::distribute_power())]
+		#[transactional]
+		pub fn distribute_power(
+			origin: OriginFor<T>,
+			distributor: (ClassId, TokenId),
+			beneficiary: T::AccountId,
+			power_amount: PowerAmount,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!power_amount.is_zero(), Error::<T>::PowerAmountIsZero);
+			ensure!(
+				T::NFTHandler::check_ownership(&who, &distributor)?,
+				Error::<T>::NotPowerDistributorOwner
+			);
+
+			let quota = DistributorPowerQuotas::<T>::get(distributor.0).ok_or(Error::<T>::NotPowerDistributor)?;
+			let current_round = Self::current_round_info()?.current;
+			DistributorPowerIssued::<T>::try_mutate(distributor, |(round, issued)| -> DispatchResult {
+				if *round != current_round {
+					*round = current_round;
+					*issued = Zero::zero();
+				}
+				let total_issued = issued.checked_add(power_amount).ok_or(ArithmeticError::Overflow)?;
+				ensure!(total_issued <= quota, Error::<T>::DistributorPowerQuotaExceeded);
+				*issued = total_issued;
+				Ok(())
+			})?;
+
+			let (bit_cost, _) = Self::convert_power_to_bit(power_amount.into(), Perbill::zero())?;
+			let bit_cost: BalanceOf<T> = bit_cost.saturated_into();
+			T::FungibleTokenCurrency::transfer(
+				T::MiningCurrencyId::get(),
+				&who,
+				&Self::economy_pallet_account_id(),
+				bit_cost,
+			)?;
+			Self::distribute_power_by_network(power_amount, &beneficiary)?;
+
+			Self::deposit_event(Event::<T>::PowerDistributed(
+				distributor,
+				beneficiary,
+				power_amount,
+				bit_cost,
+			));
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn economy_pallet_account_id() -> T::AccountId {
+		T::EconomyTreasury::get().into_account_truncating()
+	}
+
+	pub fn convert_power_to_bit(
+		power_amount: Balance,
+		commission: Perbill,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let rate = Self::get_bit_power_exchange_rate();
+		let rounding = Self::bit_power_conversion_rounding();
+
+		let bit_required =
+			multiply_by_rational_with_rounding(power_amount, rate.into_inner(), FixedU128::DIV, rounding.into())
+				.ok_or(ArithmeticError::Overflow)?;
+		let commission_fee = match rounding {
+			ConversionRounding::Down => commission.mul_floor(bit_required),
+			ConversionRounding::Up => commission.mul_ceil(bit_required),
+			ConversionRounding::Nearest => commission * bit_required,
+		};
+		let total = bit_required
+			.checked_add(commission_fee)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((total, commission_fee))
+	}
+
+	/// Quote the BIT cost, commission and target execution block of buying `power_amount` power
+	/// in `domain_id` at the current exchange rate
+	pub fn quote_power_purchase(
+		power_amount: PowerAmount,
+		domain_id: DomainId,
+	) -> Result<PowerPurchaseQuote<Balance, BlockNumberFor<T>>, DispatchError> {
+		let (bit_cost, commission) =
+			Self::convert_power_to_bit(power_amount.into(), DomainPowerCommission::<T>::get(domain_id))?;
+
+		Ok(PowerPurchaseQuote {
+			bit_cost,
+			commission,
+			target_execution_block: Self::get_target_execution_order(power_amount)?,
+		})
+	}
+
+	/// Number of entries kept in the reward ledger of each account
+	pub fn reward_ledger_capacity() -> u32 {
+		RewardLedgerRetention::<T>::get().map_or(MAX_REWARD_LEDGER_ENTRIES, |retention| {
+			retention.min(MAX_REWARD_LEDGER_ENTRIES)
+		})
+	}
+
+	/// Page of at most `limit` reward ledger entries of `who` starting at `start`, oldest first
+	pub fn reward_ledger_page(
+		who: &T::AccountId,
+		start: u32,
+		limit: u32,
+	) -> Vec<RewardLedgerEntry<BalanceOf<T>, BlockNumberFor<T>>> {
+		let (first, next) = AccountRewardLedgerBounds::<T>::get(who);
+		let start = first.saturating_add(start);
+		let end = start.saturating_add(limit.min(MAX_REWARD_LEDGER_ENTRIES)).min(next);
+		(start..end)
+			.filter_map(|index| AccountRewardLedger::<T>::get(who, index))
+			.collect()
+	}
+
+	/// All kept reward ledger entries of `who`, oldest first
+	pub fn account_reward_ledger(who: &T::AccountId) -> Vec<RewardLedgerEntry<BalanceOf<T>, BlockNumberFor<T>>> {
+		Self::reward_ledger_page(who, 0, MAX_REWARD_LEDGER_ENTRIES)
+	}
+
+	/// Remove the `count` oldest reward ledger entries of `who`
+	fn trim_reward_ledger(who: &T::AccountId, count: u32) {
+		let (first, next) = AccountRewardLedgerBounds::<T>::get(who);
+		let new_first = first.saturating_add(count).min(next);
+		for index in first..new_first {
+			AccountRewardLedger::<T>::remove(who, index);
+		}
+		if new_first == next {
+			AccountRewardLedgerBounds::<T>::remove(who);
+		} else {
+			AccountRewardLedgerBounds::<T>::insert(who, (new_first, next));
+		}
+	}
+
+	/// All exit queue entries of `who` with whether they can be withdrawn in the current round
+	pub fn exit_entries(who: &T::AccountId) -> Vec<ExitEntry<BalanceOf<T>, BlockNumberFor<T>>> {
+		let current_round = Self::current_round_info()
+			.map(|round_info| round_info.current)
+			.unwrap_or_else(|_| LastSeenRound::<T>::get());
+		let entry = |kind: ExitQueueKind, round: RoundIndex, queued_exit: QueuedExitOf<T>| ExitEntry {
+			kind,
+			round,
+			amount: queued_exit.amount,
+			estimated_unlock_block: if queued_exit.estimated_unlock_block.is_zero() {
+				Self::estimated_round_start(round)
+			} else {
+				queued_exit.estimated_unlock_block
+			},
+			ready: round <= current_round,
+		};
+
+		let mut entries: Vec<ExitEntry<BalanceOf<T>, BlockNumberFor<T>>> = ExitQueue::<T>::iter_prefix(who)
+			.map(|(round, queued_exit)| entry(ExitQueueKind::Economy101, round, queued_exit))
+			.collect();
+		entries.extend(
+			EstateExitQueue::<T>::iter_prefix((who,))
+				.map(|((round, estate_id), queued_exit)| entry(ExitQueueKind::Estate(estate_id), round, queued_exit)),
+		);
+		entries.extend(
+			InnovationStakingExitQueue::<T>::iter_prefix(who)
+				.map(|(round, queued_exit)| entry(ExitQueueKind::Innovation, round, queued_exit)),
+		);
+		entries.sort_by_key(|exit_entry| exit_entry.round);
+
+		entries
+	}
+
+	/// Consolidated staking ledger of `who` assembled from the self, estate and innovation staking
+	/// storage
+	pub fn staking_ledger(who: &T::AccountId) -> StakingLedger<BalanceOf<T>, BlockNumberFor<T>> {
+		let (innovation_shares, withdrawn_rewards) = SharesAndWithdrawnRewards::<T>::get(who);
+		let mut active_estate_stakes: Vec<(EstateId, BalanceOf<T>)> = StakedEstates::<T>::iter_key_prefix(who)
+			.filter_map(|estate_id| {
+				EstateStakingInfo::<T>::get(estate_id)
+					.filter(|bond| &bond.staker == who)
+					.map(|bond| (estate_id, bond.amount))
+			})
+			.collect();
+		active_estate_stakes.sort_by_key(|(estate_id, _)| *estate_id);
+
+		StakingLedger {
+			active_self_stake: StakingInfo::<T>::get(who),
+			locked_self_stake: LockedSelfStake::<T>::get(who),
+			active_estate_stakes,
+			active_innovation_stake: InnovationStakingInfo::<T>::get(who),
+			innovation_shares,
+			unlocking: Self::exit_entries(who),
+			claimed_rewards: withdrawn_rewards.into_iter().collect(),
+		}
+	}
+
+	/// Time-weighted average BIT to power exchange rate over the last `window` blocks
+	pub fn twap(window: BlockNumberFor<T>) -> FixedU128 {
+		let current_rate = Self::get_bit_power_exchange_rate();
+		let now = <frame_system::Pallet<T>>::block_number();
+		let window_start = now.saturating_sub(window);
+		let total_blocks: u128 = now.saturating_sub(window_start).unique_saturated_into();
+		if total_blocks.is_zero() {
+			return current_rate;
+		}
+
+		let mut weighted_sum = FixedU128::zero();
+		let mut segment_end = now;
+		let mut rate = current_rate;
+		for update in Self::bit_power_exchange_rate_history().iter().rev() {
+			if update.block <= window_start {
+				break;
+			}
+			let blocks: u128 = segment_end.saturating_sub(update.block).unique_saturated_into();
+			weighted_sum = weighted_sum.saturating_add(rate.saturating_mul(FixedU128::saturating_from_integer(blocks)));
+			segment_end = update.block;
+			rate = update.old_rate;
+		}
+		let blocks: u128 = segment_end.saturating_sub(window_start).unique_saturated_into();
+		weighted_sum = weighted_sum.saturating_add(rate.saturating_mul(FixedU128::saturating_from_integer(blocks)));
+
+		weighted_sum
+			.checked_div(&FixedU128::saturating_from_integer(total_blocks))
+			.unwrap_or(current_rate)
+	}
+
+	/// Round info of the round handler, rejected if the round went backwards from the last seen
+	/// round, e.g. while the round pallet is migrating, so exit queue entries are never keyed
+	/// by a stale round
+	pub fn current_round_info() -> Result<RoundInfo<BlockNumberFor<T>>, DispatchError> {
+		let round_info = T::RoundHandler::get_current_round_info();
+		let last_seen_round = LastSeenRound::<T>::get();
+		if round_info.current < last_seen_round {
+			log::warn!(
+				target: "economy",
+				"round handler returned round {:?} behind the last seen round {:?}",
+				round_info.current, last_seen_round
+			);
+			return Err(Error::<T>::RoundDataUnavailable.into());
+		}
+		if round_info.current > last_seen_round {
+			LastSeenRound::<T>::put(round_info.current);
+		}
+
+		Ok(round_info)
+	}
+
+	/// Block `round` is expected to start at, extrapolated from the length of the current round
+	pub fn estimated_round_start(round: RoundIndex) -> BlockNumberFor<T> {
+		let round_info = T::RoundHandler::get_current_round_info();
+		let rounds_ahead: BlockNumberFor<T> = round.saturating_sub(round_info.current).into();
+		let round_length: BlockNumberFor<T> = round_info.length.into();
+
+		round_info
+			.first
+			.saturating_add(rounds_ahead.saturating_mul(round_length))
+	}
+
+	/// Add `amount` to the `kind` exit queue entry of `who` unlocking at `round`
+	fn queue_exit(who: &T::AccountId, kind: ExitQueueKind, round: RoundIndex, amount: BalanceOf<T>) {
+		let estimated_unlock_block = Self::estimated_round_start(round);
+		let add_amount = |maybe_exit: &mut Option<QueuedExitOf<T>>| {
+			*maybe_exit = Some(QueuedExit {
+				amount: maybe_exit
+					.as_ref()
+					.map(|queued_exit| queued_exit.amount)
+					.unwrap_or_default()
+					.saturating_add(amount),
+				estimated_unlock_block,
+			});
+		};
+		match kind {
+			ExitQueueKind::Economy101 => ExitQueue::<T>::mutate(who, round, add_amount),
+			ExitQueueKind::Innovation => InnovationStakingExitQueue::<T>::mutate(who, round, add_amount),
+			ExitQueueKind::Estate(estate_id) => EstateExitQueue::<T>::mutate((who, round, estate_id), add_amount),
+		}
+		Self::schedule_exit_maturity(who, kind, round);
+
+		Self::deposit_event(Event::<T>::ExitQueued(
+			who.clone(),
+			kind,
+			round,
+			amount,
+			estimated_unlock_block,
+		));
+	}
+
+	/// Record that the `kind` exit queue entry of `who` becomes withdrawable at `round`, so
+	/// `ExitMatured` is emitted once the round starts
+	fn schedule_exit_maturity(who: &T::AccountId, kind: ExitQueueKind, round: RoundIndex) {
+		// Entries of a round that already started are withdrawable right away
+		if round <= LastSeenRound::<T>::get() {
+			return;
+		}
+		MaturingExits::<T>::insert(round, (who, kind), ());
+	}
+
+	/// Emit `ExitMatured` for the exit queue entries becoming withdrawable up to the last started
+	/// round, resuming from `MaturedExitsCursor` and looking up at most `limit` rounds and
+	/// entries. Entries withdrawn, restaked or moved in the meantime are skipped. Returns the
+	/// number of rounds and entries looked up
+	fn notify_matured_exits(limit: u32) -> u32 {
+		let (mut round, last_round) = match MaturedExitsCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => return 0,
+		};
+		let mut looked_up: u32 = 0;
+		while round <= last_round && looked_up < limit {
+			looked_up = looked_up.saturating_add(1);
+			for ((who, kind), _) in MaturingExits::<T>::drain_prefix(round) {
+				let queued_exit = match kind {
+					ExitQueueKind::Economy101 => ExitQueue::<T>::get(&who, round),
+					ExitQueueKind::Innovation => InnovationStakingExitQueue::<T>::get(&who, round),
+					ExitQueueKind::Estate(estate_id) => EstateExitQueue::<T>::get((&who, round, estate_id)),
+				};
+				if let Some(queued_exit) = queued_exit {
+					Self::deposit_event(Event::<T>::ExitMatured(who, round, queued_exit.amount));
+				}
+				looked_up = looked_up.saturating_add(1);
+				if looked_up >= limit {
+					break;
+				}
+			}
+			if MaturingExits::<T>::iter_prefix(round).next().is_some() {
+				break;
+			}
+			round = round.saturating_add(1);
+		}
+		MaturedExitsCursor::<T>::put((round, last_round));
+
+		looked_up
+	}
+
+	/// Notify the maturing exits left over by the round change hook with the weight left in the
+	/// block
+	fn step_matured_exits(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(overhead)
+			|| MaturedExitsCursor::<T>::get().map_or(true, |(round, last_round)| round > last_round)
+		{
+			return T::DbWeight::get().reads(1);
+		}
+
+		let weight_per_entry = T::DbWeight::get().reads_writes(2, 1);
+		let limit = remaining_weight
+			.saturating_sub(overhead)
+			.ref_time()
+			.checked_div(weight_per_entry.ref_time())
+			.unwrap_or(u64::MAX)
+			.min(u32::MAX as u64) as u32;
+		let looked_up = Self::notify_matured_exits(limit);
+
+		weight_per_entry
+			.saturating_mul(looked_up as u64)
+			.saturating_add(overhead)
+	}
+
+	/// Restake the matured exit queue entries of `who` if it opted in to automatic restaking
+	pub fn restake_matured_exits(who: &T::AccountId) {
+		if !Self::account_preferences(who).auto_restake_exits {
+			return;
+		}
+		let current_round = match Self::current_round_info() {
+			Ok(round_info) => round_info.current,
+			// Matured exits are restaked once round data is available again
+			Err(_) => return,
+		};
+
+		let matured_exits: Vec<(RoundIndex, BalanceOf<T>)> = ExitQueue::<T>::iter_prefix(who)
+			.filter(|(round, _)| *round <= current_round)
+			.map(|(round, queued_exit)| (round, queued_exit.amount))
+			.collect();
+		if !matured_exits.is_empty() {
+			Self::accrue_self_staking_power(who);
+		}
+		for (round, amount) in matured_exits {
+			ExitQueue::<T>::remove(who, round);
+			StakingInfo::<T>::mutate(who, |staked_balance| {
+				*staked_balance = staked_balance.saturating_add(amount)
+			});
+			TotalStake::<T>::mutate(|total| *total = total.saturating_add(amount));
+			Self::deposit_event(Event::<T>::ExitRestaked(
+				who.clone(),
+				PoolKind::Economy101,
+				round,
+				amount,
+			));
+		}
+
+		let matured_exits: Vec<(RoundIndex, BalanceOf<T>)> = InnovationStakingExitQueue::<T>::iter_prefix(who)
+			.filter(|(round, _)| *round <= current_round)
+			.map(|(round, queued_exit)| (round, queued_exit.amount))
+			.collect();
+		for (round, amount) in matured_exits {
+			// Without a staker slot the matured exit stays withdrawable instead
+			if !Self::has_innovation_staker_slot(who) {
+				break;
+			}
+			Self::checkpoint_voting_points(who);
+			InnovationStakingExitQueue::<T>::remove(who, round);
+			InnovationStakingInfo::<T>::mutate(who, |staked_balance| {
+				*staked_balance = staked_balance.saturating_add(amount)
+			});
+			TotalInnovationStaking::<T>::mutate(|total| *total = total.saturating_add(amount));
+			Self::add_share(who, amount);
+			Self::deposit_event(Event::<T>::ExitRestaked(
+				who.clone(),
+				PoolKind::Innovation,
+				round,
+				amount,
+			));
+		}
+	}
+
+	/// Free balance of `who` that can be reserved while keeping the existential deposit and
+	/// `StakeFeeHeadroom` free
+	fn free_balance_above_keep_alive(who: &T::AccountId) -> BalanceOf<T> {
+		T::Currency::free_balance(who)
+			.saturating_sub(T::Currency::minimum_balance())
+			.saturating_sub(T::StakeFeeHeadroom::get())
+	}
+
+	/// Ensure reserving `amount` does not push `who` below the existential deposit once fees are
+	/// paid
+	fn ensure_keep_alive(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(
+			Self::free_balance_above_keep_alive(who) >= amount,
+			Error::<T>::WouldKillAccount
+		);
+		Ok(())
+	}
+
+	/// Move the innovation position of `who` into the exit queue if it is below
+	/// `ExistentialStake`. Returns the folded amount
+	fn fold_dust_position(who: &T::AccountId) -> Option<BalanceOf<T>> {
+		let staked_balance = InnovationStakingInfo::<T>::get(who);
+		if staked_balance.is_zero() || staked_balance >= T::ExistentialStake::get() {
+			return None;
+		}
+
+		let current_round = Self::current_round_info().ok()?;
+		let unbond_rounds = INNOVATION_UNBOND_ROUNDS.saturating_mul(InnovationConvictions::<T>::get(who).multiplier());
+		let next_round = current_round.current.saturating_add(unbond_rounds);
+		Self::queue_exit(who, ExitQueueKind::Innovation, next_round, staked_balance);
+
+		Self::checkpoint_voting_points(who);
+		InnovationStakingInfo::<T>::remove(who);
+		InnovationConvictions::<T>::remove(who);
+		let new_total_staked = TotalInnovationStaking::<T>::get().saturating_sub(staked_balance);
+		<TotalInnovationStaking<T>>::put(new_total_staked);
+
+		Self::remove_share(who, staked_balance);
+
+		Self::deposit_event(Event::DustPositionFolded(who.clone(), staked_balance));
+
+		Some(staked_balance)
+	}
+
+	/// Accumulate `amount` of income of `who` and stake the accumulated income on innovation
+	/// staking once it reaches `MinimumIncomeStake`
+	fn do_stake_income(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		let pending = PendingIncomeStake::<T>::get(who).saturating_add(amount);
+		if pending < T::MinimumIncomeStake::get() {
+			PendingIncomeStake::<T>::insert(who, pending);
+			return Ok(());
+		}
+
+		// Part of the pending income may have been spent already
+		let amount = pending.min(Self::free_balance_above_keep_alive(who));
+		let total = InnovationStakingInfo::<T>::get(who).saturating_add(amount);
+		let exit_scheduled = match Self::current_round_info() {
+			Ok(current_round) => InnovationStakingExitQueue::<T>::contains_key(who, current_round.current),
+			// Keep the income pending until round data is available again
+			Err(_) => true,
+		};
+		if total < T::MinimumStake::get() || exit_scheduled || !Self::has_innovation_staker_slot(who) {
+			PendingIncomeStake::<T>::insert(who, amount);
+			return Ok(());
+		}
+
+		T::Currency::reserve(who, amount)?;
+		PendingIncomeStake::<T>::remove(who);
+
+		Self::checkpoint_voting_points(who);
+		InnovationStakingInfo::<T>::insert(who, total);
+		TotalInnovationStaking::<T>::mutate(|total_staked| *total_staked = total_staked.saturating_add(amount));
+		Self::add_share(who, amount);
+
+		Self::deposit_event(Event::IncomeStaked(who.clone(), amount));
+
+		Ok(())
+	}
+
+	/// Restake matured exits of opted-in accounts with the weight left in the block, resuming
+	/// from the last swept account
+	fn sweep_matured_exits(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(overhead) {
+			return Weight::zero();
+		}
+
+		let iter = match RestakeSweepCursor::<T>::get() {
+			Some(cursor) => AccountPreferences::<T>::iter_from(cursor),
+			None => AccountPreferences::<T>::iter(),
+		};
+		let (next_cursor, consumed) = migrations::step_entries(
+			iter,
+			T::WeightInfo::withdraw_unreserved(),
+			remaining_weight.saturating_sub(overhead),
+			|(who, preferences)| {
+				if preferences.auto_restake_exits {
+					Self::restake_matured_exits(&who);
+				}
+			},
+		);
+		RestakeSweepCursor::<T>::set(next_cursor);
+
+		consumed.saturating_add(overhead)
+	}
+
+	/// Step the configured multi-block migrations with the weight left in the block. Migrations
+	/// already completed or not applicable to the on-chain storage are skipped, and the next
+	/// migration starts with the weight left once one completes
+	fn step_migration(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(2, 2);
+		if remaining_weight.any_lt(overhead) {
+			return Weight::zero();
+		}
+
+		let cursor = SteppedMigrationCursor::<T>::get();
+		let mut consumed = overhead;
+		for (migration_id, is_applicable, step) in T::SteppedMigration::migrations() {
+			consumed = consumed.saturating_add(T::DbWeight::get().reads(2));
+			if CompletedSteppedMigrations::<T>::contains_key(&migration_id) || !is_applicable() {
+				continue;
+			}
+			if consumed.any_gt(remaining_weight) {
+				break;
+			}
+
+			let migration_cursor = cursor
+				.as_ref()
+				.filter(|(cursor_id, _)| *cursor_id == migration_id)
+				.map(|(_, migration_cursor)| migration_cursor.clone());
+			let (next_cursor, weight) = step(migration_cursor, remaining_weight.saturating_sub(consumed));
+			consumed = consumed.saturating_add(weight);
+			match next_cursor {
+				Some(next_cursor) => {
+					SteppedMigrationCursor::<T>::put((migration_id, next_cursor));
+					break;
+				}
+				None => {
+					consumed = consumed.saturating_add(T::DbWeight::get().writes(1));
+					SteppedMigrationCursor::<T>::kill();
+					CompletedSteppedMigrations::<T>::insert(&migration_id, ());
+					Self::deposit_event(Event::<T>::SteppedMigrationCompleted(migration_id));
+				}
+			}
+		}
+
+		consumed
+	}
+
+	/// Move the self-staking ledger and exit queue of `from` to `to`. Returns the staked and
+	/// exit queue amounts
+	fn do_transfer_self_stake(
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		ensure!(
+			StakingInfo::<T>::get(to).is_zero() && ExitQueue::<T>::iter_prefix(to).next().is_none(),
+			Error::<T>::StakeTransferTargetHasStake
+		);
+
+		Self::accrue_self_staking_power(from);
+		Self::accrue_self_staking_power(to);
+
+		let staked_amount = StakingInfo::<T>::take(from);
+		let mut exit_queue_amount: BalanceOf<T> = Zero::zero();
+		for (round, queued_exit) in ExitQueue::<T>::drain_prefix(from) {
+			exit_queue_amount = exit_queue_amount.saturating_add(queued_exit.amount);
+			ExitQueue::<T>::insert(to, round, queued_exit);
+			Self::schedule_exit_maturity(to, ExitQueueKind::Economy101, round);
+		}
+		ensure!(
+			!staked_amount.saturating_add(exit_queue_amount).is_zero(),
+			Error::<T>::NoStakeToTransfer
+		);
+
+		if !staked_amount.is_zero() {
+			StakingInfo::<T>::insert(to, staked_amount);
+		}
+		let switched_stake_locks = SwitchedStakeLocks::<T>::take(from);
+		if !switched_stake_locks.is_empty() {
+			SwitchedStakeLocks::<T>::insert(to, switched_stake_locks);
+		}
+
+		Ok((staked_amount, exit_queue_amount))
+	}
+
+	/// Move the innovation staking ledger, shares, pending rewards, conviction and exit queue of
+	/// `from` to `to`. Returns the staked and exit queue amounts
+	fn do_transfer_innovation_stake(
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		ensure!(
+			InnovationStakingInfo::<T>::get(to).is_zero()
+				&& SharesAndWithdrawnRewards::<T>::get(to).0.is_zero()
+				&& InnovationStakingExitQueue::<T>::iter_prefix(to).next().is_none(),
+			Error::<T>::StakeTransferTargetHasStake
+		);
+
+		ensure!(
+			!CreditLines::<T>::contains_key(from),
+			Error::<T>::CollateralLockedByCreditLine
+		);
+
+		Self::claim_rewards(from);
+
+		Self::checkpoint_voting_points(from);
+		Self::checkpoint_voting_points(to);
+		let staked_amount = InnovationStakingInfo::<T>::take(from);
+		let mut exit_queue_amount: BalanceOf<T> = Zero::zero();
+		for (round, queued_exit) in InnovationStakingExitQueue::<T>::drain_prefix(from) {
+			exit_queue_amount = exit_queue_amount.saturating_add(queued_exit.amount);
+			InnovationStakingExitQueue::<T>::insert(to, round, queued_exit);
+			Self::schedule_exit_maturity(to, ExitQueueKind::Innovation, round);
+		}
+		ensure!(
+			!staked_amount.saturating_add(exit_queue_amount).is_zero(),
+			Error::<T>::NoStakeToTransfer
+		);
+
+		if !staked_amount.is_zero() {
+			InnovationStakingInfo::<T>::insert(to, staked_amount);
+		}
+
+		let (share, withdrawn_rewards) = SharesAndWithdrawnRewards::<T>::take(from);
+		if !share.is_zero() {
+			SharesAndWithdrawnRewards::<T>::insert(to, (share, withdrawn_rewards));
+			Self::note_share_change(from, Zero::zero());
+			Self::note_share_change(to, share);
+		}
+
+		let pending_rewards = PendingRewardsOfStakingInnovation::<T>::take(from);
+		if !pending_rewards.is_empty() {
+			PendingRewardsOfStakingInnovation::<T>::mutate(to, |to_pending_rewards| {
+				for (currency_id, amount) in pending_rewards {
+					let pending_reward = to_pending_rewards.entry(currency_id).or_default();
+					*pending_reward = pending_reward.saturating_add(amount);
+				}
+			});
+		}
+
+		let current_era = CurrentEra::<T>::get();
+		let joined_shares = EraJoinedShares::<T>::take(current_era, from);
+		if !joined_shares.is_zero() {
+			EraJoinedShares::<T>::insert(current_era, to, joined_shares);
+		}
+		if let Some(since) = InnovationStakingSince::<T>::take(from) {
+			InnovationStakingSince::<T>::insert(to, since);
+		}
+		let conviction = InnovationConvictions::<T>::take(from);
+		if conviction != InnovationConviction::None {
+			InnovationConvictions::<T>::insert(to, conviction);
+		}
+
+		Self::evaluate_stake_tier(from);
+		Self::evaluate_stake_tier(to);
+		Self::release_directed_stake(from);
+
+		Ok((staked_amount, exit_queue_amount))
+	}
+
+	/// Entries of the forced actions log, oldest first
+	pub fn forced_actions() -> Vec<ForcedAction<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+		let count = ForcedActionCount::<T>::get();
+		let first = count.saturating_sub(MAX_FORCED_ACTIONS);
+		(first..count)
+			.filter_map(|index| ForcedActions::<T>::get(index % MAX_FORCED_ACTIONS))
+			.collect()
+	}
+
+	/// Record a force operation of the root origin on `target` in the forced actions log
+	fn record_forced_action(kind: ForcedActionKind<T::AccountId>, target: &T::AccountId, amount: BalanceOf<T>) {
+		let count = ForcedActionCount::<T>::get();
+		ForcedActions::<T>::insert(
+			count % MAX_FORCED_ACTIONS,
+			ForcedAction {
+				origin: RawOrigin::Root,
+				kind: kind.clone(),
+				target: target.clone(),
+				amount,
+				block: <frame_system::Pallet<T>>::block_number(),
+			},
+		);
+		ForcedActionCount::<T>::put(count.saturating_add(1));
+
+		Self::deposit_event(Event::<T>::ForcedActionRecorded(kind, target.clone(), amount));
+	}
+
+	/// Native balance of `who` that its staking ledgers and queued exits expect to be reserved.
+	/// Self stake backed by a balance lock is excluded, estate co-stakes are not tracked per
+	/// account and not included
+	pub fn reserved_stake_ledger(who: &T::AccountId) -> BalanceOf<T> {
+		let self_stake = ExitQueue::<T>::iter_prefix_values(who)
+			.fold(StakingInfo::<T>::get(who), |total, queued_exit| {
+				total.saturating_add(queued_exit.amount)
+			})
+			.saturating_sub(LockedSelfStake::<T>::get(who));
+		let innovation_stake = InnovationStakingExitQueue::<T>::iter_prefix_values(who)
+			.fold(InnovationStakingInfo::<T>::get(who), |total, queued_exit| {
+				total.saturating_add(queued_exit.amount)
+			});
+		let estate_stake = EstateExitQueue::<T>::iter_prefix_values((who,))
+			.fold(AccountEstateStake::<T>::get(who), |total, queued_exit| {
+				total.saturating_add(queued_exit.amount)
+			});
+
+		self_stake.saturating_add(innovation_stake).saturating_add(estate_stake)
+	}
+
+	/// Check that the reserved balance of `who` covers its staking ledgers and queued exits,
+	/// emitting `ReserveLedgerDiscrepancy` if not. Returns whether the balance is covered
+	pub fn reconcile_account(who: &T::AccountId) -> bool {
+		let reserved_balance = T::Currency::reserved_balance(who);
+		let ledger_balance = Self::reserved_stake_ledger(who);
+		if reserved_balance >= ledger_balance {
+			return true;
+		}
+
+		Self::deposit_event(Event::<T>::ReserveLedgerDiscrepancy(
+			who.clone(),
+			reserved_balance,
+			ledger_balance,
+		));
+		false
+	}
+
+	/// Release unstaked economy 101 self-stake, lifting the balance lock before unreserving
+	fn release_self_stake(who: &T::AccountId, amount: BalanceOf<T>) {
+		let locked_stake = LockedSelfStake::<T>::get(who);
+		let from_lock = amount.min(locked_stake);
+
+		if !from_lock.is_zero() {
+			let remaining_lock = locked_stake.saturating_sub(from_lock);
+			if remaining_lock.is_zero() {
+				T::Currency::remove_lock(ECONOMY_STAKE_LOCK_ID, who);
+				LockedSelfStake::<T>::remove(who);
+			} else {
+				T::Currency::set_lock(ECONOMY_STAKE_LOCK_ID, who, remaining_lock, WithdrawReasons::all());
+				LockedSelfStake::<T>::insert(who, remaining_lock);
+			}
+		}
+
+		T::Currency::unreserve(who, amount.saturating_sub(from_lock));
+	}
+
+	fn do_force_unstake(who: T::AccountId, amount: BalanceOf<T>, estate: Option<EstateId>) -> DispatchResult {
+		// Ensure amount is greater than zero
+		ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+
+		match estate {
+			None => {
+				let staked_balance = StakingInfo::<T>::get(&who);
+				ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+				Self::accrue_self_staking_power(&who);
+
+				let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+
+				let amount_to_unstake = if remaining < T::MinimumStake::get() {
+					// Remaining amount below minimum, remove all staked amount
+					staked_balance
+				} else {
+					amount
+				};
+
+				// Update staking info of user immediately
+				// Remove staking info
+				if amount_to_unstake == staked_balance {
+					StakingInfo::<T>::remove(&who);
+				} else {
+					StakingInfo::<T>::insert(&who, remaining);
+				}
+
+				let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_unstake);
+				<TotalStake<T>>::put(new_total_staked);
+
+				Self::release_self_stake(&who, amount_to_unstake);
+
+				Self::record_forced_action(ForcedActionKind::Unstake(None), &who, amount_to_unstake);
+				Self::deposit_event(Event::UnstakedAmountWithdrew(who.clone(), amount_to_unstake));
+				Self::deposit_event(Event::SelfStakingRemovedFromEconomy101(who, amount));
+			}
+			Some(estate_id) => {
+				ensure!(
+					T::EstateHandler::check_estate(estate_id.clone())?,
+					Error::<T>::StakeEstateDoesNotExist
+				);
+				let mut staked_balance: BalanceOf<T> = Zero::zero();
+				let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
+				match staking_bond_value {
+					Some(staking_bond) => {
+						ensure!(staking_bond.staker == who.clone(), Error::<T>::NoFundsStakedAtEstate);
+						staked_balance = staking_bond.amount;
+					}
+					_ => {}
+				}
+				ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+				let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+
+				let amount_to_unstake = if remaining < T::MinimumStake::get() {
+					// Remaining amount below minimum, remove all staked amount
+					staked_balance
+				} else {
+					amount
+				};
+
+				// Update staking info of user immediately
+				// Remove staking info
+				if amount_to_unstake == staked_balance {
+					EstateStakingInfo::<T>::remove(&estate_id);
+					EstateStakerRoles::<T>::remove(&estate_id);
+					EstateStakingSince::<T>::remove(&estate_id);
+				} else {
+					let new_staking_bond = Bond {
+						staker: who.clone(),
+						amount: remaining,
+					};
+					EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
+				}
+
+				let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_unstake);
+				<TotalEstateStake<T>>::put(new_total_staked);
+				AccountEstateStake::<T>::mutate(&who, |stake| *stake = stake.saturating_sub(amount_to_unstake));
+				Self::sync_weighted_estate_stake(estate_id, &who);
+
+				T::Currency::unreserve(&who, amount_to_unstake);
+
+				Self::record_forced_action(ForcedActionKind::Unstake(Some(estate_id)), &who, amount_to_unstake);
+				Self::deposit_event(Event::UnstakedAmountWithdrew(who.clone(), amount_to_unstake));
+				Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(
+					who.clone(),
+					estate_id,
+					amount,
+				));
+				Self::deposit_estate_bond_updated(&who, estate_id);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn do_stake_on_estate(who: &T::AccountId, estate_id: EstateId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(
+			T::EstateHandler::check_estate(estate_id.clone())?,
+			Error::<T>::StakeEstateDoesNotExist
+		);
+		let role = if T::EstateHandler::check_estate_ownership(who.clone(), estate_id.clone())? {
+			EstateStakerRole::Owner
+		} else if T::EstateHandler::check_estate_lease(who.clone(), estate_id.clone())? {
+			EstateStakerRole::Lessee
+		} else if EstateCoStakerCaps::<T>::contains_key(estate_id, who) {
+			return Self::do_co_stake_on_estate(who, estate_id, amount);
+		} else {
+			return Err(Error::<T>::StakerNotEstateOwner.into());
+		};
+
+		let mut staked_balance: BalanceOf<T> = Zero::zero();
+		let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
+		match staking_bond_value {
+			Some(staking_bond) => {
+				ensure!(
+					staking_bond.staker == who.clone(),
+					Error::<T>::PreviousOwnerStillStakesAtEstate
+				);
+				staked_balance = staking_bond.amount;
+			}
+			_ => {}
+		}
+
+		let total = staked_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+
+		// Ensure stake amount less than maximum
+		let co_staked = EstateCoStakeTotal::<T>::get(estate_id);
+		ensure!(
+			total.saturating_add(co_staked) <= Self::estate_stake_allowance(estate_id)?,
+			Error::<T>::StakeAmountExceedMaximumAmount
+		);
+
+		let (new_account_estate_stake, new_total_staked) = Self::ensure_estate_stake_limits(who, amount)?;
+
+		T::Currency::reserve(who, amount)?;
+
+		let new_staking_bond = Bond {
+			staker: who.clone(),
+			amount: total,
+		};
+
+		EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
+		EstateStakerRoles::<T>::insert(&estate_id, role);
+		if staked_balance.is_zero() {
+			EstateStakingSince::<T>::insert(&estate_id, <frame_system::Pallet<T>>::block_number());
+		}
+
+		Self::note_estate_stake_added(who, estate_id, amount, new_account_estate_stake, new_total_staked);
+
+		Ok(())
+	}
+
+	/// Stake on an estate as a co-staker approved by the estate owner
+	fn do_co_stake_on_estate(who: &T::AccountId, estate_id: EstateId, amount: BalanceOf<T>) -> DispatchResult {
+		let cap = EstateCoStakerCaps::<T>::get(estate_id, who).ok_or(Error::<T>::StakerNotEstateOwner)?;
+		let total = EstateCoStakes::<T>::get(estate_id, who)
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+		ensure!(total <= cap, Error::<T>::EstateCoStakeExceedsCap);
+
+		let owner_staked = EstateStakingInfo::<T>::get(estate_id)
+			.map(|bond| bond.amount)
+			.unwrap_or_default();
+		let new_co_staked = EstateCoStakeTotal::<T>::get(estate_id).saturating_add(amount);
+		ensure!(
+			owner_staked.saturating_add(new_co_staked) <= Self::estate_stake_allowance(estate_id)?,
+			Error::<T>::StakeAmountExceedMaximumAmount
+		);
+
+		let (new_account_estate_stake, new_total_staked) = Self::ensure_estate_stake_limits(who, amount)?;
+
+		T::Currency::reserve(who, amount)?;
+
+		EstateCoStakes::<T>::insert(estate_id, who, total);
+		EstateCoStakeTotal::<T>::insert(estate_id, new_co_staked);
+
+		Self::note_estate_stake_added(who, estate_id, amount, new_account_estate_stake, new_total_staked);
+
+		Ok(())
+	}
+
+	/// Unstake co-stake from an estate. The unstaked amount can be withdrawn from the next round
+	fn do_co_unstake_on_estate(who: &T::AccountId, estate_id: EstateId, amount: BalanceOf<T>) -> DispatchResult {
+		let staked_balance = EstateCoStakes::<T>::get(estate_id, who);
+		ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+		let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+		let amount_to_unstake = if remaining < T::MinimumStake::get() {
+			// Remaining amount below minimum, remove all staked amount
+			staked_balance
+		} else {
+			amount
+		};
+
+		let current_round = Self::current_round_info()?;
+		let next_round = current_round.current.saturating_add(One::one());
+		ensure!(
+			!EstateExitQueue::<T>::contains_key((who, next_round, estate_id)),
+			Error::<T>::ExitQueueAlreadyScheduled
+		);
+		Self::ensure_exit_queue_capacity(who, ExitQueueKind::Estate(estate_id), next_round)?;
+		Self::queue_exit(who, ExitQueueKind::Estate(estate_id), next_round, amount_to_unstake);
+
+		if amount_to_unstake == staked_balance {
+			EstateCoStakes::<T>::remove(estate_id, who);
+		} else {
+			EstateCoStakes::<T>::insert(estate_id, who, remaining);
+		}
+		EstateCoStakeTotal::<T>::mutate_exists(estate_id, |maybe_total| {
+			let total = maybe_total.unwrap_or_default().saturating_sub(amount_to_unstake);
+			*maybe_total = if total.is_zero() { None } else { Some(total) };
+		});
+
+		let new_total_staked = TotalEstateStake::<T>::get().saturating_sub(amount_to_unstake);
+		<TotalEstateStake<T>>::put(new_total_staked);
+		AccountEstateStake::<T>::mutate(who, |stake| *stake = stake.saturating_sub(amount_to_unstake));
+		Self::sync_weighted_estate_stake(estate_id, who);
+
+		Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(
+			who.clone(),
+			estate_id,
+			amount,
+		));
+		Self::deposit_estate_bond_updated(who, estate_id);
+
+		Ok(())
+	}
+
+	/// Emit `EstateBondUpdated` with the estate stake of `who` on `estate_id` and the stake the
+	/// estate can still take within its land unit allowance
+	fn deposit_estate_bond_updated(who: &T::AccountId, estate_id: EstateId) {
+		let owner_bond = EstateStakingInfo::<T>::get(estate_id);
+		let owner_staked = owner_bond.as_ref().map(|bond| bond.amount).unwrap_or_default();
+		let bond_total = match owner_bond {
+			Some(bond) if bond.staker == *who => bond.amount,
+			_ => EstateCoStakes::<T>::get(estate_id, who),
+		};
+		let remaining_allowance = Self::estate_stake_allowance(estate_id)
+			.unwrap_or_default()
+			.saturating_sub(owner_staked.saturating_add(EstateCoStakeTotal::<T>::get(estate_id)));
+
+		Self::deposit_event(Event::EstateBondUpdated(
+			who.clone(),
+			estate_id,
+			bond_total,
+			remaining_allowance,
+		));
+	}
+
+	/// Number of blocks the current stake bond of `estate_id` has existed for
+	pub fn estate_stake_age(estate_id: EstateId) -> Option<BlockNumberFor<T>> {
+		EstateStakingSince::<T>::get(estate_id)
+			.map(|since| <frame_system::Pallet<T>>::block_number().saturating_sub(since))
+	}
+
+	/// Maximum stake of an estate, proportional to its land units
+	fn estate_stake_allowance(estate_id: EstateId) -> Result<BalanceOf<T>, DispatchError> {
+		let total_land_units = T::EstateHandler::get_total_land_units(Some(estate_id));
+		ensure!(total_land_units > 0, Error::<T>::StakeEstateDoesNotExist);
+
+		Ok(T::MaximumEstateStake::get()
+			.saturating_mul(TryInto::<BalanceOf<T>>::try_into(total_land_units).unwrap_or_default()))
+	}
+
+	/// Ensure `amount` more estate stake of `who` stays within the estate stake limits. Returns
+	/// the new estate stake of `who` and the new total estate stake
+	fn ensure_estate_stake_limits(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let new_account_estate_stake = AccountEstateStake::<T>::get(who).saturating_add(amount);
+		if let Some(max_per_account) = MaxEstateStakePerAccount::<T>::get() {
+			ensure!(
+				new_account_estate_stake <= max_per_account,
+				Error::<T>::EstateStakeExceedsAccountLimit
+			);
+		}
+		let new_total_staked = TotalEstateStake::<T>::get().saturating_add(amount);
+		if let Some(max_total) = MaxTotalEstateStake::<T>::get() {
+			ensure!(new_total_staked <= max_total, Error::<T>::EstateStakeExceedsTotalLimit);
+		}
+
+		Ok((new_account_estate_stake, new_total_staked))
+	}
+
+	/// Record `amount` more estate stake of `who` and report saturated estate stake limits
+	fn note_estate_stake_added(
+		who: &T::AccountId,
+		estate_id: EstateId,
+		amount: BalanceOf<T>,
+		new_account_estate_stake: BalanceOf<T>,
+		new_total_staked: BalanceOf<T>,
+	) {
+		<TotalEstateStake<T>>::put(new_total_staked);
+		AccountEstateStake::<T>::insert(who, new_account_estate_stake);
+		Self::sync_weighted_estate_stake(estate_id, who);
+
+		Self::deposit_event(Event::EstateStakedToEconomy101(who.clone(), estate_id, amount));
+		Self::deposit_estate_bond_updated(who, estate_id);
+		if MaxEstateStakePerAccount::<T>::get() == Some(new_account_estate_stake) {
+			Self::deposit_event(Event::AccountEstateStakeSaturated(
+				who.clone(),
+				new_account_estate_stake,
+			));
+		}
+		if MaxTotalEstateStake::<T>::get() == Some(new_total_staked) {
+			Self::deposit_event(Event::TotalEstateStakeSaturated(new_total_staked));
+		}
+	}
+
+	fn do_unstake_on_estate(who: &T::AccountId, estate_id: EstateId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(
+			T::EstateHandler::check_estate(estate_id.clone())?,
+			Error::<T>::StakeEstateDoesNotExist
+		);
+
+		if EstateCoStakes::<T>::contains_key(estate_id, who) {
+			return Self::do_co_unstake_on_estate(who, estate_id, amount);
+		}
+
+		let mut staked_balance = Zero::zero();
+		let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
+		match staking_bond_value {
+			Some(staking_bond) => {
+				ensure!(staking_bond.staker == who.clone(), Error::<T>::NoFundsStakedAtEstate);
+				staked_balance = staking_bond.amount;
+			}
+			_ => {}
+		}
+		ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+
+		let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+
+		let amount_to_unstake = if remaining < T::MinimumStake::get() {
+			// Remaining amount below minimum, remove all staked amount
+			staked_balance
+		} else {
+			amount
+		};
+
+		let current_round = Self::current_round_info()?;
+		let next_round = current_round.current.saturating_add(One::one());
+
+		// Check if user already in estate exit queue of the current estate
+		ensure!(
+			!EstateExitQueue::<T>::contains_key((who, next_round, estate_id)),
+			Error::<T>::ExitQueueAlreadyScheduled
+		);
+
+		// This estate exit queue will be executed by exit_staking extrinsics to unreserved token
+		Self::ensure_exit_queue_capacity(who, ExitQueueKind::Estate(estate_id), next_round)?;
+		Self::queue_exit(who, ExitQueueKind::Estate(estate_id), next_round, amount_to_unstake);
+
+		// Update estate staking info of user immediately
+		// Remove estate staking info
+		if amount_to_unstake == staked_balance {
+			EstateStakingInfo::<T>::remove(&estate_id);
+			EstateStakerRoles::<T>::remove(&estate_id);
+			EstateStakingSince::<T>::remove(&estate_id);
+		} else {
+			let new_staking_bond = Bond {
+				staker: who.clone(),
+				amount: remaining,
+			};
+			EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
+		}
+
+		let new_total_staked = TotalEstateStake::<T>::get().saturating_sub(amount_to_unstake);
+		<TotalEstateStake<T>>::put(new_total_staked);
+		AccountEstateStake::<T>::mutate(who, |stake| *stake = stake.saturating_sub(amount_to_unstake));
+		Self::sync_weighted_estate_stake(estate_id, who);
+
+		Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(
+			who.clone(),
+			estate_id,
+			amount,
+		));
+		Self::deposit_estate_bond_updated(who, estate_id);
+
+		Ok(())
+	}
+
+	/// Reward weight of an estate, the product of its contiguity boost and the reward weights of
+	/// its traits
+	pub fn estate_reward_weight(estate_id: EstateId) -> FixedU128 {
+		let boost = Self::estate_boost(estate_id);
+		let estate_traits = match T::EstateHandler::get_estate_traits(estate_id) {
+			Some(estate_traits) => estate_traits,
+			None => return boost,
+		};
+
+		[
+			(EstateTraitKind::Biome, estate_traits.biome),
+			(EstateTraitKind::Rarity, estate_traits.rarity),
+			(EstateTraitKind::NeighborhoodScore, estate_traits.neighborhood_score),
+		]
+		.iter()
+		.filter_map(|(trait_kind, trait_value)| EstateTraitRewardWeights::<T>::get(trait_kind, trait_value))
+		.fold(boost, |weight, trait_weight| weight.saturating_mul(trait_weight))
+	}
+
+	/// Boost of the highest tier reached by `estate_id`, only contiguous estates are boosted
+	fn estate_boost(estate_id: EstateId) -> FixedU128 {
+		match T::EstateHandler::get_estate_shape(estate_id) {
+			Some(shape) if shape.is_contiguous => EstateBoostTiers::<T>::get()
+				.iter()
+				.rev()
+				.find(|tier| tier.min_land_units <= shape.land_units)
+				.map_or(FixedU128::one(), |tier| tier.boost),
+			_ => FixedU128::one(),
+		}
+	}
+
+	/// Recompute the weighted estate stake of `who` on `estate_id` from its bond and co-stake, and
+	/// index the bond under `who` in `StakedEstates`
+	pub(crate) fn sync_weighted_estate_stake(estate_id: EstateId, who: &T::AccountId) {
+		Self::settle_estate_reward(estate_id, who);
+
+		let mut staked = EstateCoStakes::<T>::get(estate_id, who);
+		match EstateStakingInfo::<T>::get(estate_id) {
+			Some(bond) if bond.staker == *who => {
+				staked = staked.saturating_add(bond.amount);
+				StakedEstates::<T>::insert(who, estate_id, ());
+			}
+			_ => StakedEstates::<T>::remove(who, estate_id),
+		}
+
+		let weighted: BalanceOf<T> = Self::estate_reward_weight(estate_id)
+			.saturating_mul_int(staked.saturated_into::<u128>())
+			.saturated_into();
+		let previous = WeightedEstateStakes::<T>::get(estate_id, who);
+		if weighted.is_zero() {
+			EstateRewardCheckpoints::<T>::remove(estate_id, who);
+			EstateRewardLeaseOffers::<T>::remove(estate_id, who);
+			// A lease of rewards that no longer accrue ends early
+			if let Some(lease) = EstateRewardLeases::<T>::get(estate_id, who) {
+				Self::end_estate_reward_lease(estate_id, who, lease);
+			}
+		} else if previous.is_zero() {
+			EstateRewardCheckpoints::<T>::insert(estate_id, who, EstateRewardPerWeight::<T>::get());
+		}
+		if weighted == previous {
+			return;
+		}
+
+		if weighted.is_zero() {
+			WeightedEstateStakes::<T>::remove(estate_id, who);
+		} else {
+			WeightedEstateStakes::<T>::insert(estate_id, who, weighted);
+		}
+		AccountWeightedEstateStake::<T>::mutate_exists(who, |maybe_stake| {
+			let stake = maybe_stake
+				.unwrap_or_default()
+				.saturating_sub(previous)
+				.saturating_add(weighted);
+			*maybe_stake = if stake.is_zero() { None } else { Some(stake) };
+		});
+		TotalWeightedEstateStake::<T>::mutate(|total| *total = total.saturating_sub(previous).saturating_add(weighted));
+	}
+
+	/// Credit the estate rewards accrued to the weighted stake of `staker` on `estate_id` since it
+	/// was last settled. Rewards accrued during a reward lease are credited to the lessee and a
+	/// lease past its end round is ended
+	fn settle_estate_reward(estate_id: EstateId, staker: &T::AccountId) {
+		let reward_per_weight = EstateRewardPerWeight::<T>::get();
+		let checkpoint = EstateRewardCheckpoints::<T>::get(estate_id, staker);
+		let weighted = WeightedEstateStakes::<T>::get(estate_id, staker).saturated_into::<u128>();
+		if weighted.is_zero() {
+			return;
+		}
+		let accrued = |from: FixedU128, to: FixedU128| -> BalanceOf<T> {
+			to.saturating_sub(from).saturating_mul_int(weighted).saturated_into()
+		};
+		let credit = |who: &T::AccountId, amount: BalanceOf<T>| {
+			if !amount.is_zero() {
+				PendingEstateRewards::<T>::mutate(who, |pending| *pending = pending.saturating_add(amount));
+			}
+		};
+
+		match EstateRewardLeases::<T>::get(estate_id, staker) {
+			Some(lease) if T::RoundHandler::get_current_round_info().current >= lease.end_round => {
+				let lease_end = EstateRewardPerWeightAt::<T>::get(lease.end_round)
+					.unwrap_or(reward_per_weight)
+					.max(checkpoint);
+				credit(&lease.lessee, accrued(checkpoint, lease_end));
+				credit(staker, accrued(lease_end, reward_per_weight));
+				Self::end_estate_reward_lease(estate_id, staker, lease);
+			}
+			Some(lease) => credit(&lease.lessee, accrued(checkpoint, reward_per_weight)),
+			None => credit(staker, accrued(checkpoint, reward_per_weight)),
+		}
+		EstateRewardCheckpoints::<T>::insert(estate_id, staker, reward_per_weight);
+	}
+
+	/// End the reward lease of the estate stake of `staker` on `estate_id`. The lease price is
+	/// paid to the staker pro rata of the elapsed lease rounds, the rest is unreserved
+	fn end_estate_reward_lease(
+		estate_id: EstateId,
+		staker: &T::AccountId,
+		lease: EstateRewardLease<T::AccountId, BalanceOf<T>>,
+	) {
+		let elapsed_rounds = T::RoundHandler::get_current_round_info()
+			.current
+			.min(lease.end_round)
+			.saturating_sub(lease.start_round);
+		let paid =
+			Perbill::from_rational(elapsed_rounds, lease.end_round.saturating_sub(lease.start_round)) * lease.price;
+
+		let _ = T::Currency::repatriate_reserved(&lease.lessee, staker, paid, BalanceStatus::Free);
+		T::Currency::unreserve(&lease.lessee, lease.price.saturating_sub(paid));
+		EstateRewardLeases::<T>::remove(estate_id, staker);
+		EstateRewardLeaseEnds::<T>::mutate_exists(lease.end_round, |maybe_leases| {
+			let leases = maybe_leases.unwrap_or_default().saturating_sub(1);
+			*maybe_leases = if leases.is_zero() {
+				EstateRewardPerWeightAt::<T>::remove(lease.end_round);
+				None
+			} else {
+				Some(leases)
+			};
+		});
+
+		Self::deposit_event(Event::<T>::EstateRewardLeaseEnded(
+			estate_id,
+			staker.clone(),
+			lease.lessee,
+			paid,
+		));
+	}
+
+	/// Move the owner stake bond of the subdivided `parent` estate to its `children` in proportion
+	/// to their land units. Co-stakes, and the bond if a child part would fall below the minimum
+	/// stake, are queued for exit from the next round
+	#[transactional]
+	fn subdivide_estate_stake(parent: EstateId, children: &[(EstateId, u64)]) -> DispatchResult {
+		let next_round = Self::current_round_info()?.current.saturating_add(One::one());
+		let mut exited: BalanceOf<T> = Zero::zero();
+
+		if let Some(bond) = EstateStakingInfo::<T>::take(parent) {
+			let role = EstateStakerRoles::<T>::take(parent);
+			let since = EstateStakingSince::<T>::take(parent);
+			let total_land_units = children
+				.iter()
+				.fold(0u64, |total, (_, units)| total.saturating_add(*units));
+
+			let mut child_bonds: Vec<(EstateId, BalanceOf<T>)> = Vec::new();
+			let mut allocated: BalanceOf<T> = Zero::zero();
+			for (index, (child, land_units)) in children.iter().enumerate() {
+				let amount = if index + 1 == children.len() {
+					bond.amount.saturating_sub(allocated)
+				} else {
+					Perbill::from_rational(*land_units, total_land_units) * bond.amount
+				};
+				allocated = allocated.saturating_add(amount);
+				child_bonds.push((*child, amount));
+			}
+
+			let can_split = !child_bonds.is_empty()
+				&& child_bonds.iter().all(|(child, amount)| {
+					*amount >= T::MinimumStake::get() && !EstateStakingInfo::<T>::contains_key(child)
+				});
+			if can_split {
+				for (child, amount) in child_bonds {
+					EstateStakingInfo::<T>::insert(
+						child,
+						Bond {
+							staker: bond.staker.clone(),
+							amount,
+						},
+					);
+					EstateStakerRoles::<T>::insert(child, role);
+					if let Some(since) = since {
+						EstateStakingSince::<T>::insert(child, since);
+					}
+					Self::sync_weighted_estate_stake(child, &bond.staker);
+				}
+			} else {
+				Self::queue_subdivided_estate_exit(&bond.staker, parent, next_round, bond.amount);
+				exited = exited.saturating_add(bond.amount);
+			}
+			Self::sync_weighted_estate_stake(parent, &bond.staker);
+		}
+
+		for (co_staker, amount) in EstateCoStakes::<T>::drain_prefix(parent) {
+			Self::queue_subdivided_estate_exit(&co_staker, parent, next_round, amount);
+			exited = exited.saturating_add(amount);
+			Self::sync_weighted_estate_stake(parent, &co_staker);
+		}
+		EstateCoStakeTotal::<T>::remove(parent);
+		let _ = EstateCoStakerCaps::<T>::clear_prefix(parent, u32::MAX, None);
+
+		Self::deposit_event(Event::<T>::EstateStakeSubdivided(
+			parent,
+			children.iter().map(|(child, _)| *child).collect(),
+			exited,
+		));
+
+		Ok(())
+	}
+
+	/// Queue `amount` of the estate stake of `who` on the subdivided `estate_id` for exit
+	fn queue_subdivided_estate_exit(who: &T::AccountId, estate_id: EstateId, round: RoundIndex, amount: BalanceOf<T>) {
+		Self::queue_exit(who, ExitQueueKind::Estate(estate_id), round, amount);
+		TotalEstateStake::<T>::mutate(|total| *total = total.saturating_sub(amount));
+		AccountEstateStake::<T>::mutate(who, |stake| *stake = stake.saturating_sub(amount));
+	}
+
+	/// Returns the estate owner if `operator` is the authorized staking operator of the estate
+	fn ensure_estate_staking_operator(
+		operator: &T::AccountId,
+		estate_id: EstateId,
+	) -> Result<T::AccountId, DispatchError> {
+		let (owner, estate_operator) =
+			EstateStakingOperators::<T>::get(estate_id).ok_or(Error::<T>::NotEstateStakingOperator)?;
+		ensure!(estate_operator == *operator, Error::<T>::NotEstateStakingOperator);
+		// Authorization lapses when the estate changes hands
+		ensure!(
+			T::EstateHandler::check_estate_ownership(owner.clone(), estate_id)?,
+			Error::<T>::NotEstateStakingOperator
+		);
+		Ok(owner)
+	}
+
+	/// Move the part of an estate bond above `MaximumEstateStake * land_units` into the exit
+	/// queue, returns the excess amount
+	pub fn do_rebalance_estate_stake(estate_id: EstateId) -> Result<BalanceOf<T>, DispatchError> {
+		let staking_bond = EstateStakingInfo::<T>::get(estate_id).ok_or(Error::<T>::NoFundsStakedAtEstate)?;
+
+		let total_land_units = T::EstateHandler::get_total_land_units(Some(estate_id));
+		let stake_allowance = T::MaximumEstateStake::get()
+			.saturating_mul(TryInto::<BalanceOf<T>>::try_into(total_land_units).unwrap_or_default());
+
+		if staking_bond.amount <= stake_allowance {
+			return Ok(Zero::zero());
+		}
+
+		let remaining = if stake_allowance < T::MinimumStake::get() {
+			// Remaining amount below minimum, remove all staked amount
+			Zero::zero()
+		} else {
+			stake_allowance
+		};
+		let excess = staking_bond.amount.saturating_sub(remaining);
+
+		let current_round = Self::current_round_info()?;
+		let next_round = current_round.current.saturating_add(One::one());
+
+		Self::queue_exit(
+			&staking_bond.staker,
+			ExitQueueKind::Estate(estate_id),
+			next_round,
+			excess,
+		);
+
+		if remaining.is_zero() {
+			EstateStakingInfo::<T>::remove(&estate_id);
+			EstateStakerRoles::<T>::remove(&estate_id);
+			EstateStakingSince::<T>::remove(&estate_id);
+		} else {
+			let new_staking_bond = Bond {
+				staker: staking_bond.staker.clone(),
+				amount: remaining,
+			};
+			EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
+		}
+
+		let new_total_staked = TotalEstateStake::<T>::get().saturating_sub(excess);
+		<TotalEstateStake<T>>::put(new_total_staked);
+		AccountEstateStake::<T>::mutate(&staking_bond.staker, |stake| *stake = stake.saturating_sub(excess));
+		Self::sync_weighted_estate_stake(estate_id, &staking_bond.staker);
+
+		Self::deposit_event(Event::EstateStakeRebalanced(staking_bond.staker, estate_id, excess));
+
+		Ok(excess)
+	}
+
+	/// Add the power of the elapsed rounds to the accumulated power per stake. Stakers receive
+	/// their share lazily through `accrue_self_staking_power`
+	fn distribute_self_staking_power(new_round: RoundIndex, elapsed_rounds: RoundIndex) {
+		let total_stake = TotalStake::<T>::get();
+		let power_amount = SelfStakingPowerPerRound::<T>::get().saturating_mul(elapsed_rounds.into());
+		if total_stake.is_zero() || power_amount.is_zero() {
+			return;
+		}
+
+		let power_per_stake: u128 = U256::from(power_amount)
+			.saturating_mul(U256::from(POWER_PER_STAKE_PRECISION))
+			.checked_div(U256::from(total_stake.saturated_into::<u128>()))
+			.unwrap_or_default()
+			.saturated_into();
+
+		AccumulatedPowerPerStake::<T>::mutate(|accumulated| *accumulated = accumulated.saturating_add(power_per_stake));
+
+		Self::deposit_event(Event::<T>::SelfStakingPowerDistributed(new_round, power_amount));
+	}
+
+	/// Credit the power accrued since the last checkpoint to `who`. Must be called before the
+	/// self-staking balance of `who` changes
+	pub fn accrue_self_staking_power(who: &T::AccountId) {
+		let accumulated = AccumulatedPowerPerStake::<T>::get();
+		let checkpoint = SelfStakingPowerCheckpoint::<T>::get(who);
+		if accumulated == checkpoint {
+			return;
+		}
+
+		let staked_balance = StakingInfo::<T>::get(who);
+		let accrued_power: PowerAmount = U256::from(staked_balance.saturated_into::<u128>())
+			.saturating_mul(U256::from(accumulated.saturating_sub(checkpoint)))
+			.checked_div(U256::from(POWER_PER_STAKE_PRECISION))
+			.unwrap_or_default()
+			.saturated_into();
+
+		SelfStakingPowerCheckpoint::<T>::insert(who, accumulated);
+
+		if !accrued_power.is_zero() {
+			Self::deposit_power(who, accrued_power);
+			Self::deposit_event(Event::<T>::SelfStakingPowerCredited(who.clone(), accrued_power));
+		}
+	}
+
+	/// Spend `amount` of the power balance of `who`, including power accrued by self-staking
+	pub fn spend_power(who: &T::AccountId, amount: PowerAmount) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::PowerAmountIsZero);
+
+		Self::accrue_self_staking_power(who);
+		Self::migrate_power_balance(who);
+
+		ensure!(
+			Self::get_power_balance(who) >= amount,
+			Error::<T>::AccountHasNoPowerBalance
+		);
+		T::FungibleTokenCurrency::withdraw(T::PowerCurrencyId::get(), who, amount.saturated_into())?;
+		Self::deposit_event(Event::<T>::PowerSpent(who.clone(), amount));
+
+		Ok(())
+	}
+
+	/// Ensure the unstake cooldown of `who` on the pool of `kind` elapsed and record the unstake
+	fn ensure_unstake_cooldown(who: &T::AccountId, kind: ExitQueueKind) -> DispatchResult {
+		let cooldown = UnstakeCooldown::<T>::get();
+		if cooldown.is_zero() {
+			return Ok(());
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		if let Some(last_unstake) = LastUnstakeBlocks::<T>::get(who, kind) {
+			ensure!(
+				now >= last_unstake.saturating_add(cooldown),
+				Error::<T>::OperationOnCooldown
+			);
+		}
+		LastUnstakeBlocks::<T>::insert(who, kind, now);
+
+		Ok(())
+	}
+
+	/// Ensure `who` can queue an exit at `round` in the exit queue of `kind` without exceeding
+	/// `MaxQueuedExitsPerAccount` distinct rounds. Estate exits count across all estates
+	fn ensure_exit_queue_capacity(who: &T::AccountId, kind: ExitQueueKind, round: RoundIndex) -> DispatchResult {
+		let queued_rounds: BTreeSet<RoundIndex> = match kind {
+			ExitQueueKind::Economy101 => ExitQueue::<T>::iter_key_prefix(who).collect(),
+			ExitQueueKind::Innovation => InnovationStakingExitQueue::<T>::iter_key_prefix(who).collect(),
+			ExitQueueKind::Estate(_) => EstateExitQueue::<T>::iter_key_prefix((who,))
+				.map(|(round, _)| round)
+				.collect(),
+		};
+		ensure!(
+			queued_rounds.contains(&round) || (queued_rounds.len() as u32) < T::MaxQueuedExitsPerAccount::get(),
+			Error::<T>::TooManyQueuedExits
+		);
+
+		Ok(())
+	}
+
+	/// Stake `amount` of `who` to innovation staking ledger
+	fn do_stake_on_innovation(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		Self::checkpoint_voting_points(who);
+		Self::restake_matured_exits(who);
+
+		// Check if user has enough balance for staking
+		ensure!(
+			T::Currency::free_balance(who) >= amount,
+			Error::<T>::InsufficientBalanceForStaking
+		);
+		Self::ensure_keep_alive(who, amount)?;
+
+		ensure!(
+			!amount.is_zero() || amount >= T::MinimumStake::get(),
+			Error::<T>::StakeBelowMinimum
+		);
+
+		let receipt_class = if Self::account_preferences(who).mint_position_receipt {
+			PositionReceiptClass::<T>::get()
+		} else {
+			None
+		};
+		// A position receipt stakes through a new position account
+		let has_slot = match receipt_class {
+			Some(_) => !Self::innovation_staker_cap_reached(),
+			None => Self::has_innovation_staker_slot(who),
+		};
+		if !has_slot {
+			return Self::join_innovation_waitlist(who, amount);
+		}
+
+		let current_round = Self::current_round_info()?;
+
+		if let Some(class_id) = receipt_class {
+			ensure!(amount >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+			return Self::stake_with_position_receipt(who, class_id, amount, current_round.current);
+		}
+
+		let total = Self::ensure_innovation_stake_total(who, amount)?;
+
+		T::Currency::reserve(who, amount)?;
+
+		InnovationStakingInfo::<T>::insert(who, total);
+
+		let new_total_staked = TotalInnovationStaking::<T>::get().saturating_add(amount);
+		<TotalInnovationStaking<T>>::put(new_total_staked);
+
+		Self::add_share(who, amount);
+
+		Self::deposit_event(Event::StakedInnovation(who.clone(), amount));
+
+		Ok(())
+	}
+
+	/// Whether `staker` can add innovation stake under `MaxInnovationStakers`. Accounts holding
+	/// shares always can, new stakers only while `innovation_staker_cap_reached` is false
+	fn has_innovation_staker_slot(staker: &T::AccountId) -> bool {
+		!SharesAndWithdrawnRewards::<T>::get(staker).0.is_zero() || !Self::innovation_staker_cap_reached()
+	}
+
+	/// Ensure `staker` can add innovation stake under `MaxInnovationStakers`. Every path adding
+	/// innovation shares other than waitlist admission goes through this check
+	fn ensure_innovation_staker_slot(staker: &T::AccountId) -> DispatchResult {
+		ensure!(
+			Self::has_innovation_staker_slot(staker),
+			Error::<T>::InnovationStakerCapReached
+		);
+		Ok(())
+	}
+
+	/// Whether new innovation stakers have to wait for a slot, either because the staker cap is
+	/// reached or earlier accounts are still waiting
+	fn innovation_staker_cap_reached() -> bool {
+		MaxInnovationStakers::<T>::get().map_or(false, |max_stakers| {
+			!InnovationStakerWaitlist::<T>::get().is_empty() || InnovationStakerCount::<T>::get() >= max_stakers
+		})
+	}
+
+	/// Reserve the stake of `who` and append it to the innovation staker waitlist
+	fn join_innovation_waitlist(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(amount >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+
+		let position = InnovationStakerWaitlist::<T>::try_mutate(|waitlist| -> Result<u32, DispatchError> {
+			ensure!(
+				!waitlist.iter().any(|(waiting, _)| waiting == who),
+				Error::<T>::AlreadyInInnovationWaitlist
+			);
+			waitlist
+				.try_push((who.clone(), amount))
+				.map_err(|_| Error::<T>::InnovationWaitlistFull)?;
+			Ok(waitlist.len() as u32)
+		})?;
+		T::Currency::reserve(who, amount)?;
+
+		Self::deposit_event(Event::InnovationStakerWaitlisted(who.clone(), amount, position));
+
+		Ok(())
+	}
+
+	/// Admit waitlisted innovation stakers in order while the staker cap has free slots,
+	/// returning the number of admitted stakers
+	fn admit_waitlisted_innovation_stakers() -> u32 {
+		if InnovationStakerWaitlist::<T>::get().is_empty() {
+			return 0;
+		}
+
+		let free_slots = MaxInnovationStakers::<T>::get().map_or(u32::MAX, |max_stakers| {
+			max_stakers.saturating_sub(InnovationStakerCount::<T>::get())
+		});
+
+		InnovationStakerWaitlist::<T>::mutate(|waitlist| {
+			let admitted = free_slots.min(waitlist.len() as u32);
+			for _ in 0..admitted {
+				// Stake is already reserved when joining the waitlist
+				let (who, amount) = waitlist.remove(0);
+				Self::checkpoint_voting_points(&who);
+				InnovationStakingInfo::<T>::mutate(&who, |staked_balance| {
+					*staked_balance = staked_balance.saturating_add(amount)
+				});
+				TotalInnovationStaking::<T>::mutate(|total_staked| *total_staked = total_staked.saturating_add(amount));
+				Self::add_share(&who, amount);
+
+				Self::deposit_event(Event::InnovationStakerAdmitted(who, amount));
+			}
+
+			admitted
+		})
+	}
+
+	/// Innovation stake of `who` once `amount` is added, ensuring it reaches `MinimumStake`
+	fn ensure_innovation_stake_total(who: &T::AccountId, amount: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::StakeBelowMinimum);
+		let total = InnovationStakingInfo::<T>::get(who)
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(total >= T::MinimumStake::get(), Error::<T>::StakeBelowMinimum);
+
+		Ok(total)
+	}
+
+	/// Part of the Economy 101 self-stake of `who` switched out of innovation staking that is
+	/// still locked at `current_round`
+	fn locked_switched_stake(who: &T::AccountId, current_round: RoundIndex) -> BalanceOf<T> {
+		SwitchedStakeLocks::<T>::get(who)
+			.iter()
+			.filter(|(_, unlock_round)| *unlock_round > current_round)
+			.fold(Zero::zero(), |locked: BalanceOf<T>, (amount, _)| {
+				locked.saturating_add(*amount)
+			})
+	}
 
-					// Update staking info of user immediately
-					// Remove staking info
-					if amount_to_unstake == staked_balance {
-						StakingInfo::<T>::remove(&who);
+	/// Lock `amount` of the self-stake of `who` switched out of innovation staking at
+	/// `current_round` until the innovation unbond period of the switch ends. Tranches already
+	/// unlocked are pruned, so at most `INNOVATION_UNBOND_ROUNDS` tranches are locked at once
+	fn lock_switched_stake(who: &T::AccountId, amount: BalanceOf<T>, current_round: RoundIndex) -> DispatchResult {
+		let unlock_round = current_round.saturating_add(INNOVATION_UNBOND_ROUNDS);
+		SwitchedStakeLocks::<T>::try_mutate(who, |locks| {
+			locks.retain(|(_, round)| *round > current_round);
+			match locks.iter_mut().last() {
+				Some((locked, round)) if *round == unlock_round => {
+					*locked = locked.saturating_add(amount);
+					Ok(())
+				}
+				_ => locks
+					.try_push((amount, unlock_round))
+					.map_err(|_| Error::<T>::TooManySwitchedStakeLocks.into()),
+			}
+		})
+	}
+
+	/// Release `amount` of the locked switched self-stake of `who`, starting with the tranches
+	/// unlocking last
+	fn release_switched_stake(who: &T::AccountId, amount: BalanceOf<T>, current_round: RoundIndex) {
+		let mut tranches = SwitchedStakeLocks::<T>::get(who).into_inner();
+		tranches.retain(|(_, round)| *round > current_round);
+		let mut remaining = amount;
+		while let Some((locked, unlock_round)) = tranches.pop() {
+			if locked > remaining {
+				tranches.push((locked.saturating_sub(remaining), unlock_round));
+				break;
+			}
+			remaining = remaining.saturating_sub(locked);
+		}
+
+		if tranches.is_empty() {
+			SwitchedStakeLocks::<T>::remove(who);
+		} else {
+			SwitchedStakeLocks::<T>::insert(who, BoundedVec::truncate_from(tranches));
+		}
+	}
+
+	/// The payload `staker` signs off-chain to let `sponsor` pay the fees of its first stake on
+	/// this chain
+	pub fn sponsored_stake_payload(staker: &T::AccountId, sponsor: &T::AccountId, amount: BalanceOf<T>) -> Vec<u8> {
+		(
+			b"economy/sponsored_stake",
+			Self::genesis_hash(),
+			staker,
+			sponsor,
+			amount,
+		)
+			.encode()
+	}
+
+	/// The payload `owner` signs off-chain to authorize a claim with `nonce`. The genesis hash
+	/// keeps the authorization from being replayed on another chain
+	pub fn claim_authorization_payload(owner: &T::AccountId, nonce: u64) -> Vec<u8> {
+		(b"economy/claim_reward", Self::genesis_hash(), owner, nonce).encode()
+	}
+
+	/// Hash of the genesis block, separating the signed payloads of each chain
+	fn genesis_hash() -> T::Hash {
+		frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero())
+	}
+
+	/// Validate the signature of `data` by `signer`, also accepting payloads wrapped in
+	/// `<Bytes>` tags by wallets
+	fn validate_signature(data: &Vec<u8>, signature: &T::OffchainSignature, signer: &T::AccountId) -> DispatchResult {
+		if signature.verify(&**data, signer) {
+			return Ok(());
+		}
+
+		let prefix = b"<Bytes>";
+		let suffix = b"</Bytes>";
+		let mut wrapped: Vec<u8> = Vec::with_capacity(data.len() + prefix.len() + suffix.len());
+		wrapped.extend(prefix);
+		wrapped.extend(data);
+		wrapped.extend(suffix);
+
+		ensure!(signature.verify(&*wrapped, signer), Error::<T>::WrongSignature);
+
+		Ok(())
+	}
+
+	/// Transfer `amount` of power from `from` to `to`, including power accrued by self-staking
+	pub fn do_transfer_power(from: &T::AccountId, to: &T::AccountId, amount: PowerAmount) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::PowerAmountIsZero);
+
+		Self::accrue_self_staking_power(from);
+		Self::migrate_power_balance(from);
+		Self::migrate_power_balance(to);
+
+		ensure!(
+			Self::get_power_balance(from) >= amount,
+			Error::<T>::AccountHasNoPowerBalance
+		);
+		T::FungibleTokenCurrency::transfer(T::PowerCurrencyId::get(), from, to, amount.saturated_into())?;
+		Self::deposit_event(Event::<T>::PowerTransferred(from.clone(), to.clone(), amount));
+
+		Ok(())
+	}
+
+	/// Set the power allowance of `spender` on behalf of `owner`
+	pub fn do_approve_power(owner: &T::AccountId, spender: &T::AccountId, amount: PowerAmount) {
+		PowerAllowances::<T>::insert(owner, spender, amount);
+		Self::deposit_event(Event::<T>::PowerApproved(owner.clone(), spender.clone(), amount));
+	}
+
+	/// Transfer `amount` of power from `owner` to `to` within the allowance of `spender`
+	#[transactional]
+	pub fn do_transfer_power_from(
+		spender: &T::AccountId,
+		owner: &T::AccountId,
+		to: &T::AccountId,
+		amount: PowerAmount,
+	) -> DispatchResult {
+		PowerAllowances::<T>::try_mutate(owner, spender, |allowance| -> DispatchResult {
+			*allowance = allowance
+				.checked_sub(amount)
+				.ok_or(Error::<T>::InsufficientPowerAllowance)?;
+			Ok(())
+		})?;
+
+		Self::do_transfer_power(owner, to, amount)
+	}
+
+	fn do_burn(_who: &T::AccountId, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		Self::deposit_event(Event::<T>::MiningResourceBurned(amount));
+
+		Ok(())
+	}
+
+	fn distribute_power_by_network(power_amount: PowerAmount, beneficiary: &T::AccountId) -> DispatchResult {
+		Self::deposit_power(beneficiary, power_amount);
+
+		Ok(())
+	}
+
+	/// Power balance of `who`, including the legacy balance not migrated to power tokens yet
+	pub fn get_power_balance(who: impl Borrow<T::AccountId>) -> PowerAmount {
+		let who = who.borrow();
+		let tokens: PowerAmount =
+			T::FungibleTokenCurrency::free_balance(T::PowerCurrencyId::get(), who).saturated_into();
+		tokens.saturating_add(PowerBalance::<T>::get(who))
+	}
+
+	/// Total issuance of power tokens, excluding legacy balances not migrated to power tokens yet
+	pub fn power_total_issuance() -> PowerAmount {
+		T::FungibleTokenCurrency::total_issuance(T::PowerCurrencyId::get()).saturated_into()
+	}
+
+	/// Credit `amount` of power tokens to `who`. The amount is kept in the legacy balance if
+	/// the deposit is rejected
+	fn deposit_power(who: &T::AccountId, amount: PowerAmount) {
+		if T::FungibleTokenCurrency::deposit(T::PowerCurrencyId::get(), who, amount.saturated_into()).is_err() {
+			PowerBalance::<T>::mutate(who, |power_balance| {
+				*power_balance = power_balance.saturating_add(amount)
+			});
+		}
+	}
+
+	/// Move the legacy power balance of `who` to power tokens
+	pub fn migrate_power_balance(who: &T::AccountId) {
+		let legacy_balance = PowerBalance::<T>::take(who);
+		if legacy_balance.is_zero() {
+			return;
+		}
+
+		Self::deposit_power(who, legacy_balance);
+		if PowerBalance::<T>::get(who).is_zero() {
+			Self::deposit_event(Event::<T>::PowerBalanceMigrated(who.clone(), legacy_balance));
+		}
+	}
+
+	fn get_target_execution_order(power_amount: PowerAmount) -> Result<BlockNumberFor<T>, DispatchError> {
+		let current_block_number = <frame_system::Pallet<T>>::current_block_number();
+		let target_block = if power_amount <= T::PowerAmountPerBlock::get() {
+			let target_b = current_block_number
+				.checked_add(&One::one())
+				.ok_or(ArithmeticError::Overflow)?;
+			target_b
+		} else {
+			let block_required = power_amount
+				.checked_div(T::PowerAmountPerBlock::get())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let target_b = current_block_number
+				.checked_add(&TryInto::<BlockNumberFor<T>>::try_into(block_required).unwrap_or_default())
+				.ok_or(ArithmeticError::Overflow)?;
+			target_b
+		};
+
+		Ok(target_block)
+	}
+
+	fn check_target_execution(target: BlockNumberFor<T>) -> bool {
+		let current_block_number = <frame_system::Pallet<T>>::current_block_number();
+
+		current_block_number >= target
+	}
+
+	pub fn add_share(who: &T::AccountId, add_amount: BalanceOf<T>) {
+		if add_amount.is_zero() {
+			return;
+		}
+
+		StakingRewardPoolInfo::<T>::mutate(|pool_info| {
+			let initial_total_shares = pool_info.total_shares;
+			pool_info.total_shares = pool_info.total_shares.saturating_add(add_amount);
+
+			let mut withdrawn_inflation = Vec::<(FungibleTokenId, BalanceOf<T>)>::new();
+
+			pool_info
+				.rewards
+				.iter_mut()
+				.for_each(|(reward_currency, (total_reward, total_withdrawn_reward))| {
+					if total_withdrawn_reward > total_reward {
+						Self::report_accounting_anomaly(
+							who,
+							AccountingAnomalyKind::WithdrawnExceedsTotalReward(*reward_currency),
+						);
+					}
+					let reward_inflation = if initial_total_shares.is_zero() {
+						Zero::zero()
 					} else {
-						StakingInfo::<T>::insert(&who, remaining);
+						U256::from(add_amount.to_owned().saturated_into::<u128>())
+							.saturating_mul(total_reward.to_owned().saturated_into::<u128>().into())
+							.checked_div(initial_total_shares.to_owned().saturated_into::<u128>().into())
+							.unwrap_or_default()
+							.as_u128()
+							.saturated_into()
+					};
+					*total_reward = total_reward.saturating_add(reward_inflation);
+					*total_withdrawn_reward = total_withdrawn_reward.saturating_add(reward_inflation);
+
+					withdrawn_inflation.push((*reward_currency, reward_inflation));
+				});
+
+			SharesAndWithdrawnRewards::<T>::mutate(who, |(share, withdrawn_rewards)| {
+				if share.is_zero() {
+					InnovationStakerCount::<T>::mutate(|count| *count = count.saturating_add(1));
+					InnovationStakingSince::<T>::insert(who, CurrentEra::<T>::get());
+				}
+				*share = share.saturating_add(add_amount);
+				Self::note_share_change(who, *share);
+				EraJoinedShares::<T>::mutate(CurrentEra::<T>::get(), who, |joined| {
+					*joined = joined.saturating_add(add_amount)
+				});
+				TotalEraJoinedShares::<T>::mutate(|total_joined| {
+					*total_joined = total_joined.saturating_add(add_amount)
+				});
+				// update withdrawn inflation for each reward currency
+				withdrawn_inflation
+					.into_iter()
+					.for_each(|(reward_currency, reward_inflation)| {
+						withdrawn_rewards
+							.entry(reward_currency)
+							.and_modify(|withdrawn_reward| {
+								*withdrawn_reward = withdrawn_reward.saturating_add(reward_inflation);
+							})
+							.or_insert(reward_inflation);
+					});
+			});
+		});
+
+		Self::evaluate_stake_tier(who);
+	}
+
+	pub fn remove_share(who: &T::AccountId, remove_amount: BalanceOf<T>) {
+		if remove_amount.is_zero() {
+			return;
+		}
+
+		// claim rewards firstly
+		Self::claim_rewards(who);
+
+		// Shares joined during the era are removed first
+		let current_era = CurrentEra::<T>::get();
+		let joined_removed = EraJoinedShares::<T>::get(current_era, who).min(remove_amount);
+		if !joined_removed.is_zero() {
+			EraJoinedShares::<T>::mutate_exists(current_era, who, |maybe_joined| {
+				let joined = maybe_joined.unwrap_or_default().saturating_sub(joined_removed);
+				*maybe_joined = if joined.is_zero() { None } else { Some(joined) };
+			});
+			TotalEraJoinedShares::<T>::mutate(|total_joined| {
+				*total_joined = total_joined.saturating_sub(joined_removed)
+			});
+		}
+
+		SharesAndWithdrawnRewards::<T>::mutate_exists(who, |share_info| {
+			if let Some((mut share, mut withdrawn_rewards)) = share_info.take() {
+				let remove_amount = remove_amount.min(share);
+
+				if remove_amount.is_zero() {
+					return;
+				}
+
+				StakingRewardPoolInfo::<T>::mutate_exists(|maybe_pool_info| {
+					if let Some(mut pool_info) = maybe_pool_info.take() {
+						let removing_share = U256::from(remove_amount.saturated_into::<u128>());
+
+						if remove_amount > pool_info.total_shares {
+							Self::report_accounting_anomaly(who, AccountingAnomalyKind::ShareExceedsTotalShares);
+						}
+						pool_info.total_shares = pool_info.total_shares.saturating_sub(remove_amount);
+
+						// update withdrawn rewards for each reward currency
+						withdrawn_rewards
+							.iter_mut()
+							.for_each(|(reward_currency, withdrawn_reward)| {
+								let withdrawn_reward_to_remove: BalanceOf<T> = removing_share
+									.saturating_mul(withdrawn_reward.to_owned().saturated_into::<u128>().into())
+									.checked_div(share.saturated_into::<u128>().into())
+									.unwrap_or_default()
+									.as_u128()
+									.saturated_into();
+
+								if let Some((total_reward, total_withdrawn_reward)) =
+									pool_info.rewards.get_mut(reward_currency)
+								{
+									if withdrawn_reward_to_remove > *total_withdrawn_reward {
+										Self::report_accounting_anomaly(
+											who,
+											AccountingAnomalyKind::WithdrawnRewardExceedsPool(*reward_currency),
+										);
+									}
+									*total_reward = total_reward.saturating_sub(withdrawn_reward_to_remove);
+									*total_withdrawn_reward =
+										total_withdrawn_reward.saturating_sub(withdrawn_reward_to_remove);
+
+									// remove if all reward is withdrawn
+									if total_reward.is_zero() {
+										pool_info.rewards.remove(reward_currency);
+									}
+								}
+								*withdrawn_reward = withdrawn_reward.saturating_sub(withdrawn_reward_to_remove);
+							});
+
+						if !pool_info.total_shares.is_zero() {
+							*maybe_pool_info = Some(pool_info);
+						}
 					}
+				});
 
-					let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_unstake);
-					<TotalStake<T>>::put(new_total_staked);
+				share = share.saturating_sub(remove_amount);
+				Self::note_share_change(who, share);
+				if !share.is_zero() {
+					*share_info = Some((share, withdrawn_rewards));
+				} else {
+					InnovationStakerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+					// Loyalty resets on full unstake
+					InnovationStakingSince::<T>::remove(who);
+				}
+			}
+		});
+
+		Self::evaluate_stake_tier(who);
+		Self::release_directed_stake(who);
+	}
+
+	/// Release all stake `who` directed at innovation projects once its innovation stake fell
+	/// below the directed stake
+	fn release_directed_stake(who: &T::AccountId) {
+		let directed = AccountDirectedStake::<T>::get(who);
+		if directed.is_zero() || directed <= InnovationStakingInfo::<T>::get(who) {
+			return;
+		}
+
+		for (project_id, amount) in DirectedProjectStakes::<T>::drain_prefix(who) {
+			if InnovationProjects::<T>::contains_key(project_id) {
+				InnovationProjectStake::<T>::mutate(project_id, |stake| *stake = stake.saturating_sub(amount));
+			}
+		}
+		AccountDirectedStake::<T>::remove(who);
+
+		Self::deposit_event(Event::<T>::DirectedStakeReleased(who.clone()));
+	}
+
+	/// Start ranking the innovation projects by directed stake for `era`. The ranking is stepped
+	/// in `on_idle` and replaces any ranking still in progress
+	fn select_top_innovation_projects(era: EraIndex) {
+		TopInnovationProjectsScan::<T>::put((era, None::<Vec<u8>>, Vec::new()));
+	}
+
+	/// Step the innovation project ranking with the weight left in the block and select the top
+	/// `TopInnovationProjectsCount` projects once every project stake is ranked
+	fn step_top_innovation_projects(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(1, 2);
+		if remaining_weight.any_lt(overhead) {
+			return Weight::zero();
+		}
+		let (era, cursor, mut projects) = match TopInnovationProjectsScan::<T>::get() {
+			Some(scan) => scan,
+			None => return T::DbWeight::get().reads(1),
+		};
 
-					T::Currency::unreserve(&who, amount_to_unstake);
+		let iter = match cursor {
+			Some(cursor) => InnovationProjectStake::<T>::iter_from(cursor),
+			None => InnovationProjectStake::<T>::iter(),
+		};
+		let top_count = T::TopInnovationProjectsCount::get() as usize;
+		let (next_cursor, consumed) = migrations::step_entries(
+			iter,
+			T::DbWeight::get().reads(1),
+			remaining_weight.saturating_sub(overhead),
+			|(project_id, stake)| {
+				if stake.is_zero() {
+					return;
+				}
+				let rank = projects
+					.iter()
+					.position(|(ranked_id, ranked_stake)| {
+						stake > *ranked_stake || (stake == *ranked_stake && project_id < *ranked_id)
+					})
+					.unwrap_or(projects.len());
+				if rank < top_count {
+					projects.insert(rank, (project_id, stake));
+					projects.truncate(top_count);
+				}
+			},
+		);
+
+		match next_cursor {
+			Some(next_cursor) => TopInnovationProjectsScan::<T>::put((era, Some(next_cursor), projects)),
+			None => {
+				TopInnovationProjectsScan::<T>::kill();
+				T::OnInnovationProjectsSelected::on_projects_selected(era, &projects);
+				TopInnovationProjects::<T>::put(projects.clone());
+				Self::deposit_event(Event::<T>::TopInnovationProjectsSelected(era, projects));
+			}
+		}
+
+		consumed.saturating_add(overhead)
+	}
+
+	/// Start matching the project reward pools for `era` if project matching is enabled. The
+	/// matching is stepped in `on_idle` and replaces any matching still in progress
+	fn match_project_rewards(era: EraIndex) {
+		if ProjectMatching::<T>::exists() {
+			ProjectMatchingScan::<T>::put((era, None::<Vec<u8>>, BTreeMap::new()));
+		}
+	}
+
+	/// Step the project matching with the weight left in the block, counting the eligible
+	/// distinct stakers directing stake at each project, and pay the matching once every
+	/// directed stake is counted
+	fn step_project_matching(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(2, 1);
+		if remaining_weight.any_lt(overhead) {
+			return Weight::zero();
+		}
+		let (era, cursor, mut breadth) = match ProjectMatchingScan::<T>::get() {
+			Some(scan) => scan,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let config = match ProjectMatching::<T>::get() {
+			Some(config) => config,
+			None => {
+				ProjectMatchingScan::<T>::kill();
+				return overhead;
+			}
+		};
 
-					Self::deposit_event(Event::UnstakedAmountWithdrew(who.clone(), amount_to_unstake));
-					Self::deposit_event(Event::SelfStakingRemovedFromEconomy101(who, amount));
+		let mut iter = match cursor {
+			Some(cursor) => DirectedProjectStakes::<T>::iter_from(cursor),
+			None => DirectedProjectStakes::<T>::iter(),
+		};
+		let weight_per_entry = T::DbWeight::get().reads(3);
+		let limit = remaining_weight.saturating_sub(overhead);
+		let mut consumed = Weight::zero();
+		let next_cursor = loop {
+			if consumed.saturating_add(weight_per_entry).any_gt(limit) {
+				break Some(iter.last_raw_key().to_vec());
+			}
+			let (who, project_id, amount) = match iter.next() {
+				Some(entry) => entry,
+				None => break None,
+			};
+			consumed = consumed.saturating_add(weight_per_entry);
+			if amount.is_zero()
+				|| !InnovationProjects::<T>::contains_key(project_id)
+				|| !T::MatchingEligibility::is_eligible(&who)
+			{
+				continue;
+			}
+			let project_breadth = breadth.entry(project_id).or_default();
+			*project_breadth = match config.mode {
+				MatchingMode::Linear => project_breadth.saturating_add(1),
+				MatchingMode::Quadratic => {
+					project_breadth.saturating_add(amount.saturated_into::<u128>().integer_sqrt())
 				}
-				Some(estate_id) => {
-					ensure!(
-						T::EstateHandler::check_estate(estate_id.clone())?,
-						Error::<T>::StakeEstateDoesNotExist
-					);
-					let mut staked_balance: BalanceOf<T> = Zero::zero();
-					let staking_bond_value = EstateStakingInfo::<T>::get(estate_id);
-					match staking_bond_value {
-						Some(staking_bond) => {
-							ensure!(staking_bond.staker == who.clone(), Error::<T>::NoFundsStakedAtEstate);
-							staked_balance = staking_bond.amount;
-						}
-						_ => {}
-					}
-					ensure!(amount <= staked_balance, Error::<T>::UnstakeAmountExceedStakedAmount);
+			};
+		};
 
-					let remaining = staked_balance.checked_sub(&amount).ok_or(ArithmeticError::Underflow)?;
+		let payout_weight = match next_cursor {
+			Some(next_cursor) => {
+				ProjectMatchingScan::<T>::put((era, Some(next_cursor), breadth));
+				Weight::zero()
+			}
+			None => {
+				ProjectMatchingScan::<T>::kill();
+				let projects = breadth.len() as u64;
+				Self::pay_project_matching(era, config, breadth);
+				T::DbWeight::get().reads_writes(2 + 2 * projects, 1 + 3 * projects)
+			}
+		};
 
-					let amount_to_unstake = if remaining < T::MinimumStake::get() {
-						// Remaining amount below minimum, remove all staked amount
-						staked_balance
-					} else {
-						amount
-					};
+		consumed.saturating_add(overhead).saturating_add(payout_weight)
+	}
 
-					// Update staking info of user immediately
-					// Remove staking info
-					if amount_to_unstake == staked_balance {
-						EstateStakingInfo::<T>::remove(&estate_id);
-					} else {
-						let new_staking_bond = Bond {
-							staker: who.clone(),
-							amount: remaining,
-						};
-						EstateStakingInfo::<T>::insert(&estate_id, new_staking_bond);
-					}
+	/// Top up the project reward pools from the treasury by the staker `breadth` of each
+	/// project, within the matching caps
+	fn pay_project_matching(
+		era: EraIndex,
+		config: ProjectMatchingConfig<BalanceOf<T>>,
+		mut breadth: BTreeMap<InnovationProjectId, u128>,
+	) {
+		if config.mode == MatchingMode::Quadratic {
+			breadth
+				.values_mut()
+				.for_each(|project_breadth| *project_breadth = project_breadth.saturating_mul(*project_breadth));
+		}
 
-					let new_total_staked = TotalStake::<T>::get().saturating_sub(amount_to_unstake);
-					<TotalEstateStake<T>>::put(new_total_staked);
+		let total_breadth = breadth.values().fold(0u128, |total, b| total.saturating_add(*b));
+		if total_breadth.is_zero() {
+			return;
+		}
 
-					T::Currency::unreserve(&who, amount_to_unstake);
+		let treasury = Self::economy_pallet_account_id();
+		let budget: u128 = config
+			.era_cap
+			.min(Self::free_balance_above_keep_alive(&treasury))
+			.saturated_into();
+
+		let mut matches = Vec::new();
+		for (project_id, project_breadth) in breadth {
+			let matched: BalanceOf<T> =
+				multiply_by_rational_with_rounding(budget, project_breadth, total_breadth, Rounding::Down)
+					.unwrap_or_default()
+					.saturated_into();
+			let matched = matched.min(config.project_cap);
+			if matched.is_zero() {
+				continue;
+			}
 
-					Self::deposit_event(Event::UnstakedAmountWithdrew(who.clone(), amount_to_unstake));
-					Self::deposit_event(Event::EstateStakingRemovedFromEconomy101(who, estate_id, amount));
-				}
+			if T::Currency::transfer(
+				&treasury,
+				&Self::project_pool_account_id(project_id),
+				matched,
+				ExistenceRequirement::KeepAlive,
+			)
+			.is_ok()
+			{
+				ProjectMatchingPools::<T>::mutate(project_id, |pool| *pool = pool.saturating_add(matched));
+				matches.push((project_id, matched));
 			}
-			Ok(().into())
 		}
 
-		/// Force unreserved unstake native token from staking ledger. The unstaked amount able to
-		/// unreserve immediately
-		///
-		///
-		/// The dispatch origin for this call must be _Root_.
-		///
-		/// `amount`: the stake amount
-		/// `who`: the address of staker
-		///
-		/// Emit `SelfStakingRemovedFromEconomy101` event if successful
-		#[pallet::weight(T::WeightInfo::unstake_b())]
-		pub fn force_unreserved_staking(
-			origin: OriginFor<T>,
-			amount: BalanceOf<T>,
-			who: T::AccountId,
-		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+		Self::deposit_event(Event::<T>::ProjectRewardsMatched(era, matches));
+	}
 
-			// Ensure amount is greater than zero
-			ensure!(!amount.is_zero(), Error::<T>::UnstakeAmountIsZero);
+	/// Account of the lending pot funding credit lines
+	pub fn lending_pot_account_id() -> T::AccountId {
+		T::LendingPotPalletId::get().into_account_truncating()
+	}
 
-			// Update staking info
-			let staked_reserved_balance = T::Currency::reserved_balance(&who);
+	/// Maximum BIT debt backed by `staked` innovation stake
+	fn credit_limit(config: &CreditLineConfig, staked: BalanceOf<T>) -> BalanceOf<T> {
+		config
+			.ltv
+			.mul_floor(
+				config
+					.bit_per_native
+					.saturating_mul_int(staked.saturated_into::<u128>()),
+			)
+			.saturated_into()
+	}
+
+	/// Ensure the credit line of `who` stays within the credit limit of `remaining_stake`
+	fn ensure_credit_collateral(who: &T::AccountId, remaining_stake: BalanceOf<T>) -> DispatchResult {
+		if let Some(mut credit_line) = CreditLines::<T>::get(who) {
+			let config = CreditLineTerms::<T>::get().ok_or(Error::<T>::CollateralLockedByCreditLine)?;
+			Self::accrue_credit_line(&mut credit_line, Some(&config));
 			ensure!(
-				amount <= staked_reserved_balance,
-				Error::<T>::UnstakeAmountExceedStakedAmount
+				credit_line.debt <= Self::credit_limit(&config, remaining_stake),
+				Error::<T>::CollateralLockedByCreditLine
 			);
-
-			T::Currency::unreserve(&who, amount);
-
-			Ok(().into())
 		}
+		Ok(())
+	}
 
-		/// This function only for governance origin to execute when starting the protocol or
-		/// changes of era duration.
-		#[pallet::weight(< T as Config >::WeightInfo::stake_b())]
-		pub fn update_era_config(
-			origin: OriginFor<T>,
-			last_era_updated_block: Option<BlockNumberFor<T>>,
-			frequency: Option<BlockNumberFor<T>>,
-			estimated_reward_rate_per_era: Option<BalanceOf<T>>,
-		) -> DispatchResult {
-			let _ = ensure_root(origin)?;
+	/// Start sweeping the credit lines for `era`. The sweep is stepped in `on_idle` and replaces
+	/// any sweep still in progress
+	fn accrue_credit_interest(era: EraIndex) {
+		if CreditLines::<T>::iter_keys().next().is_some() {
+			CreditLineScan::<T>::put((era, None::<Vec<u8>>));
+		}
+	}
 
-			if let Some(change) = frequency {
-				UpdateEraFrequency::<T>::put(change);
-				Self::deposit_event(Event::<T>::UpdatedInnovationStakingEraFrequency(change));
+	/// Add the interest of the eras elapsed since `credit_line` last accrued
+	fn accrue_credit_line(credit_line: &mut CreditLine<BalanceOf<T>>, config: Option<&CreditLineConfig>) {
+		let current_era = Self::current_era();
+		if let Some(config) = config {
+			for _ in credit_line.accrued_era..current_era {
+				credit_line.debt = credit_line
+					.debt
+					.saturating_add(config.interest_per_era.mul_ceil(credit_line.debt));
 			}
+		}
+		credit_line.accrued_era = credit_line.accrued_era.max(current_era);
+	}
 
-			if let Some(change) = last_era_updated_block {
-				let update_era_frequency = UpdateEraFrequency::<T>::get();
-				let current_block = <frame_system::Pallet<T>>::block_number();
-				if !update_era_frequency.is_zero() {
-					ensure!(
-						change > current_block.saturating_sub(update_era_frequency) && change <= current_block,
-						Error::<T>::InvalidLastEraUpdatedBlock
-					);
+	/// Step the credit line sweep with the weight left in the block, accruing interest and
+	/// liquidating the credit lines past their due era or over their credit limit
+	fn step_credit_lines(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(2, 1);
+		if remaining_weight.any_lt(overhead) {
+			return Weight::zero();
+		}
+		let (era, cursor) = match CreditLineScan::<T>::get() {
+			Some(scan) => scan,
+			None => return T::DbWeight::get().reads(1),
+		};
 
-					LastEraUpdatedBlock::<T>::put(change);
-					Self::deposit_event(Event::<T>::LastInnovationStakingEraUpdated(change));
+		let config = CreditLineTerms::<T>::get();
+		let iter = match cursor {
+			Some(cursor) => CreditLines::<T>::iter_from(cursor),
+			None => CreditLines::<T>::iter(),
+		};
+		let (next_cursor, consumed) = migrations::step_entries(
+			iter,
+			T::WeightInfo::unstake_on_innovation(),
+			remaining_weight.saturating_sub(overhead),
+			|(who, mut credit_line)| {
+				Self::accrue_credit_line(&mut credit_line, config.as_ref());
+				let within_limit = config.as_ref().map_or(false, |config| {
+					credit_line.debt <= Self::credit_limit(config, InnovationStakingInfo::<T>::get(&who))
+				});
+				if era >= credit_line.due_era || !within_limit {
+					Self::liquidate_credit_line(&who, credit_line.debt, config.as_ref());
+				} else {
+					CreditLines::<T>::insert(&who, credit_line);
 				}
-			}
+			},
+		);
+		match next_cursor {
+			Some(next_cursor) => CreditLineScan::<T>::put((era, Some(next_cursor))),
+			None => CreditLineScan::<T>::kill(),
+		}
+
+		consumed.saturating_add(overhead)
+	}
 
-			if let Some(reward_rate_per_era) = estimated_reward_rate_per_era {
-				EstimatedStakingRewardPerEra::<T>::put(reward_rate_per_era);
-				Self::deposit_event(Event::<T>::EstimatedRewardPerEraUpdated(reward_rate_per_era));
+	/// Settle the debt of `who` by moving the equivalent innovation stake to the lending pot
+	fn liquidate_credit_line(who: &T::AccountId, debt: BalanceOf<T>, config: Option<&CreditLineConfig>) {
+		Self::checkpoint_voting_points(who);
+		let staked = InnovationStakingInfo::<T>::get(who);
+		let debt_in_native: BalanceOf<T> =
+			config
+				.and_then(|config| config.bit_per_native.reciprocal())
+				.map_or(staked, |native_per_bit| {
+					native_per_bit
+						.saturating_mul_int(debt.saturated_into::<u128>())
+						.saturated_into()
+				});
+		let liquidated = staked.min(debt_in_native);
+
+		if !liquidated.is_zero() {
+			let remaining = staked.saturating_sub(liquidated);
+			if remaining.is_zero() {
+				InnovationStakingInfo::<T>::remove(who);
+				InnovationConvictions::<T>::remove(who);
+			} else {
+				InnovationStakingInfo::<T>::insert(who, remaining);
 			}
-			Ok(())
+			TotalInnovationStaking::<T>::mutate(|total| *total = total.saturating_sub(liquidated));
+			Self::remove_share(who, liquidated);
+
+			let _ =
+				T::Currency::repatriate_reserved(who, &Self::lending_pot_account_id(), liquidated, BalanceStatus::Free);
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	pub fn economy_pallet_account_id() -> T::AccountId {
-		T::EconomyTreasury::get().into_account_truncating()
+		CreditLines::<T>::remove(who);
+		Self::deposit_event(Event::<T>::CreditLineLiquidated(who.clone(), debt, liquidated));
 	}
 
-	pub fn convert_power_to_bit(power_amount: Balance, commission: Perbill) -> (Balance, Balance) {
-		let rate = Self::get_bit_power_exchange_rate();
+	/// Account holding the treasury matching of an innovation project
+	pub fn project_pool_account_id(project_id: InnovationProjectId) -> T::AccountId {
+		T::EconomyTreasury::get().into_sub_account_truncating((b"project", project_id))
+	}
 
-		let bit_required = power_amount
-			.checked_mul(rate)
-			.ok_or(ArithmeticError::Overflow)
-			.unwrap_or(Zero::zero());
-		let commission_fee = commission * bit_required;
-		(
-			bit_required + commission_fee,
-			TryInto::<Balance>::try_into(commission_fee).unwrap_or_default(),
-		)
+	/// Account holding the funded estate rewards
+	pub fn estate_reward_pot_account_id() -> T::AccountId {
+		T::EconomyTreasury::get().into_sub_account_truncating(b"estate")
 	}
 
-	fn do_burn(_who: &T::AccountId, amount: Balance) -> DispatchResult {
-		if amount.is_zero() {
-			return Ok(());
-		}
+	/// Pay out the pending innovation staking rewards of `who` to a foreign `beneficiary` on a
+	/// whitelisted chain. Rewards stay on the local account if sending them fails
+	pub fn do_claim_reward_to_location(who: &T::AccountId, beneficiary: MultiLocation) -> DispatchResult {
+		ensure!(
+			cfg!(feature = "foreign-beneficiary"),
+			Error::<T>::ForeignBeneficiaryDisabled
+		);
+		ensure!(
+			Self::is_cross_chain_reward_location_allowed(beneficiary),
+			Error::<T>::CrossChainRewardChainNotAllowed
+		);
 
-		Self::deposit_event(Event::<T>::MiningResourceBurned(amount));
+		Self::claim_reward_with_location(who, who, Some(beneficiary), true, None);
 
 		Ok(())
 	}
 
-	fn distribute_power_by_network(power_amount: PowerAmount, beneficiary: &T::AccountId) -> DispatchResult {
-		let mut distributor_power_balance = PowerBalance::<T>::get(beneficiary);
-		distributor_power_balance = distributor_power_balance
-			.checked_add(power_amount)
-			.ok_or(ArithmeticError::Overflow)?;
+	/// Pay out the pending innovation staking rewards of `who` to `beneficiary`, leaving rewards
+	/// below the minimum claim amount and beyond `MaxCurrenciesPerClaim` currencies pending
+	fn do_claim_reward(who: &T::AccountId, beneficiary: &T::AccountId) {
+		Self::claim_reward_with_location(who, beneficiary, None, true, None);
+	}
 
-		PowerBalance::<T>::insert(beneficiary.clone(), power_amount);
+	/// Pay out all pending innovation staking rewards of `who` to `beneficiary`
+	fn flush_reward(who: &T::AccountId, beneficiary: &T::AccountId) {
+		Self::claim_reward_with_location(who, beneficiary, None, false, None);
+	}
 
-		Ok(())
+	/// Pay out the pending innovation staking rewards of `who` to `beneficiary`, then send them to
+	/// `location` or the cross-chain reward destination of `who`. If `partial`, rewards below the
+	/// minimum claim amount stay pending and at most `MaxCurrenciesPerClaim` currencies are paid,
+	/// continuing after the claim cursor of `who`. With a `cap`, only up to the capped amount of
+	/// its currency is paid. Returns the paid rewards
+	fn claim_reward_with_location(
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		location: Option<MultiLocation>,
+		partial: bool,
+		cap: Option<(FungibleTokenId, BalanceOf<T>)>,
+	) -> Vec<(FungibleTokenId, BalanceOf<T>)> {
+		Self::claim_rewards(who);
+
+		let mut claimed_rewards: Vec<(FungibleTokenId, BalanceOf<T>)> = Vec::new();
+		let start = match cap {
+			Some((cap_currency_id, _)) => Bound::Included(cap_currency_id),
+			None => match ClaimCursors::<T>::take(who) {
+				Some(cursor) if partial => Bound::Included(cursor),
+				_ => Bound::Unbounded,
+			},
+		};
+		let max_currencies = T::MaxCurrenciesPerClaim::get().max(1);
+		let mut processed_currencies: u32 = 0;
+
+		PendingRewardsOfStakingInnovation::<T>::mutate_exists(who, |maybe_pending_multi_rewards| {
+			if let Some(pending_multi_rewards) = maybe_pending_multi_rewards {
+				for (currency_id, pending_reward) in pending_multi_rewards.range_mut((start, Bound::Unbounded)) {
+					if let Some((cap_currency_id, _)) = cap {
+						if *currency_id != cap_currency_id {
+							break;
+						}
+					}
+					if pending_reward.is_zero() {
+						continue;
+					}
+
+					if partial && cap.is_none() && processed_currencies >= max_currencies {
+						ClaimCursors::<T>::insert(who, currency_id);
+						Self::deposit_event(Event::ClaimRewardsContinued(who.clone(), *currency_id));
+						break;
+					}
+					processed_currencies = processed_currencies.saturating_add(1);
+
+					let claim_amount = cap.map_or(*pending_reward, |(_, max_amount)| max_amount.min(*pending_reward));
+					if partial && claim_amount < MinimumClaimAmount::<T>::get(currency_id) {
+						continue;
+					}
+
+					let payout_amount =
+						match Self::pay_pending_reward(who, beneficiary, *currency_id, pending_reward, claim_amount) {
+							Some(payout_amount) => payout_amount,
+							None => continue,
+						};
+
+					claimed_rewards.push((*currency_id, payout_amount));
+					if who == beneficiary {
+						Self::send_reward_cross_chain(who, *currency_id, payout_amount, location);
+					}
+
+					if who == beneficiary {
+						Self::deposit_event(Event::ClaimRewards(who.clone(), *currency_id, payout_amount));
+					} else {
+						Self::deposit_event(Event::ClaimRewardsTo(
+							who.clone(),
+							beneficiary.clone(),
+							*currency_id,
+							payout_amount,
+						));
+					}
+				}
+
+				pending_multi_rewards.retain(|_, pending_reward| !pending_reward.is_zero());
+				if pending_multi_rewards.is_empty() {
+					*maybe_pending_multi_rewards = None;
+				}
+			}
+		});
+
+		if !claimed_rewards.is_empty() {
+			Self::deposit_event(Event::ClaimRewardsSummary(
+				who.clone(),
+				beneficiary.clone(),
+				claimed_rewards.clone(),
+			));
+		}
+
+		claimed_rewards
 	}
 
-	fn get_target_execution_order(power_amount: PowerAmount) -> Result<BlockNumberFor<T>, DispatchError> {
-		let current_block_number = <frame_system::Pallet<T>>::current_block_number();
-		let target_block = if power_amount <= T::PowerAmountPerBlock::get() {
-			let target_b = current_block_number
-				.checked_add(&One::one())
-				.ok_or(ArithmeticError::Overflow)?;
-			target_b
-		} else {
-			let block_required = power_amount
-				.checked_div(T::PowerAmountPerBlock::get())
-				.ok_or(ArithmeticError::Overflow)?;
+	/// Claim the innovation staking rewards of `who` into its pending rewards and ensure its
+	/// `currency_id` reward, capped at `max_amount`, can be claimed. Returns the claimable amount
+	fn ensure_claimable_reward(
+		who: &T::AccountId,
+		currency_id: FungibleTokenId,
+		max_amount: Option<BalanceOf<T>>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::claim_rewards(who);
 
-			let target_b = current_block_number
-				.checked_add(&TryInto::<BlockNumberFor<T>>::try_into(block_required).unwrap_or_default())
-				.ok_or(ArithmeticError::Overflow)?;
-			target_b
+		let pending_reward = PendingRewardsOfStakingInnovation::<T>::get(who)
+			.get(&currency_id)
+			.copied()
+			.unwrap_or_default();
+		let claim_amount = max_amount.map_or(pending_reward, |max_amount| max_amount.min(pending_reward));
+		ensure!(!claim_amount.is_zero(), Error::<T>::NoPendingReward);
+		ensure!(
+			claim_amount >= MinimumClaimAmount::<T>::get(currency_id),
+			Error::<T>::ClaimBelowMinimum
+		);
+
+		Ok(claim_amount)
+	}
+
+	/// Pay up to `amount` of the pending `currency_id` reward of `who` to `beneficiary`. BIT
+	/// rewards repay the power advance of `who` first. The pending reward is reduced before paying
+	/// out and a failed payout is kept in `FailedPayouts` for retry. Returns the paid amount
+	fn pay_pending_reward(
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		currency_id: FungibleTokenId,
+		pending_reward: &mut BalanceOf<T>,
+		amount: BalanceOf<T>,
+	) -> Option<BalanceOf<T>> {
+		let mut payout_amount = amount.min(*pending_reward);
+		if currency_id == T::MiningCurrencyId::get() {
+			let repaid = Self::repay_power_advance(who, payout_amount);
+			*pending_reward = pending_reward.saturating_sub(repaid);
+			payout_amount = payout_amount.saturating_sub(repaid);
+		}
+		if payout_amount.is_zero() {
+			return None;
+		}
+
+		// Clear the pending reward before paying out, a failed payout is kept for retry
+		*pending_reward = pending_reward.saturating_sub(payout_amount);
+
+		match Self::distribute_reward(beneficiary, currency_id, payout_amount) {
+			Ok(_) => Some(payout_amount),
+			Err(_) => {
+				FailedPayouts::<T>::mutate(beneficiary, currency_id, |failed| {
+					*failed = failed.saturating_add(payout_amount)
+				});
+				Self::deposit_event(Event::PayoutFailed(beneficiary.clone(), currency_id, payout_amount));
+				None
+			}
+		}
+	}
+
+	/// Send a claimed reward of `who` to `location`, or its cross-chain reward destination if
+	/// none given. The reward stays on the local account if the transfer fails
+	fn send_reward_cross_chain(
+		who: &T::AccountId,
+		currency_id: FungibleTokenId,
+		amount: BalanceOf<T>,
+		location: Option<MultiLocation>,
+	) {
+		let destination = match location.or_else(|| Self::cross_chain_reward_location(who)) {
+			Some(destination) => destination,
+			None => return,
 		};
 
-		Ok(target_block)
+		let sent = Self::is_cross_chain_reward_location_allowed(destination)
+			&& Self::transfer_reward_to_chain(who, currency_id, amount, destination).is_ok();
+
+		if sent {
+			Self::deposit_event(Event::<T>::CrossChainRewardSent(
+				who.clone(),
+				currency_id,
+				amount,
+				destination,
+			));
+		} else {
+			Self::deposit_event(Event::<T>::CrossChainRewardRefunded(who.clone(), currency_id, amount));
+		}
 	}
 
-	fn check_target_execution(target: BlockNumberFor<T>) -> bool {
-		let current_block_number = <frame_system::Pallet<T>>::current_block_number();
+	/// Ensure atomic
+	#[transactional]
+	fn transfer_reward_to_chain(
+		who: &T::AccountId,
+		currency_id: FungibleTokenId,
+		amount: BalanceOf<T>,
+		destination: MultiLocation,
+	) -> DispatchResult {
+		T::CrossChainTransfer::transfer_to_chain(who, currency_id, amount, destination)
+	}
 
-		current_block_number >= target
+	/// Location of the account on the cross-chain reward destination of `who`, if any
+	fn cross_chain_reward_location(who: &T::AccountId) -> Option<MultiLocation> {
+		let destination = CrossChainRewardDestinations::<T>::get(who)?;
+
+		destination
+			.chain
+			.pushed_with_interior(Junction::AccountId32 {
+				network: None,
+				id: destination.beneficiary,
+			})
+			.ok()
 	}
 
-	pub fn add_share(who: &T::AccountId, add_amount: BalanceOf<T>) {
-		if add_amount.is_zero() {
-			return;
+	/// Whether `location` points to an account on a whitelisted cross-chain reward chain
+	fn is_cross_chain_reward_location_allowed(location: MultiLocation) -> bool {
+		match location.split_last_interior() {
+			(chain, Some(_)) => CrossChainRewardChains::<T>::contains_key(chain),
+			(_, None) => false,
 		}
+	}
 
-		StakingRewardPoolInfo::<T>::mutate(|pool_info| {
-			let initial_total_shares = pool_info.total_shares;
-			pool_info.total_shares = pool_info.total_shares.saturating_add(add_amount);
+	/// Withhold up to `available` BIT rewards of `who` to repay its power advance, releasing the
+	/// escrow once repaid. Returns the withheld amount
+	fn repay_power_advance(who: &T::AccountId, available: BalanceOf<T>) -> BalanceOf<T> {
+		let mut advance = match PowerAdvances::<T>::get(who) {
+			Some(advance) => advance,
+			None => return Zero::zero(),
+		};
 
-			let mut withdrawn_inflation = Vec::<(FungibleTokenId, BalanceOf<T>)>::new();
+		let currency_id = T::MiningCurrencyId::get();
+		let repaid = available.min(advance.debt);
+		if T::FungibleTokenCurrency::transfer(
+			currency_id,
+			&Self::get_reward_payout_account_id(),
+			&Self::economy_pallet_account_id(),
+			repaid,
+		)
+		.is_err()
+		{
+			return Zero::zero();
+		}
 
-			pool_info
-				.rewards
-				.iter_mut()
-				.for_each(|(reward_currency, (total_reward, total_withdrawn_reward))| {
-					let reward_inflation = if initial_total_shares.is_zero() {
-						Zero::zero()
-					} else {
-						U256::from(add_amount.to_owned().saturated_into::<u128>())
-							.saturating_mul(total_reward.to_owned().saturated_into::<u128>().into())
-							.checked_div(initial_total_shares.to_owned().saturated_into::<u128>().into())
-							.unwrap_or_default()
-							.as_u128()
-							.saturated_into()
-					};
-					*total_reward = total_reward.saturating_add(reward_inflation);
-					*total_withdrawn_reward = total_withdrawn_reward.saturating_add(reward_inflation);
+		advance.debt = advance.debt.saturating_sub(repaid);
+		Self::deposit_event(Event::<T>::PowerAdvanceInstallmentPaid(
+			who.clone(),
+			repaid,
+			advance.debt,
+		));
+
+		if advance.debt.is_zero() {
+			T::FungibleTokenCurrency::unreserve(currency_id, who, advance.escrow);
+			PowerAdvances::<T>::remove(who);
+			Self::deposit_event(Event::<T>::PowerAdvanceRepaid(who.clone()));
+		} else {
+			PowerAdvances::<T>::insert(who, advance);
+		}
 
-					withdrawn_inflation.push((*reward_currency, reward_inflation));
-				});
+		repaid
+	}
 
-			SharesAndWithdrawnRewards::<T>::mutate(who, |(share, withdrawn_rewards)| {
-				*share = share.saturating_add(add_amount);
-				// update withdrawn inflation for each reward currency
-				withdrawn_inflation
-					.into_iter()
-					.for_each(|(reward_currency, reward_inflation)| {
-						withdrawn_rewards
-							.entry(reward_currency)
-							.and_modify(|withdrawn_reward| {
-								*withdrawn_reward = withdrawn_reward.saturating_add(reward_inflation);
-							})
-							.or_insert(reward_inflation);
-					});
-			});
-		});
+	/// Update the stake tier of `who` according to its current innovation share
+	fn evaluate_stake_tier(who: &T::AccountId) {
+		let new_tier = Self::qualified_stake_tier(who);
+
+		// Accounts without a tier always qualify for the tier of their share
+		let version = StakeTiersVersion::<T>::get();
+		if new_tier.is_none() {
+			AccountStakeTierVersion::<T>::remove(who);
+		} else if AccountStakeTierVersion::<T>::get(who) != version {
+			AccountStakeTierVersion::<T>::insert(who, version);
+		}
+		if new_tier != AccountStakeTier::<T>::get(who) {
+			AccountStakeTier::<T>::set(who, new_tier);
+			Self::deposit_event(Event::<T>::StakeTierChanged(who.clone(), new_tier));
+		}
 	}
 
-	pub fn remove_share(who: &T::AccountId, remove_amount: BalanceOf<T>) {
-		if remove_amount.is_zero() {
-			return;
+	/// The index of the stake tier the share of `who` qualifies for under the current tiers
+	fn qualified_stake_tier(who: &T::AccountId) -> Option<u32> {
+		let (share, _) = SharesAndWithdrawnRewards::<T>::get(who);
+		if share.is_zero() {
+			return None;
 		}
+		StakeTiers::<T>::get()
+			.iter()
+			.rposition(|tier| share >= tier.threshold)
+			.map(|index| index as u32)
+	}
 
-		// claim rewards firstly
-		Self::claim_rewards(who);
+	/// The stake tier `who` currently qualifies for. An account evaluated against older tiers
+	/// qualifies for the tier of its share under the current tiers
+	pub fn current_stake_tier(who: &T::AccountId) -> Option<StakeTier<BalanceOf<T>>> {
+		let index = if AccountStakeTierVersion::<T>::get(who) == StakeTiersVersion::<T>::get() {
+			AccountStakeTier::<T>::get(who)
+		} else {
+			Self::qualified_stake_tier(who)
+		};
+		index.and_then(|index| StakeTiers::<T>::get().get(index as usize).cloned())
+	}
 
-		SharesAndWithdrawnRewards::<T>::mutate_exists(who, |share_info| {
-			if let Some((mut share, mut withdrawn_rewards)) = share_info.take() {
-				let remove_amount = remove_amount.min(share);
+	/// Innovation staking voting points of `who`, multiplied by its conviction
+	pub fn innovation_voting_points(who: &T::AccountId) -> BalanceOf<T> {
+		let multiplier: BalanceOf<T> = InnovationConvictions::<T>::get(who).multiplier().into();
+		InnovationStakingInfo::<T>::get(who).saturating_mul(multiplier)
+	}
 
-				if remove_amount.is_zero() {
-					return;
-				}
+	/// Extra voting points granted to `who` by its stake tier
+	pub fn stake_tier_voting_points(who: &T::AccountId) -> u32 {
+		Self::current_stake_tier(who)
+			.map(|tier| tier.extra_voting_points)
+			.unwrap_or_default()
+	}
 
-				StakingRewardPoolInfo::<T>::mutate_exists(|maybe_pool_info| {
-					if let Some(mut pool_info) = maybe_pool_info.take() {
-						let removing_share = U256::from(remove_amount.saturated_into::<u128>());
+	/// Innovation staking voting points of `who` archived at the end of `season`
+	pub fn season_voting_points(season: u32, who: &T::AccountId) -> Option<BalanceOf<T>> {
+		SeasonVotingPointsSnapshot::<T>::get(season)
+			.map(|snapshot| Self::voting_points_at_snapshot(snapshot, who))
+			.filter(|points| !points.is_zero())
+	}
 
-						pool_info.total_shares = pool_info.total_shares.saturating_sub(remove_amount);
+	/// Take a new voting points snapshot, returning its index. Voting points at the snapshot are
+	/// recorded lazily by `checkpoint_voting_points`
+	fn take_voting_points_snapshot() -> u32 {
+		VotingPointsSnapshotCount::<T>::mutate(|count| {
+			*count = count.saturating_add(1);
+			*count
+		})
+	}
 
-						// update withdrawn rewards for each reward currency
-						withdrawn_rewards
-							.iter_mut()
-							.for_each(|(reward_currency, withdrawn_reward)| {
-								let withdrawn_reward_to_remove: BalanceOf<T> = removing_share
-									.saturating_mul(withdrawn_reward.to_owned().saturated_into::<u128>().into())
-									.checked_div(share.saturated_into::<u128>().into())
-									.unwrap_or_default()
-									.as_u128()
-									.saturated_into();
+	/// Record the voting points of `who` at the latest snapshot before they change. Only the
+	/// first change after a snapshot is recorded
+	fn checkpoint_voting_points(who: &T::AccountId) {
+		let snapshot = VotingPointsSnapshotCount::<T>::get();
+		if snapshot.is_zero() {
+			return;
+		}
 
-								if let Some((total_reward, total_withdrawn_reward)) =
-									pool_info.rewards.get_mut(reward_currency)
-								{
-									*total_reward = total_reward.saturating_sub(withdrawn_reward_to_remove);
-									*total_withdrawn_reward =
-										total_withdrawn_reward.saturating_sub(withdrawn_reward_to_remove);
+		VotingPointsCheckpoints::<T>::mutate(who, |checkpoints| {
+			if checkpoints.last().map_or(true, |(index, _)| *index < snapshot) {
+				let _ = checkpoints
+					.force_insert_keep_right(checkpoints.len(), (snapshot, Self::innovation_voting_points(who)));
+			}
+		});
+	}
 
-									// remove if all reward is withdrawn
-									if total_reward.is_zero() {
-										pool_info.rewards.remove(reward_currency);
-									}
-								}
-								*withdrawn_reward = withdrawn_reward.saturating_sub(withdrawn_reward_to_remove);
-							});
+	/// Voting points of `who` when `snapshot` was taken: the first checkpoint recorded at or after
+	/// the snapshot, or the current voting points if they did not change since
+	fn voting_points_at_snapshot(snapshot: u32, who: &T::AccountId) -> BalanceOf<T> {
+		VotingPointsCheckpoints::<T>::get(who)
+			.into_iter()
+			.find(|(index, _)| *index >= snapshot)
+			.map_or_else(|| Self::innovation_voting_points(who), |(_, points)| points)
+	}
 
-						if !pool_info.total_shares.is_zero() {
-							*maybe_pool_info = Some(pool_info);
-						}
-					}
-				});
+	/// Extend the shares commitment with the new share of `who`
+	fn note_share_change(who: &T::AccountId, new_share: BalanceOf<T>) {
+		SharesCommitment::<T>::mutate(|commitment| {
+			*commitment = H256::from(sp_io::hashing::blake2_256(&(*commitment, who, new_share).encode()));
+		});
+		Self::update_top_stakers(who, new_share);
+	}
 
-				share = share.saturating_sub(remove_amount);
-				if !share.is_zero() {
-					*share_info = Some((share, withdrawn_rewards));
-				}
+	/// Move `who` to its position in the top stakers leaderboard. Accounts pushed out of the
+	/// leaderboard re-enter on their next share change
+	fn update_top_stakers(who: &T::AccountId, new_share: BalanceOf<T>) {
+		TopStakers::<T>::mutate(|top_stakers| {
+			top_stakers.retain(|(staker, _)| staker != who);
+			if new_share.is_zero() {
+				return;
+			}
+
+			let position = top_stakers
+				.iter()
+				.position(|(_, share)| *share < new_share)
+				.unwrap_or(top_stakers.len());
+			if position < MAX_TOP_STAKERS {
+				top_stakers.insert(position, (who.clone(), new_share));
+				top_stakers.truncate(MAX_TOP_STAKERS);
 			}
 		});
 	}
 
+	/// Record the staker set of the innovation staking pool at the start of `era`
+	fn snapshot_staker_set(era: EraIndex) {
+		let snapshot = StakerSetSnapshot {
+			total_shares: StakingRewardPoolInfo::<T>::get().total_shares,
+			staker_count: InnovationStakerCount::<T>::get(),
+			shares_commitment: SharesCommitment::<T>::get(),
+			total_innovation_stake: TotalInnovationStaking::<T>::get(),
+		};
+		EraStakerSnapshots::<T>::insert(era, snapshot);
+
+		if let Some(expired_era) = era.checked_sub(STAKER_SNAPSHOT_HISTORY_DEPTH) {
+			EraStakerSnapshots::<T>::remove(expired_era);
+		}
+	}
+
 	pub fn claim_rewards(who: &T::AccountId) {
+		Self::settle_era_joined_shares(who);
+
 		SharesAndWithdrawnRewards::<T>::mutate_exists(who, |maybe_share_withdrawn| {
 			if let Some((share, withdrawn_rewards)) = maybe_share_withdrawn {
 				if share.is_zero() {
@@ -1269,6 +8416,9 @@ impl<T: Config> Pallet<T> {
 		total_withdrawn_reward: &mut BalanceOf<T>,
 		who: &T::AccountId,
 	) {
+		if *total_withdrawn_reward > total_reward {
+			Self::report_accounting_anomaly(who, AccountingAnomalyKind::WithdrawnExceedsTotalReward(reward_currency));
+		}
 		let withdrawn_reward = withdrawn_rewards.get(&reward_currency).copied().unwrap_or_default();
 		let reward_to_withdraw = Self::reward_to_withdraw(
 			share,
@@ -1281,59 +8431,652 @@ impl<T: Config> Pallet<T> {
 			*total_withdrawn_reward = total_withdrawn_reward.saturating_add(reward_to_withdraw);
 			withdrawn_rewards.insert(reward_currency, withdrawn_reward.saturating_add(reward_to_withdraw));
 
-			// pay reward to `who`
-			Self::reward_payout(who, reward_currency, reward_to_withdraw);
+			// pay reward to `who`
+			Self::reward_payout(who, reward_currency, reward_to_withdraw);
+		}
+	}
+
+	/// Report a broken share accounting invariant instead of silently saturating
+	fn report_accounting_anomaly(who: &T::AccountId, kind: AccountingAnomalyKind) {
+		log::error!(
+			target: "economy",
+			"share accounting anomaly {:?} while updating {:?}",
+			kind, who
+		);
+		Self::deposit_event(Event::<T>::AccountingAnomaly(who.clone(), kind));
+	}
+
+	fn reward_to_withdraw(
+		share: BalanceOf<T>,
+		total_reward: BalanceOf<T>,
+		total_shares: U256,
+		withdrawn_reward: BalanceOf<T>,
+		total_withdrawn_reward: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let total_reward_proportion: BalanceOf<T> = U256::from(share.saturated_into::<u128>())
+			.saturating_mul(U256::from(total_reward.saturated_into::<u128>()))
+			.checked_div(total_shares)
+			.unwrap_or_default()
+			.as_u128()
+			.unique_saturated_into();
+		total_reward_proportion
+			.saturating_sub(withdrawn_reward)
+			.min(total_reward.saturating_sub(total_withdrawn_reward))
+	}
+
+	/// The total loyalty, stake tier and NFT holding reward bonus of `who`
+	fn reward_bonus(who: &T::AccountId) -> Perbill {
+		let tier_bonus = Self::current_stake_tier(who)
+			.map(|tier| tier.reward_bonus)
+			.unwrap_or_default();
+		Self::loyalty_bonus(who)
+			.saturating_add(tier_bonus)
+			.saturating_add(Self::nft_reward_bonus(who))
+	}
+
+	/// The best reward bonus of the NFT classes held by `who`
+	pub fn nft_reward_bonus(who: &T::AccountId) -> Perbill {
+		NftRewardBoosts::<T>::get()
+			.into_iter()
+			.filter(|(class_id, _)| T::NFTHandler::holds_class_token(who, *class_id))
+			.map(|(_, bonus)| bonus)
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Economic weight of `who`: its self-stake, weighted estate stake and innovation shares,
+	/// each scaled by the governance stake weights
+	pub fn total_economic_weight(who: &T::AccountId) -> BalanceOf<T> {
+		let weights = StakeWeights::<T>::get();
+
+		(weights.self_stake * StakingInfo::<T>::get(who))
+			.saturating_add(weights.estate_stake * AccountWeightedEstateStake::<T>::get(who))
+			.saturating_add(weights.innovation_stake * SharesAndWithdrawnRewards::<T>::get(who).0)
+	}
+
+	/// Innovation staking rewards claimable by `who`, including rewards not yet moved out of the
+	/// reward pool
+	pub fn pending_rewards(who: &T::AccountId) -> Vec<(FungibleTokenId, BalanceOf<T>)> {
+		let mut pending_rewards = PendingRewardsOfStakingInnovation::<T>::get(who);
+
+		let (share, withdrawn_rewards) = SharesAndWithdrawnRewards::<T>::get(who);
+		if !share.is_zero() {
+			let pool_info = StakingRewardPoolInfo::<T>::get();
+			let total_shares = U256::from(pool_info.total_shares.saturated_into::<u128>());
+			let bonus = Self::reward_bonus(who);
+
+			for (reward_currency, (total_reward, total_withdrawn_reward)) in pool_info.rewards.iter() {
+				let reward = Self::reward_to_withdraw(
+					share,
+					*total_reward,
+					total_shares,
+					withdrawn_rewards.get(reward_currency).copied().unwrap_or_default(),
+					*total_withdrawn_reward,
+				);
+				let reward = reward.saturating_add(bonus * reward);
+				pending_rewards
+					.entry(*reward_currency)
+					.and_modify(|current| *current = current.saturating_add(reward))
+					.or_insert(reward);
+			}
+		}
+
+		pending_rewards
+			.into_iter()
+			.filter(|(_, amount)| !amount.is_zero())
+			.collect()
+	}
+
+	/// The reward bonus of `who` for consecutive eras of innovation staking
+	pub fn loyalty_bonus(who: &T::AccountId) -> Perbill {
+		match InnovationStakingSince::<T>::get(who) {
+			Some(since_era) => {
+				let staked_eras = CurrentEra::<T>::get().saturating_sub(since_era);
+				let bonus_percent = (staked_eras / LOYALTY_ERAS_PER_BONUS_STEP)
+					.saturating_mul(LOYALTY_BONUS_PERCENT_PER_STEP)
+					.min(MAX_LOYALTY_BONUS_PERCENT);
+				Perbill::from_percent(bonus_percent)
+			}
+			None => Perbill::zero(),
+		}
+	}
+
+	fn reward_payout(who: &T::AccountId, currency_id: FungibleTokenId, payout_amount: BalanceOf<T>) {
+		if payout_amount.is_zero() {
+			return;
+		}
+		// Loyalty and stake tier bonuses are paid on top of the pool share from the reward payout
+		// account
+		let bonus = Self::reward_bonus(who);
+		let payout_amount = payout_amount.saturating_add(bonus * payout_amount);
+		PendingRewardsOfStakingInnovation::<T>::mutate(who, |rewards| {
+			rewards
+				.entry(currency_id)
+				.and_modify(|current| *current = current.saturating_add(payout_amount))
+				.or_insert(payout_amount);
+		});
+	}
+
+	/// Ensure atomic
+	#[transactional]
+	fn distribute_reward(
+		who: &T::AccountId,
+		reward_currency_id: FungibleTokenId,
+		payout_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		T::FungibleTokenCurrency::transfer(
+			reward_currency_id,
+			&Self::get_reward_payout_account_id(),
+			who,
+			payout_amount,
+		)?;
+
+		let capacity = Self::reward_ledger_capacity();
+		if Self::account_preferences(who).record_reward_ledger && capacity > 0 {
+			let (first, next) = AccountRewardLedgerBounds::<T>::get(who);
+			AccountRewardLedger::<T>::insert(
+				who,
+				next,
+				RewardLedgerEntry {
+					era: CurrentEra::<T>::get(),
+					currency_id: reward_currency_id,
+					amount: payout_amount,
+					block: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+			AccountRewardLedgerBounds::<T>::insert(who, (first, next.saturating_add(1)));
+
+			// Ledgers over a lowered retention shrink by a bounded number of entries per payout
+			let excess = next.saturating_add(1).saturating_sub(first).saturating_sub(capacity);
+			Self::trim_reward_ledger(who, excess.min(MAX_REWARD_LEDGER_TRIM));
+		}
+
+		Ok(())
+	}
+
+	pub fn get_reward_payout_account_id() -> T::AccountId {
+		RotatedRewardPayoutAccount::<T>::get()
+			.unwrap_or_else(|| T::RewardPayoutAccount::get().into_account_truncating())
+	}
+
+	pub fn bonded_pool_account_id(pool_id: PoolId) -> T::AccountId {
+		T::BondedPoolPalletId::get().into_sub_account_truncating(pool_id)
+	}
+
+	/// Remove a destroying bonded pool once all members have unbonded
+	fn try_remove_destroyed_pool(pool_id: PoolId, pool: &BondedPool<T::AccountId, BalanceOf<T>>) {
+		if pool.state == PoolState::Destroying && pool.points.is_zero() {
+			BondedPools::<T>::remove(pool_id);
+			Self::deposit_event(Event::<T>::BondedPoolDestroyed(pool_id));
+		}
+	}
+
+	/// Convert `amount` by the ratio `numerator / denominator`
+	fn convert_pool_balance(amount: BalanceOf<T>, numerator: BalanceOf<T>, denominator: BalanceOf<T>) -> BalanceOf<T> {
+		U256::from(amount.saturated_into::<u128>())
+			.saturating_mul(U256::from(numerator.saturated_into::<u128>()))
+			.checked_div(U256::from(denominator.saturated_into::<u128>()))
+			.unwrap_or_default()
+			.as_u128()
+			.saturated_into()
+	}
+
+	/// Stake free balance of a pool or position account on innovation staking
+	fn bond_innovation_stake(staker: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		Self::checkpoint_voting_points(staker);
+		Self::ensure_innovation_staker_slot(staker)?;
+		T::Currency::reserve(staker, amount)?;
+
+		InnovationStakingInfo::<T>::mutate(staker, |staked_balance| {
+			*staked_balance = staked_balance.saturating_add(amount)
+		});
+		let new_total_staked = TotalInnovationStaking::<T>::get().saturating_add(amount);
+		<TotalInnovationStaking<T>>::put(new_total_staked);
+
+		Self::add_share(staker, amount);
+
+		Ok(())
+	}
+
+	/// Claim the native reward of a bonded pool, pay the operator commission and restake the
+	/// remainder for the pool members
+	fn compound_pool_reward(pool_id: PoolId) -> DispatchResult {
+		let pool = BondedPools::<T>::get(pool_id).ok_or(Error::<T>::BondedPoolDoesNotExist)?;
+		let pool_account = Self::bonded_pool_account_id(pool_id);
+
+		Self::claim_rewards(&pool_account);
+
+		let reward_currency = FungibleTokenId::NativeToken(0);
+		let mut reward: BalanceOf<T> = Zero::zero();
+		PendingRewardsOfStakingInnovation::<T>::mutate_exists(&pool_account, |maybe_pending_multi_rewards| {
+			if let Some(pending_multi_rewards) = maybe_pending_multi_rewards {
+				reward = pending_multi_rewards.remove(&reward_currency).unwrap_or_default();
+				if pending_multi_rewards.is_empty() {
+					*maybe_pending_multi_rewards = None;
+				}
+			}
+		});
+		if reward.is_zero() {
+			return Ok(());
+		}
+
+		Self::distribute_reward(&pool_account, reward_currency, reward)?;
+
+		let commission = pool.commission * reward;
+		let protocol_income = ProtocolCommissionShare::<T>::get() * commission;
+		if !protocol_income.is_zero() {
+			T::Currency::transfer(
+				&pool_account,
+				&Self::economy_pallet_account_id(),
+				protocol_income,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Self::deposit_event(Event::<T>::ProtocolIncomeDeposited(protocol_income));
+		}
+		let operator_commission = commission.saturating_sub(protocol_income);
+		if !operator_commission.is_zero() {
+			T::Currency::transfer(
+				&pool_account,
+				&pool.operator,
+				operator_commission,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		let restaked_amount = reward.saturating_sub(commission);
+		Self::bond_innovation_stake(&pool_account, restaked_amount)?;
+
+		Self::deposit_event(Event::<T>::BondedPoolRewardCompounded(
+			pool_id,
+			restaked_amount,
+			commission,
+		));
+
+		Ok(())
+	}
+
+	/// Create a reward campaign funded by `creator`, reserving `deposit` from it
+	fn do_create_reward_campaign(
+		creator: T::AccountId,
+		deposit: BalanceOf<T>,
+		currency_id: FungibleTokenId,
+		budget: BalanceOf<T>,
+		start: BlockNumberFor<T>,
+		end: BlockNumberFor<T>,
+		eligibility: CampaignEligibility,
+	) -> Result<CampaignId, DispatchError> {
+		ensure!(
+			!budget.is_zero() && start < end && end > <frame_system::Pallet<T>>::block_number(),
+			Error::<T>::InvalidRewardCampaign
+		);
+
+		T::Currency::reserve(&creator, deposit)?;
+		T::FungibleTokenCurrency::transfer(currency_id, &creator, &Self::get_reward_payout_account_id(), budget)?;
+
+		let campaign_id = NextCampaignId::<T>::try_mutate(|id| -> Result<CampaignId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+			Ok(current_id)
+		})?;
+		ActiveRewardCampaigns::<T>::try_mutate(|campaigns| campaigns.try_push(campaign_id))
+			.map_err(|_| Error::<T>::TooManyRewardCampaigns)?;
+
+		RewardCampaigns::<T>::insert(
+			campaign_id,
+			RewardCampaign {
+				creator: creator.clone(),
+				currency_id,
+				remaining_budget: budget,
+				start,
+				end,
+				eligibility,
+				deposit,
+			},
+		);
+
+		Self::deposit_event(Event::<T>::RewardCampaignCreated(
+			campaign_id,
+			creator,
+			currency_id,
+			budget,
+		));
+
+		Ok(campaign_id)
+	}
+
+	/// Account holding the stake of an innovation staking position receipt
+	pub fn position_account_id(position: (ClassId, TokenId)) -> T::AccountId {
+		T::EconomyTreasury::get().into_sub_account_truncating(position)
+	}
+
+	/// Mint a position receipt to `who` and stake `amount` on innovation staking on behalf of the
+	/// receipt
+	fn stake_with_position_receipt(
+		who: &T::AccountId,
+		class_id: ClassId,
+		amount: BalanceOf<T>,
+		current_round: RoundIndex,
+	) -> DispatchResult {
+		let mut attributes: Attributes = BTreeMap::new();
+		attributes.insert(b"staked_amount".to_vec(), amount.encode());
+		attributes.insert(b"staked_round".to_vec(), current_round.encode());
+
+		let token_id = T::NFTHandler::mint_token(who, class_id, b"innovation staking position".to_vec(), attributes)?;
+		let position = (class_id, token_id);
+		let position_account = Self::position_account_id(position);
+
+		T::Currency::transfer(who, &position_account, amount, ExistenceRequirement::AllowDeath)?;
+		Self::bond_innovation_stake(&position_account, amount)?;
+		StakingPositions::<T>::insert(position, amount);
+
+		Self::deposit_event(Event::StakedInnovation(who.clone(), amount));
+		Self::deposit_event(Event::<T>::PositionReceiptMinted(who.clone(), position, amount));
+
+		Ok(())
+	}
+
+	/// Move `amount` of the innovation stake of position account `from` to position account `to`.
+	/// Withdrawn rewards move in proportion to the moved share, so neither position gains or
+	/// loses rewards. Pending rewards follow the stake once `from` is emptied
+	fn move_position_stake(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		Self::checkpoint_voting_points(from);
+		Self::checkpoint_voting_points(to);
+		Self::ensure_innovation_staker_slot(to)?;
+		Self::claim_rewards(from);
+		Self::claim_rewards(to);
+
+		let (share, mut withdrawn_rewards) = SharesAndWithdrawnRewards::<T>::get(from);
+		ensure!(
+			!amount.is_zero() && amount <= share && amount <= InnovationStakingInfo::<T>::get(from),
+			Error::<T>::InvalidPositionSplitAmount
+		);
+		let remaining_share = share.saturating_sub(amount);
+
+		let mut moved_rewards: WithdrawnRewards<T> = BTreeMap::new();
+		for (currency_id, withdrawn_reward) in withdrawn_rewards.iter_mut() {
+			let moved_reward: BalanceOf<T> = if remaining_share.is_zero() {
+				*withdrawn_reward
+			} else {
+				U256::from(withdrawn_reward.saturated_into::<u128>())
+					.saturating_mul(amount.saturated_into::<u128>().into())
+					.checked_div(share.saturated_into::<u128>().into())
+					.unwrap_or_default()
+					.as_u128()
+					.saturated_into()
+			};
+			*withdrawn_reward = withdrawn_reward.saturating_sub(moved_reward);
+			moved_rewards.insert(*currency_id, moved_reward);
+		}
+
+		if remaining_share.is_zero() {
+			SharesAndWithdrawnRewards::<T>::remove(from);
+			InnovationStakerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+		} else {
+			SharesAndWithdrawnRewards::<T>::insert(from, (remaining_share, withdrawn_rewards));
+		}
+		Self::note_share_change(from, remaining_share);
+
+		SharesAndWithdrawnRewards::<T>::mutate(to, |(to_share, to_withdrawn_rewards)| {
+			if to_share.is_zero() {
+				InnovationStakerCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			}
+			*to_share = to_share.saturating_add(amount);
+			Self::note_share_change(to, *to_share);
+			for (currency_id, moved_reward) in moved_rewards {
+				let withdrawn_reward = to_withdrawn_rewards.entry(currency_id).or_default();
+				*withdrawn_reward = withdrawn_reward.saturating_add(moved_reward);
+			}
+		});
+
+		// Shares joined during the era move first, matching `remove_share`
+		let current_era = CurrentEra::<T>::get();
+		let joined_moved = EraJoinedShares::<T>::get(current_era, from).min(amount);
+		if !joined_moved.is_zero() {
+			EraJoinedShares::<T>::mutate_exists(current_era, from, |maybe_joined| {
+				let joined = maybe_joined.unwrap_or_default().saturating_sub(joined_moved);
+				*maybe_joined = if joined.is_zero() { None } else { Some(joined) };
+			});
+			EraJoinedShares::<T>::mutate(current_era, to, |joined| *joined = joined.saturating_add(joined_moved));
+		}
+
+		// The merged position keeps the most recent staking era
+		if let Some(since) = InnovationStakingSince::<T>::get(from) {
+			InnovationStakingSince::<T>::mutate(to, |to_since| {
+				*to_since = Some(to_since.map_or(since, |to_since| to_since.max(since)))
+			});
+		}
+
+		InnovationStakingInfo::<T>::mutate_exists(from, |maybe_staked| {
+			let staked = maybe_staked.unwrap_or_default().saturating_sub(amount);
+			*maybe_staked = if staked.is_zero() { None } else { Some(staked) };
+		});
+		InnovationStakingInfo::<T>::mutate(to, |staked| *staked = staked.saturating_add(amount));
+
+		T::Currency::unreserve(from, amount);
+		T::Currency::transfer(from, to, amount, ExistenceRequirement::AllowDeath)?;
+		T::Currency::reserve(to, amount)?;
+
+		if remaining_share.is_zero() {
+			InnovationStakingSince::<T>::remove(from);
+			let pending_rewards = PendingRewardsOfStakingInnovation::<T>::take(from);
+			if !pending_rewards.is_empty() {
+				PendingRewardsOfStakingInnovation::<T>::mutate(to, |to_pending_rewards| {
+					for (currency_id, amount) in pending_rewards {
+						let pending_reward = to_pending_rewards.entry(currency_id).or_default();
+						*pending_reward = pending_reward.saturating_add(amount);
+					}
+				});
+			}
+		}
+
+		Self::evaluate_stake_tier(from);
+		Self::evaluate_stake_tier(to);
+
+		Ok(())
+	}
+
+	pub fn mission_pot_account_id() -> T::AccountId {
+		T::MissionPotPalletId::get().into_account_truncating()
+	}
+
+	/// Pay `amount` of `currency_id` from the mission pot to `account` and account it to
+	/// `mission_id`
+	pub fn do_distribute_mission_reward(
+		account: &T::AccountId,
+		currency_id: FungibleTokenId,
+		amount: BalanceOf<T>,
+		mission_id: MissionId,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::MissionRewardIsZero);
+
+		T::FungibleTokenCurrency::transfer(currency_id, &Self::mission_pot_account_id(), account, amount)?;
+
+		MissionRewards::<T>::mutate(mission_id, currency_id, |distributed| {
+			*distributed = distributed.saturating_add(amount)
+		});
+
+		Self::deposit_event(Event::<T>::MissionRewardDistributed(
+			mission_id,
+			account.clone(),
+			currency_id,
+			amount,
+		));
+
+		Ok(())
+	}
+
+	pub fn insurance_fund_account_id() -> T::AccountId {
+		T::InsuranceFundAccount::get().into_account_truncating()
+	}
+
+	/// Free balance of the insurance fund
+	pub fn insurance_fund_balance() -> BalanceOf<T> {
+		T::Currency::free_balance(&Self::insurance_fund_account_id())
+	}
+
+	/// Remove up to `amount` from the self stake, the innovation stake, the estate stake bonds and
+	/// then the pending exit queue entries of `who`, latest unlock round first. The slashed amount
+	/// stays reserved for the caller to move, so self stake backed by a balance lock is not
+	/// slashed. Returns the slashed amount
+	fn slash_stake(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+		Self::checkpoint_voting_points(who);
+		let staked_balance = StakingInfo::<T>::get(who);
+		let self_exiting = ExitQueue::<T>::iter_prefix_values(who)
+			.fold(Zero::zero(), |total: BalanceOf<T>, queued_exit| {
+				total.saturating_add(queued_exit.amount)
+			});
+		let mut self_reserved = staked_balance
+			.saturating_add(self_exiting)
+			.saturating_sub(LockedSelfStake::<T>::get(who));
+		let self_slash = staked_balance.min(self_reserved).min(amount);
+		self_reserved = self_reserved.saturating_sub(self_slash);
+		if !self_slash.is_zero() {
+			Self::accrue_self_staking_power(who);
+			let remaining = staked_balance.saturating_sub(self_slash);
+			if remaining.is_zero() {
+				StakingInfo::<T>::remove(who);
+			} else {
+				StakingInfo::<T>::insert(who, remaining);
+			}
+			TotalStake::<T>::mutate(|total| *total = total.saturating_sub(self_slash));
+		}
+
+		let innovation_staked = InnovationStakingInfo::<T>::get(who);
+		let innovation_slash = innovation_staked.min(amount.saturating_sub(self_slash));
+		if !innovation_slash.is_zero() {
+			let remaining = innovation_staked.saturating_sub(innovation_slash);
+			if remaining.is_zero() {
+				InnovationStakingInfo::<T>::remove(who);
+				InnovationConvictions::<T>::remove(who);
+			} else {
+				InnovationStakingInfo::<T>::insert(who, remaining);
+			}
+			TotalInnovationStaking::<T>::mutate(|total| *total = total.saturating_sub(innovation_slash));
+			Self::remove_share(who, innovation_slash);
+			Self::fold_dust_position(who);
+		}
+
+		let mut remaining = amount.saturating_sub(self_slash).saturating_sub(innovation_slash);
+		for estate_id in StakedEstates::<T>::iter_key_prefix(who).collect::<Vec<_>>() {
+			if remaining.is_zero() {
+				break;
+			}
+			let bond = match EstateStakingInfo::<T>::get(estate_id) {
+				Some(bond) if bond.staker == *who => bond,
+				_ => continue,
+			};
+			let estate_slash = bond.amount.min(remaining);
+			if estate_slash == bond.amount {
+				EstateStakingInfo::<T>::remove(estate_id);
+				EstateStakerRoles::<T>::remove(estate_id);
+				EstateStakingSince::<T>::remove(estate_id);
+			} else {
+				EstateStakingInfo::<T>::insert(
+					estate_id,
+					Bond {
+						staker: who.clone(),
+						amount: bond.amount.saturating_sub(estate_slash),
+					},
+				);
+			}
+			TotalEstateStake::<T>::mutate(|total| *total = total.saturating_sub(estate_slash));
+			AccountEstateStake::<T>::mutate(who, |stake| *stake = stake.saturating_sub(estate_slash));
+			Self::sync_weighted_estate_stake(estate_id, who);
+			remaining = remaining.saturating_sub(estate_slash);
+		}
+
+		for exit_entry in Self::exit_entries(who).into_iter().rev() {
+			if remaining.is_zero() {
+				break;
+			}
+			let mut exit_slash = exit_entry.amount.min(remaining);
+			if exit_entry.kind == ExitQueueKind::Economy101 {
+				exit_slash = exit_slash.min(self_reserved);
+				self_reserved = self_reserved.saturating_sub(exit_slash);
+			}
+			if exit_slash.is_zero() {
+				continue;
+			}
+			let slash_exit = |maybe_exit: &mut Option<QueuedExitOf<T>>| {
+				if let Some(queued_exit) = maybe_exit {
+					queued_exit.amount = queued_exit.amount.saturating_sub(exit_slash);
+					if queued_exit.amount.is_zero() {
+						*maybe_exit = None;
+					}
+				}
+			};
+			match exit_entry.kind {
+				ExitQueueKind::Economy101 => ExitQueue::<T>::mutate(who, exit_entry.round, slash_exit),
+				ExitQueueKind::Innovation => InnovationStakingExitQueue::<T>::mutate(who, exit_entry.round, slash_exit),
+				ExitQueueKind::Estate(estate_id) => {
+					EstateExitQueue::<T>::mutate((who, exit_entry.round, estate_id), slash_exit)
+				}
+			}
+			remaining = remaining.saturating_sub(exit_slash);
 		}
-	}
 
-	fn reward_to_withdraw(
-		share: BalanceOf<T>,
-		total_reward: BalanceOf<T>,
-		total_shares: U256,
-		withdrawn_reward: BalanceOf<T>,
-		total_withdrawn_reward: BalanceOf<T>,
-	) -> BalanceOf<T> {
-		let total_reward_proportion: BalanceOf<T> = U256::from(share.saturated_into::<u128>())
-			.saturating_mul(U256::from(total_reward.saturated_into::<u128>()))
-			.checked_div(total_shares)
-			.unwrap_or_default()
-			.as_u128()
-			.unique_saturated_into();
-		total_reward_proportion
-			.saturating_sub(withdrawn_reward)
-			.min(total_reward.saturating_sub(total_withdrawn_reward))
+		amount.saturating_sub(remaining)
 	}
 
-	fn reward_payout(who: &T::AccountId, currency_id: FungibleTokenId, payout_amount: BalanceOf<T>) {
-		if payout_amount.is_zero() {
-			return;
+	/// Split a penalty or slashed amount of `from` between the reward pool, the treasury and the
+	/// insurance fund according to the penalty split. The reward pool share goes to the
+	/// insurance fund while there are no stakers to claim it
+	pub fn redistribute_penalty(from: &T::AccountId, amount: BalanceOf<T>, reserved: bool) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
 		}
-		PendingRewardsOfStakingInnovation::<T>::mutate(who, |rewards| {
-			rewards
-				.entry(currency_id)
-				.and_modify(|current| *current = current.saturating_add(payout_amount))
-				.or_insert(payout_amount);
-		});
-	}
 
-	/// Ensure atomic
-	#[transactional]
-	fn distribute_reward(
-		who: &T::AccountId,
-		reward_currency_id: FungibleTokenId,
-		payout_amount: BalanceOf<T>,
-	) -> DispatchResult {
-		T::FungibleTokenCurrency::transfer(
-			reward_currency_id,
-			&Self::get_reward_payout_account_id(),
-			who,
-			payout_amount,
-		)?;
+		let split = PenaltySplitConfig::<T>::get();
+		let to_reward_pool = if StakingRewardPoolInfo::<T>::get().total_shares.is_zero() {
+			Zero::zero()
+		} else {
+			split.reward_pool * amount
+		};
+		let to_treasury = split.treasury * amount;
+		let to_insurance_fund = amount.saturating_sub(to_reward_pool).saturating_sub(to_treasury);
+
+		let move_penalty = |to: &T::AccountId, part: BalanceOf<T>| -> DispatchResult {
+			if part.is_zero() {
+				return Ok(());
+			}
+			if reserved {
+				T::Currency::repatriate_reserved(from, to, part, BalanceStatus::Free)?;
+			} else {
+				T::Currency::transfer(from, to, part, ExistenceRequirement::AllowDeath)?;
+			}
+			Ok(())
+		};
+		move_penalty(&Self::get_reward_payout_account_id(), to_reward_pool)?;
+		Self::accumulate_reward(FungibleTokenId::NativeToken(0), to_reward_pool)?;
+		move_penalty(&Self::economy_pallet_account_id(), to_treasury)?;
+		move_penalty(&Self::insurance_fund_account_id(), to_insurance_fund)?;
+
+		Self::deposit_event(Event::<T>::PenaltyRedistributed(
+			from.clone(),
+			to_reward_pool,
+			to_insurance_fund,
+			to_treasury,
+		));
+
 		Ok(())
 	}
 
-	pub fn get_reward_payout_account_id() -> T::AccountId {
-		T::RewardPayoutAccount::get().into_account_truncating()
+	/// Move a penalty or slashed amount of `from` into the insurance fund
+	pub fn deposit_to_insurance_fund(from: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		T::Currency::transfer(
+			from,
+			&Self::insurance_fund_account_id(),
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		Self::deposit_event(Event::<T>::InsuranceFundDeposited(from.clone(), amount));
+
+		Ok(())
 	}
 
 	pub fn get_era_index(block_number: BlockNumberFor<T>) -> EraIndex {
@@ -1350,23 +9093,267 @@ impl<T: Config> Pallet<T> {
 		let new_era = previous_era.saturating_add(era_index);
 
 		Self::handle_reward_distribution_to_reward_pool_every_era(previous_era, new_era.clone())?;
+		// Shares joined during the ended era are settled lazily and swept in `on_idle`
+		if !TotalEraJoinedShares::<T>::take().is_zero() && !EraJoinedSharesSettlements::<T>::contains_key(previous_era)
+		{
+			EraJoinedSharesSettlements::<T>::insert(previous_era, (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()));
+		}
 		CurrentEra::<T>::put(new_era.clone());
+		Self::roll_voting_season(new_era);
 		LastEraUpdatedBlock::<T>::put(<frame_system::Pallet<T>>::block_number());
+		Self::snapshot_staker_set(new_era);
+		Self::distribute_reward_campaigns();
+		Self::select_top_innovation_projects(new_era);
+		Self::match_project_rewards(new_era);
+		Self::accrue_credit_interest(new_era);
+		Self::check_reward_pot_watermark();
+
+		// Stakers evaluated against older tiers are re-evaluated when next touched
+		if StakeTiersChanged::<T>::take() {
+			StakeTiersVersion::<T>::mutate(|version| *version = version.wrapping_add(1));
+		}
+
+		let era_metrics = Self::take_era_metrics();
+		T::OnEraChange::on_era_change(new_era, &era_metrics.rewards_added);
 
 		Self::deposit_event(Event::<T>::CurrentInnovationStakingEraUpdated(new_era.clone()));
+		Self::deposit_event(Event::<T>::EraSummary(new_era, era_metrics));
+		Ok(())
+	}
+
+	/// Ensure `origin` is signed by an authorized keeper and count the call against the keeper
+	/// calls of the current block
+	fn ensure_keeper(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+		let who = ensure_signed(origin)?;
+		ensure!(
+			AuthorizedKeepers::<T>::contains_key(&who),
+			Error::<T>::NotAuthorizedKeeper
+		);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let (block, calls) = KeeperCallsInBlock::<T>::get();
+		let calls = if block == now { calls } else { 0 };
+		ensure!(calls < MAX_KEEPER_CALLS_PER_BLOCK, Error::<T>::KeeperRateLimitExceeded);
+		KeeperCallsInBlock::<T>::put((now, calls.saturating_add(1)));
+
+		Ok(who)
+	}
+
+	/// Refund the leftover budget and deposit of an expired reward campaign to its creator
+	fn do_refund_reward_campaign(campaign_id: CampaignId) -> DispatchResult {
+		let campaign = RewardCampaigns::<T>::get(campaign_id).ok_or(Error::<T>::RewardCampaignDoesNotExist)?;
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() >= campaign.end,
+			Error::<T>::RewardCampaignNotExpired
+		);
+
+		T::FungibleTokenCurrency::transfer(
+			campaign.currency_id,
+			&Self::get_reward_payout_account_id(),
+			&campaign.creator,
+			campaign.remaining_budget,
+		)?;
+		T::Currency::unreserve(&campaign.creator, campaign.deposit);
+		RewardCampaigns::<T>::remove(campaign_id);
+		ActiveRewardCampaigns::<T>::mutate(|campaigns| campaigns.retain(|id| *id != campaign_id));
+
+		Self::deposit_event(Event::<T>::RewardCampaignRefunded(
+			campaign_id,
+			campaign.creator,
+			campaign.remaining_budget,
+		));
+
 		Ok(())
 	}
 
+	/// Emit `RewardPotLow` if the native balance of the reward payout account dropped below
+	/// `RewardPotLowWatermark`
+	fn check_reward_pot_watermark() {
+		let watermark = RewardPotLowWatermark::<T>::get();
+		if watermark.is_zero() {
+			return;
+		}
+
+		let balance = T::Currency::free_balance(&Self::get_reward_payout_account_id());
+		if balance < watermark {
+			Self::deposit_event(Event::<T>::RewardPotLow(balance));
+		}
+	}
+
+	/// Sample up to `n` distinct innovation stakers with a probability proportional to their
+	/// shares, using the runtime randomness for `seed`. Only the first
+	/// `MAX_WEIGHTED_SELECTION_CANDIDATES` stakers are considered
+	pub fn select_weighted_stakers(n: u32, seed: &[u8]) -> Vec<T::AccountId> {
+		let mut candidates: Vec<(T::AccountId, u128)> = SharesAndWithdrawnRewards::<T>::iter()
+			.take(MAX_WEIGHTED_SELECTION_CANDIDATES)
+			.map(|(who, (shares, _))| (who, shares.saturated_into::<u128>()))
+			.filter(|(_, shares)| !shares.is_zero())
+			.collect();
+		let mut total_shares = candidates
+			.iter()
+			.fold(0u128, |total, (_, shares)| total.saturating_add(*shares));
+		let (random, _) = T::Randomness::random(&(b"economy/weighted_stakers", seed).encode());
+
+		let mut selected = Vec::new();
+		for draw in 0..n {
+			if total_shares.is_zero() {
+				break;
+			}
+
+			let hash = sp_io::hashing::blake2_256(&(random, draw).encode());
+			let mut point = u128::from_le_bytes(hash[..16].try_into().unwrap_or_default()) % total_shares;
+			let index = candidates
+				.iter()
+				.position(|(_, shares)| {
+					if point < *shares {
+						return true;
+					}
+					point = point.saturating_sub(*shares);
+					false
+				})
+				.unwrap_or_default();
+
+			let (who, shares) = candidates.swap_remove(index);
+			total_shares = total_shares.saturating_sub(shares);
+			selected.push(who);
+		}
+
+		selected
+	}
+
+	/// Native balance of the reward payout account not owed to the reward pool
+	pub fn reward_pot_surplus() -> BalanceOf<T> {
+		let unclaimed = StakingRewardPoolInfo::<T>::get()
+			.rewards
+			.get(&FungibleTokenId::NativeToken(0))
+			.map(|(total_reward, total_withdrawn)| total_reward.saturating_sub(*total_withdrawn))
+			.unwrap_or_default();
+
+		T::Currency::free_balance(&Self::get_reward_payout_account_id()).saturating_sub(unclaimed)
+	}
+
+	/// End the current voting season once `VotingSeasonLength` eras passed, snapshotting the
+	/// voting points of the stakers under the ending season
+	fn roll_voting_season(new_era: EraIndex) {
+		let season_length = VotingSeasonLength::<T>::get();
+		if season_length.is_zero() || new_era < VotingSeasonStartEra::<T>::get().saturating_add(season_length) {
+			return;
+		}
+
+		let season = CurrentVotingSeason::<T>::get();
+		SeasonVotingPointsSnapshot::<T>::insert(season, Self::take_voting_points_snapshot());
+		CurrentVotingSeason::<T>::put(season.saturating_add(1));
+		VotingSeasonStartEra::<T>::put(new_era);
+
+		Self::deposit_event(Event::<T>::VotingSeasonEnded(season, InnovationStakerCount::<T>::get()));
+	}
+
+	/// Metrics of the staking pools at era rollover, resetting the rewards added during the era
+	fn take_era_metrics() -> EraMetrics<BalanceOf<T>> {
+		EraMetrics {
+			total_stake: TotalStake::<T>::get(),
+			total_innovation_stake: TotalInnovationStaking::<T>::get(),
+			total_shares: StakingRewardPoolInfo::<T>::get().total_shares,
+			staker_count: InnovationStakerCount::<T>::get(),
+			rewards_added: EraRewardsAdded::<T>::take().into_iter().collect(),
+		}
+	}
+
+	/// Add the era share of every active reward campaign budget to the reward pool
+	fn distribute_reward_campaigns() {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let blocks_per_era = UpdateEraFrequency::<T>::get().max(One::one());
+
+		let mut active_campaigns = ActiveRewardCampaigns::<T>::get();
+		active_campaigns.retain(|campaign_id| {
+			RewardCampaigns::<T>::get(campaign_id).map_or(false, |campaign| {
+				now < campaign.end && !campaign.remaining_budget.is_zero()
+			})
+		});
+		ActiveRewardCampaigns::<T>::put(&active_campaigns);
+
+		for campaign_id in active_campaigns {
+			let mut campaign = match RewardCampaigns::<T>::get(campaign_id) {
+				Some(campaign) if now >= campaign.start => campaign,
+				_ => continue,
+			};
+
+			// Spread the remaining budget over the remaining eras of the campaign
+			let remaining_eras = campaign
+				.end
+				.saturating_sub(now)
+				.saturating_add(blocks_per_era.saturating_sub(One::one()))
+				/ blocks_per_era;
+			let amount = campaign
+				.remaining_budget
+				.checked_div(&remaining_eras.max(One::one()).saturated_into::<u128>().saturated_into())
+				.unwrap_or_default();
+
+			if amount.is_zero() || Self::accumulate_reward(campaign.currency_id, amount).is_err() {
+				continue;
+			}
+
+			campaign.remaining_budget = campaign.remaining_budget.saturating_sub(amount);
+			RewardCampaigns::<T>::insert(campaign_id, campaign);
+
+			Self::deposit_event(Event::<T>::RewardCampaignDistributed(campaign_id, amount));
+		}
+	}
+
+	/// Set the era frequency and reward budget that are given
+	fn apply_era_config(frequency: Option<BlockNumberFor<T>>, reward_per_era: Option<EraRewardBudget<BalanceOf<T>>>) {
+		if let Some(change) = frequency {
+			UpdateEraFrequency::<T>::put(change);
+			Self::deposit_event(Event::<T>::UpdatedInnovationStakingEraFrequency(change));
+		}
+
+		if let Some(reward_per_era) = reward_per_era {
+			EstimatedStakingRewardPerEra::<T>::put(reward_per_era);
+			Self::deposit_event(Event::<T>::EstimatedRewardPerEraUpdated(reward_per_era));
+		}
+	}
+
+	/// Reward budget of an era, computed from the total innovation stake if set as a rate
+	pub fn estimated_reward_per_era() -> BalanceOf<T> {
+		match EstimatedStakingRewardPerEra::<T>::get() {
+			EraRewardBudget::Rate(rate) => rate * TotalInnovationStaking::<T>::get(),
+			EraRewardBudget::Amount(amount) => amount,
+		}
+	}
+
+	/// Add one block worth of the era reward budget to the reward pool
+	fn drip_reward_to_reward_pool() -> DispatchResult {
+		let blocks_per_era = UpdateEraFrequency::<T>::get();
+		if blocks_per_era.is_zero() {
+			return Ok(());
+		}
+
+		let reward_per_era = Self::estimated_reward_per_era();
+		let reward_per_block = reward_per_era
+			.checked_div(&blocks_per_era.saturated_into::<u128>().saturated_into())
+			.unwrap_or_default();
+
+		// Make sure reward holding account has enough balance
+		let reward_holding_origin = Self::get_reward_payout_account_id();
+		let amount_to_send = reward_per_block.min(T::Currency::free_balance(&reward_holding_origin));
+
+		Self::accumulate_reward(FungibleTokenId::NativeToken(0), amount_to_send)
+	}
+
 	fn handle_reward_distribution_to_reward_pool_every_era(
 		previous_era: EraIndex,
 		new_era: EraIndex,
 	) -> DispatchResult {
 		let era_changes = new_era.saturating_sub(previous_era);
 		ensure!(!era_changes.is_zero(), Error::<T>::Unexpected);
+		if Self::reward_distribution_mode() == RewardDistributionMode::PerBlock {
+			// Era budget is already dripped into the reward pool every block
+			return Ok(());
+		}
 		// Get reward per era that set up Governance
-		let reward_per_era = EstimatedStakingRewardPerEra::<T>::get();
+		let reward_per_era = Self::estimated_reward_per_era();
 		// Get reward holding account
-		let reward_holding_origin = T::RewardPayoutAccount::get().into_account_truncating();
+		let reward_holding_origin = Self::get_reward_payout_account_id();
 		let reward_holding_balance = T::Currency::free_balance(&reward_holding_origin);
 
 		if reward_holding_balance.is_zero() {
@@ -1381,12 +9368,114 @@ impl<T: Config> Pallet<T> {
 			amount_to_send = reward_holding_balance
 		}
 
-		Self::accumulate_reward(FungibleTokenId::NativeToken(0), amount_to_send)?;
+		Self::accumulate_era_reward(previous_era, amount_to_send)
+	}
+
+	/// Add the reward of `era` to the reward pool, priced per share staked at the era start so
+	/// shares joining or leaving during the era neither dilute nor inflate it. Falls back to the
+	/// current pool shares if the era has no snapshot
+	fn accumulate_era_reward(era: EraIndex, reward: BalanceOf<T>) -> DispatchResult {
+		if RewardPoolFrozenSince::<T>::exists() {
+			return Ok(());
+		}
+		let reward_currency = FungibleTokenId::NativeToken(0);
+		let snapshot_shares = match EraStakerSnapshots::<T>::get(era) {
+			Some(snapshot) if !snapshot.total_shares.is_zero() => snapshot.total_shares,
+			_ => return Self::accumulate_reward(reward_currency, reward),
+		};
+		let total_shares = StakingRewardPoolInfo::<T>::get().total_shares;
+		let eligible_shares = total_shares
+			.saturating_sub(TotalEraJoinedShares::<T>::get())
+			.min(snapshot_shares);
+		if eligible_shares.is_zero() {
+			return Ok(());
+		}
+
+		// Reward earned by the shares staked for the whole era
+		let distributed = Self::proportion_of(reward, eligible_shares, snapshot_shares);
+		// Pool-wide increment paying `distributed` to the eligible shares
+		let increment = Self::proportion_of(distributed, total_shares, eligible_shares);
+		Self::accumulate_reward(reward_currency, increment)?;
+
+		// Shares joined during the era forgo their part of the increment once settled
+		if !TotalEraJoinedShares::<T>::get().is_zero() {
+			EraJoinedSharesSettlements::<T>::insert(era, (increment, total_shares));
+		}
+
 		Ok(())
 	}
 
+	/// Settle the shares `who` joined during ended eras, before its rewards are claimed
+	fn settle_era_joined_shares(who: &T::AccountId) {
+		for (era, (increment, total_shares)) in EraJoinedSharesSettlements::<T>::iter() {
+			let joined_shares = EraJoinedShares::<T>::take(era, who);
+			Self::forgo_era_reward(who, joined_shares, increment, total_shares);
+		}
+	}
+
+	/// Settle the shares joined during the oldest ended era with the weight left in the block.
+	/// Settled entries are drained, so the next call resumes where this one stopped
+	fn settle_ended_era_joined_shares(remaining_weight: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(overhead) {
+			return Weight::zero();
+		}
+
+		let (era, (increment, total_shares)) = match EraJoinedSharesSettlements::<T>::iter().next() {
+			Some(settlement) => settlement,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let (next_cursor, consumed) = migrations::step_entries(
+			EraJoinedShares::<T>::drain_prefix(era),
+			T::DbWeight::get().reads_writes(2, 3),
+			remaining_weight.saturating_sub(overhead),
+			|(who, joined_shares)| Self::forgo_era_reward(&who, joined_shares, increment, total_shares),
+		);
+		if next_cursor.is_none() {
+			EraJoinedSharesSettlements::<T>::remove(era);
+		}
+
+		consumed.saturating_add(overhead)
+	}
+
+	/// Withdraw the part of the era reward `increment` paid to `joined_shares` of `who`
+	fn forgo_era_reward(
+		who: &T::AccountId,
+		joined_shares: BalanceOf<T>,
+		increment: BalanceOf<T>,
+		total_shares: BalanceOf<T>,
+	) {
+		let reward_currency = FungibleTokenId::NativeToken(0);
+		let forgone = Self::proportion_of(increment, joined_shares, total_shares);
+		if forgone.is_zero() {
+			return;
+		}
+		SharesAndWithdrawnRewards::<T>::mutate_exists(who, |maybe_share_withdrawn| {
+			if let Some((_, withdrawn_rewards)) = maybe_share_withdrawn {
+				let withdrawn_reward = withdrawn_rewards.entry(reward_currency).or_default();
+				*withdrawn_reward = withdrawn_reward.saturating_add(forgone);
+			}
+		});
+		StakingRewardPoolInfo::<T>::mutate(|pool_info| {
+			if let Some((_, total_withdrawn_reward)) = pool_info.rewards.get_mut(&reward_currency) {
+				*total_withdrawn_reward = total_withdrawn_reward.saturating_add(forgone);
+			}
+		});
+	}
+
+	/// `amount * numerator / denominator` without intermediate overflow
+	fn proportion_of(amount: BalanceOf<T>, numerator: BalanceOf<T>, denominator: BalanceOf<T>) -> BalanceOf<T> {
+		U256::from(amount.saturated_into::<u128>())
+			.saturating_mul(U256::from(numerator.saturated_into::<u128>()))
+			.checked_div(U256::from(denominator.saturated_into::<u128>()))
+			.unwrap_or_default()
+			.saturated_into::<u128>()
+			.saturated_into()
+	}
+
 	pub fn accumulate_reward(reward_currency: FungibleTokenId, reward_increment: BalanceOf<T>) -> DispatchResult {
-		if reward_increment.is_zero() {
+		// A frozen reward pool takes no new rewards, they stay in the reward payout account
+		if reward_increment.is_zero() || RewardPoolFrozenSince::<T>::exists() {
 			return Ok(());
 		}
 		StakingRewardPoolInfo::<T>::mutate_exists(|maybe_pool_info| -> DispatchResult {
@@ -1401,6 +9490,190 @@ impl<T: Config> Pallet<T> {
 				.or_insert((reward_increment, Zero::zero()));
 
 			Ok(())
+		})?;
+
+		EraRewardsAdded::<T>::mutate(|rewards_added| {
+			let reward_added = rewards_added.entry(reward_currency).or_default();
+			*reward_added = reward_added.saturating_add(reward_increment);
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config> StakeWeightProvider<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn stake_weight(who: &T::AccountId) -> BalanceOf<T> {
+		Self::total_economic_weight(who)
+	}
+}
+
+impl<T: Config> RewardPoolHandler<T::AccountId, BalanceOf<T>, FungibleTokenId> for Pallet<T> {
+	fn accumulate_reward(reward_currency: FungibleTokenId, reward_increment: BalanceOf<T>) -> DispatchResult {
+		Self::accumulate_reward(reward_currency, reward_increment)
+	}
+
+	fn claim_rewards(who: &T::AccountId) {
+		Self::claim_rewards(who)
+	}
+
+	fn shares_and_withdrawn_rewards(who: &T::AccountId) -> (BalanceOf<T>, BTreeMap<FungibleTokenId, BalanceOf<T>>) {
+		SharesAndWithdrawnRewards::<T>::get(who)
+	}
+
+	fn pool_info() -> (BalanceOf<T>, BTreeMap<FungibleTokenId, (BalanceOf<T>, BalanceOf<T>)>) {
+		let pool_info = StakingRewardPoolInfo::<T>::get();
+		(pool_info.total_shares, pool_info.rewards)
+	}
+
+	fn pending_rewards(who: &T::AccountId) -> Vec<(FungibleTokenId, BalanceOf<T>)> {
+		Self::pending_rewards(who)
+	}
+}
+
+impl<T: Config> WeightedStakerSelector<T::AccountId> for Pallet<T> {
+	fn select_weighted_stakers(n: u32, seed: &[u8]) -> Vec<T::AccountId> {
+		Self::select_weighted_stakers(n, seed)
+	}
+}
+
+impl<T: Config> OnEstateSubdivided for Pallet<T> {
+	fn on_estate_subdivided(parent: EstateId, children: &[(EstateId, u64)]) -> DispatchResult {
+		Self::subdivide_estate_stake(parent, children)
+	}
+}
+
+impl<T: Config> OnIncome<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_income(who: &T::AccountId, amount: BalanceOf<T>) {
+		if amount.is_zero() || !Self::account_preferences(who).auto_stake_income {
+			return;
+		}
+		if let Err(e) = Self::do_stake_income(who, amount) {
+			log::warn!(target: "economy", "on_income: failed to stake income of {:?}: {:?}", who, e);
+		}
+	}
+}
+
+impl<T: Config> PowerConversion<Balance> for Pallet<T> {
+	fn convert_power_to_bit(power_amount: Balance, commission: Perbill) -> Result<(Balance, Balance), DispatchError> {
+		Self::convert_power_to_bit(power_amount, commission)
+	}
+}
+
+impl<T: Config> VotingPointsSnapshotProvider<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn snapshot_voting_points(proposal_id: ProposalId) -> DispatchResult {
+		ensure!(
+			!ProposalVotingPointsSnapshot::<T>::contains_key(proposal_id),
+			Error::<T>::VotingPointsSnapshotAlreadyTaken
+		);
+
+		let snapshot = Self::take_voting_points_snapshot();
+		ProposalVotingPointsSnapshot::<T>::insert(proposal_id, snapshot);
+
+		Self::deposit_event(Event::<T>::VotingPointsSnapshotTaken(proposal_id, snapshot));
+
+		Ok(())
+	}
+
+	fn voting_points_at(proposal_id: ProposalId, who: &T::AccountId) -> BalanceOf<T> {
+		VotingPointsSnapshots::<T>::get(proposal_id, who).unwrap_or_else(|| {
+			ProposalVotingPointsSnapshot::<T>::get(proposal_id)
+				.map(|snapshot| Self::voting_points_at_snapshot(snapshot, who))
+				.unwrap_or_default()
 		})
 	}
+
+	fn record_voting_points(proposal_id: ProposalId, who: &T::AccountId) -> BalanceOf<T> {
+		if let Some(points) = VotingPointsSnapshots::<T>::get(proposal_id, who) {
+			return points;
+		}
+
+		let points = Self::voting_points_at(proposal_id, who);
+		if ProposalVotingPointsSnapshot::<T>::contains_key(proposal_id) {
+			VotingPointsSnapshots::<T>::insert(proposal_id, who, points);
+		}
+		points
+	}
+
+	fn clear_voting_points_snapshot(proposal_id: ProposalId) {
+		let _ = VotingPointsSnapshots::<T>::clear_prefix(proposal_id, MAX_VOTING_POINTS_SNAPSHOT_STAKERS, None);
+		ProposalVotingPointsSnapshot::<T>::remove(proposal_id);
+	}
+}
+
+impl<T: Config> RoundChangeHandler for Pallet<T> {
+	fn on_round_change(previous_round: RoundIndex, new_round: RoundIndex) -> Weight {
+		let elapsed_rounds = new_round.saturating_sub(previous_round);
+		if elapsed_rounds.is_zero() {
+			return Weight::zero();
+		}
+
+		Self::distribute_self_staking_power(new_round, elapsed_rounds);
+
+		// Estate rewards accrued to leased estate stakes up to the end of their lease
+		let reward_per_weight = EstateRewardPerWeight::<T>::get();
+		for round in previous_round.saturating_add(1)..=new_round {
+			if EstateRewardLeaseEnds::<T>::contains_key(round) {
+				EstateRewardPerWeightAt::<T>::insert(round, reward_per_weight);
+			}
+		}
+
+		// Entries beyond the limit are notified in on_idle
+		let next_round = MaturedExitsCursor::<T>::get().map_or(previous_round.saturating_add(1), |(round, _)| round);
+		MaturedExitsCursor::<T>::put((next_round, new_round));
+		let matured_exits = Self::notify_matured_exits(MAX_MATURED_EXIT_NOTIFICATIONS) as u64;
+
+		T::DbWeight::get().reads_writes(
+			6u64.saturating_add((elapsed_rounds as u64).saturating_mul(2))
+				.saturating_add(matured_exits.saturating_mul(2)),
+			2u64.saturating_add((elapsed_rounds as u64).saturating_mul(2))
+				.saturating_add(matured_exits),
+		)
+	}
+}
+
+/// Negative imbalance of the native currency
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// Transaction fee handler routing `FeeShareRatio` of every fee and tip into the innovation
+/// staking reward pool. The remainder is handed over to `R`
+pub struct FeeSharing<T, R>(sp_std::marker::PhantomData<(T, R)>);
+
+impl<T: Config, R: OnUnbalanced<NegativeImbalanceOf<T>>> FeeSharing<T, R> {
+	/// Move the reward pool share of `imbalance` to the reward payout account and return the
+	/// remainder
+	fn take_share(imbalance: NegativeImbalanceOf<T>) -> NegativeImbalanceOf<T> {
+		let share = Pallet::<T>::fee_share_ratio() * imbalance.peek();
+		// Without stakers the shared fees could never be claimed, a frozen pool accrues no rewards
+		if share.is_zero()
+			|| Pallet::<T>::staking_reward_pool_info().total_shares.is_zero()
+			|| RewardPoolFrozenSince::<T>::exists()
+		{
+			return imbalance;
+		}
+
+		if Pallet::<T>::accumulate_reward(FungibleTokenId::NativeToken(0), share).is_err() {
+			return imbalance;
+		}
+
+		let (to_reward_pool, remainder) = imbalance.split(share);
+		T::Currency::resolve_creating(&Pallet::<T>::get_reward_payout_account_id(), to_reward_pool);
+		Pallet::<T>::deposit_event(Event::<T>::FeesSharedToRewardPool(share));
+
+		remainder
+	}
+}
+
+impl<T: Config, R: OnUnbalanced<NegativeImbalanceOf<T>>> OnUnbalanced<NegativeImbalanceOf<T>> for FeeSharing<T, R> {
+	fn on_unbalanceds<B>(fees_then_tips: impl Iterator<Item = NegativeImbalanceOf<T>>)
+	where
+		NegativeImbalanceOf<T>: Imbalance<B>,
+	{
+		let remainders: Vec<NegativeImbalanceOf<T>> = fees_then_tips.map(Self::take_share).collect();
+		R::on_unbalanceds::<B>(remainders.into_iter());
+	}
+
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+		R::on_unbalanced(Self::take_share(amount));
+	}
 }
@@ -0,0 +1,507 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations of the economy pallet. Each migration only runs when the on-chain storage
+//! version matches the version it migrates from, and bumps the version once done.
+//!
+//! Restructurings of large maps such as `SharesAndWithdrawnRewards` or `PowerBalance` must not
+//! run in a single block. They implement [`SteppedMigration`] instead and are configured as
+//! `Config::SteppedMigration`, which the pallet steps through in `on_idle` with the weight left
+//! in each block, persisting a cursor between blocks.
+
+use frame_support::storage::PrefixIterator;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use primitives::Balance;
+
+use crate::{BitPowerExchangeRate, Config, EstateStakingInfo, Pallet, PowerBalance};
+
+/// Migration executed over multiple blocks
+pub trait SteppedMigration {
+	/// Stable identifier of the migration, recorded in `CompletedSteppedMigrations` once done
+	fn id() -> Vec<u8>;
+
+	/// Whether there is anything to migrate, e.g. the on-chain storage version is the one the
+	/// migration migrates from
+	fn is_applicable() -> bool {
+		true
+	}
+
+	/// Migrate entries starting after `cursor` using at most `limit` weight. Returns the cursor
+	/// to resume from, `None` once the migration is done, and the consumed weight
+	fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight);
+}
+
+type StepFn = fn(Option<Vec<u8>>, Weight) -> (Option<Vec<u8>>, Weight);
+
+/// Stepped migration of a sequence: its stable id, applicability check and step function
+pub type SteppedMigrationEntry = (Vec<u8>, fn() -> bool, StepFn);
+
+/// Sequence of stepped migrations, run one after the other. Completion is tracked per migration
+/// id, so the rest of the sequence is unaffected by a migration finishing
+pub trait SteppedMigrations {
+	/// The migrations of the sequence in execution order
+	fn migrations() -> Vec<SteppedMigrationEntry>;
+}
+
+impl<M: SteppedMigration> SteppedMigrations for M {
+	fn migrations() -> Vec<SteppedMigrationEntry> {
+		sp_std::vec![(M::id(), M::is_applicable as fn() -> bool, M::step as StepFn)]
+	}
+}
+
+macro_rules! impl_stepped_migrations_for_tuples {
+	($($migration:ident),*) => {
+		impl<$($migration: SteppedMigration),*> SteppedMigrations for ($($migration,)*) {
+			fn migrations() -> Vec<SteppedMigrationEntry> {
+				sp_std::vec![$(($migration::id(), $migration::is_applicable as fn() -> bool, $migration::step as StepFn)),*]
+			}
+		}
+	};
+}
+
+impl_stepped_migrations_for_tuples!();
+impl_stepped_migrations_for_tuples!(A, B);
+impl_stepped_migrations_for_tuples!(A, B, C);
+impl_stepped_migrations_for_tuples!(A, B, C, D);
+impl_stepped_migrations_for_tuples!(A, B, C, D, E);
+
+/// Step `steps` one after the other, starting the next step with the weight left once the
+/// previous one is done. The cursor records the index of the running step next to its own cursor
+fn step_in_sequence(steps: &[StepFn], cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+	let (mut index, mut inner_cursor) = cursor
+		.and_then(|cursor| <(u32, Option<Vec<u8>>)>::decode(&mut &cursor[..]).ok())
+		.unwrap_or_default();
+	let mut consumed = Weight::zero();
+	while let Some(step) = steps.get(index as usize) {
+		let (next_cursor, weight) = step(inner_cursor, limit.saturating_sub(consumed));
+		consumed = consumed.saturating_add(weight);
+		if let Some(next_cursor) = next_cursor {
+			return (Some((index, Some(next_cursor)).encode()), consumed);
+		}
+		index = index.saturating_add(1);
+		inner_cursor = None;
+	}
+
+	(None, consumed)
+}
+
+/// Apply `f` to the entries of `iter` until `limit` is reached, charging `weight_per_entry` per
+/// entry. Returns the raw key to resume from with `iter_from`, `None` once all entries are
+/// migrated, and the consumed weight
+pub fn step_entries<I>(
+	mut iter: PrefixIterator<I>,
+	weight_per_entry: Weight,
+	limit: Weight,
+	mut f: impl FnMut(I),
+) -> (Option<Vec<u8>>, Weight) {
+	let mut consumed = Weight::zero();
+	loop {
+		if consumed.saturating_add(weight_per_entry).any_gt(limit) {
+			return (Some(iter.last_raw_key().to_vec()), consumed);
+		}
+		match iter.next() {
+			Some(entry) => {
+				f(entry);
+				consumed = consumed.saturating_add(weight_per_entry);
+			}
+			None => return (None, consumed),
+		}
+	}
+}
+
+/// Initialise the trait-weighted estate stake of existing estate stakers and index their
+/// estates in `StakedEstates`
+pub struct SyncWeightedEstateStakes<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for SyncWeightedEstateStakes<T> {
+	fn id() -> Vec<u8> {
+		b"sync_weighted_estate_stakes".to_vec()
+	}
+
+	fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => EstateStakingInfo::<T>::iter_from(cursor),
+			None => EstateStakingInfo::<T>::iter(),
+		};
+		step_entries(
+			iter,
+			T::DbWeight::get().reads_writes(4, 3),
+			limit,
+			|(estate_id, bond)| Pallet::<T>::sync_weighted_estate_stake(estate_id, &bond.staker),
+		)
+	}
+}
+
+/// Storage version 5: move the legacy power balances to power tokens of
+/// `Config::PowerCurrencyId`
+pub struct MigratePowerBalanceToTokens<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for MigratePowerBalanceToTokens<T> {
+	fn id() -> Vec<u8> {
+		b"migrate_power_balance_to_tokens".to_vec()
+	}
+
+	fn is_applicable() -> bool {
+		Pallet::<T>::on_chain_storage_version() == 4
+	}
+
+	fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => PowerBalance::<T>::iter_from(cursor),
+			None => PowerBalance::<T>::iter(),
+		};
+		let (next_cursor, consumed) =
+			step_entries(iter, T::DbWeight::get().reads_writes(3, 3), limit, |(who, _power)| {
+				Pallet::<T>::migrate_power_balance(&who)
+			});
+		if next_cursor.is_none() {
+			StorageVersion::new(5).put::<Pallet<T>>();
+			log::info!(target: "economy", "Power balance to power tokens migration completed");
+		}
+
+		(next_cursor, consumed)
+	}
+}
+
+/// Storage version 1: the BIT to power exchange rate is stored as a fixed-point number
+pub mod v1 {
+	use super::*;
+
+	/// Convert the integer BIT to power exchange rate to a fixed-point rate
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				log::info!(
+					target: "economy",
+					"Skipping economy migration to v1, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: "economy", "Start upgrading BIT to power exchange rate to fixed-point");
+			let _ = BitPowerExchangeRate::<T>::translate(|old_rate: Option<Balance>| {
+				old_rate.map(FixedU128::saturating_from_integer)
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "economy", "BIT to power exchange rate upgrade completed");
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let old_rate = frame_support::storage::unhashed::get::<Balance>(&BitPowerExchangeRate::<T>::hashed_key());
+			Ok(old_rate.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"Economy storage version was not updated"
+			);
+
+			let old_rate = Option::<Balance>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the old BIT to power exchange rate")?;
+			if let Some(old_rate) = old_rate {
+				ensure!(
+					BitPowerExchangeRate::<T>::get() == FixedU128::saturating_from_integer(old_rate),
+					"BIT to power exchange rate was not migrated"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
+
+/// Storage version 2: staker set snapshots record the total innovation stake
+pub mod v2 {
+	use super::*;
+	use crate::{BalanceOf, EraStakerSnapshots, StakerSetSnapshot};
+	use sp_core::H256;
+
+	/// Staker set snapshot before storage version 2
+	#[derive(Encode, Decode)]
+	struct OldStakerSetSnapshot<Balance> {
+		total_shares: Balance,
+		staker_count: u32,
+		shares_commitment: H256,
+	}
+
+	/// Add the total innovation stake to the staker set snapshots. Innovation shares are issued
+	/// one to one for the stake, so the total shares of the snapshot are used
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 1 {
+				log::info!(
+					target: "economy",
+					"Skipping economy migration to v2, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated: u64 = 0;
+			EraStakerSnapshots::<T>::translate::<OldStakerSetSnapshot<BalanceOf<T>>, _>(|_era, old_snapshot| {
+				translated = translated.saturating_add(1);
+				Some(StakerSetSnapshot {
+					total_shares: old_snapshot.total_shares,
+					staker_count: old_snapshot.staker_count,
+					shares_commitment: old_snapshot.shares_commitment,
+					total_innovation_stake: old_snapshot.total_shares,
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "economy", "Migrated {} staker set snapshots to v2", translated);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((EraStakerSnapshots::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 2,
+				"Economy storage version was not updated"
+			);
+
+			let snapshot_count =
+				u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the staker set snapshot count")?;
+			ensure!(
+				EraStakerSnapshots::<T>::iter_values().count() as u32 == snapshot_count,
+				"Staker set snapshots were not migrated"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Storage version 3: exit queue entries record the block their unlock round is expected at
+pub mod v3 {
+	use super::*;
+	use crate::{BalanceOf, EstateExitQueue, ExitQueue, InnovationStakingExitQueue, QueuedExit};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use primitives::RoundIndex;
+	use sp_runtime::traits::Zero;
+
+	/// Convert the bare exit queue balances to queued exits with an estimated unlock block. The
+	/// three exit queues are converted one after the other, a few entries per block. Until then
+	/// `QueuedExit` decodes the bare balances with a zero estimated unlock block, so the exit
+	/// queues stay usable while the entries are mixed
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToV3<T> {
+		/// Queued exit with the estimated unlock block of `round` filled in, `None` if it was
+		/// already queued in the storage version 3 format
+		fn migrated_exit(
+			round: RoundIndex,
+			queued_exit: QueuedExit<BalanceOf<T>, BlockNumberFor<T>>,
+		) -> Option<QueuedExit<BalanceOf<T>, BlockNumberFor<T>>> {
+			if !queued_exit.estimated_unlock_block.is_zero() {
+				return None;
+			}
+			Some(QueuedExit {
+				amount: queued_exit.amount,
+				estimated_unlock_block: Pallet::<T>::estimated_round_start(round),
+			})
+		}
+
+		fn weight_per_entry() -> Weight {
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+
+		fn step_exit_queue(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+			let iter = match cursor {
+				Some(cursor) => ExitQueue::<T>::iter_from(cursor),
+				None => ExitQueue::<T>::iter(),
+			};
+			step_entries(iter, Self::weight_per_entry(), limit, |(who, round, queued_exit)| {
+				if let Some(migrated_exit) = Self::migrated_exit(round, queued_exit) {
+					ExitQueue::<T>::insert(who, round, migrated_exit)
+				}
+			})
+		}
+
+		fn step_innovation_exit_queue(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+			let iter = match cursor {
+				Some(cursor) => InnovationStakingExitQueue::<T>::iter_from(cursor),
+				None => InnovationStakingExitQueue::<T>::iter(),
+			};
+			step_entries(iter, Self::weight_per_entry(), limit, |(who, round, queued_exit)| {
+				if let Some(migrated_exit) = Self::migrated_exit(round, queued_exit) {
+					InnovationStakingExitQueue::<T>::insert(who, round, migrated_exit)
+				}
+			})
+		}
+
+		fn step_estate_exit_queue(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+			let iter = match cursor {
+				Some(cursor) => EstateExitQueue::<T>::iter_from(cursor),
+				None => EstateExitQueue::<T>::iter(),
+			};
+			step_entries(
+				iter,
+				Self::weight_per_entry(),
+				limit,
+				|((who, round, estate_id), queued_exit)| {
+					if let Some(migrated_exit) = Self::migrated_exit(round, queued_exit) {
+						EstateExitQueue::<T>::insert((who, round, estate_id), migrated_exit)
+					}
+				},
+			)
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToV3<T> {
+		fn id() -> Vec<u8> {
+			b"migrate_exit_queues_to_v3".to_vec()
+		}
+
+		fn is_applicable() -> bool {
+			Pallet::<T>::on_chain_storage_version() == 2
+		}
+
+		fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+			let (next_cursor, consumed) = step_in_sequence(
+				&[
+					Self::step_exit_queue as StepFn,
+					Self::step_innovation_exit_queue as StepFn,
+					Self::step_estate_exit_queue as StepFn,
+				],
+				cursor,
+				limit,
+			);
+			if next_cursor.is_none() {
+				StorageVersion::new(3).put::<Pallet<T>>();
+				log::info!(target: "economy", "Exit queue migration to v3 completed");
+			}
+
+			(next_cursor, consumed)
+		}
+	}
+}
+
+/// Storage version 4: the estimated staking reward per era is an era reward budget
+pub mod v4 {
+	use super::*;
+	use crate::{BalanceOf, EraRewardBudget, EstimatedStakingRewardPerEra};
+	use codec::DecodeAll;
+
+	/// Convert the absolute estimated staking reward per era to an era reward budget amount. The
+	/// value is translated at upgrade time whatever the on-chain storage version, as the stepped
+	/// migration to storage version 3 may still be running. The storage version is bumped once
+	/// version 3 is reached, which is also stepped on chains upgrading from version 2
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToV4<T> {
+		/// Estimated staking reward per era still stored as an absolute balance
+		fn legacy_reward() -> Option<BalanceOf<T>> {
+			let raw = frame_support::storage::unhashed::get_raw(&EstimatedStakingRewardPerEra::<T>::hashed_key())?;
+			if EraRewardBudget::<BalanceOf<T>>::decode_all(&mut &raw[..]).is_ok() {
+				return None;
+			}
+			BalanceOf::<T>::decode_all(&mut &raw[..]).ok()
+		}
+
+		fn bump_version() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 3 {
+				return T::DbWeight::get().reads(1);
+			}
+			StorageVersion::new(4).put::<Pallet<T>>();
+			log::info!(target: "economy", "Economy migration to v4 completed");
+
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToV4<T> {
+		fn id() -> Vec<u8> {
+			b"migrate_era_reward_budget_to_v4".to_vec()
+		}
+
+		fn is_applicable() -> bool {
+			Pallet::<T>::on_chain_storage_version() == 3
+		}
+
+		fn step(_cursor: Option<Vec<u8>>, _limit: Weight) -> (Option<Vec<u8>>, Weight) {
+			(None, Self::bump_version())
+		}
+	}
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if let Some(old_reward) = Self::legacy_reward() {
+				EstimatedStakingRewardPerEra::<T>::put(EraRewardBudget::Amount(old_reward));
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				log::info!(target: "economy", "Estimated staking reward per era upgrade completed");
+			}
+
+			weight.saturating_add(Self::bump_version())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((Pallet::<T>::on_chain_storage_version(), Self::legacy_reward()).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (old_version, old_reward) = <(StorageVersion, Option<BalanceOf<T>>)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the old estimated staking reward per era")?;
+			ensure!(
+				Self::legacy_reward().is_none(),
+				"Estimated staking reward per era is still an absolute balance"
+			);
+			if let Some(old_reward) = old_reward {
+				ensure!(
+					EstimatedStakingRewardPerEra::<T>::get() == EraRewardBudget::Amount(old_reward),
+					"Estimated staking reward per era was not migrated"
+				);
+			}
+			if old_version == 3 {
+				ensure!(
+					Pallet::<T>::on_chain_storage_version() == 4,
+					"Economy storage version was not updated"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
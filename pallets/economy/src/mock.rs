@@ -1,6 +1,7 @@
 #![cfg(test)]
 
-use frame_support::traits::{Contains, InstanceFilter, Nothing};
+use frame_support::traits::{Contains, InstanceFilter, Nothing, Randomness};
+use frame_support::weights::RuntimeDbWeight;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::Call as SystemCall;
 use frame_system::EnsureSignedBy;
@@ -12,7 +13,7 @@ use sp_runtime::{traits::IdentityLookup, MultiSignature, Perbill};
 
 use auction_manager::*;
 use core_primitives::NftAssetData;
-use primitives::estate::Estate;
+use primitives::estate::{Estate, EstateShape, EstateTraits};
 use primitives::staking::MetaverseStakingTrait;
 use primitives::ClassId;
 use primitives::{Amount, AuctionId, EstateId, FungibleTokenId, ItemId, UndeployedLandBlockId};
@@ -75,6 +76,7 @@ parameter_types! {
 	pub const MaximumBlockWeight: u32 = 1024;
 	pub const MaximumBlockLength: u32 = 2 * 1024;
 	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub static MockDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
 }
 impl frame_system::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
@@ -94,7 +96,7 @@ impl frame_system::Config for Runtime {
 	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
-	type DbWeight = ();
+	type DbWeight = MockDbWeight;
 	type BaseCallFilter = frame_support::traits::Everything;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
@@ -109,6 +111,26 @@ parameter_types! {
 	pub const MaxTokenMetadata: u32 = 1024;
 	pub const MinimumStake: Balance = 100;
 	pub const MaximumEstateStake: Balance = 100;
+	pub const RewardPoolDrainGracePeriod: u64 = 100;
+	pub const MaxQueuedExitsPerAccount: u32 = 3;
+	pub const PowerCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(1);
+	pub const MaxCurrenciesPerClaim: u32 = 3;
+	pub const LendingPotPalletId: PalletId = PalletId(*b"bit/lend");
+	pub const TopInnovationProjectsCount: u32 = 2;
+	pub const MinimumProjectDeposit: Balance = 100;
+	pub const OffenceReporterReward: Perbill = Perbill::from_percent(10);
+	pub const OffenceReportDeposit: Balance = 100;
+	pub const EconomySpendDeposit: Balance = 100;
+	pub const MaxOpenEconomySpends: u32 = 2;
+	pub const StakeFeeHeadroom: Balance = 10;
+	pub const ExistentialStake: Balance = 10;
+	pub const MinimumIncomeStake: Balance = 50;
+	pub const MissionPotPalletId: PalletId = PalletId(*b"bit/msnp");
+	pub const RewardCampaignDeposit: Balance = 10;
+	pub const BondedPoolPalletId: PalletId = PalletId(*b"bit/bdpl");
+	pub const InsuranceFundPalletId: PalletId = PalletId(*b"bit/insr");
+	pub const MaxEstateStakeBatch: u32 = 3;
+	pub const MaxStakeTiers: u32 = 20;
 }
 
 impl pallet_balances::Config for Runtime {
@@ -190,6 +212,31 @@ impl Estate<AccountId> for EstateHandler {
 	fn is_estate_leased(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(false)
 	}
+
+	fn check_estate_lease(leasor: AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
+		if estate_id == OWNED_ESTATE_ID && leasor == BOB {
+			return Ok(true);
+		}
+		Ok(false)
+	}
+
+	fn get_estate_traits(estate_id: EstateId) -> Option<EstateTraits> {
+		if estate_id == OWNED_ESTATE_ID {
+			return Some(EstateTraits {
+				biome: 1,
+				rarity: 3,
+				neighborhood_score: 7,
+			});
+		}
+		None
+	}
+
+	fn get_estate_shape(estate_id: EstateId) -> Option<EstateShape> {
+		Some(EstateShape {
+			land_units: 10,
+			is_contiguous: estate_id == OWNED_ESTATE_ID,
+		})
+	}
 }
 
 pub struct MetaverseStakingHandler;
@@ -218,6 +265,7 @@ impl pallet_mining::Config for Runtime {
 	type NetworkTreasuryAccount = TreasuryModuleAccount;
 	type StorageDepositFee = StorageDepositFee;
 	type Currency = Balances;
+	type RoundChangeHandler = Economy;
 	type WeightInfo = ();
 }
 
@@ -226,6 +274,49 @@ ord_parameter_types! {
 	pub const Two: AccountId = AccountId32::new([2;32]);
 	pub const PowerAmountPerBlock: u32 = 10;
 }
+pub const STEPPED_MIGRATION_WEIGHT_PER_ENTRY: Weight = Weight::from_parts(10, 0);
+
+/// Deterministic randomness derived from the subject
+pub struct TestRandomness;
+
+impl Randomness<H256, BlockNumber> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		(H256::from(sp_io::hashing::blake2_256(subject)), 0)
+	}
+}
+
+/// Doubles every power balance, a few entries per block
+pub struct TestSteppedMigration;
+
+impl SteppedMigration for TestSteppedMigration {
+	fn id() -> Vec<u8> {
+		b"double_power_balance".to_vec()
+	}
+
+	fn step(cursor: Option<Vec<u8>>, limit: Weight) -> (Option<Vec<u8>>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => PowerBalance::<Runtime>::iter_from(cursor),
+			None => PowerBalance::<Runtime>::iter(),
+		};
+		migrations::step_entries(iter, STEPPED_MIGRATION_WEIGHT_PER_ENTRY, limit, |(who, power)| {
+			PowerBalance::<Runtime>::insert(who, power * 2)
+		})
+	}
+}
+
+parameter_types! {
+	pub static EraChanges: Vec<(EraIndex, Vec<(FungibleTokenId, Balance)>)> = vec![];
+}
+
+/// Records every era change notified by the economy pallet
+pub struct MockOnEraChange;
+
+impl OnEraChange<Balance> for MockOnEraChange {
+	fn on_era_change(new_era: EraIndex, distributed_rewards: &[(FungibleTokenId, Balance)]) {
+		EraChanges::mutate(|era_changes| era_changes.push((new_era, distributed_rewards.to_vec())));
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -239,6 +330,44 @@ impl Config for Runtime {
 	type MaximumEstateStake = MaximumEstateStake;
 	type PowerAmountPerBlock = PowerAmountPerBlock;
 	type RewardPayoutAccount = InnovationStakingRewardPayoutAccountPalletId;
+	type MaxEstateStakeBatch = MaxEstateStakeBatch;
+	type RewardSwapper = ();
+	type InsuranceFundAccount = InsuranceFundPalletId;
+	type BondedPoolPalletId = BondedPoolPalletId;
+	type RewardCampaignDeposit = RewardCampaignDeposit;
+	type MissionRewardOrigin = EnsureSignedBy<One, AccountId>;
+	type MissionPotPalletId = MissionPotPalletId;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	type EconomySpendOrigin = EnsureSignedBy<One, AccountId>;
+	type EconomySpendDeposit = EconomySpendDeposit;
+	type MaxOpenEconomySpends = MaxOpenEconomySpends;
+	type SteppedMigration = (
+		migrations::v3::MigrateToV3<Runtime>,
+		migrations::v4::MigrateToV4<Runtime>,
+		migrations::SyncWeightedEstateStakes<Runtime>,
+		migrations::MigratePowerBalanceToTokens<Runtime>,
+		TestSteppedMigration,
+	);
+	type MinimumIncomeStake = MinimumIncomeStake;
+	type ExistentialStake = ExistentialStake;
+	type StakeFeeHeadroom = StakeFeeHeadroom;
+	type OffenceJudge = EnsureSignedBy<One, AccountId>;
+	type OffenceReportDeposit = OffenceReportDeposit;
+	type OffenceReporterReward = OffenceReporterReward;
+	type MinimumProjectDeposit = MinimumProjectDeposit;
+	type TopInnovationProjectsCount = TopInnovationProjectsCount;
+	type OnInnovationProjectsSelected = ();
+	type MatchingEligibility = ();
+	type LendingPotPalletId = LendingPotPalletId;
+	type CrossChainTransfer = ();
+	type MaxCurrenciesPerClaim = MaxCurrenciesPerClaim;
+	type PowerCurrencyId = PowerCurrencyId;
+	type MaxQueuedExitsPerAccount = MaxQueuedExitsPerAccount;
+	type Randomness = TestRandomness;
+	type RewardPoolDrainGracePeriod = RewardPoolDrainGracePeriod;
+	type OnEraChange = MockOnEraChange;
+	type MaxStakeTiers = MaxStakeTiers;
 	type WeightInfo = ();
 }
 
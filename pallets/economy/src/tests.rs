@@ -17,9 +17,12 @@
 
 #![cfg(test)]
 
+use frame_support::traits::OnRuntimeUpgrade;
 use frame_support::{assert_noop, assert_ok};
 
-use sp_runtime::traits::AccountIdConversion;
+use sp_core::Pair;
+use sp_runtime::traits::{AccountIdConversion, BadOrigin, IdentifyAccount};
+use sp_runtime::{MultiSignature, MultiSigner};
 use sp_std::default::Default;
 
 use core_primitives::{Attributes, CollectionType, TokenType};
@@ -79,18 +82,100 @@ fn stake_should_fail_insufficient_balance() {
 }
 
 #[test]
-fn stake_should_fail_exit_queue_scheduled() {
+fn stake_should_work_with_exit_queue_scheduled() {
 	ExtBuilder::default().build().execute_with(|| {
-		// Add account entry to ExitQueue
-		ExitQueue::<Runtime>::insert(account(1), CURRENT_ROUND, STAKE_BALANCE);
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			None
+		));
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(account(1)),
+			UNSTAKE_AMOUNT,
+			None
+		));
+		let next_round: RoundIndex = CURRENT_ROUND + 1;
 
+		// Staking while the exit is queued only uses the free balance
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(account(1)), 200, None));
+		assert_eq!(
+			EconomyModule::get_staking_info(account(1)),
+			STAKE_BALANCE - UNSTAKE_AMOUNT + 200
+		);
+		assert_eq!(
+			EconomyModule::staking_exit_queue(account(1), next_round).map(|queued_exit| queued_exit.amount),
+			Some(UNSTAKE_AMOUNT)
+		);
 		assert_noop!(
-			EconomyModule::stake(RuntimeOrigin::signed(account(1)), STAKE_BELOW_MINIMUM_BALANCE, None),
+			EconomyModule::stake(RuntimeOrigin::signed(account(1)), FREE_BALANCE, None),
+			Error::<Runtime>::InsufficientBalanceForStaking
+		);
+
+		// Staking in the round the exit matured keeps the exit withdrawable
+		run_to_block(25);
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(account(1)), 200, None));
+		assert_ok!(EconomyModule::withdraw_unreserved(
+			RuntimeOrigin::signed(account(1)),
+			next_round
+		));
+		assert_eq!(
+			EconomyModule::get_staking_info(account(1)),
+			STAKE_BALANCE - UNSTAKE_AMOUNT + 400
+		);
+		assert_eq!(
+			Balances::reserved_balance(account(1)),
+			STAKE_BALANCE - UNSTAKE_AMOUNT + 400
+		);
+
+		// Queuing a second exit for the same round still conflicts
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(account(1)),
+			UNSTAKE_AMOUNT,
+			None
+		));
+		assert_noop!(
+			EconomyModule::unstake(RuntimeOrigin::signed(account(1)), UNSTAKE_AMOUNT, None),
 			Error::<Runtime>::ExitQueueAlreadyScheduled
 		);
 	});
 }
 
+#[test]
+fn stake_on_innovation_and_estate_should_work_with_exit_queue_scheduled() {
+	ExtBuilder::default().build().execute_with(|| {
+		InnovationStakingExitQueue::<Runtime>::insert(
+			account(1),
+			CURRENT_ROUND,
+			QueuedExit {
+				amount: STAKE_BALANCE,
+				estimated_unlock_block: 1,
+			},
+		);
+		EstateExitQueue::<Runtime>::insert(
+			(account(1), CURRENT_ROUND, OWNED_ESTATE_ID),
+			QueuedExit {
+				amount: STAKE_BALANCE,
+				estimated_unlock_block: 1,
+			},
+		);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			500,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::get_innovation_staking_info(account(1)), STAKE_BALANCE);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).map(|bond| bond.amount),
+			Some(500)
+		);
+	});
+}
+
 #[test]
 fn stake_should_fail_below_minimum() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -172,12 +257,19 @@ fn stake_should_work_for_estate() {
 			Some(OWNED_ESTATE_ID)
 		));
 
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::EstateStakedToEconomy101(
+				account(1),
+				OWNED_ESTATE_ID,
+				STAKE_BALANCE
+			))));
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::EstateStakedToEconomy101(
+			RuntimeEvent::Economy(crate::Event::EstateBondUpdated(
 				account(1),
 				OWNED_ESTATE_ID,
-				STAKE_BALANCE
+				STAKE_BALANCE,
+				0
 			))
 		);
 
@@ -298,7 +390,7 @@ fn unstake_should_work() {
 		assert_eq!(EconomyModule::total_stake(), total_staked_balance);
 		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
 		assert_eq!(
-			EconomyModule::staking_exit_queue(account(1), next_round),
+			EconomyModule::staking_exit_queue(account(1), next_round).map(|queued_exit| queued_exit.amount),
 			Some(UNSTAKE_AMOUNT)
 		);
 	});
@@ -324,11 +416,18 @@ fn unstake_should_work_for_estate() {
 			Some(OWNED_ESTATE_ID)
 		));
 
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::EstateStakingRemovedFromEconomy101(
+				account(1),
+				OWNED_ESTATE_ID,
+				UNSTAKE_AMOUNT
+			))));
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::EstateStakingRemovedFromEconomy101(
+			RuntimeEvent::Economy(crate::Event::EstateBondUpdated(
 				account(1),
 				OWNED_ESTATE_ID,
+				STAKE_BALANCE - UNSTAKE_AMOUNT,
 				UNSTAKE_AMOUNT
 			))
 		);
@@ -346,7 +445,8 @@ fn unstake_should_work_for_estate() {
 
 		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
 		assert_eq!(
-			EconomyModule::estate_staking_exit_queue((account(1), next_round, OWNED_ESTATE_ID)),
+			EconomyModule::estate_staking_exit_queue((account(1), next_round, OWNED_ESTATE_ID))
+				.map(|queued_exit| queued_exit.amount),
 			Some(UNSTAKE_AMOUNT)
 		);
 	});
@@ -381,7 +481,7 @@ fn withdraw_unstake_should_work() {
 		assert_eq!(EconomyModule::total_stake(), total_staked_balance);
 		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
 		assert_eq!(
-			EconomyModule::staking_exit_queue(account(1), next_round),
+			EconomyModule::staking_exit_queue(account(1), next_round).map(|queued_exit| queued_exit.amount),
 			Some(UNSTAKE_AMOUNT)
 		);
 
@@ -508,11 +608,18 @@ fn unstake_new_estate_owner_should_work() {
 			RuntimeOrigin::signed(account(1)),
 			OWNED_ESTATE_ID
 		));
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::EstateStakingRemovedFromEconomy101(
+				account(1),
+				OWNED_ESTATE_ID,
+				STAKE_BALANCE
+			))));
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::EstateStakingRemovedFromEconomy101(
-				account(1),
+			RuntimeEvent::Economy(crate::Event::EstateBondUpdated(
+				account(2),
 				OWNED_ESTATE_ID,
+				0,
 				STAKE_BALANCE
 			))
 		);
@@ -596,7 +703,7 @@ fn unstake_on_innovation_should_work() {
 		assert_eq!(EconomyModule::total_innovation_staking(), total_staked_balance);
 		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(28u32);
 		assert_eq!(
-			EconomyModule::innovation_staking_exit_queue(account(1), next_round),
+			EconomyModule::innovation_staking_exit_queue(account(1), next_round).map(|queued_exit| queued_exit.amount),
 			Some(UNSTAKE_AMOUNT)
 		);
 
@@ -609,6 +716,57 @@ fn unstake_on_innovation_should_work() {
 	});
 }
 
+#[test]
+fn unstake_on_innovation_should_respect_unstake_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+		));
+		assert_ok!(EconomyModule::set_unstake_cooldown(RuntimeOrigin::root(), 30));
+
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 100));
+
+		run_to_block(21);
+		assert_noop!(
+			EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 100),
+			Error::<Runtime>::OperationOnCooldown
+		);
+
+		// The cooldown is tracked per pool
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 100, None));
+
+		run_to_block(41);
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 100));
+	});
+}
+
+#[test]
+fn unstake_on_innovation_should_bound_queued_exit_rounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+		));
+
+		for block in [1, 21, 41] {
+			run_to_block(block);
+			assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 100));
+		}
+		assert_eq!(
+			InnovationStakingExitQueue::<Runtime>::iter_prefix(ALICE).count() as u32,
+			MaxQueuedExitsPerAccount::get()
+		);
+
+		run_to_block(61);
+		assert_noop!(
+			EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 100),
+			Error::<Runtime>::TooManyQueuedExits
+		);
+	});
+}
+
 #[test]
 fn claim_reward_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -635,10 +793,10 @@ fn claim_reward_should_work() {
 
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::ClaimRewards(
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
 				account(1),
-				FungibleTokenId::NativeToken(0),
-				reward_amount
+				account(1),
+				vec![(FungibleTokenId::NativeToken(0), reward_amount)]
 			))
 		);
 
@@ -654,6 +812,129 @@ fn claim_reward_should_work() {
 	});
 }
 
+#[test]
+fn claim_reward_below_minimum_claim_amount_should_stay_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+		));
+		assert_ok!(EconomyModule::set_minimum_claim_amount(
+			RuntimeOrigin::root(),
+			FungibleTokenId::NativeToken(0),
+			200
+		));
+
+		let mut reward_map: BTreeMap<FungibleTokenId, u128> = BTreeMap::new();
+		reward_map.insert(FungibleTokenId::NativeToken(0), 100u128);
+		PendingRewardsOfStakingInnovation::<Runtime>::insert(account(1), reward_map);
+
+		let balance = Balances::free_balance(account(1));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(account(1))));
+		assert_eq!(Balances::free_balance(account(1)), balance);
+		assert_eq!(
+			EconomyModule::pending_rewards(&account(1)),
+			vec![(FungibleTokenId::NativeToken(0), 100u128)]
+		);
+
+		assert_noop!(
+			EconomyModule::force_flush_small_claims(
+				RuntimeOrigin::signed(account(1)),
+				vec![account(1)].try_into().unwrap()
+			),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::force_flush_small_claims(
+			RuntimeOrigin::root(),
+			vec![account(1)].try_into().unwrap()
+		));
+		assert_eq!(Balances::free_balance(account(1)), balance + 100);
+		assert_eq!(EconomyModule::pending_rewards(&account(1)), vec![]);
+	});
+}
+
+#[test]
+fn claim_reward_should_continue_after_max_currencies_per_claim() {
+	let reward_payout_account = EconomyModule::get_reward_payout_account_id();
+	ExtBuilder::default()
+		.balances(vec![
+			(reward_payout_account.clone(), FungibleTokenId::FungibleToken(1), 1000),
+			(reward_payout_account.clone(), FungibleTokenId::FungibleToken(2), 1000),
+			(reward_payout_account, FungibleTokenId::FungibleToken(3), 1000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EconomyModule::stake_on_innovation(
+				RuntimeOrigin::signed(ALICE),
+				STAKE_BALANCE
+			));
+
+			let mut reward_map: BTreeMap<FungibleTokenId, u128> = BTreeMap::new();
+			reward_map.insert(FungibleTokenId::NativeToken(0), 100u128);
+			reward_map.insert(FungibleTokenId::FungibleToken(1), 100u128);
+			reward_map.insert(FungibleTokenId::FungibleToken(2), 100u128);
+			reward_map.insert(FungibleTokenId::FungibleToken(3), 100u128);
+			PendingRewardsOfStakingInnovation::<Runtime>::insert(ALICE, reward_map);
+
+			// Mock runtime pays out at most 3 currencies per claim
+			assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(
+				EconomyModule::pending_rewards(&ALICE),
+				vec![(FungibleTokenId::FungibleToken(3), 100u128)]
+			);
+			assert_eq!(
+				EconomyModule::claim_cursor(ALICE),
+				Some(FungibleTokenId::FungibleToken(3))
+			);
+			assert!(System::events().iter().any(|record| record.event
+				== RuntimeEvent::Economy(crate::Event::ClaimRewardsContinued(
+					ALICE,
+					FungibleTokenId::FungibleToken(3)
+				))));
+
+			assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(EconomyModule::pending_rewards(&ALICE), vec![]);
+			assert_eq!(EconomyModule::claim_cursor(ALICE), None);
+			assert_eq!(OrmlTokens::free_balance(FungibleTokenId::FungibleToken(3), &ALICE), 100);
+		});
+}
+
+#[test]
+fn initialize_reward_pool_should_allow_accumulating_before_first_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100),
+			Error::<Runtime>::RewardPoolDoesNotExist
+		);
+
+		assert_noop!(
+			EconomyModule::initialize_reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				vec![FungibleTokenId::NativeToken(0)].try_into().unwrap()
+			),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::initialize_reward_pool(
+			RuntimeOrigin::root(),
+			vec![FungibleTokenId::NativeToken(0)].try_into().unwrap()
+		));
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(0, 0))
+		);
+
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(100, 0))
+		);
+	});
+}
+
 #[test]
 fn claim_reward_with_multiple_stakers_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -683,7 +964,7 @@ fn claim_reward_with_multiple_stakers_should_work() {
 		);
 		assert_eq!(EconomyModule::total_innovation_staking(), STAKE_BALANCE);
 
-		EstimatedStakingRewardPerEra::<Runtime>::set(2000u128);
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(2000u128));
 		UpdateEraFrequency::<Runtime>::set(1u64);
 
 		run_to_block(2);
@@ -692,10 +973,10 @@ fn claim_reward_with_multiple_stakers_should_work() {
 
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::ClaimRewards(
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
 				account(1),
-				FungibleTokenId::NativeToken(0),
-				3000u128
+				account(1),
+				vec![(FungibleTokenId::NativeToken(0), 3000u128)]
 			))
 		);
 
@@ -716,10 +997,10 @@ fn claim_reward_with_multiple_stakers_should_work() {
 
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::ClaimRewards(
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
 				account(2),
-				FungibleTokenId::NativeToken(0),
-				1000u128
+				account(2),
+				vec![(FungibleTokenId::NativeToken(0), 1000u128)]
 			))
 		);
 
@@ -745,7 +1026,7 @@ fn claim_reward_twice_should_not_update_balance_twice() {
 			STAKE_BALANCE,
 		));
 
-		EstimatedStakingRewardPerEra::<Runtime>::set(100u128);
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
 		UpdateEraFrequency::<Runtime>::set(3u64);
 
 		run_to_block(4);
@@ -754,10 +1035,10 @@ fn claim_reward_twice_should_not_update_balance_twice() {
 
 		assert_eq!(
 			last_event(),
-			RuntimeEvent::Economy(crate::Event::ClaimRewards(
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
 				account(1),
-				FungibleTokenId::NativeToken(0),
-				100u128
+				account(1),
+				vec![(FungibleTokenId::NativeToken(0), 100u128)]
 			))
 		);
 
@@ -781,7 +1062,7 @@ fn claim_reward_after_unstaking_should_not_update_balance() {
 			STAKE_BALANCE,
 		));
 
-		EstimatedStakingRewardPerEra::<Runtime>::set(100u128);
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
 		UpdateEraFrequency::<Runtime>::set(4u64);
 
 		run_to_block(4);
@@ -805,3 +1086,5032 @@ fn claim_reward_after_unstaking_should_not_update_balance() {
 		assert_eq!(Balances::free_balance(account(1)), 9100u128);
 	});
 }
+
+#[test]
+fn switch_stake_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			None
+		));
+
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			500,
+			PoolKind::Economy101,
+			PoolKind::Innovation
+		));
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::StakeSwitched(
+				account(1),
+				PoolKind::Economy101,
+				PoolKind::Innovation,
+				500
+			))
+		);
+
+		assert_eq!(Balances::reserved_balance(account(1)), STAKE_BALANCE);
+		assert_eq!(EconomyModule::get_staking_info(account(1)), 500);
+		assert_eq!(EconomyModule::total_stake(), 500);
+		assert_eq!(EconomyModule::get_innovation_staking_info(account(1)), 500);
+		assert_eq!(EconomyModule::total_innovation_staking(), 500);
+		assert_eq!(
+			EconomyModule::shares_and_withdrawn_rewards(account(1)),
+			(500, Default::default())
+		);
+	});
+}
+
+#[test]
+fn switch_stake_should_fail_for_same_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::switch_stake(
+				RuntimeOrigin::signed(account(1)),
+				STAKE_BALANCE,
+				PoolKind::Innovation,
+				PoolKind::Innovation
+			),
+			Error::<Runtime>::SwitchToSamePool
+		);
+	});
+}
+
+#[test]
+fn switch_stake_out_of_innovation_should_keep_innovation_unbond() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			600,
+			PoolKind::Innovation,
+			PoolKind::Economy101
+		));
+		assert_eq!(
+			EconomyModule::switched_stake_locks(account(1)).into_inner(),
+			vec![(600, CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS)]
+		);
+
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(account(1)), 500, None));
+		assert_noop!(
+			EconomyModule::unstake(RuntimeOrigin::signed(account(1)), 600, None),
+			Error::<Runtime>::SwitchedStakeLocked
+		);
+		// Only self-stake above the switched amount can leave with the one round exit
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(account(1)), 500, None));
+
+		// Moving the stake back into innovation staking releases the lock
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			600,
+			PoolKind::Economy101,
+			PoolKind::Innovation
+		));
+		assert!(EconomyModule::switched_stake_locks(account(1)).is_empty());
+		assert_eq!(EconomyModule::get_innovation_staking_info(account(1)), STAKE_BALANCE);
+	});
+}
+
+#[test]
+fn switched_stake_should_unlock_after_innovation_unbond() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			PoolKind::Innovation,
+			PoolKind::Economy101
+		));
+		assert_noop!(
+			EconomyModule::unstake(RuntimeOrigin::signed(account(1)), STAKE_BALANCE, None),
+			Error::<Runtime>::SwitchedStakeLocked
+		);
+
+		run_to_block(600);
+
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			None
+		));
+	});
+}
+
+#[test]
+fn switched_stake_should_unlock_per_tranche() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			300,
+			PoolKind::Innovation,
+			PoolKind::Economy101
+		));
+
+		let second_switch_round = CURRENT_ROUND + 2;
+		while Mining::get_current_round_info().current < second_switch_round {
+			run_to_block(System::block_number() + 1);
+		}
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			300,
+			PoolKind::Innovation,
+			PoolKind::Economy101
+		));
+		// The second switch does not push back the unlock round of the first one
+		assert_eq!(
+			EconomyModule::switched_stake_locks(account(1)).into_inner(),
+			vec![
+				(300, CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS),
+				(300, second_switch_round + INNOVATION_UNBOND_ROUNDS)
+			]
+		);
+
+		while Mining::get_current_round_info().current < CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS {
+			run_to_block(System::block_number() + 1);
+		}
+		assert_noop!(
+			EconomyModule::unstake(RuntimeOrigin::signed(account(1)), 600, None),
+			Error::<Runtime>::SwitchedStakeLocked
+		);
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(account(1)), 300, None));
+
+		// Switching back releases the tranche unlocking last
+		assert_ok!(EconomyModule::switch_stake(
+			RuntimeOrigin::signed(account(1)),
+			300,
+			PoolKind::Economy101,
+			PoolKind::Innovation
+		));
+		assert!(EconomyModule::switched_stake_locks(account(1)).is_empty());
+	});
+}
+
+#[test]
+fn switch_stake_into_innovation_should_enforce_minimum_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			None
+		));
+
+		assert_noop!(
+			EconomyModule::switch_stake(
+				RuntimeOrigin::signed(account(1)),
+				50,
+				PoolKind::Economy101,
+				PoolKind::Innovation
+			),
+			Error::<Runtime>::StakeBelowMinimum
+		);
+	});
+}
+
+#[test]
+fn stake_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_batch(
+			RuntimeOrigin::signed(account(1)),
+			vec![(OWNED_ESTATE_ID, STAKE_BALANCE)].try_into().unwrap()
+		));
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateBatchStakedToEconomy101(
+				account(1),
+				1,
+				STAKE_BALANCE
+			))
+		);
+
+		assert_eq!(Balances::reserved_balance(account(1)), STAKE_BALANCE);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).unwrap().amount,
+			STAKE_BALANCE
+		);
+		assert_eq!(EconomyModule::total_estate_stake(), STAKE_BALANCE);
+	});
+}
+
+#[test]
+fn stake_batch_should_be_all_or_nothing() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::stake_batch(
+				RuntimeOrigin::signed(account(1)),
+				vec![(OWNED_ESTATE_ID, STAKE_BALANCE), (EXISTING_ESTATE_ID, STAKE_BALANCE)]
+					.try_into()
+					.unwrap()
+			),
+			Error::<Runtime>::StakerNotEstateOwner
+		);
+
+		assert_noop!(
+			EconomyModule::stake_batch(RuntimeOrigin::signed(account(1)), Default::default()),
+			Error::<Runtime>::InvalidBatchSize
+		);
+	});
+}
+
+#[test]
+fn stake_should_work_for_estate_lessee() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).unwrap().staker,
+			BOB
+		);
+		assert_eq!(
+			EconomyModule::get_estate_staker_role(OWNED_ESTATE_ID),
+			EstateStakerRole::Lessee
+		);
+
+		// Owner cannot evict the stake of an active lessee
+		assert_noop!(
+			EconomyModule::unstake_new_estate_owner(RuntimeOrigin::signed(ALICE), OWNED_ESTATE_ID),
+			Error::<Runtime>::LesseeStakeStillActive
+		);
+	});
+}
+
+#[test]
+fn operator_stake_should_work_for_authorized_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::operator_stake(RuntimeOrigin::signed(BOB), OWNED_ESTATE_ID, STAKE_BALANCE),
+			Error::<Runtime>::NotEstateStakingOperator
+		);
+
+		assert_ok!(EconomyModule::set_estate_staking_operator(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			Some(BOB)
+		));
+
+		assert_ok!(EconomyModule::operator_stake(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID,
+			STAKE_BALANCE
+		));
+
+		// Funds are staked from the estate owner
+		assert_eq!(Balances::reserved_balance(ALICE), STAKE_BALANCE);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).unwrap().staker,
+			ALICE
+		);
+
+		assert_ok!(EconomyModule::operator_unstake(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID,
+			UNSTAKE_AMOUNT
+		));
+
+		// Exit queue entry belongs to the estate owner
+		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
+		assert_eq!(
+			EconomyModule::estate_staking_exit_queue((ALICE, next_round, OWNED_ESTATE_ID))
+				.map(|queued_exit| queued_exit.amount),
+			Some(UNSTAKE_AMOUNT)
+		);
+	});
+}
+
+#[test]
+fn rebalance_estate_stake_should_queue_excess_for_exit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+
+		assert_noop!(
+			EconomyModule::rebalance_estate_stake(RuntimeOrigin::signed(BOB), OWNED_ESTATE_ID),
+			Error::<Runtime>::EstateStakeWithinAllowance
+		);
+
+		// Estate bond above the land unit allowance
+		EstateStakingInfo::<Runtime>::insert(
+			&OWNED_ESTATE_ID,
+			Bond {
+				staker: ALICE,
+				amount: STAKE_BALANCE + 500,
+			},
+		);
+
+		assert_ok!(EconomyModule::rebalance_estate_stake(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID
+		));
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateStakeRebalanced(ALICE, OWNED_ESTATE_ID, 500))
+		);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).unwrap().amount,
+			STAKE_BALANCE
+		);
+		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
+		assert_eq!(
+			EconomyModule::estate_staking_exit_queue((ALICE, next_round, OWNED_ESTATE_ID))
+				.map(|queued_exit| queued_exit.amount),
+			Some(500)
+		);
+	});
+}
+
+#[test]
+fn self_staking_power_should_be_distributed_every_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_self_staking_power_per_round(
+			RuntimeOrigin::root(),
+			100
+		));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), STAKE_BALANCE, None));
+
+		// Default round length is 20 blocks so moving 25 blocks will move to the next round
+		run_to_block(25);
+
+		assert_ok!(EconomyModule::claim_staking_power(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::SelfStakingPowerCredited(ALICE, 50))
+		);
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 50);
+
+		// Claiming again in the same round does not credit twice
+		assert_ok!(EconomyModule::claim_staking_power(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 50);
+
+		// Unstaking settles accrued power first
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(BOB), UNSTAKE_AMOUNT, None));
+		assert_eq!(EconomyModule::get_power_balance(BOB), 50);
+	});
+}
+
+#[test]
+fn round_change_hook_should_distribute_elapsed_rounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_self_staking_power_per_round(
+			RuntimeOrigin::root(),
+			100
+		));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+
+		<EconomyModule as RoundChangeHandler>::on_round_change(1, 3);
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::SelfStakingPowerDistributed(3, 200))
+		);
+
+		assert_ok!(EconomyModule::claim_staking_power(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 200);
+	});
+}
+
+#[test]
+fn round_change_hook_should_notify_matured_exits() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(ALICE),
+			UNSTAKE_AMOUNT,
+			None
+		));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(BOB), UNSTAKE_AMOUNT, None));
+		assert_ok!(EconomyModule::withdraw_unreserved(
+			RuntimeOrigin::signed(BOB),
+			CURRENT_ROUND + 1
+		));
+
+		let exit_matured = |who| {
+			System::events().iter().any(|record| {
+				record.event == RuntimeEvent::Economy(crate::Event::ExitMatured(who, CURRENT_ROUND + 1, UNSTAKE_AMOUNT))
+			})
+		};
+		assert!(!exit_matured(ALICE));
+
+		<EconomyModule as RoundChangeHandler>::on_round_change(CURRENT_ROUND, CURRENT_ROUND + 1);
+
+		assert!(exit_matured(ALICE));
+		// Entries withdrawn before maturity are not notified
+		assert!(!exit_matured(BOB));
+		assert!(MaturingExits::<Runtime>::iter_prefix(CURRENT_ROUND + 1)
+			.next()
+			.is_none());
+		assert_eq!(
+			EconomyModule::matured_exits_cursor(),
+			Some((CURRENT_ROUND + 2, CURRENT_ROUND + 1))
+		);
+	});
+}
+
+#[test]
+fn matured_exits_beyond_round_change_limit_should_be_notified_in_on_idle() {
+	ExtBuilder::default().build().execute_with(|| {
+		let stakers: Vec<AccountId> = (100..100 + MAX_MATURED_EXIT_NOTIFICATIONS as u128)
+			.map(account)
+			.collect();
+		for who in stakers.iter() {
+			Balances::make_free_balance_be(who, 10000);
+			assert_ok!(EconomyModule::stake(
+				RuntimeOrigin::signed(who.clone()),
+				STAKE_BALANCE,
+				None
+			));
+			assert_ok!(EconomyModule::unstake(
+				RuntimeOrigin::signed(who.clone()),
+				UNSTAKE_AMOUNT,
+				None
+			));
+		}
+
+		<EconomyModule as RoundChangeHandler>::on_round_change(CURRENT_ROUND, CURRENT_ROUND + 1);
+
+		// The round lookup and the notified entries are bounded by the limit
+		assert_eq!(MaturingExits::<Runtime>::iter_prefix(CURRENT_ROUND + 1).count(), 1);
+		assert_eq!(
+			EconomyModule::matured_exits_cursor(),
+			Some((CURRENT_ROUND + 1, CURRENT_ROUND + 1))
+		);
+
+		EconomyModule::on_idle(System::block_number(), Weight::MAX);
+
+		assert!(MaturingExits::<Runtime>::iter_prefix(CURRENT_ROUND + 1)
+			.next()
+			.is_none());
+		assert_eq!(
+			EconomyModule::matured_exits_cursor(),
+			Some((CURRENT_ROUND + 2, CURRENT_ROUND + 1))
+		);
+		for who in stakers {
+			assert!(System::events().iter().any(|record| {
+				record.event
+					== RuntimeEvent::Economy(crate::Event::ExitMatured(
+						who.clone(),
+						CURRENT_ROUND + 1,
+						UNSTAKE_AMOUNT,
+					))
+			}));
+		}
+	});
+}
+
+#[test]
+fn era_update_should_snapshot_staker_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(BOB), 500));
+		assert_eq!(EconomyModule::innovation_staker_count(), 2);
+
+		UpdateEraFrequency::<Runtime>::set(1u64);
+		run_to_block(2);
+
+		// Two eras elapsed since the last era update block
+		let snapshot = EconomyModule::era_staker_snapshot(2).unwrap();
+		assert_eq!(snapshot.total_shares, STAKE_BALANCE + 500);
+		assert_eq!(snapshot.staker_count, 2);
+		assert_eq!(snapshot.shares_commitment, EconomyModule::shares_commitment());
+
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(BOB), 500));
+		assert_eq!(EconomyModule::innovation_staker_count(), 1);
+		assert_ne!(snapshot.shares_commitment, EconomyModule::shares_commitment());
+	});
+}
+
+#[test]
+fn voting_season_should_archive_voting_points_every_season() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_noop!(
+			EconomyModule::set_voting_season_length(RuntimeOrigin::signed(ALICE), 2),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_voting_season_length(RuntimeOrigin::root(), 2));
+
+		UpdateEraFrequency::<Runtime>::set(1u64);
+		run_to_block(2);
+		assert_eq!(EconomyModule::season_voting_points(0, &ALICE), Some(STAKE_BALANCE));
+		assert_eq!(EconomyModule::current_voting_season(), 1);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == RuntimeEvent::Economy(crate::Event::VotingSeasonEnded(0, 1))));
+
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(BOB), 500));
+		run_to_block(3);
+		assert_eq!(EconomyModule::current_voting_season(), 1);
+
+		run_to_block(4);
+		assert_eq!(EconomyModule::current_voting_season(), 2);
+		assert_eq!(EconomyModule::season_voting_points(1, &BOB), Some(500));
+		assert_eq!(EconomyModule::season_voting_points(0, &BOB), None);
+
+		// Archived points do not change with the stake after the season ended
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(BOB), 500));
+		assert_eq!(EconomyModule::season_voting_points(1, &BOB), Some(500));
+		assert_eq!(EconomyModule::season_voting_points(0, &BOB), None);
+	});
+}
+
+#[test]
+fn loyalty_bonus_should_increase_rewards_for_long_term_stakers() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::innovation_staking_since(ALICE), Some(0));
+
+		CurrentEra::<Runtime>::put(25);
+		assert_eq!(EconomyModule::loyalty_bonus(&ALICE), Perbill::from_percent(2));
+
+		CurrentEra::<Runtime>::put(500);
+		assert_eq!(EconomyModule::loyalty_bonus(&ALICE), Perbill::from_percent(20));
+
+		CurrentEra::<Runtime>::put(25);
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
+				ALICE,
+				ALICE,
+				vec![(FungibleTokenId::NativeToken(0), 1020)]
+			))
+		);
+
+		// Full unstake resets loyalty
+		assert_ok!(EconomyModule::unstake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::innovation_staking_since(ALICE), None);
+	});
+}
+
+#[test]
+fn nft_reward_boost_should_apply_best_bonus_of_held_classes() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(ALICE), 0, 0);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		assert_noop!(
+			EconomyModule::set_nft_reward_boost(RuntimeOrigin::signed(ALICE), 0, Some(Perbill::from_percent(10))),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_nft_reward_boost(
+			RuntimeOrigin::root(),
+			0,
+			Some(Perbill::from_percent(5))
+		));
+		assert_ok!(EconomyModule::set_nft_reward_boost(
+			RuntimeOrigin::root(),
+			0,
+			Some(Perbill::from_percent(10))
+		));
+		// Class not held by the staker
+		assert_ok!(EconomyModule::set_nft_reward_boost(
+			RuntimeOrigin::root(),
+			1,
+			Some(Perbill::from_percent(50))
+		));
+		assert_eq!(EconomyModule::nft_reward_bonus(&ALICE), Perbill::from_percent(10));
+		assert_eq!(EconomyModule::nft_reward_bonus(&BOB), Perbill::zero());
+
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
+				ALICE,
+				ALICE,
+				vec![(FungibleTokenId::NativeToken(0), 1100)]
+			))
+		);
+
+		assert_ok!(EconomyModule::set_nft_reward_boost(RuntimeOrigin::root(), 0, None));
+		assert_eq!(EconomyModule::nft_reward_bonus(&ALICE), Perbill::zero());
+	});
+}
+
+#[test]
+fn stake_tiers_should_be_evaluated_on_share_change_and_era_change() {
+	ExtBuilder::default().build().execute_with(|| {
+		let tier = |threshold, bonus, extra_voting_points| StakeTier {
+			threshold,
+			reward_bonus: Perbill::from_percent(bonus),
+			extra_voting_points,
+		};
+
+		assert_noop!(
+			EconomyModule::set_stake_tiers(
+				RuntimeOrigin::root(),
+				vec![tier(500, 5, 2), tier(100, 0, 1)].try_into().unwrap()
+			),
+			Error::<Runtime>::InvalidStakeTiers
+		);
+		assert_ok!(EconomyModule::set_stake_tiers(
+			RuntimeOrigin::root(),
+			vec![tier(100, 0, 1), tier(500, 5, 2), tier(2000, 10, 5)]
+				.try_into()
+				.unwrap()
+		));
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::account_stake_tier(ALICE), Some(1));
+		assert_eq!(EconomyModule::stake_tier_voting_points(&ALICE), 2);
+
+		// Raised thresholds apply from the next era
+		assert_ok!(EconomyModule::set_stake_tiers(
+			RuntimeOrigin::root(),
+			vec![tier(100, 0, 1), tier(1500, 5, 2)].try_into().unwrap()
+		));
+		assert_eq!(EconomyModule::account_stake_tier(ALICE), Some(1));
+		assert_eq!(EconomyModule::stake_tier_voting_points(&ALICE), 2);
+		assert_ok!(EconomyModule::update_current_era(1));
+		assert_eq!(EconomyModule::stake_tiers_version(), 1);
+		assert_eq!(EconomyModule::stake_tier_voting_points(&ALICE), 1);
+
+		// The stored tier is migrated once the staker is touched
+		assert_eq!(EconomyModule::account_stake_tier(ALICE), Some(1));
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 10));
+		assert_eq!(EconomyModule::account_stake_tier(ALICE), Some(0));
+		assert_eq!(EconomyModule::account_stake_tier_version(ALICE), 1);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == RuntimeEvent::Economy(crate::Event::StakeTierChanged(ALICE, Some(0)))));
+
+		assert_ok!(EconomyModule::unstake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE + 10
+		));
+		assert_eq!(EconomyModule::account_stake_tier(ALICE), None);
+		assert_eq!(EconomyModule::stake_tier_voting_points(&ALICE), 0);
+	});
+}
+
+#[test]
+fn set_preferences_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let preferences = AccountPreference {
+			auto_compound: true,
+			auto_withdraw: false,
+			reward_destination: RewardDestination::Account(BOB),
+			preferred_claim_currency: Some(FungibleTokenId::NativeToken(0)),
+			mint_position_receipt: false,
+			auto_restake_exits: false,
+			auto_stake_income: false,
+			record_reward_ledger: false,
+		};
+
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			preferences.clone()
+		));
+		assert_eq!(EconomyModule::account_preferences(ALICE), preferences);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::AccountPreferencesUpdated(ALICE))
+		);
+
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			Default::default()
+		));
+		assert!(!AccountPreferences::<Runtime>::contains_key(ALICE));
+	});
+}
+
+#[test]
+fn claim_reward_to_should_pay_beneficiary() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+
+		let bob_balance = Balances::free_balance(BOB);
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::claim_reward_to(RuntimeOrigin::signed(ALICE), BOB));
+
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 1000);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
+				ALICE,
+				BOB,
+				vec![(FungibleTokenId::NativeToken(0), 1000)]
+			))
+		);
+	});
+}
+
+#[test]
+fn claim_reward_partial_should_keep_remainder_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+
+		assert_ok!(EconomyModule::claim_reward_partial(
+			RuntimeOrigin::signed(ALICE),
+			FungibleTokenId::NativeToken(0),
+			400
+		));
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::ClaimRewards(ALICE, FungibleTokenId::NativeToken(0), 400))));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ClaimRewardsSummary(
+				ALICE,
+				ALICE,
+				vec![(FungibleTokenId::NativeToken(0), 400)]
+			))
+		);
+		assert_eq!(
+			EconomyModule::pending_multi_rewards(ALICE).get(&FungibleTokenId::NativeToken(0)),
+			Some(&600)
+		);
+
+		assert_ok!(EconomyModule::claim_reward_partial(
+			RuntimeOrigin::signed(ALICE),
+			FungibleTokenId::NativeToken(0),
+			1000
+		));
+		assert!(EconomyModule::pending_multi_rewards(ALICE).is_empty());
+
+		assert_noop!(
+			EconomyModule::claim_reward_partial(RuntimeOrigin::signed(ALICE), FungibleTokenId::NativeToken(0), 1),
+			Error::<Runtime>::NoPendingReward
+		);
+	});
+}
+
+#[test]
+fn claim_reward_partial_should_respect_minimum_claim_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+		assert_ok!(EconomyModule::set_minimum_claim_amount(
+			RuntimeOrigin::root(),
+			FungibleTokenId::NativeToken(0),
+			200
+		));
+
+		assert_noop!(
+			EconomyModule::claim_reward_partial(RuntimeOrigin::signed(ALICE), FungibleTokenId::NativeToken(0), 100),
+			Error::<Runtime>::ClaimBelowMinimum
+		);
+		assert_noop!(
+			EconomyModule::claim_reward_partial(RuntimeOrigin::signed(ALICE), FungibleTokenId::NativeToken(1), 500),
+			Error::<Runtime>::NoPendingReward
+		);
+
+		let balance = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::claim_reward_partial(
+			RuntimeOrigin::signed(ALICE),
+			FungibleTokenId::NativeToken(0),
+			900
+		));
+		assert_eq!(Balances::free_balance(ALICE), balance + 900);
+
+		// The remaining 100 is below the minimum claim amount
+		assert_noop!(
+			EconomyModule::claim_reward_partial(RuntimeOrigin::signed(ALICE), FungibleTokenId::NativeToken(0), 1000),
+			Error::<Runtime>::ClaimBelowMinimum
+		);
+		assert_noop!(
+			EconomyModule::claim_reward_swapped(
+				RuntimeOrigin::signed(ALICE),
+				FungibleTokenId::NativeToken(0),
+				FungibleTokenId::NativeToken(1),
+				0
+			),
+			Error::<Runtime>::ClaimBelowMinimum
+		);
+	});
+}
+
+#[test]
+fn claim_reward_swapped_should_fail_without_swapper() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+
+		assert_noop!(
+			EconomyModule::claim_reward_swapped(
+				RuntimeOrigin::signed(ALICE),
+				FungibleTokenId::NativeToken(0),
+				FungibleTokenId::NativeToken(0),
+				900
+			),
+			Error::<Runtime>::InvalidSwapCurrency
+		);
+
+		// Mock runtime has no reward swapper, the claim is reverted
+		assert_noop!(
+			EconomyModule::claim_reward_swapped(
+				RuntimeOrigin::signed(ALICE),
+				FungibleTokenId::NativeToken(0),
+				FungibleTokenId::NativeToken(1),
+				900
+			),
+			DispatchError::Other("Reward swap is not supported")
+		);
+	});
+}
+
+#[test]
+fn per_block_distribution_mode_should_drip_era_reward() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::set_reward_distribution_mode(
+			RuntimeOrigin::root(),
+			RewardDistributionMode::PerBlock
+		));
+
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
+		UpdateEraFrequency::<Runtime>::set(4u64);
+
+		run_to_block(3);
+		let reward_pool = EconomyModule::staking_reward_pool_info();
+		assert_eq!(
+			reward_pool.rewards.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(50u128, 0u128))
+		);
+
+		// Era rollover does not add the lump sum on top of the drip
+		run_to_block(4);
+		let reward_pool = EconomyModule::staking_reward_pool_info();
+		assert_eq!(
+			reward_pool.rewards.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(75u128, 0u128))
+		);
+	});
+}
+
+#[test]
+fn compensate_from_insurance_fund_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::deposit_to_insurance_fund(&ALICE, 500));
+		assert_eq!(EconomyModule::insurance_fund_balance(), 500);
+
+		assert_noop!(
+			EconomyModule::compensate_from_insurance_fund(RuntimeOrigin::signed(ALICE), BOB, 200),
+			BadOrigin
+		);
+		assert_noop!(
+			EconomyModule::compensate_from_insurance_fund(RuntimeOrigin::root(), BOB, 501),
+			Error::<Runtime>::InsufficientInsuranceFund
+		);
+
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::compensate_from_insurance_fund(
+			RuntimeOrigin::root(),
+			BOB,
+			200
+		));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 200);
+		assert_eq!(EconomyModule::insurance_fund_balance(), 300);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::InsuranceCompensationPaid(BOB, 200))
+		);
+	});
+}
+
+#[test]
+fn bonded_pool_should_stake_and_compound_for_small_stakers() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxPoolCommission::<Runtime>::put(Perbill::from_percent(10));
+		assert_ok!(EconomyModule::create_pool(
+			RuntimeOrigin::signed(BOB),
+			Perbill::from_percent(10)
+		));
+		assert_noop!(
+			EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 1, 50),
+			Error::<Runtime>::BondedPoolDoesNotExist
+		);
+
+		// Amount below minimum stake can be bonded through the pool
+		assert_ok!(EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 50));
+		let pool_account = EconomyModule::bonded_pool_account_id(0);
+		assert_eq!(EconomyModule::get_innovation_staking_info(pool_account.clone()), 50);
+		assert_eq!(EconomyModule::pool_members(ALICE).unwrap().points, 50);
+
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+
+		assert_ok!(EconomyModule::unbond_from_pool(RuntimeOrigin::signed(ALICE), 50));
+
+		// 10% commission paid to the operator, the rest compounded for members
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 10);
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS)
+				.map(|queued_exit| queued_exit.amount),
+			Some(140)
+		);
+		assert_eq!(EconomyModule::get_innovation_staking_info(pool_account), 0);
+		assert_eq!(EconomyModule::pool_members(ALICE), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::BondedPoolUnbonded(ALICE, 0, 50, 140))
+		);
+	});
+}
+
+#[test]
+fn bonded_pool_unbond_should_follow_innovation_unbond_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::create_pool(RuntimeOrigin::signed(BOB), Perbill::zero()));
+		assert_ok!(EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 50));
+		assert_ok!(EconomyModule::unbond_from_pool(RuntimeOrigin::signed(ALICE), 50));
+
+		let unbond_round = CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS;
+		assert_eq!(EconomyModule::staking_exit_queue(ALICE, CURRENT_ROUND + 1), None);
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, unbond_round).map(|queued_exit| queued_exit.amount),
+			Some(50)
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 50);
+
+		assert_noop!(
+			EconomyModule::withdraw_unreserved(RuntimeOrigin::signed(ALICE), CURRENT_ROUND + 1),
+			Error::<Runtime>::ExitQueueDoesNotExit
+		);
+		assert_noop!(
+			EconomyModule::withdraw_innovation_unreserved(RuntimeOrigin::signed(ALICE), unbond_round),
+			Error::<Runtime>::InnovationExitQueueNotMatured
+		);
+
+		run_to_block(600);
+
+		assert_noop!(
+			EconomyModule::withdraw_innovation_unreserved(RuntimeOrigin::signed(ALICE), unbond_round + 1),
+			Error::<Runtime>::InnovationExitQueueDoesNotExist
+		);
+		assert_ok!(EconomyModule::withdraw_innovation_unreserved(
+			RuntimeOrigin::signed(ALICE),
+			unbond_round
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::UnstakedAmountWithdrew(ALICE, 50))
+		);
+	});
+}
+
+#[test]
+fn bonded_pool_should_reject_non_members_and_dust() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::create_pool(RuntimeOrigin::signed(BOB), Perbill::zero()));
+		assert_ok!(EconomyModule::create_pool(RuntimeOrigin::signed(BOB), Perbill::zero()));
+
+		assert_noop!(
+			EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 5),
+			Error::<Runtime>::StakeBelowMinimum
+		);
+		assert_ok!(EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 50));
+
+		// The operator is not a member of its own pool
+		assert_noop!(
+			EconomyModule::unbond_from_pool(RuntimeOrigin::signed(BOB), 50),
+			Error::<Runtime>::NotPoolMember
+		);
+		assert_noop!(
+			EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 1, 50),
+			Error::<Runtime>::AlreadyPoolMember
+		);
+		assert_noop!(
+			EconomyModule::unbond_from_pool(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::UnstakeAmountIsZero
+		);
+		assert_noop!(
+			EconomyModule::unbond_from_pool(RuntimeOrigin::signed(ALICE), 51),
+			Error::<Runtime>::InsufficientPoolPoints
+		);
+	});
+}
+
+#[test]
+fn bonded_pool_unbond_should_compound_pending_reward_first() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&FREEDY, 10000);
+		MaxPoolCommission::<Runtime>::put(Perbill::from_percent(10));
+		assert_ok!(EconomyModule::create_pool(
+			RuntimeOrigin::signed(BOB),
+			Perbill::from_percent(10)
+		));
+		assert_ok!(EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 50));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+
+		// Joining compounds the pending reward first, so the late member does not share it
+		assert_ok!(EconomyModule::join_pool(RuntimeOrigin::signed(FREEDY), 0, 70));
+		let pool_account = EconomyModule::bonded_pool_account_id(0);
+		assert_eq!(EconomyModule::get_innovation_staking_info(pool_account.clone()), 210);
+		assert_eq!(EconomyModule::pool_members(FREEDY).unwrap().points, 25);
+
+		assert_ok!(EconomyModule::unbond_from_pool(RuntimeOrigin::signed(ALICE), 50));
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS)
+				.map(|queued_exit| queued_exit.amount),
+			Some(140)
+		);
+		assert_eq!(EconomyModule::get_innovation_staking_info(pool_account), 70);
+		assert_eq!(EconomyModule::bonded_pools(0).unwrap().points, 25);
+	});
+}
+
+#[test]
+fn bonded_pool_operator_should_manage_commission_and_state() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_max_pool_commission(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(20)
+		));
+		assert_noop!(
+			EconomyModule::create_pool(RuntimeOrigin::signed(BOB), Perbill::from_percent(21)),
+			Error::<Runtime>::PoolCommissionExceedsMaximum
+		);
+		assert_ok!(EconomyModule::create_pool(
+			RuntimeOrigin::signed(BOB),
+			Perbill::from_percent(5)
+		));
+
+		assert_noop!(
+			EconomyModule::set_pool_commission(RuntimeOrigin::signed(ALICE), 0, Perbill::from_percent(10)),
+			Error::<Runtime>::NotPoolOperator
+		);
+		assert_ok!(EconomyModule::set_pool_commission(
+			RuntimeOrigin::signed(BOB),
+			0,
+			Perbill::from_percent(10)
+		));
+		assert_eq!(
+			EconomyModule::bonded_pools(0).unwrap().commission,
+			Perbill::from_percent(10)
+		);
+
+		assert_ok!(EconomyModule::set_pool_state(
+			RuntimeOrigin::signed(BOB),
+			0,
+			PoolState::Blocked
+		));
+		assert_noop!(
+			EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 50),
+			Error::<Runtime>::BondedPoolNotOpen
+		);
+		assert_ok!(EconomyModule::set_pool_state(
+			RuntimeOrigin::signed(BOB),
+			0,
+			PoolState::Open
+		));
+		assert_ok!(EconomyModule::join_pool(RuntimeOrigin::signed(ALICE), 0, 50));
+
+		assert_ok!(EconomyModule::nominate_pool_operator(
+			RuntimeOrigin::signed(BOB),
+			0,
+			ALICE
+		));
+		assert_ok!(EconomyModule::set_pool_state(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			PoolState::Destroying
+		));
+		assert_noop!(
+			EconomyModule::set_pool_state(RuntimeOrigin::signed(ALICE), 0, PoolState::Open),
+			Error::<Runtime>::InvalidPoolStateTransition
+		);
+
+		// Destroying pool is removed once the last member unbonds
+		assert_ok!(EconomyModule::unbond_from_pool(RuntimeOrigin::signed(ALICE), 50));
+		assert_eq!(EconomyModule::bonded_pools(0), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::BondedPoolDestroyed(0))
+		);
+	});
+}
+
+#[test]
+fn reward_campaigns_should_be_capped_while_active() {
+	ExtBuilder::default().build().execute_with(|| {
+		UpdateEraFrequency::<Runtime>::set(5u64);
+
+		for _ in 0..MAX_ACTIVE_REWARD_CAMPAIGNS {
+			assert_ok!(EconomyModule::create_reward_campaign(
+				RuntimeOrigin::signed(BOB),
+				FungibleTokenId::NativeToken(0),
+				1,
+				1,
+				3,
+				CampaignEligibility::AllInnovationStakers
+			));
+		}
+		assert_noop!(
+			EconomyModule::create_reward_campaign(
+				RuntimeOrigin::signed(BOB),
+				FungibleTokenId::NativeToken(0),
+				1,
+				1,
+				11,
+				CampaignEligibility::AllInnovationStakers
+			),
+			Error::<Runtime>::TooManyRewardCampaigns
+		);
+
+		// Ended campaigns leave the active set at the era change and free their slot
+		run_to_block(5);
+		assert!(EconomyModule::active_reward_campaigns().is_empty());
+		assert_ok!(EconomyModule::create_reward_campaign(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			1,
+			1,
+			11,
+			CampaignEligibility::AllInnovationStakers
+		));
+		assert_eq!(
+			EconomyModule::active_reward_campaigns().into_inner(),
+			vec![MAX_ACTIVE_REWARD_CAMPAIGNS]
+		);
+	});
+}
+
+#[test]
+fn reward_campaign_should_be_distributed_while_active_and_refunded_after_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		UpdateEraFrequency::<Runtime>::set(5u64);
+
+		assert_noop!(
+			EconomyModule::create_reward_campaign(
+				RuntimeOrigin::signed(BOB),
+				FungibleTokenId::NativeToken(0),
+				1000,
+				11,
+				1,
+				CampaignEligibility::AllInnovationStakers
+			),
+			Error::<Runtime>::InvalidRewardCampaign
+		);
+		assert_ok!(EconomyModule::create_reward_campaign(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			1000,
+			1,
+			11,
+			CampaignEligibility::AllInnovationStakers
+		));
+		assert_ok!(EconomyModule::create_reward_campaign(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			300,
+			1,
+			3,
+			CampaignEligibility::AllInnovationStakers
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 20);
+
+		// Two eras remain for the first campaign, the second one has already expired
+		run_to_block(5);
+		assert_eq!(EconomyModule::reward_campaigns(0).unwrap().remaining_budget, 500);
+		assert_eq!(EconomyModule::reward_campaigns(1).unwrap().remaining_budget, 300);
+		assert_eq!(EconomyModule::active_reward_campaigns().into_inner(), vec![0]);
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(500u128, 0u128))
+		);
+
+		assert_noop!(
+			EconomyModule::refund_reward_campaign(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::RewardCampaignNotExpired
+		);
+
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::refund_reward_campaign(RuntimeOrigin::signed(ALICE), 1));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 300 + 10);
+		assert_eq!(EconomyModule::reward_campaigns(1), None);
+	});
+}
+
+#[test]
+fn distribute_mission_reward_should_pay_from_mission_pot() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mission_pot = EconomyModule::mission_pot_account_id();
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), mission_pot, 1000));
+
+		assert_noop!(
+			EconomyModule::distribute_mission_reward(
+				RuntimeOrigin::signed(BOB),
+				BOB,
+				FungibleTokenId::NativeToken(0),
+				100,
+				7
+			),
+			BadOrigin
+		);
+
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::distribute_mission_reward(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			FungibleTokenId::NativeToken(0),
+			100,
+			7
+		));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 100);
+		assert_eq!(EconomyModule::mission_rewards(7, FungibleTokenId::NativeToken(0)), 100);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::MissionRewardDistributed(
+				7,
+				BOB,
+				FungibleTokenId::NativeToken(0),
+				100
+			))
+		);
+	});
+}
+
+#[test]
+fn top_stakers_should_be_ordered_by_innovation_share() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 500));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::top_stakers(), vec![(BOB, STAKE_BALANCE), (ALICE, 500)]);
+
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 600));
+		assert_eq!(EconomyModule::top_stakers(), vec![(ALICE, 1100), (BOB, STAKE_BALANCE)]);
+
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 1100));
+		assert_eq!(EconomyModule::top_stakers(), vec![(BOB, STAKE_BALANCE)]);
+	});
+}
+
+#[test]
+fn position_receipt_should_transfer_reward_and_unbonding_rights() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(ALICE), 0, 0);
+		assert_ok!(EconomyModule::set_position_receipt_class(RuntimeOrigin::root(), 0));
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			}
+		));
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		let position = (0, 1);
+		assert_eq!(EconomyModule::staking_positions(position), Some(STAKE_BALANCE));
+		assert_eq!(
+			EconomyModule::get_innovation_staking_info(EconomyModule::position_account_id(position)),
+			STAKE_BALANCE
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PositionReceiptMinted(ALICE, position, STAKE_BALANCE))
+		);
+
+		assert_ok!(<NFTModule as NFTTrait<AccountId, Balance>>::transfer_nft(
+			&ALICE, &BOB, &position
+		));
+		assert_noop!(
+			EconomyModule::unstake_position(RuntimeOrigin::signed(ALICE), position),
+			Error::<Runtime>::NotPositionReceiptOwner
+		);
+
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::unstake_position(RuntimeOrigin::signed(BOB), position));
+
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 100);
+		assert_eq!(EconomyModule::staking_exit_queue(BOB, CURRENT_ROUND + 1), None);
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(BOB, CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS)
+				.map(|queued_exit| queued_exit.amount),
+			Some(STAKE_BALANCE)
+		);
+		assert_noop!(
+			EconomyModule::withdraw_innovation_unreserved(
+				RuntimeOrigin::signed(BOB),
+				CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS
+			),
+			Error::<Runtime>::InnovationExitQueueNotMatured
+		);
+		assert_eq!(EconomyModule::staking_positions(position), None);
+	});
+}
+
+#[test]
+fn split_and_merge_positions_should_preserve_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(RuntimeOrigin::signed(ALICE), 0, 0);
+		assert_ok!(EconomyModule::set_position_receipt_class(RuntimeOrigin::root(), 0));
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			}
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		let position = (0, 1);
+		let new_position = (0, 2);
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+
+		assert_noop!(
+			EconomyModule::split_position(RuntimeOrigin::signed(BOB), position, 400),
+			Error::<Runtime>::NotPositionReceiptOwner
+		);
+		assert_noop!(
+			EconomyModule::split_position(RuntimeOrigin::signed(ALICE), position, 950),
+			Error::<Runtime>::InvalidPositionSplitAmount
+		);
+		assert_ok!(EconomyModule::split_position(
+			RuntimeOrigin::signed(ALICE),
+			position,
+			400
+		));
+		assert_eq!(EconomyModule::staking_positions(position), Some(600));
+		assert_eq!(EconomyModule::staking_positions(new_position), Some(400));
+		assert_eq!(
+			EconomyModule::get_innovation_staking_info(EconomyModule::position_account_id(new_position)),
+			400
+		);
+		assert_eq!(EconomyModule::total_innovation_staking(), STAKE_BALANCE);
+		assert_eq!(EconomyModule::staking_reward_pool_info().total_shares, STAKE_BALANCE);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PositionSplit(ALICE, position, new_position, 400))
+		);
+
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+		assert_eq!(
+			EconomyModule::pending_rewards(&EconomyModule::position_account_id(new_position)),
+			vec![(FungibleTokenId::NativeToken(0), 40)]
+		);
+
+		assert_noop!(
+			EconomyModule::merge_positions(
+				RuntimeOrigin::signed(ALICE),
+				vec![position, position].try_into().unwrap()
+			),
+			Error::<Runtime>::InvalidPositionsToMerge
+		);
+		assert_ok!(EconomyModule::merge_positions(
+			RuntimeOrigin::signed(ALICE),
+			vec![position, new_position].try_into().unwrap()
+		));
+		assert_eq!(EconomyModule::staking_positions(position), Some(STAKE_BALANCE));
+		assert_eq!(EconomyModule::staking_positions(new_position), None);
+		assert_eq!(
+			EconomyModule::pending_rewards(&EconomyModule::position_account_id(position)),
+			vec![(FungibleTokenId::NativeToken(0), 200)]
+		);
+
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::unstake_position(RuntimeOrigin::signed(ALICE), position));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 200);
+	});
+}
+
+#[test]
+fn fee_sharing_should_route_share_of_fees_to_reward_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_fee_share_ratio(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(10)
+		));
+
+		// Without stakers the fees are passed through
+		FeeSharing::<Runtime, ()>::on_unbalanced(<Balances as Currency<AccountId>>::issue(1000));
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0)),
+			None
+		);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		let payout_balance = Balances::free_balance(EconomyModule::get_reward_payout_account_id());
+
+		FeeSharing::<Runtime, ()>::on_unbalanced(<Balances as Currency<AccountId>>::issue(1000));
+
+		assert_eq!(
+			Balances::free_balance(EconomyModule::get_reward_payout_account_id()),
+			payout_balance + 100
+		);
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(100, 0))
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::FeesSharedToRewardPool(100))
+		);
+
+		// A frozen reward pool takes no fee share
+		assert_ok!(EconomyModule::freeze_reward_pool(RuntimeOrigin::signed(ALICE)));
+		FeeSharing::<Runtime, ()>::on_unbalanced(<Balances as Currency<AccountId>>::issue(1000));
+		assert_eq!(
+			Balances::free_balance(EconomyModule::get_reward_payout_account_id()),
+			payout_balance + 100
+		);
+		assert_ne!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::FeesSharedToRewardPool(100))
+		);
+	});
+}
+
+#[test]
+fn pending_rewards_should_include_unclaimed_pool_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::pending_rewards(&ALICE), vec![]);
+
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+		assert_eq!(
+			EconomyModule::pending_rewards(&ALICE),
+			vec![(FungibleTokenId::NativeToken(0), 100)]
+		);
+
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(EconomyModule::pending_rewards(&ALICE), vec![]);
+	});
+}
+
+#[test]
+fn spend_power_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::spend_power(&ALICE, 0),
+			Error::<Runtime>::PowerAmountIsZero
+		);
+		assert_noop!(
+			EconomyModule::spend_power(&ALICE, 10),
+			Error::<Runtime>::AccountHasNoPowerBalance
+		);
+
+		PowerBalance::<Runtime>::insert(ALICE, 100);
+		assert_ok!(EconomyModule::spend_power(&ALICE, 40));
+
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 60);
+		assert_eq!(last_event(), RuntimeEvent::Economy(crate::Event::PowerSpent(ALICE, 40)));
+	});
+}
+
+#[test]
+fn power_transfer_and_allowance_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		PowerBalance::<Runtime>::insert(ALICE, 100);
+
+		assert_ok!(EconomyModule::transfer_power(RuntimeOrigin::signed(ALICE), BOB, 30));
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 70);
+		assert_eq!(EconomyModule::get_power_balance(BOB), 30);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PowerTransferred(ALICE, BOB, 30))
+		);
+
+		assert_ok!(EconomyModule::approve_power(RuntimeOrigin::signed(ALICE), BOB, 50));
+		assert_eq!(EconomyModule::power_allowance(ALICE, BOB), 50);
+
+		assert_noop!(
+			EconomyModule::transfer_power_from(RuntimeOrigin::signed(BOB), ALICE, BOB, 60),
+			Error::<Runtime>::InsufficientPowerAllowance
+		);
+		assert_ok!(EconomyModule::transfer_power_from(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BOB,
+			50
+		));
+		assert_eq!(EconomyModule::power_allowance(ALICE, BOB), 0);
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 20);
+		assert_eq!(EconomyModule::get_power_balance(BOB), 80);
+
+		assert_noop!(
+			EconomyModule::transfer_power(RuntimeOrigin::signed(ALICE), BOB, 21),
+			Error::<Runtime>::AccountHasNoPowerBalance
+		);
+	});
+}
+
+#[test]
+fn legacy_power_balance_should_migrate_to_power_tokens() {
+	ExtBuilder::default().build().execute_with(|| {
+		PowerBalance::<Runtime>::insert(ALICE, 100);
+		PowerBalance::<Runtime>::insert(BOB, 50);
+		assert_eq!(EconomyModule::get_power_balance(ALICE), 100);
+
+		// Migrated lazily on first use
+		assert_ok!(EconomyModule::transfer_power(RuntimeOrigin::signed(ALICE), BOB, 30));
+		assert!(!PowerBalance::<Runtime>::contains_key(ALICE));
+		assert!(!PowerBalance::<Runtime>::contains_key(BOB));
+		assert_eq!(OrmlTokens::free_balance(PowerCurrencyId::get(), &ALICE), 70);
+		assert_eq!(OrmlTokens::free_balance(PowerCurrencyId::get(), &BOB), 80);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == RuntimeEvent::Economy(crate::Event::PowerBalanceMigrated(ALICE, 100))));
+
+		// Remaining legacy balances are migrated by the stepped migration
+		assert!(!migrations::MigratePowerBalanceToTokens::<Runtime>::is_applicable());
+		StorageVersion::new(4).put::<EconomyModule>();
+		assert!(migrations::MigratePowerBalanceToTokens::<Runtime>::is_applicable());
+		PowerBalance::<Runtime>::insert(account(10), 10);
+		PowerBalance::<Runtime>::insert(account(11), 20);
+		let (cursor, _) = migrations::MigratePowerBalanceToTokens::<Runtime>::step(None, Weight::MAX);
+		assert_eq!(cursor, None);
+		assert_eq!(PowerBalance::<Runtime>::iter().count(), 0);
+		assert_eq!(EconomyModule::get_power_balance(account(10)), 10);
+		assert_eq!(OrmlTokens::free_balance(PowerCurrencyId::get(), &account(11)), 20);
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(5));
+		assert!(!migrations::MigratePowerBalanceToTokens::<Runtime>::is_applicable());
+	});
+}
+
+#[test]
+fn stepped_migrations_should_keep_their_ids_in_a_sequence() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ids: Vec<Vec<u8>> = <Runtime as Config>::SteppedMigration::migrations()
+			.into_iter()
+			.map(|(id, _, _)| id)
+			.collect();
+		assert_eq!(
+			ids,
+			vec![
+				b"migrate_exit_queues_to_v3".to_vec(),
+				b"migrate_era_reward_budget_to_v4".to_vec(),
+				b"sync_weighted_estate_stakes".to_vec(),
+				b"migrate_power_balance_to_tokens".to_vec(),
+				b"double_power_balance".to_vec(),
+			]
+		);
+
+		// Ids do not depend on the on-chain storage version
+		StorageVersion::new(2).put::<EconomyModule>();
+		assert_eq!(
+			<Runtime as Config>::SteppedMigration::migrations()
+				.into_iter()
+				.map(|(id, _, _)| id)
+				.collect::<Vec<_>>(),
+			ids
+		);
+	});
+}
+
+#[test]
+fn stepped_migrations_should_run_v3_to_v5_across_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+		StorageVersion::new(2).put::<EconomyModule>();
+		let next_round: RoundIndex = CURRENT_ROUND + 1;
+		for id in 20..26u8 {
+			frame_support::storage::unhashed::put_raw(
+				&ExitQueue::<Runtime>::hashed_key_for(account(id), next_round),
+				&200u128.encode(),
+			);
+		}
+		frame_support::storage::unhashed::put_raw(
+			&InnovationStakingExitQueue::<Runtime>::hashed_key_for(ALICE, next_round),
+			&300u128.encode(),
+		);
+		PowerBalance::<Runtime>::insert(account(10), 10);
+		PowerBalance::<Runtime>::insert(account(11), 20);
+		System::reset_events();
+
+		// A read or write weighs one, so each block only migrates a few entries
+		MockDbWeight::set(frame_support::weights::RuntimeDbWeight { read: 1, write: 1 });
+		let block_limit = Weight::from_parts(20, 0);
+		EconomyModule::on_idle(1, block_limit);
+		assert_eq!(
+			EconomyModule::stepped_migration_cursor().map(|(id, _)| id),
+			Some(b"migrate_exit_queues_to_v3".to_vec())
+		);
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(2));
+
+		let mut blocks = 1;
+		while EconomyModule::completed_stepped_migrations(b"double_power_balance".to_vec()).is_none() {
+			blocks += 1;
+			assert!(blocks <= 20, "Stepped migrations did not complete");
+			EconomyModule::on_idle(blocks, block_limit);
+		}
+		MockDbWeight::set(frame_support::weights::RuntimeDbWeight { read: 0, write: 0 });
+		assert!(blocks > 2);
+
+		let completed: Vec<Vec<u8>> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Economy(crate::Event::SteppedMigrationCompleted(id)) => Some(id),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(
+			completed,
+			vec![
+				b"migrate_exit_queues_to_v3".to_vec(),
+				b"migrate_era_reward_budget_to_v4".to_vec(),
+				b"sync_weighted_estate_stakes".to_vec(),
+				b"migrate_power_balance_to_tokens".to_vec(),
+				b"double_power_balance".to_vec(),
+			]
+		);
+		assert_eq!(EconomyModule::stepped_migration_cursor(), None);
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(5));
+		assert_eq!(
+			EconomyModule::staking_exit_queue(account(25), next_round),
+			Some(QueuedExit {
+				amount: 200,
+				estimated_unlock_block: EconomyModule::estimated_round_start(next_round),
+			})
+		);
+		assert_eq!(PowerBalance::<Runtime>::iter().count(), 0);
+		assert_eq!(OrmlTokens::free_balance(PowerCurrencyId::get(), &account(11)), 20);
+
+		// Completed migrations are not run again
+		System::reset_events();
+		EconomyModule::on_idle(blocks + 1, Weight::MAX);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Economy(crate::Event::SteppedMigrationCompleted(..))
+		)));
+	});
+}
+
+#[test]
+fn claim_with_signature_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner_pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+		let owner: AccountId = MultiSigner::Sr25519(owner_pair.public()).into_account();
+		Balances::make_free_balance_be(&owner, 10000);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(owner.clone()),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+
+		let signature =
+			MultiSignature::Sr25519(owner_pair.sign(&EconomyModule::claim_authorization_payload(&owner, 0)));
+
+		assert_noop!(
+			EconomyModule::claim_with_signature(RuntimeOrigin::signed(BOB), owner.clone(), 1, signature.clone()),
+			Error::<Runtime>::InvalidClaimNonce
+		);
+		assert_noop!(
+			EconomyModule::claim_with_signature(RuntimeOrigin::signed(BOB), ALICE, 0, signature.clone()),
+			Error::<Runtime>::WrongSignature
+		);
+
+		// The authorization is bound to the chain it was signed for
+		let genesis_hash = frame_system::BlockHash::<Runtime>::get(0);
+		frame_system::BlockHash::<Runtime>::insert(0, H256::repeat_byte(1));
+		assert_noop!(
+			EconomyModule::claim_with_signature(RuntimeOrigin::signed(BOB), owner.clone(), 0, signature.clone()),
+			Error::<Runtime>::WrongSignature
+		);
+		frame_system::BlockHash::<Runtime>::insert(0, genesis_hash);
+
+		let owner_balance = Balances::free_balance(&owner);
+		assert_ok!(EconomyModule::claim_with_signature(
+			RuntimeOrigin::signed(BOB),
+			owner.clone(),
+			0,
+			signature.clone()
+		));
+		assert_eq!(Balances::free_balance(&owner), owner_balance + 100);
+		assert_eq!(EconomyModule::claim_nonce(&owner), 1);
+
+		// Replaying the authorization fails
+		assert_noop!(
+			EconomyModule::claim_with_signature(RuntimeOrigin::signed(BOB), owner, 0, signature),
+			Error::<Runtime>::InvalidClaimNonce
+		);
+	});
+}
+
+#[test]
+fn sponsored_stake_on_innovation_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let staker_pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+		let staker: AccountId = MultiSigner::Sr25519(staker_pair.public()).into_account();
+		Balances::make_free_balance_be(&staker, 10000);
+
+		let signature = MultiSignature::Sr25519(staker_pair.sign(&EconomyModule::sponsored_stake_payload(
+			&staker,
+			&BOB,
+			STAKE_BALANCE,
+		)));
+
+		assert_noop!(
+			EconomyModule::sponsored_stake_on_innovation(
+				RuntimeOrigin::signed(BOB),
+				staker.clone(),
+				STAKE_BALANCE,
+				signature.clone()
+			),
+			Error::<Runtime>::NotStakeSponsor
+		);
+		assert_ok!(EconomyModule::set_stake_sponsor(RuntimeOrigin::root(), BOB, Some(1)));
+		assert_noop!(
+			EconomyModule::sponsored_stake_on_innovation(
+				RuntimeOrigin::signed(BOB),
+				staker.clone(),
+				STAKE_BALANCE + 1,
+				signature.clone()
+			),
+			Error::<Runtime>::WrongSignature
+		);
+
+		// The signature is bound to the chain it was signed for
+		let genesis_hash = frame_system::BlockHash::<Runtime>::get(0);
+		frame_system::BlockHash::<Runtime>::insert(0, H256::repeat_byte(1));
+		assert_noop!(
+			EconomyModule::sponsored_stake_on_innovation(
+				RuntimeOrigin::signed(BOB),
+				staker.clone(),
+				STAKE_BALANCE,
+				signature.clone()
+			),
+			Error::<Runtime>::WrongSignature
+		);
+		frame_system::BlockHash::<Runtime>::insert(0, genesis_hash);
+
+		assert_ok!(EconomyModule::sponsored_stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			staker.clone(),
+			STAKE_BALANCE,
+			signature.clone()
+		));
+		assert_eq!(EconomyModule::get_innovation_staking_info(&staker), STAKE_BALANCE);
+		assert_eq!(Balances::reserved_balance(&staker), STAKE_BALANCE);
+		assert_eq!(EconomyModule::stake_sponsor_budget(BOB), Some(0));
+		assert_eq!(EconomyModule::stake_sponsor_of(&staker), Some(BOB));
+		System::assert_last_event(RuntimeEvent::Economy(crate::Event::SponsoredStakedInnovation(
+			BOB,
+			staker.clone(),
+			STAKE_BALANCE,
+		)));
+
+		// Only the first stake of an account is sponsored
+		assert_ok!(EconomyModule::set_stake_sponsor(RuntimeOrigin::root(), BOB, Some(1)));
+		assert_noop!(
+			EconomyModule::sponsored_stake_on_innovation(RuntimeOrigin::signed(BOB), staker, STAKE_BALANCE, signature),
+			Error::<Runtime>::NotFirstTimeStaker
+		);
+	});
+}
+
+#[test]
+fn subdivided_estate_stake_should_move_to_children_or_exit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+
+		assert_ok!(<EconomyModule as OnEstateSubdivided>::on_estate_subdivided(
+			OWNED_ESTATE_ID,
+			&[(10, 6), (11, 4)]
+		));
+		assert!(EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).is_none());
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(10).map(|bond| bond.amount),
+			Some(600)
+		);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(11).map(|bond| bond.amount),
+			Some(400)
+		);
+		assert_eq!(EconomyModule::get_account_estate_stake(ALICE), STAKE_BALANCE);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateStakeSubdivided(OWNED_ESTATE_ID, vec![10, 11], 0))
+		);
+
+		// A child part below the minimum stake queues the whole bond for exit
+		assert_ok!(<EconomyModule as OnEstateSubdivided>::on_estate_subdivided(
+			10,
+			&[(12, 9), (13, 1)]
+		));
+		assert!(EconomyModule::get_estate_staking_info(12).is_none());
+		assert_eq!(
+			EstateExitQueue::<Runtime>::get((ALICE, CURRENT_ROUND + 1, 10)).map(|queued_exit| queued_exit.amount),
+			Some(600)
+		);
+		assert_eq!(EconomyModule::get_account_estate_stake(ALICE), 400);
+	});
+}
+
+#[test]
+fn select_weighted_stakers_should_sample_distinct_innovation_stakers() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(EconomyModule::select_weighted_stakers(1, b"quest").is_empty());
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(BOB), 500));
+
+		let selected = EconomyModule::select_weighted_stakers(1, b"quest");
+		assert_eq!(selected.len(), 1);
+		assert_eq!(
+			<EconomyModule as WeightedStakerSelector<AccountId>>::select_weighted_stakers(1, b"quest"),
+			selected
+		);
+
+		let mut selected = EconomyModule::select_weighted_stakers(5, b"airdrop");
+		selected.sort();
+		let mut stakers = vec![ALICE, BOB];
+		stakers.sort();
+		assert_eq!(selected, stakers);
+	});
+}
+
+#[test]
+fn stake_weight_should_combine_weighted_stakes() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 500));
+		assert_eq!(EconomyModule::get_account_estate_stake(ALICE), STAKE_BALANCE);
+		assert_eq!(
+			<EconomyModule as StakeWeightProvider<AccountId, Balance>>::stake_weight(&ALICE),
+			1500
+		);
+
+		let weights = StakeWeight {
+			self_stake: Perbill::one(),
+			estate_stake: Perbill::from_percent(50),
+			innovation_stake: Perbill::from_percent(10),
+		};
+		assert_noop!(
+			EconomyModule::set_stake_weights(RuntimeOrigin::signed(BOB), weights.clone()),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_stake_weights(RuntimeOrigin::root(), weights));
+		assert_eq!(
+			<EconomyModule as StakeWeightProvider<AccountId, Balance>>::stake_weight(&ALICE),
+			550
+		);
+		assert_eq!(EconomyModule::total_economic_weight(&ALICE), 550);
+	});
+}
+
+#[test]
+fn innovation_conviction_should_extend_unbond_period_and_voting_points() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::set_innovation_conviction(RuntimeOrigin::signed(ALICE), InnovationConviction::Locked2x),
+			Error::<Runtime>::NotInnovationStaker
+		);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::innovation_voting_points(&ALICE), STAKE_BALANCE);
+
+		assert_ok!(EconomyModule::set_innovation_conviction(
+			RuntimeOrigin::signed(ALICE),
+			InnovationConviction::Locked4x
+		));
+		assert_eq!(EconomyModule::innovation_voting_points(&ALICE), 4 * STAKE_BALANCE);
+		assert_noop!(
+			EconomyModule::set_innovation_conviction(RuntimeOrigin::signed(ALICE), InnovationConviction::Locked2x),
+			Error::<Runtime>::ConvictionCannotBeReduced
+		);
+		assert_noop!(
+			EconomyModule::switch_stake(
+				RuntimeOrigin::signed(ALICE),
+				STAKE_BALANCE,
+				PoolKind::Innovation,
+				PoolKind::Economy101
+			),
+			Error::<Runtime>::InnovationStakeLockedByConviction
+		);
+
+		assert_ok!(EconomyModule::unstake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, CURRENT_ROUND + 4 * INNOVATION_UNBOND_ROUNDS)
+				.map(|queued_exit| queued_exit.amount),
+			Some(STAKE_BALANCE)
+		);
+		assert_eq!(EconomyModule::innovation_conviction(ALICE), InnovationConviction::None);
+	});
+}
+
+#[test]
+fn snapshot_voting_points_should_freeze_points_at_proposal_creation() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		assert_ok!(<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::snapshot_voting_points(1));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::VotingPointsSnapshotTaken(1, 1))
+		);
+		assert_noop!(
+			<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::snapshot_voting_points(1),
+			Error::<Runtime>::VotingPointsSnapshotAlreadyTaken
+		);
+
+		// Staking after the proposal is created does not change the snapshot
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 500));
+		assert_ok!(<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::snapshot_voting_points(2));
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 500));
+		assert_eq!(
+			<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::voting_points_at(1, &ALICE),
+			STAKE_BALANCE
+		);
+		assert_eq!(
+			<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::voting_points_at(2, &ALICE),
+			STAKE_BALANCE + 500
+		);
+		assert_eq!(
+			<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::voting_points_at(1, &BOB),
+			0
+		);
+		assert_eq!(
+			<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::voting_points_at(2, &BOB),
+			STAKE_BALANCE
+		);
+
+		// Points are recorded once the voter votes
+		assert_eq!(
+			<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::record_voting_points(1, &ALICE),
+			STAKE_BALANCE
+		);
+		assert_eq!(EconomyModule::voting_points_snapshot(1, ALICE), Some(STAKE_BALANCE));
+
+		<EconomyModule as VotingPointsSnapshotProvider<AccountId, Balance>>::clear_voting_points_snapshot(1);
+		assert_eq!(EconomyModule::voting_points_snapshot(1, ALICE), None);
+		assert_eq!(EconomyModule::proposal_voting_points_snapshot(1), None);
+	});
+}
+
+#[test]
+fn reward_pot_should_be_funded_swept_and_rotated() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reward_pot = EconomyModule::get_reward_payout_account_id();
+		Balances::make_free_balance_be(&EconomyModule::economy_pallet_account_id(), 5000);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		assert_noop!(
+			EconomyModule::fund_reward_pot(RuntimeOrigin::signed(BOB), 1000),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::fund_reward_pot(RuntimeOrigin::signed(ALICE), 1000));
+		assert_eq!(Balances::free_balance(&reward_pot), 31000);
+
+		assert_ok!(EconomyModule::set_reward_pot_low_watermark(
+			RuntimeOrigin::root(),
+			40000
+		));
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(2000u128));
+		UpdateEraFrequency::<Runtime>::set(1u64);
+		run_to_block(2);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == RuntimeEvent::Economy(crate::Event::RewardPotLow(31000))));
+
+		// Rewards owed to the reward pool cannot be swept
+		let surplus = EconomyModule::reward_pot_surplus();
+		assert!(surplus < 31000);
+		assert_noop!(
+			EconomyModule::sweep_reward_pot(RuntimeOrigin::signed(ALICE), surplus + 1),
+			Error::<Runtime>::RewardPotSurplusExceeded
+		);
+		assert_ok!(EconomyModule::sweep_reward_pot(RuntimeOrigin::signed(ALICE), 1000));
+		assert_eq!(Balances::free_balance(&reward_pot), 30000);
+
+		assert_ok!(EconomyModule::rotate_reward_pot(RuntimeOrigin::signed(ALICE), FREEDY));
+		assert_eq!(EconomyModule::get_reward_payout_account_id(), FREEDY);
+		assert_eq!(Balances::free_balance(&reward_pot), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::RewardPotRotated(reward_pot, FREEDY))
+		);
+	});
+}
+
+#[test]
+fn economy_spend_should_redeploy_protocol_income() {
+	ExtBuilder::default().build().execute_with(|| {
+		let economy_treasury = EconomyModule::economy_pallet_account_id();
+		Balances::make_free_balance_be(&economy_treasury, 5000);
+
+		assert_noop!(
+			EconomyModule::propose_economy_spend(
+				RuntimeOrigin::signed(BOB),
+				FungibleTokenId::NativeToken(0),
+				0,
+				EconomySpendTarget::Beneficiary(BOB)
+			),
+			Error::<Runtime>::EconomySpendAmountIsZero
+		);
+		assert_ok!(EconomyModule::propose_economy_spend(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			500,
+			EconomySpendTarget::Beneficiary(BOB)
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+		assert_eq!(EconomyModule::open_economy_spends(), 1);
+		assert_noop!(
+			EconomyModule::approve_economy_spend(RuntimeOrigin::signed(BOB), 0),
+			BadOrigin
+		);
+
+		// The deposit is returned on approval
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::approve_economy_spend(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 500 + 100);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(EconomyModule::economy_spend_proposals(0), None);
+		assert_eq!(EconomyModule::open_economy_spends(), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EconomySpendApproved(0))
+		);
+
+		assert_ok!(EconomyModule::propose_economy_spend(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			1000,
+			EconomySpendTarget::RewardCampaign { start: 1, end: 100 }
+		));
+		assert_ok!(EconomyModule::approve_economy_spend(RuntimeOrigin::signed(ALICE), 1));
+		let campaign = EconomyModule::reward_campaigns(0).unwrap();
+		assert_eq!(campaign.creator, economy_treasury);
+		assert_eq!(campaign.remaining_budget, 1000);
+		assert_eq!(Balances::free_balance(&economy_treasury), 3500);
+
+		assert_ok!(EconomyModule::propose_economy_spend(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			1000,
+			EconomySpendTarget::Beneficiary(BOB)
+		));
+		assert_ok!(EconomyModule::propose_economy_spend(
+			RuntimeOrigin::signed(BOB),
+			FungibleTokenId::NativeToken(0),
+			1000,
+			EconomySpendTarget::Beneficiary(BOB)
+		));
+		assert_noop!(
+			EconomyModule::propose_economy_spend(
+				RuntimeOrigin::signed(BOB),
+				FungibleTokenId::NativeToken(0),
+				1000,
+				EconomySpendTarget::Beneficiary(BOB)
+			),
+			Error::<Runtime>::TooManyOpenEconomySpends
+		);
+
+		// The deposit is forfeited on rejection
+		let bob_balance = Balances::total_balance(&BOB);
+		assert_ok!(EconomyModule::reject_economy_spend(RuntimeOrigin::signed(ALICE), 2));
+		assert_eq!(Balances::total_balance(&BOB), bob_balance - 100);
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+		assert_eq!(EconomyModule::open_economy_spends(), 1);
+		assert_noop!(
+			EconomyModule::approve_economy_spend(RuntimeOrigin::signed(ALICE), 2),
+			Error::<Runtime>::EconomySpendDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn set_bit_power_exchange_rate_should_convert_with_rounding() {
+	ExtBuilder::default().build().execute_with(|| {
+		let rate = FixedU128::saturating_from_rational(3, 2);
+		assert_noop!(
+			EconomyModule::set_bit_power_exchange_rate(RuntimeOrigin::signed(ALICE), rate, ConversionRounding::Up),
+			BadOrigin
+		);
+
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			rate,
+			ConversionRounding::Down
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::BitPowerExchangeRateUpdated(
+				rate,
+				ConversionRounding::Down
+			))
+		);
+		assert_eq!(EconomyModule::convert_power_to_bit(3, Perbill::zero()), Ok((4, 0)));
+		assert_eq!(
+			EconomyModule::convert_power_to_bit(3, Perbill::from_percent(10)),
+			Ok((4, 0))
+		);
+
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			rate,
+			ConversionRounding::Up
+		));
+		assert_eq!(EconomyModule::convert_power_to_bit(3, Perbill::zero()), Ok((5, 0)));
+		assert_eq!(
+			EconomyModule::convert_power_to_bit(3, Perbill::from_percent(10)),
+			Ok((6, 1))
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v1_should_migrate_integer_rate_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(0).put::<EconomyModule>();
+		frame_support::storage::unhashed::put_raw(
+			&BitPowerExchangeRate::<Runtime>::hashed_key(),
+			&Balance::from(5u128).encode(),
+		);
+
+		migrations::v1::MigrateToV1::<Runtime>::on_runtime_upgrade();
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(
+			EconomyModule::get_bit_power_exchange_rate(),
+			FixedU128::saturating_from_integer(5u128)
+		);
+		assert_eq!(EconomyModule::convert_power_to_bit(10, Perbill::zero()), Ok((50, 0)));
+
+		migrations::v1::MigrateToV1::<Runtime>::on_runtime_upgrade();
+		assert_eq!(
+			EconomyModule::get_bit_power_exchange_rate(),
+			FixedU128::saturating_from_integer(5u128)
+		);
+	});
+}
+
+#[test]
+fn twap_should_weight_exchange_rates_by_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			FixedU128::one(),
+			ConversionRounding::Down
+		));
+		System::set_block_number(11);
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			FixedU128::saturating_from_integer(3u128),
+			ConversionRounding::Down
+		));
+		assert_eq!(
+			EconomyModule::bit_power_exchange_rate_history(),
+			vec![
+				ExchangeRateUpdate {
+					block: 1,
+					old_rate: FixedU128::zero(),
+					new_rate: FixedU128::one(),
+				},
+				ExchangeRateUpdate {
+					block: 11,
+					old_rate: FixedU128::one(),
+					new_rate: FixedU128::saturating_from_integer(3u128),
+				},
+			]
+		);
+
+		System::set_block_number(21);
+		assert_eq!(EconomyModule::twap(20), FixedU128::saturating_from_integer(2u128));
+		assert_eq!(EconomyModule::twap(10), FixedU128::saturating_from_integer(3u128));
+		assert_eq!(EconomyModule::twap(0), FixedU128::saturating_from_integer(3u128));
+	});
+}
+
+#[test]
+fn convert_power_to_bit_should_fail_on_overflow() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			FixedU128::saturating_from_integer(2u128),
+			ConversionRounding::Down
+		));
+		assert_eq!(
+			EconomyModule::convert_power_to_bit(Balance::MAX, Perbill::zero()),
+			Err(DispatchError::Arithmetic(ArithmeticError::Overflow))
+		);
+
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			FixedU128::one(),
+			ConversionRounding::Down
+		));
+		assert_eq!(
+			EconomyModule::convert_power_to_bit(Balance::MAX, Perbill::zero()),
+			Ok((Balance::MAX, 0))
+		);
+		assert_eq!(
+			<EconomyModule as PowerConversion<Balance>>::convert_power_to_bit(Balance::MAX, Perbill::from_percent(10)),
+			Err(DispatchError::Arithmetic(ArithmeticError::Overflow))
+		);
+	});
+}
+
+#[test]
+fn stake_on_estate_should_respect_estate_stake_limits() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::set_estate_stake_limits(RuntimeOrigin::signed(ALICE), Some(500), None),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_estate_stake_limits(
+			RuntimeOrigin::root(),
+			Some(500),
+			None
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateStakeLimitsUpdated(Some(500), None))
+		);
+
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(ALICE), 600, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::EstateStakeExceedsAccountLimit
+		);
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			500,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::AccountEstateStakeSaturated(ALICE, 500))
+		);
+
+		assert_ok!(EconomyModule::set_estate_stake_limits(
+			RuntimeOrigin::root(),
+			None,
+			Some(600)
+		));
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(ALICE), 200, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::EstateStakeExceedsTotalLimit
+		);
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			100,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::TotalEstateStakeSaturated(600))
+		);
+		assert_eq!(EconomyModule::get_account_estate_stake(ALICE), 600);
+	});
+}
+
+#[test]
+fn force_unstake_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), STAKE_BALANCE, None));
+
+		assert_noop!(
+			EconomyModule::force_unstake_batch(
+				RuntimeOrigin::signed(ALICE),
+				vec![(ALICE, STAKE_BALANCE, None)].try_into().unwrap()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			EconomyModule::force_unstake_batch(
+				RuntimeOrigin::root(),
+				vec![(BOB, STAKE_BALANCE, None), (ALICE, STAKE_BALANCE + 1, None)]
+					.try_into()
+					.unwrap()
+			),
+			Error::<Runtime>::UnstakeAmountExceedStakedAmount
+		);
+
+		assert_ok!(EconomyModule::force_unstake_batch(
+			RuntimeOrigin::root(),
+			vec![(BOB, STAKE_BALANCE, None), (ALICE, STAKE_BALANCE, None)]
+				.try_into()
+				.unwrap()
+		));
+		assert_eq!(EconomyModule::get_staking_info(ALICE), 0);
+		assert_eq!(EconomyModule::get_staking_info(BOB), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::SelfStakingRemovedFromEconomy101(ALICE, STAKE_BALANCE))
+		);
+	});
+}
+
+#[test]
+fn force_operations_should_be_recorded_in_forced_actions_log() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+
+		assert_ok!(EconomyModule::force_unstake(RuntimeOrigin::root(), 400, ALICE, None));
+		assert_ok!(EconomyModule::force_unreserved_staking(
+			RuntimeOrigin::root(),
+			100,
+			ALICE
+		));
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::ForcedActionRecorded(
+				ForcedActionKind::UnreservedStaking,
+				ALICE,
+				100
+			))));
+
+		assert_eq!(
+			EconomyModule::forced_actions(),
+			vec![
+				ForcedAction {
+					origin: frame_system::RawOrigin::Root,
+					kind: ForcedActionKind::Unstake(None),
+					target: ALICE,
+					amount: 400,
+					block: 1,
+				},
+				ForcedAction {
+					origin: frame_system::RawOrigin::Root,
+					kind: ForcedActionKind::UnreservedStaking,
+					target: ALICE,
+					amount: 100,
+					block: 1,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn forced_actions_log_should_overwrite_oldest_entries() {
+	ExtBuilder::default().build().execute_with(|| {
+		for amount in 0..MAX_FORCED_ACTIONS as Balance + 2 {
+			EconomyModule::record_forced_action(ForcedActionKind::UnreservedStaking, &ALICE, amount);
+		}
+
+		assert_eq!(EconomyModule::forced_action_count(), MAX_FORCED_ACTIONS + 2);
+		assert_eq!(EconomyModule::forced_action(0).map(|action| action.amount), Some(500));
+		assert_eq!(EconomyModule::forced_action(2).map(|action| action.amount), Some(2));
+
+		let actions = EconomyModule::forced_actions();
+		assert_eq!(actions.len(), MAX_FORCED_ACTIONS as usize);
+		assert_eq!(actions.first().map(|action| action.amount), Some(2));
+		assert_eq!(actions.last().map(|action| action.amount), Some(501));
+	});
+}
+
+#[test]
+fn force_transfer_stake_should_move_position() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 200, None));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		assert_noop!(
+			EconomyModule::force_transfer_stake(RuntimeOrigin::signed(ALICE), ALICE, FREEDY, PoolKind::Economy101),
+			BadOrigin
+		);
+		assert_noop!(
+			EconomyModule::force_transfer_stake(RuntimeOrigin::root(), BOB, FREEDY, PoolKind::Economy101),
+			Error::<Runtime>::NoStakeToTransfer
+		);
+
+		assert_ok!(EconomyModule::force_transfer_stake(
+			RuntimeOrigin::root(),
+			ALICE,
+			FREEDY,
+			PoolKind::Economy101
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::StakeForceTransferred(
+				ALICE,
+				FREEDY,
+				PoolKind::Economy101,
+				STAKE_BALANCE - 200,
+				200
+			))
+		);
+		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
+		assert_eq!(EconomyModule::get_staking_info(ALICE), 0);
+		assert_eq!(EconomyModule::get_staking_info(FREEDY), STAKE_BALANCE - 200);
+		assert_eq!(
+			EconomyModule::staking_exit_queue(ALICE, next_round).map(|queued_exit| queued_exit.amount),
+			None
+		);
+		assert_eq!(
+			EconomyModule::staking_exit_queue(FREEDY, next_round).map(|queued_exit| queued_exit.amount),
+			Some(200)
+		);
+		assert_eq!(EconomyModule::total_stake(), STAKE_BALANCE - 200);
+		assert_eq!(Balances::reserved_balance(ALICE), STAKE_BALANCE);
+		assert_eq!(Balances::reserved_balance(FREEDY), STAKE_BALANCE);
+
+		assert_ok!(EconomyModule::force_transfer_stake(
+			RuntimeOrigin::root(),
+			ALICE,
+			FREEDY,
+			PoolKind::Innovation
+		));
+		assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), 0);
+		assert_eq!(EconomyModule::get_innovation_staking_info(FREEDY), STAKE_BALANCE);
+		assert_eq!(EconomyModule::shares_and_withdrawn_rewards(ALICE).0, 0);
+		assert_eq!(EconomyModule::shares_and_withdrawn_rewards(FREEDY).0, STAKE_BALANCE);
+		assert_eq!(EconomyModule::staking_reward_pool_info().total_shares, STAKE_BALANCE);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(FREEDY), 2 * STAKE_BALANCE);
+
+		assert_noop!(
+			EconomyModule::force_transfer_stake(RuntimeOrigin::root(), BOB, FREEDY, PoolKind::Innovation),
+			Error::<Runtime>::StakeTransferTargetHasStake
+		);
+	});
+}
+
+#[test]
+fn stepped_migration_should_resume_across_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		for id in 10..15u8 {
+			PowerBalance::<Runtime>::insert(account(id), 10);
+		}
+		let step_limit = STEPPED_MIGRATION_WEIGHT_PER_ENTRY.saturating_mul(2);
+
+		EconomyModule::on_idle(1, step_limit);
+		assert!(EconomyModule::stepped_migration_cursor().is_some());
+		assert_eq!(
+			PowerBalance::<Runtime>::iter_values()
+				.filter(|power| *power == 20)
+				.count(),
+			2
+		);
+
+		EconomyModule::on_idle(2, step_limit);
+		assert_eq!(
+			PowerBalance::<Runtime>::iter_values()
+				.filter(|power| *power == 20)
+				.count(),
+			4
+		);
+
+		EconomyModule::on_idle(3, step_limit);
+		assert_eq!(
+			PowerBalance::<Runtime>::iter_values()
+				.filter(|power| *power == 20)
+				.count(),
+			5
+		);
+		assert_eq!(EconomyModule::stepped_migration_cursor(), None);
+		assert_eq!(
+			EconomyModule::completed_stepped_migrations(b"double_power_balance".to_vec()),
+			Some(())
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::SteppedMigrationCompleted(
+				b"double_power_balance".to_vec()
+			))
+		);
+
+		EconomyModule::on_idle(4, step_limit);
+		assert_eq!(
+			PowerBalance::<Runtime>::iter_values()
+				.filter(|power| *power == 20)
+				.count(),
+			5
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_report_accounting_anomaly() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		StakingRewardPoolInfo::<Runtime>::mutate(|pool_info| {
+			pool_info.rewards.insert(FungibleTokenId::NativeToken(0), (100, 200));
+		});
+		EconomyModule::claim_rewards(&ALICE);
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::AccountingAnomaly(
+				ALICE,
+				AccountingAnomalyKind::WithdrawnExceedsTotalReward(FungibleTokenId::NativeToken(0))
+			))
+		);
+	});
+}
+
+#[test]
+fn era_rollover_should_emit_era_summary() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
+		UpdateEraFrequency::<Runtime>::set(3u64);
+
+		run_to_block(4);
+
+		let era_summary = RuntimeEvent::Economy(crate::Event::EraSummary(
+			1,
+			EraMetrics {
+				total_stake: 0,
+				total_innovation_stake: STAKE_BALANCE,
+				total_shares: STAKE_BALANCE,
+				staker_count: 1,
+				rewards_added: vec![(FungibleTokenId::NativeToken(0), 100)],
+			},
+		));
+		assert!(System::events().iter().any(|record| record.event == era_summary));
+		assert_eq!(EraRewardsAdded::<Runtime>::get(), BTreeMap::new());
+	});
+}
+
+#[test]
+fn era_rollover_should_notify_era_change_handlers() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
+		UpdateEraFrequency::<Runtime>::set(3u64);
+
+		run_to_block(4);
+
+		assert_eq!(
+			EraChanges::get(),
+			vec![(1, vec![(FungibleTokenId::NativeToken(0), 100)])]
+		);
+
+		run_to_block(7);
+
+		assert_eq!(EraChanges::get().len(), 2);
+		assert_eq!(EraChanges::get()[1].0, 2);
+	});
+}
+
+#[test]
+fn era_reward_should_exclude_shares_joined_during_the_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
+		UpdateEraFrequency::<Runtime>::set(3u64);
+
+		run_to_block(3);
+		assert_eq!(
+			EconomyModule::era_staker_snapshot(1).unwrap().total_innovation_stake,
+			STAKE_BALANCE
+		);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::era_joined_shares(1, BOB), STAKE_BALANCE);
+
+		// Bob joined during era 1 and does not share its reward
+		run_to_block(6);
+		assert_eq!(EconomyModule::total_era_joined_shares(), 0);
+		assert!(EconomyModule::era_joined_shares_settlement(1).is_some());
+
+		// Both stakers share the reward of era 2
+		run_to_block(9);
+		EconomyModule::claim_rewards(&ALICE);
+		EconomyModule::claim_rewards(&BOB);
+		assert_eq!(
+			EconomyModule::pending_multi_rewards(ALICE).get(&FungibleTokenId::NativeToken(0)),
+			Some(&250u128)
+		);
+		assert_eq!(
+			EconomyModule::pending_multi_rewards(BOB).get(&FungibleTokenId::NativeToken(0)),
+			Some(&50u128)
+		);
+	});
+}
+
+#[test]
+fn era_joined_shares_should_be_settled_in_on_idle_steps() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(100u128));
+		UpdateEraFrequency::<Runtime>::set(3u64);
+
+		run_to_block(3);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+		Balances::make_free_balance_be(&FREEDY, 10000);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(FREEDY),
+			STAKE_BALANCE
+		));
+
+		run_to_block(6);
+		assert!(EconomyModule::era_joined_shares_settlement(1).is_some());
+		assert_eq!(EraJoinedShares::<Runtime>::iter_prefix(1).count(), 2);
+
+		EconomyModule::on_idle(6, Weight::MAX);
+		assert_eq!(EconomyModule::era_joined_shares_settlement(1), None);
+		assert_eq!(EraJoinedShares::<Runtime>::iter_prefix(1).count(), 0);
+
+		// Swept stakers forgo the reward of era 1 like lazily settled ones
+		EconomyModule::claim_rewards(&ALICE);
+		EconomyModule::claim_rewards(&BOB);
+		assert_eq!(
+			EconomyModule::pending_multi_rewards(ALICE).get(&FungibleTokenId::NativeToken(0)),
+			Some(&200u128)
+		);
+		assert_eq!(
+			EconomyModule::pending_multi_rewards(BOB).get(&FungibleTokenId::NativeToken(0)),
+			None
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v2_should_add_total_innovation_stake_to_snapshots() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(1).put::<EconomyModule>();
+		frame_support::storage::unhashed::put_raw(
+			&EraStakerSnapshots::<Runtime>::hashed_key_for(5),
+			&(1000u128, 3u32, H256::zero()).encode(),
+		);
+
+		migrations::v2::MigrateToV2::<Runtime>::on_runtime_upgrade();
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(2));
+		assert_eq!(
+			EconomyModule::era_staker_snapshot(5),
+			Some(StakerSetSnapshot {
+				total_shares: 1000,
+				staker_count: 3,
+				shares_commitment: H256::zero(),
+				total_innovation_stake: 1000,
+			})
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v3_should_estimate_exit_queue_unlock_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(2).put::<EconomyModule>();
+		let next_round: RoundIndex = CURRENT_ROUND + 1;
+		frame_support::storage::unhashed::put_raw(
+			&ExitQueue::<Runtime>::hashed_key_for(ALICE, next_round),
+			&200u128.encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&ExitQueue::<Runtime>::hashed_key_for(BOB, next_round),
+			&300u128.encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&InnovationStakingExitQueue::<Runtime>::hashed_key_for(ALICE, next_round),
+			&400u128.encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&EstateExitQueue::<Runtime>::hashed_key_for((ALICE, next_round, 1)),
+			&500u128.encode(),
+		);
+		let queued_exit = |amount: Balance| {
+			Some(QueuedExit {
+				amount,
+				estimated_unlock_block: EconomyModule::estimated_round_start(next_round),
+			})
+		};
+
+		assert!(migrations::v3::MigrateToV3::<Runtime>::is_applicable());
+		let (cursor, _) = migrations::v3::MigrateToV3::<Runtime>::step(None, Weight::MAX);
+		assert_eq!(cursor, None);
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(3));
+		assert_eq!(EconomyModule::staking_exit_queue(ALICE, next_round), queued_exit(200));
+		assert_eq!(EconomyModule::staking_exit_queue(BOB, next_round), queued_exit(300));
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, next_round),
+			queued_exit(400)
+		);
+		assert_eq!(
+			EconomyModule::estate_staking_exit_queue((ALICE, next_round, 1)),
+			queued_exit(500)
+		);
+		assert!(!migrations::v3::MigrateToV3::<Runtime>::is_applicable());
+	});
+}
+
+#[test]
+fn legacy_exit_queue_entries_should_stay_usable_until_migrated_to_v3() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		StorageVersion::new(2).put::<EconomyModule>();
+		let next_round: RoundIndex = CURRENT_ROUND + 1;
+		frame_support::storage::unhashed::put_raw(
+			&ExitQueue::<Runtime>::hashed_key_for(ALICE, CURRENT_ROUND),
+			&200u128.encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&InnovationStakingExitQueue::<Runtime>::hashed_key_for(ALICE, next_round),
+			&300u128.encode(),
+		);
+
+		// Bare balances decode with the unlock block estimated on read
+		assert!(EconomyModule::exit_entries(&ALICE).contains(&ExitEntry {
+			kind: ExitQueueKind::Economy101,
+			round: CURRENT_ROUND,
+			amount: 200,
+			estimated_unlock_block: EconomyModule::estimated_round_start(CURRENT_ROUND),
+			ready: true,
+		}));
+
+		// Queuing more on a legacy entry adds to its amount
+		EconomyModule::queue_exit(&ALICE, ExitQueueKind::Innovation, next_round, 100);
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, next_round),
+			Some(QueuedExit {
+				amount: 400,
+				estimated_unlock_block: EconomyModule::estimated_round_start(next_round),
+			})
+		);
+
+		assert_ok!(EconomyModule::withdraw_unreserved(
+			RuntimeOrigin::signed(ALICE),
+			CURRENT_ROUND
+		));
+		assert_eq!(EconomyModule::staking_exit_queue(ALICE, CURRENT_ROUND), None);
+		System::assert_last_event(RuntimeEvent::Economy(crate::Event::UnstakedAmountWithdrew(ALICE, 200)));
+
+		let (cursor, _) = migrations::v3::MigrateToV3::<Runtime>::step(None, Weight::MAX);
+		assert_eq!(cursor, None);
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(ALICE, next_round),
+			Some(QueuedExit {
+				amount: 400,
+				estimated_unlock_block: EconomyModule::estimated_round_start(next_round),
+			})
+		);
+	});
+}
+
+#[test]
+fn unstake_should_queue_exit_with_estimated_unlock_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		run_to_block(5);
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 200, None));
+
+		let round_info = Mining::get_current_round_info();
+		let next_round: RoundIndex = round_info.current + 1;
+		let unlock_block = round_info.first + round_info.length as u64;
+		assert_eq!(EconomyModule::estimated_round_start(next_round), unlock_block);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::ExitQueued(
+				ALICE,
+				ExitQueueKind::Economy101,
+				next_round,
+				200,
+				unlock_block
+			))));
+	});
+}
+
+#[test]
+fn matured_exits_should_be_restaked_when_opted_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				auto_restake_exits: true,
+				..Default::default()
+			}
+		));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 200, None));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(BOB), 200, None));
+
+		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
+		// Exit has not matured yet
+		EconomyModule::on_idle(1, Weight::MAX);
+		assert_eq!(
+			EconomyModule::staking_exit_queue(ALICE, next_round).map(|queued_exit| queued_exit.amount),
+			Some(200)
+		);
+
+		run_to_block(25);
+		EconomyModule::on_idle(25, Weight::MAX);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ExitRestaked(ALICE, PoolKind::Economy101, next_round, 200))
+		);
+		assert_eq!(
+			EconomyModule::staking_exit_queue(ALICE, next_round).map(|queued_exit| queued_exit.amount),
+			None
+		);
+		assert_eq!(EconomyModule::get_staking_info(ALICE), STAKE_BALANCE);
+		assert_eq!(Balances::reserved_balance(ALICE), STAKE_BALANCE);
+
+		// Accounts that did not opt in keep their exit queue entry
+		assert_eq!(
+			EconomyModule::staking_exit_queue(BOB, next_round).map(|queued_exit| queued_exit.amount),
+			Some(200)
+		);
+		assert_eq!(EconomyModule::total_stake(), 2 * STAKE_BALANCE - 200);
+	});
+}
+
+#[test]
+fn approved_co_staker_should_stake_on_estate_up_to_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&FREEDY, 10000);
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(FREEDY), 200, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::StakerNotEstateOwner
+		);
+		assert_noop!(
+			EconomyModule::approve_estate_co_staker(RuntimeOrigin::signed(FREEDY), OWNED_ESTATE_ID, FREEDY, 300),
+			Error::<Runtime>::StakerNotEstateOwner
+		);
+
+		assert_ok!(EconomyModule::approve_estate_co_staker(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			FREEDY,
+			300
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateCoStakerApproved(OWNED_ESTATE_ID, FREEDY, 300))
+		);
+
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			600,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(FREEDY),
+			200,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(FREEDY), 200, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::EstateCoStakeExceedsCap
+		);
+		// Co-stake counts towards the estate stake allowance
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(ALICE), 300, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::StakeAmountExceedMaximumAmount
+		);
+
+		assert_eq!(EconomyModule::estate_co_stake(OWNED_ESTATE_ID, FREEDY), 200);
+		assert_eq!(EconomyModule::estate_co_stake_total(OWNED_ESTATE_ID), 200);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).unwrap().staker,
+			ALICE
+		);
+		assert_eq!(EconomyModule::total_estate_stake(), 800);
+		assert_eq!(Balances::reserved_balance(FREEDY), 200);
+
+		assert_ok!(EconomyModule::revoke_estate_co_staker(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			FREEDY
+		));
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(FREEDY), 100, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::StakerNotEstateOwner
+		);
+
+		// Revoked co-stakers can still unstake
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(FREEDY),
+			200,
+			Some(OWNED_ESTATE_ID)
+		));
+		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
+		assert_eq!(
+			EconomyModule::estate_staking_exit_queue((FREEDY, next_round, OWNED_ESTATE_ID))
+				.map(|queued_exit| queued_exit.amount),
+			Some(200)
+		);
+		assert_eq!(EconomyModule::estate_co_stake_total(OWNED_ESTATE_ID), 0);
+		assert_eq!(EconomyModule::total_estate_stake(), 600);
+		assert_eq!(
+			EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID).unwrap().amount,
+			600
+		);
+	});
+}
+
+#[test]
+fn income_should_be_staked_when_opted_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Accounts that did not opt in keep their income
+		<EconomyModule as OnIncome<AccountId, Balance>>::on_income(&ALICE, 500);
+		assert_eq!(EconomyModule::pending_income_stake(ALICE), 0);
+
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				auto_stake_income: true,
+				..Default::default()
+			}
+		));
+
+		// Income below the threshold is accumulated
+		<EconomyModule as OnIncome<AccountId, Balance>>::on_income(&ALICE, 30);
+		assert_eq!(EconomyModule::pending_income_stake(ALICE), 30);
+		assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), 0);
+
+		// Accumulated income below the minimum stake is kept pending
+		<EconomyModule as OnIncome<AccountId, Balance>>::on_income(&ALICE, 30);
+		assert_eq!(EconomyModule::pending_income_stake(ALICE), 60);
+
+		<EconomyModule as OnIncome<AccountId, Balance>>::on_income(&ALICE, 60);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::IncomeStaked(ALICE, 120))
+		);
+		assert_eq!(EconomyModule::pending_income_stake(ALICE), 0);
+		assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), 120);
+		assert_eq!(EconomyModule::total_innovation_staking(), 120);
+		assert_eq!(Balances::reserved_balance(ALICE), 120);
+	});
+}
+
+#[test]
+fn reap_dust_position_should_fold_position_into_exit_queue() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::bond_innovation_stake(&BOB, 5));
+
+		assert_noop!(
+			EconomyModule::reap_dust_position(RuntimeOrigin::signed(BOB), ALICE),
+			Error::<Runtime>::NotDustPosition
+		);
+		assert_noop!(
+			EconomyModule::reap_dust_position(RuntimeOrigin::signed(BOB), FREEDY),
+			Error::<Runtime>::NotDustPosition
+		);
+
+		assert_ok!(EconomyModule::reap_dust_position(RuntimeOrigin::signed(ALICE), BOB));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::DustPositionFolded(BOB, 5))
+		);
+
+		let exit_round: RoundIndex = CURRENT_ROUND.saturating_add(INNOVATION_UNBOND_ROUNDS);
+		assert_eq!(
+			EconomyModule::innovation_staking_exit_queue(BOB, exit_round).map(|queued_exit| queued_exit.amount),
+			Some(5)
+		);
+		assert_eq!(EconomyModule::get_innovation_staking_info(BOB), 0);
+		assert_eq!(EconomyModule::total_innovation_staking(), STAKE_BALANCE);
+		assert_eq!(Balances::reserved_balance(BOB), 5);
+	});
+}
+
+#[test]
+fn stake_should_fail_when_it_would_kill_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ALICE holds 10000, the existential deposit is 1 and the fee headroom is 10
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(ALICE), 9990, None),
+			Error::<Runtime>::WouldKillAccount
+		);
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(ALICE), 9990, Some(OWNED_ESTATE_ID)),
+			Error::<Runtime>::WouldKillAccount
+		);
+		assert_noop!(
+			EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 9990),
+			Error::<Runtime>::WouldKillAccount
+		);
+
+		assert_ok!(EconomyModule::stake_on_innovation(RuntimeOrigin::signed(ALICE), 9989));
+		assert_eq!(Balances::free_balance(ALICE), 11);
+	});
+}
+
+#[test]
+fn estate_stake_should_be_weighted_by_estate_traits() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::set_estate_trait_reward_weight(
+				RuntimeOrigin::signed(ALICE),
+				EstateTraitKind::Rarity,
+				3,
+				Some(FixedU128::saturating_from_rational(3, 2))
+			),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_estate_trait_reward_weight(
+			RuntimeOrigin::root(),
+			EstateTraitKind::Rarity,
+			3,
+			Some(FixedU128::saturating_from_rational(3, 2))
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateTraitRewardWeightUpdated(
+				EstateTraitKind::Rarity,
+				3,
+				Some(FixedU128::saturating_from_rational(3, 2))
+			))
+		);
+		// Weights of other trait values do not apply
+		assert_ok!(EconomyModule::set_estate_trait_reward_weight(
+			RuntimeOrigin::root(),
+			EstateTraitKind::Biome,
+			2,
+			Some(FixedU128::saturating_from_integer(4u128))
+		));
+
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			400,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::weighted_estate_stake(OWNED_ESTATE_ID, ALICE), 600);
+		assert_eq!(
+			<EconomyModule as StakeWeightProvider<AccountId, Balance>>::stake_weight(&ALICE),
+			600
+		);
+
+		assert_ok!(EconomyModule::set_estate_trait_reward_weight(
+			RuntimeOrigin::root(),
+			EstateTraitKind::NeighborhoodScore,
+			7,
+			Some(FixedU128::saturating_from_integer(2u128))
+		));
+		assert_ok!(EconomyModule::refresh_weighted_estate_stake(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID,
+			ALICE
+		));
+		assert_eq!(EconomyModule::account_weighted_estate_stake(ALICE), 1200);
+
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(ALICE),
+			400,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::weighted_estate_stake(OWNED_ESTATE_ID, ALICE), 0);
+		assert_eq!(EconomyModule::account_weighted_estate_stake(ALICE), 0);
+	});
+}
+
+#[test]
+fn confirmed_offence_should_slash_offender_and_reward_reporter() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&FREEDY, 1000);
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), 500, None));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+
+		assert_noop!(
+			EconomyModule::report_offence(RuntimeOrigin::signed(BOB), BOB, vec![1u8].try_into().unwrap()),
+			Error::<Runtime>::SelfOffenceReport
+		);
+		assert_ok!(EconomyModule::report_offence(
+			RuntimeOrigin::signed(FREEDY),
+			BOB,
+			vec![1u8, 2, 3].try_into().unwrap()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::OffenceReported(0, FREEDY, BOB))
+		);
+		assert_eq!(Balances::reserved_balance(FREEDY), 100);
+
+		assert_noop!(
+			EconomyModule::confirm_offence(RuntimeOrigin::signed(BOB), 0, 800),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::confirm_offence(RuntimeOrigin::signed(ALICE), 0, 800));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::OffenceConfirmed(0, BOB, 800, 80))
+		);
+
+		// Self stake is slashed first, then innovation stake
+		assert_eq!(EconomyModule::get_staking_info(BOB), 0);
+		assert_eq!(EconomyModule::get_innovation_staking_info(BOB), 700);
+		assert_eq!(EconomyModule::total_innovation_staking(), 700);
+		assert_eq!(Balances::reserved_balance(BOB), 700);
+		assert_eq!(Balances::reserved_balance(FREEDY), 0);
+		assert_eq!(Balances::free_balance(FREEDY), 1080);
+		assert_eq!(EconomyModule::insurance_fund_balance(), 720);
+		assert_eq!(EconomyModule::offence_reports(0), None);
+	});
+}
+
+#[test]
+fn confirmed_offence_should_slash_estate_stake_and_pending_exits() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&FREEDY, 1000);
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), 500, None));
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			300,
+			Some(OWNED_ESTATE_ID)
+		));
+		// Unstaking ahead of the judgement does not escape the slash
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 500, None));
+		assert_eq!(EconomyModule::get_staking_info(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 800);
+
+		assert_ok!(EconomyModule::report_offence(
+			RuntimeOrigin::signed(FREEDY),
+			ALICE,
+			vec![1u8].try_into().unwrap()
+		));
+		assert_ok!(EconomyModule::confirm_offence(RuntimeOrigin::signed(ALICE), 0, 700));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::OffenceConfirmed(0, ALICE, 700, 70))
+		);
+
+		// Estate stake is slashed before the pending exits
+		assert_eq!(EconomyModule::get_estate_staking_info(OWNED_ESTATE_ID), None);
+		assert_eq!(EconomyModule::staked_estate(ALICE, OWNED_ESTATE_ID), None);
+		assert_eq!(EconomyModule::get_account_estate_stake(ALICE), 0);
+		assert_eq!(EconomyModule::total_estate_stake(), 0);
+		assert_eq!(
+			EconomyModule::staking_exit_queue(ALICE, CURRENT_ROUND + 1).map(|queued_exit| queued_exit.amount),
+			Some(100)
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(FREEDY), 1070);
+	});
+}
+
+#[test]
+fn rejected_offence_should_forfeit_reporter_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::report_offence(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			vec![1u8].try_into().unwrap()
+		));
+		assert_ok!(EconomyModule::reject_offence(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(last_event(), RuntimeEvent::Economy(crate::Event::OffenceRejected(0)));
+
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 20000 - 100);
+		assert_eq!(EconomyModule::insurance_fund_balance(), 100);
+		assert_noop!(
+			EconomyModule::reject_offence(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::OffenceReportDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn slashes_should_be_redistributed_by_penalty_split() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::set_penalty_split(
+				RuntimeOrigin::root(),
+				PenaltySplit {
+					reward_pool: Perbill::from_percent(80),
+					treasury: Perbill::from_percent(30),
+				}
+			),
+			Error::<Runtime>::PenaltySplitExceedsTotal
+		);
+		let split = PenaltySplit {
+			reward_pool: Perbill::from_percent(50),
+			treasury: Perbill::from_percent(20),
+		};
+		assert_ok!(EconomyModule::set_penalty_split(RuntimeOrigin::root(), split.clone()));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PenaltySplitUpdated(split))
+		);
+
+		Balances::make_free_balance_be(&FREEDY, 1000);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::report_offence(
+			RuntimeOrigin::signed(FREEDY),
+			BOB,
+			vec![1u8].try_into().unwrap()
+		));
+
+		let payout_balance = Balances::free_balance(EconomyModule::get_reward_payout_account_id());
+		let treasury_balance = Balances::free_balance(EconomyModule::economy_pallet_account_id());
+		assert_ok!(EconomyModule::confirm_offence(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			STAKE_BALANCE
+		));
+
+		// 100 to the reporter, the remaining 900 split 50% / 20% / 30%
+		let penalty_redistributed = RuntimeEvent::Economy(crate::Event::PenaltyRedistributed(BOB, 450, 270, 180));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == penalty_redistributed));
+		assert_eq!(
+			Balances::free_balance(EconomyModule::get_reward_payout_account_id()),
+			payout_balance + 450
+		);
+		assert_eq!(
+			Balances::free_balance(EconomyModule::economy_pallet_account_id()),
+			treasury_balance + 180
+		);
+		assert_eq!(EconomyModule::insurance_fund_balance(), 270);
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0)),
+			Some(&(450u128, 0u128))
+		);
+	});
+}
+
+#[test]
+fn staking_should_fail_when_round_goes_backwards() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::last_seen_round(), CURRENT_ROUND);
+
+		// Round handler returns a round behind the last seen round, e.g. mid-migration
+		LastSeenRound::<Runtime>::put(CURRENT_ROUND + 5);
+		assert_noop!(
+			EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), STAKE_BALANCE),
+			Error::<Runtime>::RoundDataUnavailable
+		);
+		assert_noop!(
+			EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None),
+			Error::<Runtime>::RoundDataUnavailable
+		);
+
+		LastSeenRound::<Runtime>::put(CURRENT_ROUND);
+		assert_ok!(EconomyModule::unstake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+	});
+}
+
+#[test]
+fn exit_entries_should_report_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 200, None));
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(ALICE),
+			300,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 400));
+
+		let next_round: RoundIndex = CURRENT_ROUND + 1;
+		let innovation_round: RoundIndex = CURRENT_ROUND + INNOVATION_UNBOND_ROUNDS;
+		let entries = EconomyModule::exit_entries(&ALICE);
+		assert_eq!(entries.len(), 3);
+		assert!(entries.contains(&ExitEntry {
+			kind: ExitQueueKind::Economy101,
+			round: next_round,
+			amount: 200,
+			estimated_unlock_block: EconomyModule::estimated_round_start(next_round),
+			ready: false,
+		}));
+		assert!(entries.contains(&ExitEntry {
+			kind: ExitQueueKind::Estate(OWNED_ESTATE_ID),
+			round: next_round,
+			amount: 300,
+			estimated_unlock_block: EconomyModule::estimated_round_start(next_round),
+			ready: false,
+		}));
+		assert_eq!(
+			entries[2],
+			ExitEntry {
+				kind: ExitQueueKind::Innovation,
+				round: innovation_round,
+				amount: 400,
+				estimated_unlock_block: EconomyModule::estimated_round_start(innovation_round),
+				ready: false,
+			}
+		);
+
+		run_to_block(25);
+		let entries = EconomyModule::exit_entries(&ALICE);
+		assert!(entries[0].ready && entries[1].ready);
+		assert!(!entries[2].ready);
+	});
+}
+
+#[test]
+fn staking_ledger_should_consolidate_positions() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(EconomyModule::staking_ledger(&ALICE), StakingLedger::default());
+
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(ALICE), STAKE_BALANCE, None));
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::unstake(RuntimeOrigin::signed(ALICE), 200, None));
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 400));
+
+		let ledger = EconomyModule::staking_ledger(&ALICE);
+		assert_eq!(ledger.active_self_stake, STAKE_BALANCE - 200);
+		assert_eq!(ledger.locked_self_stake, 0);
+		assert_eq!(ledger.active_estate_stakes, vec![(OWNED_ESTATE_ID, STAKE_BALANCE)]);
+		assert_eq!(ledger.active_innovation_stake, STAKE_BALANCE - 400);
+		assert_eq!(
+			ledger.innovation_shares,
+			EconomyModule::shares_and_withdrawn_rewards(&ALICE).0
+		);
+		assert_eq!(ledger.unlocking, EconomyModule::exit_entries(&ALICE));
+		assert_eq!(ledger.unlocking.len(), 2);
+		assert_eq!(EconomyModule::staked_estate(ALICE, OWNED_ESTATE_ID), Some(()));
+
+		// Estate stakes are looked up by account
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::staked_estate(ALICE, OWNED_ESTATE_ID), None);
+		assert!(EconomyModule::staking_ledger(&ALICE).active_estate_stakes.is_empty());
+	});
+}
+
+#[test]
+fn innovation_staker_cap_should_apply_to_every_new_staker_path() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&FREEDY, 10000);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::set_max_innovation_stakers(
+			RuntimeOrigin::root(),
+			Some(1)
+		));
+
+		// Switching self-stake into innovation staking
+		assert_ok!(EconomyModule::stake(RuntimeOrigin::signed(BOB), STAKE_BALANCE, None));
+		assert_noop!(
+			EconomyModule::switch_stake(
+				RuntimeOrigin::signed(BOB),
+				500,
+				PoolKind::Economy101,
+				PoolKind::Innovation
+			),
+			Error::<Runtime>::InnovationStakerCapReached
+		);
+
+		// Joining a pool stakes through a new pool account
+		assert_ok!(EconomyModule::create_pool(RuntimeOrigin::signed(BOB), Perbill::zero()));
+		assert_noop!(
+			EconomyModule::join_pool(RuntimeOrigin::signed(FREEDY), 0, 50),
+			Error::<Runtime>::InnovationStakerCapReached
+		);
+
+		// Minting a position receipt stakes through a new position account
+		init_test_nft(RuntimeOrigin::signed(ALICE), 0, 0);
+		assert_ok!(EconomyModule::set_position_receipt_class(RuntimeOrigin::root(), 0));
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			}
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::InnovationStakerWaitlisted(ALICE, STAKE_BALANCE, 1))
+		);
+		assert_eq!(EconomyModule::staking_positions((0, 1)), None);
+		assert_eq!(EconomyModule::innovation_staker_count(), 1);
+
+		// Existing stakers keep adding stake without a slot
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference::default()
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), 2 * STAKE_BALANCE);
+	});
+}
+
+#[test]
+fn innovation_staker_cap_should_waitlist_and_admit_in_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&FREEDY, 10000);
+		assert_ok!(EconomyModule::set_max_innovation_stakers(
+			RuntimeOrigin::root(),
+			Some(1)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::MaxInnovationStakersUpdated(Some(1)))
+		);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::InnovationStakerWaitlisted(BOB, STAKE_BALANCE, 1))
+		);
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(FREEDY),
+			STAKE_BALANCE
+		));
+		assert_noop!(
+			EconomyModule::stake_on_innovation(RuntimeOrigin::signed(BOB), STAKE_BALANCE),
+			Error::<Runtime>::AlreadyInInnovationWaitlist
+		);
+
+		assert_eq!(EconomyModule::get_innovation_staking_info(BOB), 0);
+		assert_eq!(Balances::reserved_balance(BOB), STAKE_BALANCE);
+		assert_eq!(EconomyModule::innovation_staker_count(), 1);
+
+		assert_ok!(EconomyModule::unstake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		run_to_block(2);
+
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event
+				== RuntimeEvent::Economy(crate::Event::InnovationStakerAdmitted(BOB, STAKE_BALANCE))));
+		assert_eq!(EconomyModule::get_innovation_staking_info(BOB), STAKE_BALANCE);
+		assert_eq!(EconomyModule::get_innovation_staking_info(FREEDY), 0);
+		assert_eq!(
+			EconomyModule::innovation_staker_waitlist().into_inner(),
+			vec![(FREEDY, STAKE_BALANCE)]
+		);
+
+		assert_ok!(EconomyModule::leave_innovation_waitlist(RuntimeOrigin::signed(FREEDY)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::InnovationWaitlistLeft(FREEDY, STAKE_BALANCE))
+		);
+		assert_eq!(Balances::reserved_balance(FREEDY), 0);
+		assert_noop!(
+			EconomyModule::leave_innovation_waitlist(RuntimeOrigin::signed(FREEDY)),
+			Error::<Runtime>::NotInInnovationWaitlist
+		);
+	});
+}
+
+#[test]
+fn reward_ledger_should_record_opted_in_payouts() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		assert!(EconomyModule::account_reward_ledger(&ALICE).is_empty());
+
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				record_reward_ledger: true,
+				..Default::default()
+			}
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 500));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		run_to_block(2);
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 300));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+
+		let ledger = EconomyModule::account_reward_ledger(&ALICE);
+		assert_eq!(ledger.len(), 2);
+		assert_eq!(
+			ledger[0],
+			RewardLedgerEntry {
+				era: EconomyModule::current_era(),
+				currency_id: FungibleTokenId::NativeToken(0),
+				amount: 500,
+				block: 1,
+			}
+		);
+		assert_eq!(
+			EconomyModule::reward_ledger_page(&ALICE, 1, 10),
+			vec![ledger[1].clone()]
+		);
+		assert_eq!(EconomyModule::reward_ledger_page(&ALICE, 0, 1), vec![ledger[0].clone()]);
+		assert_eq!(ledger[1].amount, 300);
+		assert_eq!(ledger[1].block, 2);
+	});
+}
+
+#[test]
+fn reward_ledger_should_be_pruned_and_retained() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::set_preferences(
+			RuntimeOrigin::signed(ALICE),
+			AccountPreference {
+				record_reward_ledger: true,
+				..Default::default()
+			}
+		));
+		for reward in [100, 200, 300] {
+			assert_ok!(EconomyModule::accumulate_reward(
+				FungibleTokenId::NativeToken(0),
+				reward
+			));
+			assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		}
+		assert_eq!(EconomyModule::account_reward_ledger(&ALICE).len(), 3);
+
+		assert_ok!(EconomyModule::prune_my_history(RuntimeOrigin::signed(ALICE), 2));
+		let ledger = EconomyModule::account_reward_ledger(&ALICE);
+		assert_eq!(
+			ledger.iter().map(|entry| entry.amount).collect::<Vec<_>>(),
+			vec![200, 300]
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::RewardLedgerPruned(ALICE, 1))
+		);
+
+		assert_noop!(
+			EconomyModule::set_reward_ledger_retention(RuntimeOrigin::root(), Some(MAX_REWARD_LEDGER_ENTRIES + 1)),
+			Error::<Runtime>::InvalidRewardLedgerRetention
+		);
+		assert_ok!(EconomyModule::set_reward_ledger_retention(
+			RuntimeOrigin::root(),
+			Some(1)
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 400));
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		let ledger = EconomyModule::account_reward_ledger(&ALICE);
+		assert_eq!(ledger.iter().map(|entry| entry.amount).collect::<Vec<_>>(), vec![400]);
+
+		assert_ok!(EconomyModule::prune_my_history(RuntimeOrigin::signed(ALICE), 0));
+		assert!(EconomyModule::account_reward_ledger(&ALICE).is_empty());
+	});
+}
+
+#[test]
+fn innovation_projects_should_be_ranked_by_directed_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::register_innovation_project(
+				RuntimeOrigin::signed(BOB),
+				b"bafy".to_vec().try_into().unwrap(),
+				50
+			),
+			Error::<Runtime>::ProjectDepositBelowMinimum
+		);
+		for _ in 0..3 {
+			assert_ok!(EconomyModule::register_innovation_project(
+				RuntimeOrigin::signed(BOB),
+				b"bafy".to_vec().try_into().unwrap(),
+				100
+			));
+		}
+		assert_eq!(Balances::reserved_balance(BOB), 300);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::direct_innovation_stake(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			200
+		));
+		assert_ok!(EconomyModule::direct_innovation_stake(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			500
+		));
+		assert_ok!(EconomyModule::direct_innovation_stake(
+			RuntimeOrigin::signed(ALICE),
+			2,
+			100
+		));
+		assert_noop!(
+			EconomyModule::direct_innovation_stake(RuntimeOrigin::signed(ALICE), 2, 201),
+			Error::<Runtime>::DirectedStakeExceedsInnovationStake
+		);
+
+		assert_ok!(EconomyModule::update_current_era(1));
+		assert!(EconomyModule::top_innovation_projects().is_empty());
+		assert!(EconomyModule::top_innovation_projects_scan().is_some());
+		EconomyModule::on_idle(1, Weight::MAX);
+		assert_eq!(EconomyModule::top_innovation_projects_scan(), None);
+		assert_eq!(EconomyModule::top_innovation_projects(), vec![(1, 500), (0, 200)]);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::TopInnovationProjectsSelected(1, vec![(1, 500), (0, 200)]))));
+
+		assert_ok!(EconomyModule::withdraw_directed_stake(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			450
+		));
+		assert_noop!(
+			EconomyModule::deregister_innovation_project(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::NotInnovationProjectOwner
+		);
+		assert_ok!(EconomyModule::deregister_innovation_project(
+			RuntimeOrigin::signed(BOB),
+			0
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 200);
+
+		assert_ok!(EconomyModule::update_current_era(1));
+		EconomyModule::on_idle(2, Weight::MAX);
+		assert_eq!(EconomyModule::top_innovation_projects(), vec![(2, 100), (1, 50)]);
+
+		// Directed stake is released once the innovation stake falls below it
+		assert_ok!(EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 900));
+		assert_eq!(EconomyModule::account_directed_stake(ALICE), 0);
+		assert_eq!(EconomyModule::innovation_project_stake(1), 0);
+		assert_eq!(EconomyModule::innovation_project_stake(2), 0);
+	});
+}
+
+#[test]
+fn project_matching_should_top_up_by_staker_breadth() {
+	ExtBuilder::default().build().execute_with(|| {
+		let treasury = EconomyModule::economy_pallet_account_id();
+		Balances::make_free_balance_be(&treasury, 10000);
+		for _ in 0..2 {
+			assert_ok!(EconomyModule::register_innovation_project(
+				RuntimeOrigin::signed(BOB),
+				b"bafy".to_vec().try_into().unwrap(),
+				100
+			));
+		}
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(BOB),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::direct_innovation_stake(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			400
+		));
+		assert_ok!(EconomyModule::direct_innovation_stake(
+			RuntimeOrigin::signed(BOB),
+			0,
+			100
+		));
+		assert_ok!(EconomyModule::direct_innovation_stake(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			400
+		));
+
+		// Matching is disabled by default
+		assert_ok!(EconomyModule::update_current_era(1));
+		assert_eq!(EconomyModule::project_matching_scan(), None);
+		assert_eq!(EconomyModule::project_matching_pool(0), 0);
+
+		assert_ok!(EconomyModule::set_project_matching(
+			RuntimeOrigin::root(),
+			Some(ProjectMatchingConfig {
+				mode: MatchingMode::Linear,
+				era_cap: 300,
+				project_cap: 150,
+			})
+		));
+		assert_ok!(EconomyModule::update_current_era(1));
+		assert!(EconomyModule::project_matching_scan().is_some());
+		EconomyModule::on_idle(2, Weight::MAX);
+		assert_eq!(EconomyModule::project_matching_scan(), None);
+		assert_eq!(EconomyModule::project_matching_pool(0), 150);
+		assert_eq!(EconomyModule::project_matching_pool(1), 100);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::ProjectRewardsMatched(2, vec![(0, 150), (1, 100)]))));
+
+		assert_ok!(EconomyModule::set_project_matching(
+			RuntimeOrigin::root(),
+			Some(ProjectMatchingConfig {
+				mode: MatchingMode::Quadratic,
+				era_cap: 300,
+				project_cap: 150,
+			})
+		));
+		assert_ok!(EconomyModule::update_current_era(1));
+		EconomyModule::on_idle(3, Weight::MAX);
+		assert_eq!(EconomyModule::project_matching_pool(0), 300);
+		assert_eq!(EconomyModule::project_matching_pool(1), 192);
+		assert_eq!(Balances::free_balance(treasury), 10000 - 300 - 192);
+
+		assert_noop!(
+			EconomyModule::claim_project_matching(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::NotInnovationProjectOwner
+		);
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::claim_project_matching(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 300);
+		assert_noop!(
+			EconomyModule::claim_project_matching(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NoProjectMatchingToClaim
+		);
+	});
+}
+
+#[test]
+fn contiguous_estate_stake_should_be_boosted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EconomyModule::set_estate_boost_tiers(
+				RuntimeOrigin::root(),
+				vec![
+					EstateBoostTier {
+						min_land_units: 10,
+						boost: FixedU128::saturating_from_integer(2u128),
+					},
+					EstateBoostTier {
+						min_land_units: 10,
+						boost: FixedU128::saturating_from_integer(3u128),
+					},
+				]
+				.try_into()
+				.unwrap()
+			),
+			Error::<Runtime>::InvalidEstateBoostTiers
+		);
+		assert_ok!(EconomyModule::set_estate_boost_tiers(
+			RuntimeOrigin::root(),
+			vec![
+				EstateBoostTier {
+					min_land_units: 5,
+					boost: FixedU128::saturating_from_rational(3, 2),
+				},
+				EstateBoostTier {
+					min_land_units: 10,
+					boost: FixedU128::saturating_from_integer(2u128),
+				},
+				EstateBoostTier {
+					min_land_units: 20,
+					boost: FixedU128::saturating_from_integer(3u128),
+				},
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateBoostTiersUpdated(3))
+		);
+
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			400,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::weighted_estate_stake(OWNED_ESTATE_ID, ALICE), 800);
+		// Fragmented estates are not boosted
+		assert_eq!(EconomyModule::estate_reward_weight(2), FixedU128::one());
+	});
+}
+
+#[test]
+fn credit_line_should_be_liquidated_from_stake_when_not_repaid() {
+	let lending_pot: AccountId = LendingPotPalletId::get().into_account_truncating();
+	ExtBuilder::default()
+		.balances(vec![(lending_pot.clone(), MiningCurrencyId::get(), 10000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 100),
+				Error::<Runtime>::CreditLinesDisabled
+			);
+			assert_ok!(EconomyModule::set_credit_line_config(
+				RuntimeOrigin::root(),
+				Some(CreditLineConfig {
+					ltv: Perbill::from_percent(50),
+					bit_per_native: FixedU128::saturating_from_integer(2u128),
+					interest_per_era: Perbill::from_percent(10),
+					term_eras: 3,
+				})
+			));
+			assert_ok!(EconomyModule::stake_on_innovation(
+				RuntimeOrigin::signed(ALICE),
+				STAKE_BALANCE
+			));
+
+			assert_noop!(
+				EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 1001),
+				Error::<Runtime>::BorrowExceedsCreditLimit
+			);
+			assert_ok!(EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 600));
+			assert_eq!(OrmlTokens::free_balance(MiningCurrencyId::get(), &ALICE), 600);
+			assert_noop!(
+				EconomyModule::unstake_on_innovation(RuntimeOrigin::signed(ALICE), 500),
+				Error::<Runtime>::CollateralLockedByCreditLine
+			);
+
+			assert_ok!(EconomyModule::repay_credit(RuntimeOrigin::signed(ALICE), 100));
+			assert_eq!(
+				EconomyModule::credit_line(ALICE),
+				Some(CreditLine {
+					debt: 500,
+					due_era: 3,
+					accrued_era: 0
+				})
+			);
+
+			assert_ok!(EconomyModule::update_current_era(1));
+			EconomyModule::on_idle(1, Weight::MAX);
+			assert_eq!(EconomyModule::credit_line_scan(), None);
+			assert_eq!(EconomyModule::credit_line(ALICE).map(|line| line.debt), Some(550));
+
+			// Interest of two more eras is added before the due credit line is liquidated
+			assert_ok!(EconomyModule::update_current_era(2));
+			EconomyModule::on_idle(2, Weight::MAX);
+			assert_eq!(EconomyModule::credit_line(ALICE), None);
+			assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), 667);
+			assert_eq!(Balances::free_balance(lending_pot), 333);
+			assert!(System::events().iter().any(
+				|record| record.event == RuntimeEvent::Economy(crate::Event::CreditLineLiquidated(ALICE, 666, 333))
+			));
+		});
+}
+
+#[test]
+fn credit_line_should_accrue_interest_when_touched() {
+	let lending_pot: AccountId = LendingPotPalletId::get().into_account_truncating();
+	ExtBuilder::default()
+		.balances(vec![
+			(lending_pot.clone(), MiningCurrencyId::get(), 10000),
+			(ALICE, MiningCurrencyId::get(), 100),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EconomyModule::set_credit_line_config(
+				RuntimeOrigin::root(),
+				Some(CreditLineConfig {
+					ltv: Perbill::from_percent(50),
+					bit_per_native: FixedU128::saturating_from_integer(2u128),
+					interest_per_era: Perbill::from_percent(10),
+					term_eras: 3,
+				})
+			));
+			assert_ok!(EconomyModule::stake_on_innovation(
+				RuntimeOrigin::signed(ALICE),
+				STAKE_BALANCE
+			));
+			assert_ok!(EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 600));
+
+			// The sweep has not run yet, the next draw accrues the interest first
+			assert_ok!(EconomyModule::update_current_era(1));
+			assert_eq!(EconomyModule::credit_line(ALICE).map(|line| line.debt), Some(600));
+			assert_noop!(
+				EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 341),
+				Error::<Runtime>::BorrowExceedsCreditLimit
+			);
+			assert_ok!(EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 340));
+			assert_eq!(
+				EconomyModule::credit_line(ALICE),
+				Some(CreditLine {
+					debt: 1000,
+					due_era: 3,
+					accrued_era: 1
+				})
+			);
+
+			// Repayment is capped at the debt and closes the credit line
+			assert_ok!(EconomyModule::repay_credit(RuntimeOrigin::signed(ALICE), 2000));
+			assert_eq!(EconomyModule::credit_line(ALICE), None);
+			assert_eq!(OrmlTokens::free_balance(MiningCurrencyId::get(), &ALICE), 40);
+			assert_eq!(OrmlTokens::free_balance(MiningCurrencyId::get(), &lending_pot), 10060);
+			assert_eq!(
+				last_event(),
+				RuntimeEvent::Economy(crate::Event::CreditRepaid(ALICE, 1000, 0))
+			);
+			assert_noop!(
+				EconomyModule::repay_credit(RuntimeOrigin::signed(ALICE), 1),
+				Error::<Runtime>::NoCreditLine
+			);
+
+			EconomyModule::on_idle(1, Weight::MAX);
+			assert_eq!(EconomyModule::credit_line_scan(), None);
+			assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), STAKE_BALANCE);
+		});
+}
+
+#[test]
+fn credit_line_over_limit_after_interest_should_be_liquidated() {
+	let lending_pot: AccountId = LendingPotPalletId::get().into_account_truncating();
+	ExtBuilder::default()
+		.balances(vec![(lending_pot.clone(), MiningCurrencyId::get(), 10000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EconomyModule::set_credit_line_config(
+				RuntimeOrigin::root(),
+				Some(CreditLineConfig {
+					ltv: Perbill::from_percent(50),
+					bit_per_native: FixedU128::saturating_from_integer(2u128),
+					interest_per_era: Perbill::from_percent(10),
+					term_eras: 10,
+				})
+			));
+			assert_ok!(EconomyModule::stake_on_innovation(
+				RuntimeOrigin::signed(ALICE),
+				STAKE_BALANCE
+			));
+			assert_ok!(EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 1000));
+			assert_noop!(
+				EconomyModule::borrow_against_stake(RuntimeOrigin::signed(ALICE), 1),
+				Error::<Runtime>::BorrowExceedsCreditLimit
+			);
+
+			// A single era of interest pushes the debt over the credit limit long before it is due
+			assert_ok!(EconomyModule::update_current_era(1));
+			EconomyModule::on_idle(1, Weight::MAX);
+			assert_eq!(EconomyModule::credit_line(ALICE), None);
+			assert_eq!(EconomyModule::get_innovation_staking_info(ALICE), 450);
+			assert_eq!(Balances::free_balance(lending_pot), 550);
+			assert!(System::events()
+				.iter()
+				.any(|record| record.event
+					== RuntimeEvent::Economy(crate::Event::CreditLineLiquidated(ALICE, 1100, 550))));
+		});
+}
+
+#[test]
+fn power_advance_should_be_repaid_from_rewards_and_escrow() {
+	let reward_payout_account = EconomyModule::get_reward_payout_account_id();
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, MiningCurrencyId::get(), 1000),
+			(reward_payout_account, MiningCurrencyId::get(), 10000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				EconomyModule::request_power_advance(RuntimeOrigin::signed(ALICE), 100),
+				Error::<Runtime>::PowerAdvancesDisabled
+			);
+			assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+				RuntimeOrigin::root(),
+				FixedU128::saturating_from_integer(2u128),
+				ConversionRounding::Down
+			));
+			assert_ok!(EconomyModule::set_power_advance_config(
+				RuntimeOrigin::root(),
+				Some(PowerAdvanceConfig {
+					fee: Perbill::from_percent(10),
+					repayment_rounds: 1,
+				})
+			));
+
+			assert_ok!(EconomyModule::request_power_advance(RuntimeOrigin::signed(ALICE), 100));
+			assert_eq!(EconomyModule::get_power_balance(ALICE), 100);
+			assert_eq!(OrmlTokens::reserved_balance(MiningCurrencyId::get(), &ALICE), 220);
+			assert_noop!(
+				EconomyModule::request_power_advance(RuntimeOrigin::signed(ALICE), 100),
+				Error::<Runtime>::PowerAdvanceAlreadyOpen
+			);
+
+			// BIT staking rewards repay the advance first
+			assert_ok!(EconomyModule::stake_on_innovation(
+				RuntimeOrigin::signed(ALICE),
+				STAKE_BALANCE
+			));
+			assert_ok!(EconomyModule::accumulate_reward(MiningCurrencyId::get(), 100));
+			assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(OrmlTokens::free_balance(MiningCurrencyId::get(), &ALICE), 780);
+			assert_eq!(
+				EconomyModule::power_advance(ALICE).map(|advance| advance.debt),
+				Some(120)
+			);
+
+			assert_noop!(
+				EconomyModule::settle_power_advance(RuntimeOrigin::signed(BOB), ALICE),
+				Error::<Runtime>::PowerAdvanceNotDue
+			);
+			run_to_block(50);
+			assert_ok!(EconomyModule::settle_power_advance(RuntimeOrigin::signed(BOB), ALICE));
+			assert_eq!(
+				last_event(),
+				RuntimeEvent::Economy(crate::Event::PowerAdvanceDefaulted(ALICE, 120))
+			);
+			assert_eq!(OrmlTokens::reserved_balance(MiningCurrencyId::get(), &ALICE), 0);
+			assert_eq!(OrmlTokens::free_balance(MiningCurrencyId::get(), &ALICE), 880);
+			assert_eq!(
+				OrmlTokens::free_balance(MiningCurrencyId::get(), &EconomyModule::economy_pallet_account_id()),
+				220
+			);
+			assert_eq!(EconomyModule::power_advance(ALICE), None);
+		});
+}
+
+#[test]
+fn cross_chain_reward_should_stay_local_when_transfer_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_hub = MultiLocation::new(1, xcm::v3::Junctions::X1(Junction::Parachain(1000)));
+		let destination = CrossChainRewardDestination {
+			chain: asset_hub,
+			beneficiary: [2; 32],
+		};
+		assert_noop!(
+			EconomyModule::set_cross_chain_reward_destination(RuntimeOrigin::signed(ALICE), Some(destination.clone())),
+			Error::<Runtime>::CrossChainRewardChainNotAllowed
+		);
+		assert_ok!(EconomyModule::set_cross_chain_reward_chain(
+			RuntimeOrigin::root(),
+			asset_hub,
+			true
+		));
+		assert_ok!(EconomyModule::set_cross_chain_reward_destination(
+			RuntimeOrigin::signed(ALICE),
+			Some(destination)
+		));
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+
+		// Mock runtime has no cross-chain transfer, the reward stays on the local account
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 1000);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::CrossChainRewardRefunded(
+				ALICE,
+				FungibleTokenId::NativeToken(0),
+				1000
+			))));
+	});
+}
+
+#[test]
+fn claim_reward_to_location_should_require_whitelisted_chain() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_hub = MultiLocation::new(1, xcm::v3::Junctions::X1(Junction::Parachain(1000)));
+		let beneficiary = asset_hub
+			.pushed_with_interior(Junction::AccountId32 {
+				network: None,
+				id: [2; 32],
+			})
+			.unwrap();
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 1000));
+
+		if !cfg!(feature = "foreign-beneficiary") {
+			assert_noop!(
+				EconomyModule::claim_reward_to_location(RuntimeOrigin::signed(ALICE), beneficiary),
+				Error::<Runtime>::ForeignBeneficiaryDisabled
+			);
+			return;
+		}
+
+		assert_noop!(
+			EconomyModule::claim_reward_to_location(RuntimeOrigin::signed(ALICE), beneficiary),
+			Error::<Runtime>::CrossChainRewardChainNotAllowed
+		);
+		assert_ok!(EconomyModule::set_cross_chain_reward_chain(
+			RuntimeOrigin::root(),
+			asset_hub,
+			true
+		));
+
+		// Mock runtime has no cross-chain transfer, the reward stays on the local account
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::claim_reward_to_location(
+			RuntimeOrigin::signed(ALICE),
+			beneficiary
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 1000);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::CrossChainRewardRefunded(
+				ALICE,
+				FungibleTokenId::NativeToken(0),
+				1000
+			))));
+	});
+}
+
+#[test]
+fn stake_with_lock_should_work_with_vesting_locked_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::set_lock(*b"vesting ", &account(1), FREE_BALANCE, WithdrawReasons::all());
+		assert!(EconomyModule::stake(RuntimeOrigin::signed(account(1)), STAKE_BALANCE, None).is_err());
+
+		assert_ok!(EconomyModule::stake_with_lock(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::SelfStakedWithLockToEconomy101(account(1), STAKE_BALANCE))
+		);
+		assert_eq!(EconomyModule::get_staking_info(account(1)), STAKE_BALANCE);
+		assert_eq!(EconomyModule::locked_self_stake(account(1)), STAKE_BALANCE);
+		assert_eq!(Balances::free_balance(account(1)), FREE_BALANCE);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+
+		assert_noop!(
+			EconomyModule::stake_with_lock(RuntimeOrigin::signed(account(1)), FREE_BALANCE),
+			Error::<Runtime>::InsufficientBalanceForStaking
+		);
+		assert_noop!(
+			EconomyModule::switch_stake(
+				RuntimeOrigin::signed(account(1)),
+				UNSTAKE_AMOUNT,
+				PoolKind::Economy101,
+				PoolKind::Innovation
+			),
+			Error::<Runtime>::LockedStakeNotTransferable
+		);
+
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(account(1)),
+			UNSTAKE_AMOUNT,
+			None
+		));
+		let next_round: RoundIndex = CURRENT_ROUND.saturating_add(1);
+
+		run_to_block(25);
+		assert_ok!(EconomyModule::withdraw_unreserved(
+			RuntimeOrigin::signed(account(1)),
+			next_round
+		));
+		assert_eq!(
+			EconomyModule::locked_self_stake(account(1)),
+			STAKE_BALANCE - UNSTAKE_AMOUNT
+		);
+		assert_eq!(Balances::locks(account(1)).len(), 2);
+		assert_eq!(Balances::free_balance(account(1)), FREE_BALANCE);
+	});
+}
+
+#[test]
+fn keeper_maintenance_calls_should_be_free_and_rate_limited() {
+	ExtBuilder::default().build().execute_with(|| {
+		let accounts: BoundedVec<AccountId, ConstU32<MAX_KEEPER_BATCH>> = vec![ALICE].try_into().unwrap();
+
+		assert_noop!(
+			EconomyModule::set_keeper(RuntimeOrigin::signed(ALICE), BOB, true),
+			BadOrigin
+		);
+		assert_noop!(
+			EconomyModule::keeper_restake_matured_exits(RuntimeOrigin::signed(BOB), accounts.clone()),
+			Error::<Runtime>::NotAuthorizedKeeper
+		);
+
+		assert_ok!(EconomyModule::set_keeper(RuntimeOrigin::root(), BOB, true));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::KeeperUpdated(BOB, true))
+		);
+
+		assert_noop!(
+			EconomyModule::keeper_update_era(RuntimeOrigin::signed(BOB)),
+			Error::<Runtime>::NoEraUpdateDue
+		);
+
+		for _ in 0..MAX_KEEPER_CALLS_PER_BLOCK {
+			let post_info =
+				EconomyModule::keeper_restake_matured_exits(RuntimeOrigin::signed(BOB), accounts.clone()).unwrap();
+			assert_eq!(post_info.pays_fee, Pays::No);
+		}
+		assert_noop!(
+			EconomyModule::keeper_restake_matured_exits(RuntimeOrigin::signed(BOB), accounts.clone()),
+			Error::<Runtime>::KeeperRateLimitExceeded
+		);
+
+		run_to_block(2);
+		assert_ok!(EconomyModule::keeper_restake_matured_exits(
+			RuntimeOrigin::signed(BOB),
+			accounts.clone()
+		));
+
+		assert_ok!(EconomyModule::set_keeper(RuntimeOrigin::root(), BOB, false));
+		assert_noop!(
+			EconomyModule::keeper_restake_matured_exits(RuntimeOrigin::signed(BOB), accounts),
+			Error::<Runtime>::NotAuthorizedKeeper
+		);
+	});
+}
+
+#[test]
+fn set_position_memo_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let memo: BoundedVec<u8, ConstU32<MAX_POSITION_MEMO_LENGTH>> = b"fund-a/treasury".to_vec().try_into().unwrap();
+
+		assert_noop!(
+			EconomyModule::set_position_memo(RuntimeOrigin::signed(account(1)), Some(memo.clone())),
+			Error::<Runtime>::NotInnovationStaker
+		);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+		));
+		assert_ok!(EconomyModule::set_position_memo(
+			RuntimeOrigin::signed(account(1)),
+			Some(memo.clone())
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PositionMemoUpdated(account(1), Some(memo.clone())))
+		);
+		assert_eq!(EconomyModule::position_memo(account(1)), Some(memo));
+
+		assert_ok!(EconomyModule::set_position_memo(
+			RuntimeOrigin::signed(account(1)),
+			None
+		));
+		assert_eq!(EconomyModule::position_memo(account(1)), None);
+	});
+}
+
+#[test]
+fn quote_power_purchase_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+			RuntimeOrigin::root(),
+			FixedU128::saturating_from_integer(2u128),
+			ConversionRounding::Up
+		));
+		assert_noop!(
+			EconomyModule::set_domain_power_commission(RuntimeOrigin::signed(ALICE), 1, Perbill::from_percent(10)),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_domain_power_commission(
+			RuntimeOrigin::root(),
+			1,
+			Perbill::from_percent(10)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::DomainPowerCommissionUpdated(1, Perbill::from_percent(10)))
+		);
+
+		// 30 power at 10 power per block is executed 3 blocks later
+		assert_eq!(
+			EconomyModule::quote_power_purchase(30, 1),
+			Ok(PowerPurchaseQuote {
+				bit_cost: 66,
+				commission: 6,
+				target_execution_block: System::block_number() + 3,
+			})
+		);
+		assert_eq!(
+			EconomyModule::quote_power_purchase(5, 2),
+			Ok(PowerPurchaseQuote {
+				bit_cost: 10,
+				commission: 0,
+				target_execution_block: System::block_number() + 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn failed_reward_payout_should_be_kept_for_retry() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+		));
+
+		let currency_id = FungibleTokenId::FungibleToken(5);
+		let mut reward_map: BTreeMap<FungibleTokenId, u128> = BTreeMap::new();
+		reward_map.insert(currency_id, 100);
+		PendingRewardsOfStakingInnovation::<Runtime>::insert(account(1), reward_map);
+
+		// The reward payout account holds none of the reward currency
+		assert_ok!(EconomyModule::claim_reward(RuntimeOrigin::signed(account(1))));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::PayoutFailed(account(1), currency_id, 100))
+		);
+		assert_eq!(EconomyModule::failed_payouts(account(1), currency_id), 100);
+		assert_eq!(
+			EconomyModule::pending_multi_rewards(account(1))
+				.get(&currency_id)
+				.copied()
+				.unwrap_or_default(),
+			0
+		);
+
+		assert!(EconomyModule::retry_failed_payout(RuntimeOrigin::signed(account(1)), currency_id).is_err());
+		assert_eq!(EconomyModule::failed_payouts(account(1), currency_id), 100);
+
+		assert_ok!(OrmlTokens::deposit(
+			currency_id,
+			&EconomyModule::get_reward_payout_account_id(),
+			100
+		));
+		assert_ok!(EconomyModule::retry_failed_payout(
+			RuntimeOrigin::signed(account(1)),
+			currency_id
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ClaimRewards(account(1), currency_id, 100))
+		);
+		assert_eq!(OrmlTokens::free_balance(currency_id, &account(1)), 100);
+		assert_noop!(
+			EconomyModule::retry_failed_payout(RuntimeOrigin::signed(account(1)), currency_id),
+			Error::<Runtime>::NoFailedPayout
+		);
+	});
+}
+
+#[test]
+fn estimated_reward_per_era_should_scale_with_innovation_stake_rate() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::update_era_config(
+			RuntimeOrigin::root(),
+			None,
+			None,
+			Some(EraRewardBudget::Rate(Perbill::from_percent(10))),
+			None
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstimatedRewardPerEraUpdated(EraRewardBudget::Rate(
+				Perbill::from_percent(10)
+			)))
+		);
+		assert_eq!(EconomyModule::estimated_reward_per_era(), 0);
+
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+		));
+		assert_eq!(EconomyModule::estimated_reward_per_era(), STAKE_BALANCE / 10);
+
+		EstimatedStakingRewardPerEra::<Runtime>::set(EraRewardBudget::Amount(500u128));
+		assert_eq!(EconomyModule::estimated_reward_per_era(), 500);
+	});
+}
+
+#[test]
+fn migrate_to_v4_should_keep_absolute_reward_per_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(3).put::<EconomyModule>();
+		frame_support::storage::unhashed::put_raw(
+			&EstimatedStakingRewardPerEra::<Runtime>::hashed_key(),
+			&500u128.encode(),
+		);
+
+		migrations::v4::MigrateToV4::<Runtime>::on_runtime_upgrade();
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(4));
+		assert_eq!(
+			EstimatedStakingRewardPerEra::<Runtime>::get(),
+			EraRewardBudget::Amount(500u128)
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v4_should_translate_reward_per_era_before_v3_is_stepped() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(2).put::<EconomyModule>();
+		frame_support::storage::unhashed::put_raw(
+			&EstimatedStakingRewardPerEra::<Runtime>::hashed_key(),
+			&500u128.encode(),
+		);
+
+		migrations::v4::MigrateToV4::<Runtime>::on_runtime_upgrade();
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(2));
+		assert_eq!(
+			EstimatedStakingRewardPerEra::<Runtime>::get(),
+			EraRewardBudget::Amount(500u128)
+		);
+
+		// The version is bumped once the exit queues are migrated, keeping the translated value
+		let (cursor, _) = migrations::v3::MigrateToV3::<Runtime>::step(None, Weight::MAX);
+		assert_eq!(cursor, None);
+		assert!(migrations::v4::MigrateToV4::<Runtime>::is_applicable());
+		let (cursor, _) = migrations::v4::MigrateToV4::<Runtime>::step(None, Weight::MAX);
+		assert_eq!(cursor, None);
+		assert_eq!(EconomyModule::on_chain_storage_version(), StorageVersion::new(4));
+		assert_eq!(
+			EstimatedStakingRewardPerEra::<Runtime>::get(),
+			EraRewardBudget::Amount(500u128)
+		);
+	});
+}
+
+#[test]
+fn verify_account_should_report_reserve_ledger_discrepancy() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			STAKE_BALANCE,
+			None
+		));
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(account(1)),
+			UNSTAKE_AMOUNT,
+			None
+		));
+		assert_eq!(EconomyModule::reserved_stake_ledger(&account(1)), STAKE_BALANCE);
+
+		assert_ok!(EconomyModule::verify_account(RuntimeOrigin::signed(BOB), account(1)));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Economy(crate::Event::ReserveLedgerDiscrepancy(..))
+		)));
+
+		// Unreserving outside of the staking ledgers desynchronizes them
+		assert_ok!(EconomyModule::force_unreserved_staking(
+			RuntimeOrigin::root(),
+			UNSTAKE_AMOUNT,
+			account(1)
+		));
+		assert_ok!(EconomyModule::verify_account(RuntimeOrigin::signed(BOB), account(1)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::ReserveLedgerDiscrepancy(
+				account(1),
+				STAKE_BALANCE - UNSTAKE_AMOUNT,
+				STAKE_BALANCE
+			))
+		);
+	});
+}
+
+#[test]
+fn update_era_config_should_apply_scheduled_change_at_activation_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		UpdateEraFrequency::<Runtime>::set(100u64);
+		assert_noop!(
+			EconomyModule::update_era_config(RuntimeOrigin::root(), None, Some(50), None, Some(1)),
+			Error::<Runtime>::InvalidEraConfigActivationBlock
+		);
+
+		assert_ok!(EconomyModule::update_era_config(
+			RuntimeOrigin::root(),
+			None,
+			Some(50),
+			Some(EraRewardBudget::Amount(300u128)),
+			Some(10)
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EraConfigUpdateScheduled(10))
+		);
+		assert_eq!(EconomyModule::update_era_frequency(), 100);
+		assert_eq!(
+			EconomyModule::pending_era_config(),
+			Some(ScheduledEraConfig {
+				activation_block: 10,
+				frequency: Some(50),
+				reward_per_era: Some(EraRewardBudget::Amount(300u128)),
+			})
+		);
+
+		run_to_block(9);
+		assert_eq!(EconomyModule::update_era_frequency(), 100);
+
+		run_to_block(10);
+		assert_eq!(EconomyModule::update_era_frequency(), 50);
+		assert_eq!(EconomyModule::estimated_reward_per_era(), 300);
+		assert_eq!(EconomyModule::pending_era_config(), None);
+	});
+}
+
+#[test]
+fn frozen_reward_pool_should_stop_accumulating_and_drain_after_grace_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reward_pot = EconomyModule::get_reward_payout_account_id();
+		let treasury = EconomyModule::economy_pallet_account_id();
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+
+		assert_noop!(
+			EconomyModule::drain_reward_pool(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::RewardPoolNotFrozen
+		);
+		assert_noop!(EconomyModule::freeze_reward_pool(RuntimeOrigin::signed(BOB)), BadOrigin);
+		assert_ok!(EconomyModule::freeze_reward_pool(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(last_event(), RuntimeEvent::Economy(crate::Event::RewardPoolFrozen(1)));
+		assert_noop!(
+			EconomyModule::freeze_reward_pool(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::RewardPoolAlreadyFrozen
+		);
+
+		// No new rewards are added to the frozen pool
+		assert_ok!(EconomyModule::accumulate_reward(FungibleTokenId::NativeToken(0), 50));
+		assert_eq!(
+			EconomyModule::staking_reward_pool_info()
+				.rewards
+				.get(&FungibleTokenId::NativeToken(0))
+				.map(|(total_reward, _)| *total_reward),
+			Some(100)
+		);
+
+		assert_noop!(
+			EconomyModule::drain_reward_pool(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::RewardPoolDrainGracePeriodNotElapsed
+		);
+		run_to_block(101);
+		let treasury_balance = Balances::free_balance(&treasury);
+		assert_ok!(EconomyModule::drain_reward_pool(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::RewardPoolDrained(29900))
+		);
+		// Unclaimed rewards stay in the reward payout account
+		assert_eq!(Balances::free_balance(&reward_pot), 100);
+		assert_eq!(Balances::free_balance(&treasury), treasury_balance + 29900);
+	});
+}
+
+#[test]
+fn reward_pool_handler_should_expose_innovation_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake_on_innovation(
+			RuntimeOrigin::signed(ALICE),
+			STAKE_BALANCE
+		));
+		assert_ok!(<EconomyModule as RewardPoolHandler<
+			AccountId,
+			Balance,
+			FungibleTokenId,
+		>>::accumulate_reward(FungibleTokenId::NativeToken(0), 100));
+
+		let (total_shares, rewards) =
+			<EconomyModule as RewardPoolHandler<AccountId, Balance, FungibleTokenId>>::pool_info();
+		assert_eq!(total_shares, STAKE_BALANCE);
+		assert_eq!(rewards.get(&FungibleTokenId::NativeToken(0)), Some(&(100, 0)));
+		assert_eq!(
+			<EconomyModule as RewardPoolHandler<AccountId, Balance, FungibleTokenId>>::shares_and_withdrawn_rewards(
+				&ALICE
+			)
+			.0,
+			STAKE_BALANCE
+		);
+
+		<EconomyModule as RewardPoolHandler<AccountId, Balance, FungibleTokenId>>::claim_rewards(&ALICE);
+		assert_eq!(
+			<EconomyModule as RewardPoolHandler<AccountId, Balance, FungibleTokenId>>::pending_rewards(&ALICE),
+			vec![(FungibleTokenId::NativeToken(0), 100)]
+		);
+	});
+}
+
+#[test]
+fn estate_staking_since_should_track_bond_age() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			500,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::estate_staking_since(OWNED_ESTATE_ID), Some(1));
+
+		// Topping up the bond keeps its start block
+		run_to_block(5);
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(account(1)),
+			100,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::estate_staking_since(OWNED_ESTATE_ID), Some(1));
+		assert_eq!(EconomyModule::estate_stake_age(OWNED_ESTATE_ID), Some(4));
+
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(account(1)),
+			600,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::estate_staking_since(OWNED_ESTATE_ID), None);
+		assert_eq!(EconomyModule::estate_stake_age(OWNED_ESTATE_ID), None);
+	});
+}
+
+#[test]
+fn estate_rewards_should_be_paid_to_the_lessee_during_a_reward_lease() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reward_pot = EconomyModule::estate_reward_pot_account_id();
+		Balances::make_free_balance_be(&EconomyModule::economy_pallet_account_id(), 5000);
+		assert_noop!(
+			EconomyModule::fund_estate_rewards(RuntimeOrigin::signed(ALICE), 400),
+			Error::<Runtime>::NoWeightedEstateStake
+		);
+
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			400,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(EconomyModule::total_weighted_estate_stake(), 400);
+
+		assert_noop!(
+			EconomyModule::fund_estate_rewards(RuntimeOrigin::signed(BOB), 400),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::fund_estate_rewards(RuntimeOrigin::signed(ALICE), 400));
+		assert_eq!(Balances::free_balance(&reward_pot), 400);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateRewardsFunded(400))
+		);
+
+		assert_noop!(
+			EconomyModule::offer_estate_reward_lease(RuntimeOrigin::signed(BOB), OWNED_ESTATE_ID, 2, 300),
+			Error::<Runtime>::NoFundsStakedAtEstate
+		);
+		assert_noop!(
+			EconomyModule::offer_estate_reward_lease(RuntimeOrigin::signed(ALICE), OWNED_ESTATE_ID, 0, 300),
+			Error::<Runtime>::InvalidEstateRewardLease
+		);
+		assert_ok!(EconomyModule::offer_estate_reward_lease(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			2,
+			300
+		));
+		assert_noop!(
+			EconomyModule::accept_estate_reward_lease(RuntimeOrigin::signed(ALICE), OWNED_ESTATE_ID, ALICE, 300),
+			Error::<Runtime>::InvalidEstateRewardLease
+		);
+		assert_noop!(
+			EconomyModule::accept_estate_reward_lease(RuntimeOrigin::signed(BOB), OWNED_ESTATE_ID, ALICE, 200),
+			Error::<Runtime>::EstateRewardLeasePriceTooHigh
+		);
+
+		let bob_reserved = Balances::reserved_balance(BOB);
+		assert_ok!(EconomyModule::accept_estate_reward_lease(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID,
+			ALICE,
+			300
+		));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved + 300);
+		assert_eq!(EconomyModule::estate_reward_lease_offer(OWNED_ESTATE_ID, ALICE), None);
+		let lease = EconomyModule::estate_reward_lease(OWNED_ESTATE_ID, ALICE).unwrap();
+		assert_eq!(lease.lessee, BOB);
+		assert_eq!(lease.end_round, lease.start_round + 2);
+		assert_noop!(
+			EconomyModule::offer_estate_reward_lease(RuntimeOrigin::signed(ALICE), OWNED_ESTATE_ID, 2, 300),
+			Error::<Runtime>::EstateRewardsAlreadyLeased
+		);
+
+		// Rewards funded before the lease stay with the staker, rewards funded during the lease
+		// are paid to the lessee
+		assert_ok!(EconomyModule::fund_estate_rewards(RuntimeOrigin::signed(ALICE), 400));
+		let bob_free = Balances::free_balance(BOB);
+		assert_ok!(EconomyModule::claim_estate_reward(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID,
+			ALICE
+		));
+		assert_eq!(Balances::free_balance(BOB), bob_free + 400);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Economy(crate::Event::EstateRewardClaimed(BOB, 400))
+		);
+		let alice_free = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::claim_estate_reward(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			ALICE
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_free + 400);
+		assert_noop!(
+			EconomyModule::claim_estate_reward(RuntimeOrigin::signed(ALICE), OWNED_ESTATE_ID, ALICE),
+			Error::<Runtime>::NoEstateRewardToClaim
+		);
+
+		while Mining::get_current_round_info().current < lease.end_round {
+			run_to_block(System::block_number() + 1);
+		}
+		assert_eq!(
+			EconomyModule::estate_reward_per_weight_at(lease.end_round),
+			Some(FixedU128::saturating_from_integer(2u128))
+		);
+
+		// Rewards funded after the lease are paid to the staker along with the lease price
+		assert_ok!(EconomyModule::fund_estate_rewards(RuntimeOrigin::signed(ALICE), 400));
+		let alice_free = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::claim_estate_reward(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			ALICE
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_free + 300 + 400);
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(EconomyModule::estate_reward_lease(OWNED_ESTATE_ID, ALICE), None);
+		assert_eq!(EconomyModule::estate_reward_per_weight_at(lease.end_round), None);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::EstateRewardLeaseEnded(OWNED_ESTATE_ID, ALICE, BOB, 300))));
+		assert_eq!(Balances::free_balance(&reward_pot), 0);
+	});
+}
+
+#[test]
+fn estate_reward_lease_should_end_early_when_the_stake_is_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EconomyModule::stake(
+			RuntimeOrigin::signed(ALICE),
+			400,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_ok!(EconomyModule::offer_estate_reward_lease(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			2,
+			300
+		));
+		assert_ok!(EconomyModule::cancel_estate_reward_lease_offer(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID
+		));
+		assert_noop!(
+			EconomyModule::accept_estate_reward_lease(RuntimeOrigin::signed(BOB), OWNED_ESTATE_ID, ALICE, 300),
+			Error::<Runtime>::EstateRewardLeaseOfferDoesNotExist
+		);
+
+		assert_ok!(EconomyModule::offer_estate_reward_lease(
+			RuntimeOrigin::signed(ALICE),
+			OWNED_ESTATE_ID,
+			2,
+			300
+		));
+		let bob_reserved = Balances::reserved_balance(BOB);
+		assert_ok!(EconomyModule::accept_estate_reward_lease(
+			RuntimeOrigin::signed(BOB),
+			OWNED_ESTATE_ID,
+			ALICE,
+			300
+		));
+		let lease = EconomyModule::estate_reward_lease(OWNED_ESTATE_ID, ALICE).unwrap();
+		while Mining::get_current_round_info().current < lease.start_round + 1 {
+			run_to_block(System::block_number() + 1);
+		}
+
+		// Half of the lease elapsed, half of the price is paid
+		let alice_free = Balances::free_balance(ALICE);
+		assert_ok!(EconomyModule::unstake(
+			RuntimeOrigin::signed(ALICE),
+			400,
+			Some(OWNED_ESTATE_ID)
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_free + 150);
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(EconomyModule::estate_reward_lease(OWNED_ESTATE_ID, ALICE), None);
+		assert_eq!(EconomyModule::estate_reward_lease_ends(lease.end_round), 0);
+		assert_eq!(EconomyModule::total_weighted_estate_stake(), 0);
+		assert!(System::events().iter().any(|record| record.event
+			== RuntimeEvent::Economy(crate::Event::EstateRewardLeaseEnded(OWNED_ESTATE_ID, ALICE, BOB, 150))));
+	});
+}
+
+#[test]
+fn distributor_power_issuance_should_respect_per_round_quota() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, MiningCurrencyId::get(), 1000)])
+		.build()
+		.execute_with(|| {
+			init_test_nft(RuntimeOrigin::signed(ALICE), 0, 0);
+			assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+				RuntimeOrigin::root(),
+				FixedU128::saturating_from_integer(2u128),
+				ConversionRounding::Down
+			));
+			assert_noop!(
+				EconomyModule::distribute_power(RuntimeOrigin::signed(ALICE), (0, 0), BOB, 100),
+				Error::<Runtime>::NotPowerDistributor
+			);
+
+			assert_noop!(
+				EconomyModule::set_distributor_power_quota(RuntimeOrigin::signed(ALICE), 0, Some(150)),
+				BadOrigin
+			);
+			assert_ok!(EconomyModule::set_distributor_power_quota(
+				RuntimeOrigin::root(),
+				0,
+				Some(150)
+			));
+			assert_eq!(
+				last_event(),
+				RuntimeEvent::Economy(crate::Event::DistributorPowerQuotaUpdated(0, Some(150)))
+			);
+			assert_noop!(
+				EconomyModule::distribute_power(RuntimeOrigin::signed(BOB), (0, 0), BOB, 100),
+				Error::<Runtime>::NotPowerDistributorOwner
+			);
+
+			assert_ok!(EconomyModule::distribute_power(
+				RuntimeOrigin::signed(ALICE),
+				(0, 0),
+				BOB,
+				100
+			));
+			assert_eq!(EconomyModule::get_power_balance(BOB), 100);
+			assert_eq!(OrmlTokens::free_balance(MiningCurrencyId::get(), &ALICE), 800);
+			assert_eq!(
+				OrmlTokens::free_balance(MiningCurrencyId::get(), &EconomyModule::economy_pallet_account_id()),
+				200
+			);
+			assert_eq!(
+				last_event(),
+				RuntimeEvent::Economy(crate::Event::PowerDistributed((0, 0), BOB, 100, 200))
+			);
+			assert_noop!(
+				EconomyModule::distribute_power(RuntimeOrigin::signed(ALICE), (0, 0), BOB, 51),
+				Error::<Runtime>::DistributorPowerQuotaExceeded
+			);
+			assert_ok!(EconomyModule::distribute_power(
+				RuntimeOrigin::signed(ALICE),
+				(0, 0),
+				BOB,
+				50
+			));
+
+			// The quota resets when the round changes
+			let round = Mining::get_current_round_info().current;
+			while Mining::get_current_round_info().current == round {
+				run_to_block(System::block_number() + 1);
+			}
+			assert_ok!(EconomyModule::distribute_power(
+				RuntimeOrigin::signed(ALICE),
+				(0, 0),
+				BOB,
+				150
+			));
+			assert_eq!(EconomyModule::distributor_power_issuance((0, 0)), (round + 1, 150));
+			assert_eq!(EconomyModule::get_power_balance(BOB), 300);
+		});
+}
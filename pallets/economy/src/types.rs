@@ -0,0 +1,643 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Miscellaneous additional datatypes.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_system::RawOrigin;
+use primitives::{EraIndex, EstateId, FungibleTokenId, RoundIndex};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{FixedU128, Perbill, Rounding, RuntimeDebug};
+use sp_std::vec::Vec;
+use xcm::v3::MultiLocation;
+
+/// The staking pools an account can hold a position in
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PoolKind {
+	/// Economy 101 self-staking
+	Economy101,
+	/// Innovation staking
+	Innovation,
+}
+
+/// The role under which an account holds the stake bond of an estate
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EstateStakerRole {
+	/// The staker owns the estate
+	Owner,
+	/// The staker holds an active lease on the estate
+	Lessee,
+}
+
+impl Default for EstateStakerRole {
+	fn default() -> Self {
+		EstateStakerRole::Owner
+	}
+}
+
+/// Estate metadata trait weighted by the estate reward weight tables
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EstateTraitKind {
+	/// Biome of the estate land
+	Biome,
+	/// Rarity tier of the estate
+	Rarity,
+	/// Score of the estate neighborhood
+	NeighborhoodScore,
+}
+
+/// Exit queue holding an unstaked amount
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ExitQueueKind {
+	/// Economy 101 self-staking exit queue
+	Economy101,
+	/// Estate staking exit queue of the estate
+	Estate(EstateId),
+	/// Innovation staking exit queue
+	Innovation,
+}
+
+/// Unstaked amount held in an exit queue
+#[derive(Encode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct QueuedExit<Balance, BlockNumber> {
+	/// Unstaked amount
+	pub amount: Balance,
+	/// Block the unlock round is expected to start at, extrapolated from the round length when
+	/// the amount was queued. Zero for entries queued before storage version 3
+	pub estimated_unlock_block: BlockNumber,
+}
+
+impl<Balance: Decode, BlockNumber: Decode + Default> Decode for QueuedExit<Balance, BlockNumber> {
+	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		let amount = Balance::decode(input)?;
+		// Exit queues held the bare amount before storage version 3. These entries stay readable
+		// while `MigrateToV3` is still stepping through the exit queues
+		if input.remaining_len()? == Some(0) {
+			return Ok(Self {
+				amount,
+				estimated_unlock_block: Default::default(),
+			});
+		}
+
+		Ok(Self {
+			amount,
+			estimated_unlock_block: BlockNumber::decode(input)?,
+		})
+	}
+}
+
+/// Quote of a power purchase
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PowerPurchaseQuote<Balance, BlockNumber> {
+	/// BIT cost of the power, including the commission
+	pub bit_cost: Balance,
+	/// Commission part of the BIT cost
+	pub commission: Balance,
+	/// Block the purchase is expected to be executed at
+	pub target_execution_block: BlockNumber,
+}
+
+/// Exit queue entry of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ExitEntry<Balance, BlockNumber> {
+	/// Exit queue holding the amount
+	pub kind: ExitQueueKind,
+	/// Round the amount unlocks
+	pub round: RoundIndex,
+	/// Unstaked amount
+	pub amount: Balance,
+	/// Block the unlock round is expected to start at
+	pub estimated_unlock_block: BlockNumber,
+	/// Whether the round is reached and the amount can be withdrawn
+	pub ready: bool,
+}
+
+/// Consolidated staking position of an account across all staking subsystems
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StakingLedger<Balance, BlockNumber> {
+	/// Active economy 101 self-stake, including the locked part
+	pub active_self_stake: Balance,
+	/// Part of the active self-stake held by a balance lock instead of a reserve
+	pub locked_self_stake: Balance,
+	/// Active estate stake bonds held by the account
+	pub active_estate_stakes: Vec<(EstateId, Balance)>,
+	/// Active innovation stake
+	pub active_innovation_stake: Balance,
+	/// Innovation reward pool shares
+	pub innovation_shares: Balance,
+	/// Unbonding chunks of all exit queues, oldest unlock round first
+	pub unlocking: Vec<ExitEntry<Balance, BlockNumber>>,
+	/// Innovation rewards already withdrawn per currency
+	pub claimed_rewards: Vec<(FungibleTokenId, Balance)>,
+}
+
+/// Compact snapshot of the innovation staker set taken at an era boundary
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StakerSetSnapshot<Balance> {
+	/// Total shares of the innovation staking pool
+	pub total_shares: Balance,
+	/// Number of accounts holding shares
+	pub staker_count: u32,
+	/// Hash chain over every share change up to the snapshot
+	pub shares_commitment: H256,
+	/// Total innovation stake
+	pub total_innovation_stake: Balance,
+}
+
+/// Governance-defined innovation staking tier
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StakeTier<Balance> {
+	/// Minimum innovation stake required for the tier
+	pub threshold: Balance,
+	/// Reward bonus paid on top of the pool share
+	pub reward_bonus: Perbill,
+	/// Extra voting points granted to tier members
+	pub extra_voting_points: u32,
+}
+
+/// Governance-defined boost of large contiguous estates
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EstateBoostTier {
+	/// Minimum number of contiguous land units required for the tier
+	pub min_land_units: u64,
+	/// Multiplier of the estate stake weight
+	pub boost: FixedU128,
+}
+
+/// Where innovation staking rewards are paid out
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RewardDestination<AccountId> {
+	/// Pay rewards to the staker
+	Staker,
+	/// Pay rewards to another account
+	Account(AccountId),
+}
+
+impl<AccountId> Default for RewardDestination<AccountId> {
+	fn default() -> Self {
+		RewardDestination::Staker
+	}
+}
+
+/// Per-account staking preferences
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AccountPreference<AccountId> {
+	/// Restake claimed rewards automatically
+	pub auto_compound: bool,
+	/// Withdraw unstaked amount automatically once it is unlocked
+	pub auto_withdraw: bool,
+	/// Where rewards are paid out
+	pub reward_destination: RewardDestination<AccountId>,
+	/// Currency rewards are preferably claimed in
+	pub preferred_claim_currency: Option<FungibleTokenId>,
+	/// Mint a transferable NFT receipt for new innovation staking positions
+	pub mint_position_receipt: bool,
+	/// Restake matured exit queue entries instead of leaving them to be withdrawn
+	pub auto_restake_exits: bool,
+	/// Stake income received from other pallets on innovation staking
+	pub auto_stake_income: bool,
+	/// Record reward payouts in the account reward ledger
+	pub record_reward_ledger: bool,
+}
+
+/// How the per-era reward budget is added to the innovation staking reward pool
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RewardDistributionMode {
+	/// Add the whole era budget at era rollover
+	EraLump,
+	/// Drip `reward_per_era / blocks_per_era` every block
+	PerBlock,
+}
+
+impl Default for RewardDistributionMode {
+	fn default() -> Self {
+		RewardDistributionMode::EraLump
+	}
+}
+
+/// Reward budget added to the innovation staking reward pool every era
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EraRewardBudget<Balance> {
+	/// Share of the total innovation stake, scaling with participation
+	Rate(Perbill),
+	/// Fixed amount
+	Amount(Balance),
+}
+
+impl<Balance: Default> Default for EraRewardBudget<Balance> {
+	fn default() -> Self {
+		EraRewardBudget::Amount(Default::default())
+	}
+}
+
+/// Era configuration change applied at a future block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledEraConfig<BlockNumber, Balance> {
+	/// Block the change is applied at
+	pub activation_block: BlockNumber,
+	/// New era frequency, unchanged if `None`
+	pub frequency: Option<BlockNumber>,
+	/// New era reward budget, unchanged if `None`
+	pub reward_per_era: Option<EraRewardBudget<Balance>>,
+}
+
+/// Bonded pool identifier
+pub type PoolId = u32;
+
+/// Lifecycle state of a bonded pool
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PoolState {
+	/// Anyone can join the pool
+	Open,
+	/// New members cannot join the pool
+	Blocked,
+	/// The pool is winding down, members can only unbond. The pool is removed once empty
+	Destroying,
+}
+
+/// Pool staking on the innovation staking ledger on behalf of its members
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BondedPool<AccountId, Balance> {
+	/// Operator receiving the pool commission
+	pub operator: AccountId,
+	/// Commission taken from pool rewards
+	pub commission: Perbill,
+	/// Total points issued to pool members
+	pub points: Balance,
+	/// Lifecycle state of the pool
+	pub state: PoolState,
+}
+
+/// Membership of an account in a bonded pool
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolMember<Balance> {
+	/// The pool the account is a member of
+	pub pool_id: PoolId,
+	/// Points held in the pool
+	pub points: Balance,
+}
+
+/// Reward campaign identifier
+pub type CampaignId = u32;
+
+/// Stakers eligible for the rewards of a campaign
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CampaignEligibility {
+	/// All innovation stakers, by share of the reward pool
+	AllInnovationStakers,
+}
+
+/// Reward program adding its budget to the innovation staking reward pool while active
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RewardCampaign<AccountId, Balance, BlockNumber> {
+	/// Account funding the campaign, refunded with the leftover budget
+	pub creator: AccountId,
+	/// Reward currency
+	pub currency_id: FungibleTokenId,
+	/// Budget not distributed yet
+	pub remaining_budget: Balance,
+	/// First block the campaign is active
+	pub start: BlockNumber,
+	/// Block the campaign expires
+	pub end: BlockNumber,
+	/// Stakers eligible for the campaign rewards
+	pub eligibility: CampaignEligibility,
+	/// Deposit reserved from the creator
+	pub deposit: Balance,
+}
+
+/// Gameplay mission identifier
+pub type MissionId = u64;
+
+/// Economy treasury spending proposal identifier
+pub type EconomySpendId = u32;
+
+/// Split of penalties and slashes between their destinations. The insurance fund receives the
+/// remainder
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PenaltySplit {
+	/// Share added to the innovation staking reward pool
+	pub reward_pool: Perbill,
+	/// Share paid to the economy treasury
+	pub treasury: Perbill,
+}
+
+/// Innovation project identifier
+pub type InnovationProjectId = u32;
+
+/// Innovation project registered for curation by directed stake
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct InnovationProject<AccountId, Balance> {
+	/// Project owner
+	pub owner: AccountId,
+	/// Content id of the project metadata
+	pub metadata_cid: Vec<u8>,
+	/// Reserved registration deposit
+	pub deposit: Balance,
+}
+
+/// How the treasury matching of an innovation project is weighted
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum MatchingMode {
+	/// Proportional to the number of distinct stakers
+	Linear,
+	/// Proportional to the square of the sum of the square roots of the directed stakes
+	Quadratic,
+}
+
+/// Treasury matching of innovation project reward pools
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProjectMatchingConfig<Balance> {
+	/// Matching weight
+	pub mode: MatchingMode,
+	/// Maximum matched by the treasury every era across all projects
+	pub era_cap: Balance,
+	/// Maximum matched to a single project every era
+	pub project_cap: Balance,
+}
+
+/// Governance terms of credit lines against innovation stake
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CreditLineConfig {
+	/// Maximum debt as a share of the stake value
+	pub ltv: Perbill,
+	/// BIT value of a staked native token
+	pub bit_per_native: FixedU128,
+	/// Interest added to the debt every era
+	pub interest_per_era: Perbill,
+	/// Eras before an unpaid credit line is liquidated
+	pub term_eras: EraIndex,
+}
+
+/// BIT borrowed against the innovation stake of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CreditLine<Balance> {
+	/// Borrowed BIT including accrued interest
+	pub debt: Balance,
+	/// Era at which the credit line is liquidated if not repaid
+	pub due_era: EraIndex,
+	/// Era up to which interest is included in the debt
+	pub accrued_era: EraIndex,
+}
+
+/// Governance terms of power advances
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PowerAdvanceConfig {
+	/// Fee added to the BIT value of the advanced power
+	pub fee: Perbill,
+	/// Rounds to repay the advance from staking rewards before the escrow covers the remainder
+	pub repayment_rounds: RoundIndex,
+}
+
+/// Power advanced against a BIT deposit held in escrow
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PowerAdvance<Balance> {
+	/// Reserved BIT deposit
+	pub escrow: Balance,
+	/// BIT left to repay
+	pub debt: Balance,
+	/// Round after which the escrow covers the unpaid debt
+	pub due_round: RoundIndex,
+}
+
+/// Account on another chain receiving the claimed rewards of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CrossChainRewardDestination {
+	/// Whitelisted destination chain
+	pub chain: MultiLocation,
+	/// Receiving account on the destination chain
+	pub beneficiary: [u8; 32],
+}
+
+/// Offence report identifier
+pub type OffenceReportId = u32;
+
+/// Evidence of reward pool manipulation awaiting judgement
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OffenceReport<AccountId, Balance> {
+	/// Account submitting the report
+	pub reporter: AccountId,
+	/// Account accused of the offence
+	pub offender: AccountId,
+	/// Evidence of the offence, e.g. a forged distributor conversion
+	pub proof: Vec<u8>,
+	/// Deposit reserved from the reporter
+	pub deposit: Balance,
+}
+
+/// What an economy treasury spend is used for
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EconomySpendTarget<AccountId, BlockNumber> {
+	/// Transfer the amount to a beneficiary
+	Beneficiary(AccountId),
+	/// Fund a reward campaign for all innovation stakers
+	RewardCampaign { start: BlockNumber, end: BlockNumber },
+}
+
+/// Proposal to spend protocol income held by the economy treasury
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EconomySpendProposal<AccountId, Balance, BlockNumber> {
+	/// Account submitting the proposal
+	pub proposer: AccountId,
+	/// Currency spent
+	pub currency_id: FungibleTokenId,
+	/// Amount spent
+	pub amount: Balance,
+	/// What the amount is spent on
+	pub target: EconomySpendTarget<AccountId, BlockNumber>,
+	/// Deposit reserved from the proposer
+	pub deposit: Balance,
+}
+
+/// Weights of each staking category in the stake weight of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StakeWeight {
+	/// Weight of economy 101 self-stake
+	pub self_stake: Perbill,
+	/// Weight of estate stake
+	pub estate_stake: Perbill,
+	/// Weight of innovation stake
+	pub innovation_stake: Perbill,
+}
+
+impl Default for StakeWeight {
+	fn default() -> Self {
+		StakeWeight {
+			self_stake: Perbill::one(),
+			estate_stake: Perbill::one(),
+			innovation_stake: Perbill::one(),
+		}
+	}
+}
+
+/// Voluntary extension of the innovation staking unbond period in exchange for more voting points
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum InnovationConviction {
+	/// Default unbond period, 1x voting points
+	None,
+	/// 2x unbond period, 2x voting points
+	Locked2x,
+	/// 4x unbond period, 4x voting points
+	Locked4x,
+	/// 8x unbond period, 8x voting points
+	Locked8x,
+}
+
+impl Default for InnovationConviction {
+	fn default() -> Self {
+		InnovationConviction::None
+	}
+}
+
+impl InnovationConviction {
+	/// Multiplier applied to both the unbond period and the voting points
+	pub fn multiplier(self) -> u32 {
+		match self {
+			InnovationConviction::None => 1,
+			InnovationConviction::Locked2x => 2,
+			InnovationConviction::Locked4x => 4,
+			InnovationConviction::Locked8x => 8,
+		}
+	}
+}
+
+/// Rounding policy applied when converting power to BIT
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ConversionRounding {
+	/// Round towards zero
+	Down,
+	/// Round away from zero
+	Up,
+	/// Round to the nearest value, halves rounded up
+	Nearest,
+}
+
+impl Default for ConversionRounding {
+	fn default() -> Self {
+		ConversionRounding::Down
+	}
+}
+
+impl From<ConversionRounding> for Rounding {
+	fn from(rounding: ConversionRounding) -> Self {
+		match rounding {
+			ConversionRounding::Down => Rounding::Down,
+			ConversionRounding::Up => Rounding::Up,
+			ConversionRounding::Nearest => Rounding::NearestPrefUp,
+		}
+	}
+}
+
+/// Record of a BIT to power exchange rate update
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ExchangeRateUpdate<BlockNumber> {
+	/// Block the rate was updated
+	pub block: BlockNumber,
+	/// Rate before the update
+	pub old_rate: FixedU128,
+	/// Rate after the update
+	pub new_rate: FixedU128,
+}
+
+/// Reward payout recorded in the reward ledger of an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RewardLedgerEntry<Balance, BlockNumber> {
+	/// Innovation staking era of the payout
+	pub era: EraIndex,
+	/// Reward currency
+	pub currency_id: FungibleTokenId,
+	/// Paid out amount
+	pub amount: Balance,
+	/// Block of the payout
+	pub block: BlockNumber,
+}
+
+/// Share accounting invariant found violated
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AccountingAnomalyKind {
+	/// Withdrawn rewards of the pool exceed its total rewards in the currency
+	WithdrawnExceedsTotalReward(FungibleTokenId),
+	/// Removed share exceeds the total shares of the pool
+	ShareExceedsTotalShares,
+	/// Withdrawn reward removed with a share exceeds the pool withdrawn rewards in the currency
+	WithdrawnRewardExceedsPool(FungibleTokenId),
+}
+
+/// Staking metrics reported at each era rollover
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EraMetrics<Balance> {
+	/// Total economy 101 self-stake
+	pub total_stake: Balance,
+	/// Total innovation stake
+	pub total_innovation_stake: Balance,
+	/// Total shares of the innovation staking reward pool
+	pub total_shares: Balance,
+	/// Number of accounts holding innovation shares
+	pub staker_count: u32,
+	/// Rewards added to the reward pool during the era, per currency
+	pub rewards_added: Vec<(FungibleTokenId, Balance)>,
+}
+
+/// Privileged intervention on a staking position
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ForcedActionKind<AccountId> {
+	/// Stake force unstaked from the economy 101 ledger or from an estate
+	Unstake(Option<EstateId>),
+	/// Reserved balance force unreserved
+	UnreservedStaking,
+	/// Staking position of a pool force transferred to the account
+	TransferStake(PoolKind, AccountId),
+}
+
+/// Entry of the forced actions log
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ForcedAction<AccountId, Balance, BlockNumber> {
+	/// Origin that dispatched the action
+	pub origin: RawOrigin<AccountId>,
+	/// The action taken
+	pub kind: ForcedActionKind<AccountId>,
+	/// Account the action was taken on
+	pub target: AccountId,
+	/// Amount affected by the action
+	pub amount: Balance,
+	/// Block the action was taken
+	pub block: BlockNumber,
+}
+
+/// Offer of an estate staker to lease the estate rewards of its stake
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EstateRewardLeaseOffer<Balance> {
+	/// Number of rounds the reward rights are leased for
+	pub rounds: RoundIndex,
+	/// Upfront price of the lease
+	pub price: Balance,
+}
+
+/// Lease of the estate rewards of an estate stake. The price stays reserved on the lessee until
+/// the lease ends
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EstateRewardLease<AccountId, Balance> {
+	/// Account receiving the estate rewards during the lease
+	pub lessee: AccountId,
+	/// Round the lease started
+	pub start_round: RoundIndex,
+	/// Round the lease ends
+	pub end_round: RoundIndex,
+	/// Upfront price of the lease
+	pub price: Balance,
+}
@@ -31,14 +31,14 @@ use sp_runtime::{
 	traits::{AccountIdConversion, Convert, One, Saturating},
 	DispatchError, Perbill, SaturatedConversion,
 };
-use sp_std::vec::Vec;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 use auction_manager::{Auction, CheckAuctionItemHandler};
 use core_primitives::*;
 pub use pallet::*;
 use primitives::estate::EstateInfo;
 use primitives::{
-	estate::{Estate, LandUnitStatus, LeaseContract, OwnerId},
+	estate::{Estate, EstateShape, LandUnitStatus, LeaseContract, OwnerId},
 	Attributes, ClassId, EstateId, ItemId, MetaverseId, NftMetadata, TokenId, UndeployedLandBlock,
 	UndeployedLandBlockId, UndeployedLandBlockType,
 };
@@ -1571,6 +1571,37 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whether all `land_units` are connected through horizontally or vertically adjacent
+	/// coordinates
+	pub fn is_contiguous(land_units: &[(i32, i32)]) -> bool {
+		let land_unit_set: BTreeSet<(i32, i32)> = land_units.iter().cloned().collect();
+		let start = match land_units.first() {
+			Some(start) => *start,
+			None => return true,
+		};
+
+		let mut visited = BTreeSet::new();
+		let mut to_visit = Vec::new();
+		to_visit.push(start);
+		while let Some((x, y)) = to_visit.pop() {
+			if !visited.insert((x, y)) {
+				continue;
+			}
+			for neighbour in [
+				(x.saturating_add(1), y),
+				(x.saturating_sub(1), y),
+				(x, y.saturating_add(1)),
+				(x, y.saturating_sub(1)),
+			] {
+				if land_unit_set.contains(&neighbour) && !visited.contains(&neighbour) {
+					to_visit.push(neighbour);
+				}
+			}
+		}
+
+		visited.len() == land_unit_set.len()
+	}
+
 	/// Internal getter for new estate ID
 	fn get_new_estate_id() -> Result<EstateId, DispatchError> {
 		let estate_id = NextEstateId::<T>::try_mutate(|id| -> Result<EstateId, DispatchError> {
@@ -2300,6 +2331,14 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 		TotalUndeployedLandUnit::<T>::get()
 	}
 
+	fn get_estate_shape(estate_id: EstateId) -> Option<EstateShape> {
+		let estate_info = Estates::<T>::get(estate_id)?;
+		Some(EstateShape {
+			land_units: estate_info.land_units.len() as u64,
+			is_contiguous: Self::is_contiguous(&estate_info.land_units),
+		})
+	}
+
 	fn check_estate_ownership(owner: T::AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
 		let owner_value = Self::get_estate_owner(estate_id);
 		match owner_value {
@@ -2318,4 +2357,14 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 	fn is_estate_leased(estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(EstateLeases::<T>::contains_key(estate_id))
 	}
+
+	fn check_estate_lease(leasor: T::AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
+		if !EstateLeasors::<T>::contains_key(leasor, estate_id) {
+			return Ok(false);
+		}
+		match EstateLeases::<T>::get(estate_id) {
+			Some(lease) => Ok(lease.end_block > <frame_system::Pallet<T>>::block_number()),
+			None => Ok(false),
+		}
+	}
 }
@@ -2906,3 +2906,37 @@ fn collect_rent_should_work() {
 		assert_eq!(Balances::free_balance(BENEFICIARY_ID), 1000029);
 	});
 }
+
+#[test]
+fn get_estate_shape_should_report_contiguity() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::mint_estate(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_ok!(EstateModule::mint_estate(
+			RuntimeOrigin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_3, COORDINATE_IN_4]
+		));
+
+		assert_eq!(
+			EstateModule::get_estate_shape(0),
+			Some(EstateShape {
+				land_units: 2,
+				is_contiguous: true,
+			})
+		);
+		assert_eq!(
+			EstateModule::get_estate_shape(1),
+			Some(EstateShape {
+				land_units: 2,
+				is_contiguous: false,
+			})
+		);
+		assert_eq!(EstateModule::get_estate_shape(2), None);
+	});
+}
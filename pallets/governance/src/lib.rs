@@ -37,7 +37,7 @@ use sp_runtime::{Perbill, SaturatedConversion};
 use sp_std::prelude::*;
 
 use frame_system::pallet_prelude::BlockNumberFor;
-use metaverse_primitive::MetaverseTrait;
+use metaverse_primitive::{MetaverseTrait, VotingPointsSnapshotProvider};
 pub use pallet::*;
 use primitives::{MetaverseId, ProposalId, ReferendumId};
 pub use types::*;
@@ -140,6 +140,9 @@ pub mod pallet {
 		/// Network treasury account
 		#[pallet::constant]
 		type NetworkTreasury: Get<Self::AccountId>;
+
+		/// Snapshots of staker voting points taken when proposals are created
+		type VotingPointsSnapshot: VotingPointsSnapshotProvider<Self::AccountId, BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -392,7 +395,7 @@ pub mod pallet {
 		/// - `proposal_description`: description of the proposal encoded as vector of numbers
 		///
 		/// Emits `Tabled` if successful.
-		#[pallet::weight(Weight::from_parts(10000, 0)+ T::DbWeight::get().writes(1))]
+		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().reads_writes(2, 3))]
 		pub fn propose(
 			origin: OriginFor<T>,
 			metaverse_id: MetaverseId,
@@ -450,6 +453,7 @@ pub mod pallet {
 							ExistenceRequirement::KeepAlive,
 						)?;
 						<Proposals<T>>::insert(metaverse_id, proposal_id, proposal_info);
+						T::VotingPointsSnapshot::snapshot_voting_points(proposal_id)?;
 
 						Self::update_proposals_per_metaverse_number(metaverse_id, true);
 						T::Currency::reserve(&from, balance);
@@ -518,6 +522,7 @@ pub mod pallet {
 			let _proposal_info = Self::proposals(metaverse_id, proposal).ok_or(Error::<T>::ProposalDoesNotExist)?;
 			if let Some((depositors, deposit)) = <DepositOf<T>>::take(proposal) {
 				<Proposals<T>>::remove(metaverse_id, proposal);
+				T::VotingPointsSnapshot::clear_voting_points_snapshot(proposal);
 				Self::update_proposals_per_metaverse_number(metaverse_id, false); // slash depositors
 				for d in &depositors {
 					T::Slash::on_unbalanced(T::Currency::slash_reserved(d, deposit).0);
@@ -596,7 +601,7 @@ pub mod pallet {
 		/// - `vote`: the vote value, balance, and conviction
 		///
 		/// Emits `VoteRecorded` if successful.
-		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().reads_writes(5, 2))]
 		pub fn try_vote(
 			origin: OriginFor<T>,
 			metaverse: MetaverseId,
@@ -619,12 +624,18 @@ pub mod pallet {
 					Ok(_i) => Err(Error::<T>::AccountAlreadyVoted.into()),
 					Err(i) => {
 						votes.insert(i, (referendum, vote.clone()));
+						// Staking voting points frozen at the proposal creation are added to the vote
+						let voting_points = T::VotingPointsSnapshot::record_voting_points(status.proposal, &from);
 
 						<ReferendumInfoOf<T>>::try_mutate(
 							metaverse,
 							referendum,
 							|referendum_info| -> DispatchResultWithPostInfo {
 								status.tally.add(vote.clone()).ok_or(Error::<T>::TallyOverflow)?;
+								status
+									.tally
+									.add_points(vote.aye, voting_points)
+									.ok_or(Error::<T>::TallyOverflow)?;
 								*referendum_info = Some(ReferendumInfo::Ongoing(status));
 
 								Ok(().into())
@@ -646,7 +657,7 @@ pub mod pallet {
 		/// - `metaverse`: the metaverse ID of the local referendum
 		///
 		/// Emits `VoteRemoved` if successful.
-		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(Weight::from_parts(10000, 0) + T::DbWeight::get().reads_writes(1, 1))]
 		pub fn try_remove_vote(
 			origin: OriginFor<T>,
 			referendum: ReferendumId,
@@ -661,6 +672,11 @@ pub mod pallet {
 						let vote = votes.remove(i).1;
 						match info {
 							Some(ReferendumInfo::Ongoing(mut status)) => {
+								let voting_points = T::VotingPointsSnapshot::voting_points_at(status.proposal, &from);
+								status
+									.tally
+									.remove_points(vote.aye, voting_points)
+									.ok_or(Error::<T>::TallyOverflow)?;
 								status.tally.remove(vote).ok_or(Error::<T>::TallyOverflow)?;
 								T::Currency::transfer(
 									&from,
@@ -721,6 +737,7 @@ pub mod pallet {
 					<ReferendumInfoOf<T>>::remove(metaverse, referendum);
 					Self::update_proposals_per_metaverse_number(referendum_status.metaverse, false);
 					<DepositOf<T>>::remove(referendum_status.proposal);
+					T::VotingPointsSnapshot::clear_voting_points_snapshot(referendum_status.proposal);
 					Self::deposit_event(Event::ReferendumCancelled(referendum));
 				}
 				_ => (),
@@ -1028,6 +1045,7 @@ impl<T: Config> Pallet<T> {
 
 			Ok(())
 		});
+		T::VotingPointsSnapshot::clear_voting_points_snapshot(referendum_status.proposal);
 
 		// Enact proposal if it passed the threshold
 		if is_referendum_approved {
@@ -427,6 +427,10 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		1000
 	}
 
+	fn holds_class_token(_who: &AccountId, _class_id: Self::ClassId) -> bool {
+		false
+	}
+
 	fn reserve_stackable_nft_balance(
 		_who: &AccountId,
 		_asset_id: &(Self::ClassId, Self::TokenId),
@@ -522,6 +526,7 @@ impl Config for Runtime {
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
 	type ProposalType = ProposalType;
 	type StorageDepositFee = StorageDepositFee;
+	type VotingPointsSnapshot = ();
 	type NetworkTreasury = TreasuryModuleAccount;
 }
 
@@ -269,6 +269,26 @@ impl<Balance: From<u8> + Zero + Copy + CheckedAdd + CheckedSub + CheckedMul + Ch
 		Some(())
 	}
 
+	/// Add staking voting points of an account to the side of its vote.
+	pub fn add_points(&mut self, aye: bool, points: Balance) -> Option<()> {
+		match aye {
+			true => self.ayes = self.ayes.checked_add(&points)?,
+			false => self.nays = self.nays.checked_add(&points)?,
+		}
+		self.turnout = self.turnout.checked_add(&points)?;
+		Some(())
+	}
+
+	/// Remove staking voting points of an account from the side of its vote.
+	pub fn remove_points(&mut self, aye: bool, points: Balance) -> Option<()> {
+		match aye {
+			true => self.ayes = self.ayes.checked_sub(&points)?,
+			false => self.nays = self.nays.checked_sub(&points)?,
+		}
+		self.turnout = self.turnout.checked_sub(&points)?;
+		Some(())
+	}
+
 	/// Add an account's vote into the tally.
 	pub fn remove(&mut self, vote: Vote<Balance>) -> Option<()> {
 		match vote.aye {
@@ -288,6 +288,10 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		1000
 	}
 
+	fn holds_class_token(_who: &AccountId, _class_id: Self::ClassId) -> bool {
+		false
+	}
+
 	fn reserve_stackable_nft_balance(
 		_who: &AccountId,
 		_asset_id: &(Self::ClassId, Self::TokenId),
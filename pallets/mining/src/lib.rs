@@ -128,6 +128,9 @@ pub mod pallet {
 		/// The Currency for managing storage deposits.
 		type Currency: Currency<Self::AccountId>;
 
+		/// Handler notified when a new round starts
+		type RoundChangeHandler: RoundChangeHandler;
+
 		// Weight implementation for mining extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -351,11 +354,12 @@ pub mod pallet {
 				// mining reward to BIT treasury
 				Self::treasury_reward(allocation_range);
 
+				let previous_round = round.current;
 				round.update(n);
 				Round::<T>::put(round);
 				CurrentMiningResourceAllocation::<T>::put(allocation_range);
 				Self::deposit_event(Event::NewMiningRound(round.current, allocation_range));
-				Weight::from_parts(0, 0)
+				T::RoundChangeHandler::on_round_change(previous_round, round.current)
 			} else {
 				Weight::from_parts(0, 0)
 			}
@@ -192,6 +192,10 @@ impl Estate<u128> for EstateHandler {
 	fn is_estate_leased(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(false)
 	}
+
+	fn check_estate_lease(_leasor: u128, _estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
 }
 
 pub struct MetaverseStakingHandler;
@@ -220,6 +224,7 @@ impl Config for Runtime {
 	type NetworkTreasuryAccount = TreasuryModuleAccount;
 	type StorageDepositFee = StorageDepositFee;
 	type Currency = Balances;
+	type RoundChangeHandler = ();
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -1736,6 +1736,12 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 		total_balance.saturating_sub(reserved_balance)
 	}
 
+	fn holds_class_token(who: &T::AccountId, class_id: Self::ClassId) -> bool {
+		orml_nft::TokensByOwner::<T>::iter_prefix((who, class_id))
+			.next()
+			.is_some()
+	}
+
 	fn reserve_stackable_nft_balance(
 		who: &T::AccountId,
 		asset_id: &(Self::ClassId, Self::TokenId),
@@ -41,6 +41,40 @@ pub trait Estate<AccountId> {
 	fn is_estate_leasor(leasor: AccountId, estate_id: EstateId) -> Result<bool, DispatchError>;
 
 	fn is_estate_leased(estate_id: EstateId) -> Result<bool, DispatchError>;
+
+	fn check_estate_lease(leasor: AccountId, estate_id: EstateId) -> Result<bool, DispatchError>;
+
+	/// Metadata traits of the estate, `None` if the estate has no metadata
+	fn get_estate_traits(_estate_id: EstateId) -> Option<EstateTraits> {
+		None
+	}
+
+	/// Size and contiguity of the estate land units, `None` if the estate does not exist
+	fn get_estate_shape(_estate_id: EstateId) -> Option<EstateShape> {
+		None
+	}
+}
+
+/// Metadata traits of an estate
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateTraits {
+	/// Biome of the estate land
+	pub biome: u8,
+	/// Rarity tier of the estate
+	pub rarity: u8,
+	/// Score of the estate neighborhood
+	pub neighborhood_score: u8,
+}
+
+/// Size and contiguity of the land units of an estate
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateShape {
+	/// Number of land units in the estate
+	pub land_units: u64,
+	/// Whether all land units are connected through adjacent coordinates
+	pub is_contiguous: bool,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
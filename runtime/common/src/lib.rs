@@ -32,6 +32,7 @@ use primitives::BuyWeightRate;
 
 pub mod currencies;
 pub mod nft;
+pub mod power;
 pub mod precompiles;
 
 #[cfg(test)]
@@ -0,0 +1,208 @@
+use evm_mapping::AddressMapping as EvmMapping;
+
+use pallet_evm::{ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use sp_core::H160;
+use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use precompile_utils::data::{Address, EvmDataWriter};
+use precompile_utils::handle::PrecompileHandleExt;
+use precompile_utils::modifier::FunctionModifier;
+use precompile_utils::prelude::RuntimeHelper;
+use precompile_utils::{succeed, EvmResult};
+use primitives::evm::Output;
+use primitives::PowerAmount;
+
+#[precompile_utils_macro::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	TotalSupply = "totalSupply()",
+	BalanceOf = "balanceOf(address)",
+	Allowance = "allowance(address,address)",
+	Transfer = "transfer(address,uint256)",
+	Approve = "approve(address,uint256)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+	Name = "name()",
+	Symbol = "symbol()",
+	Decimals = "decimals()",
+}
+
+/// The ERC-20 `Power` facade precompile.
+///
+/// Exposes the economy pallet power balance to EVM tooling.
+///
+/// Actions:
+/// - Query total supply.
+/// - Query balance. Rest `input` bytes: `account_id`.
+/// - Query allowance. Rest `input` bytes: `owner`, `spender`.
+/// - Transfer. Rest `input` bytes: `to`, `amount`.
+/// - Approve. Rest `input` bytes: `spender`, `amount`.
+/// - Transfer from. Rest `input` bytes: `from`, `to`, `amount`.
+pub struct PowerPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Default for PowerPrecompile<Runtime> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime> Precompile for PowerPrecompile<Runtime>
+where
+	Runtime: economy::Config + pallet_evm::Config + frame_system::Config + evm_mapping::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::Approve | Action::Transfer | Action::TransferFrom => FunctionModifier::NonPayable,
+			_ => FunctionModifier::View,
+		})?;
+
+		match selector {
+			Action::TotalSupply => Self::total_supply(handle),
+			Action::BalanceOf => Self::balance_of(handle),
+			Action::Allowance => Self::allowance(handle),
+			Action::Transfer => Self::transfer(handle),
+			Action::Approve => Self::approve(handle),
+			Action::TransferFrom => Self::transfer_from(handle),
+			Action::Name => Self::name(handle),
+			Action::Symbol => Self::symbol(handle),
+			Action::Decimals => Self::decimals(handle),
+		}
+	}
+}
+
+impl<Runtime> PowerPrecompile<Runtime>
+where
+	Runtime: economy::Config + pallet_evm::Config + frame_system::Config + evm_mapping::Config,
+{
+	fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		let total_issuance = <economy::Pallet<Runtime>>::power_total_issuance();
+
+		Ok(succeed(Output::encode_uint(total_issuance)))
+	}
+
+	fn name(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		Ok(succeed(Output::encode_bytes(b"Power")))
+	}
+
+	fn symbol(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		Ok(succeed(Output::encode_bytes(b"POWER")))
+	}
+
+	fn decimals(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		// Power is an integer amount
+		Ok(succeed(Output::encode_uint(0u8)))
+	}
+
+	fn balance_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input of index 1 (owner)
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let who = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&owner);
+
+		let balance = <economy::Pallet<Runtime>>::get_power_balance(&who);
+
+		log::debug!(target: "evm", "power: who: {:?} balance: {:?}", who, balance);
+
+		Ok(succeed(Output::encode_uint(balance)))
+	}
+
+	fn allowance(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+		let owner = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&owner);
+		let spender = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&spender);
+
+		let allowance = <economy::Pallet<Runtime>>::power_allowance(&owner, &spender);
+
+		Ok(succeed(Output::encode_uint(allowance)))
+	}
+
+	fn transfer(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(2))?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<PowerAmount>()?;
+
+		let origin = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&handle.context().caller);
+		let to = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&to);
+
+		log::debug!(target: "evm", "power: transfer from: {:?}, to: {:?}, amount: {:?}", origin, to, amount);
+
+		<economy::Pallet<Runtime>>::do_transfer_power(&origin, &to, amount).map_err(Self::revert)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn approve(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<PowerAmount>()?;
+
+		let origin = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&handle.context().caller);
+		let spender = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&spender);
+
+		<economy::Pallet<Runtime>>::do_approve_power(&origin, &spender, amount);
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer_from(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(3))?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let from: H160 = input.read::<Address>()?.into();
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<PowerAmount>()?;
+
+		let spender = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&handle.context().caller);
+		let from = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&from);
+		let to = <Runtime as evm_mapping::Config>::AddressMapping::get_account_id(&to);
+
+		<economy::Pallet<Runtime>>::do_transfer_power_from(&spender, &from, &to, amount).map_err(Self::revert)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn revert(error: DispatchError) -> PrecompileFailure {
+		PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: Into::<&str>::into(error).as_bytes().to_vec(),
+		}
+	}
+}
@@ -12,6 +12,7 @@ use sp_std::marker::PhantomData;
 
 use crate::currencies::MultiCurrencyPrecompile;
 use crate::nft::NftPrecompile;
+use crate::power::PowerPrecompile;
 
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to MultiCurrencyPrecompile
@@ -19,6 +20,8 @@ pub const ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[0u8; 9];
 /// The NFT precompile address prefix. Addresses that match against this prefix will be routed
 /// to NftPrecompile
 pub const NFT_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[2u8; 9];
+/// The ERC-20 facade address of the economy pallet power balance
+pub const POWER_PRECOMPILE_INDEX: u64 = 1028;
 /// The PrecompileSet installed in the Metaverse runtime.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct MetaverseNetworkPrecompiles<R>(PhantomData<R>);
@@ -34,9 +37,10 @@ impl<R> MetaverseNetworkPrecompiles<R> {
 /// 1024-2047 Precompiles that are not in Ethereum Mainnet
 impl<R> PrecompileSet for MetaverseNetworkPrecompiles<R>
 where
-	R: pallet_evm::Config + currencies_pallet::Config + nft_pallet::Config,
+	R: pallet_evm::Config + currencies_pallet::Config + nft_pallet::Config + economy::Config,
 	MultiCurrencyPrecompile<R>: Precompile,
 	NftPrecompile<R>: Precompile,
+	PowerPrecompile<R>: Precompile,
 	Dispatch<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
@@ -66,6 +70,7 @@ where
 			a if a == hash(1026) => Some(ECRecoverPublicKey::execute(handle)),
 			a if a == hash(1027) => Some(Ed25519Verify::execute(handle)),
 			// Metaverse Network precompiles
+			a if a == hash(POWER_PRECOMPILE_INDEX) => Some(PowerPrecompile::<R>::execute(handle)),
 			// If the address matches asset prefix, the we route through the asset precompile set
 			a if &a.to_fixed_bytes()[0..9] == ASSET_PRECOMPILE_ADDRESS_PREFIX => {
 				Some(MultiCurrencyPrecompile::<R>::execute(handle))
@@ -159,7 +159,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	OnRuntimeUpgrade,
+	(
+		OnRuntimeUpgrade,
+		economy::migrations::v1::MigrateToV1<Runtime>,
+		economy::migrations::v2::MigrateToV2<Runtime>,
+		economy::migrations::v4::MigrateToV4<Runtime>,
+	),
 >;
 
 pub struct OnRuntimeUpgrade;
@@ -229,10 +234,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("continuum-runtime"),
 	impl_name: create_runtime_str!("continuum-runtime"),
 	authoring_version: 1,
-	spec_version: 9,
+	spec_version: 10,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 0,
 };
 
@@ -473,7 +478,8 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction =
+		pallet_transaction_payment::CurrencyAdapter<Balances, economy::FeeSharing<Runtime, DealWithFees>>;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type WeightToFee = WeightToFee;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
@@ -762,6 +768,27 @@ impl orml_xtokens::Config for Runtime {
 	type ReserveProvider = AbsoluteReserveProvider;
 }
 
+/// Sends claimed economy rewards to other chains through an XTokens reserve transfer
+pub struct XTokensRewardTransfer;
+
+impl core_primitives::CrossChainTransfer<AccountId, Balance, MultiLocation> for XTokensRewardTransfer {
+	fn transfer_to_chain(
+		who: &AccountId,
+		currency_id: FungibleTokenId,
+		amount: Balance,
+		dest: MultiLocation,
+	) -> sp_runtime::DispatchResult {
+		<XTokens as orml_traits::XcmTransfer<AccountId, Balance, FungibleTokenId>>::transfer(
+			who.clone(),
+			currency_id,
+			amount,
+			dest,
+			WeightLimit::Unlimited,
+		)
+		.map(|_| ())
+	}
+}
+
 impl orml_unknown_tokens::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
@@ -1440,6 +1467,7 @@ impl mining::Config for Runtime {
 	type NetworkTreasuryAccount = TreasuryModuleAccount;
 	type StorageDepositFee = MiningStorageDeposit;
 	type Currency = Balances;
+	type RoundChangeHandler = Economy;
 	type WeightInfo = weights::module_mining::WeightInfo<Runtime>;
 }
 
@@ -1613,6 +1641,26 @@ impl crowdloan::Config for Runtime {
 parameter_types! {
 	pub const MiningCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
 	pub const PowerAmountPerBlock: u32 = 100;
+	pub const RewardPoolDrainGracePeriod: BlockNumber = 30 * DAYS;
+	pub const MaxQueuedExitsPerAccount: u32 = 32;
+	pub const PowerCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(1);
+	pub const MaxCurrenciesPerClaim: u32 = 16;
+	pub const LendingPotPalletId: PalletId = PalletId(*b"bit/lend");
+	pub const TopInnovationProjectsCount: u32 = 10;
+	pub const MinimumProjectDeposit: Balance = 100 * DOLLARS;
+	pub const OffenceReporterReward: Perbill = Perbill::from_percent(10);
+	pub const OffenceReportDeposit: Balance = 100 * DOLLARS;
+	pub const EconomySpendDeposit: Balance = 100 * DOLLARS;
+	pub const MaxOpenEconomySpends: u32 = 50;
+	pub const StakeFeeHeadroom: Balance = DOLLARS;
+	pub const ExistentialStake: Balance = DOLLARS;
+	pub const MinimumIncomeStake: Balance = 10 * DOLLARS;
+	pub const MissionPotPalletId: PalletId = PalletId(*b"bit/msnp");
+	pub const RewardCampaignDeposit: Balance = 100 * DOLLARS;
+	pub const BondedPoolPalletId: PalletId = PalletId(*b"bit/bdpl");
+	pub const InsuranceFundPalletId: PalletId = PalletId(*b"bit/insr");
+	pub const MaxEstateStakeBatch: u32 = 50;
+	pub const MaxStakeTiers: u32 = 20;
 	pub const InnovationStakingRewardPayoutAccountPalletId: PalletId = PalletId(*b"bit/sred");
 }
 
@@ -1630,6 +1678,43 @@ impl economy::Config for Runtime {
 	type WeightInfo = weights::module_economy::WeightInfo<Runtime>;
 	type MaximumEstateStake = MaximumEstateStake;
 	type RewardPayoutAccount = InnovationStakingRewardPayoutAccountPalletId;
+	type MaxEstateStakeBatch = MaxEstateStakeBatch;
+	type RewardSwapper = ();
+	type InsuranceFundAccount = InsuranceFundPalletId;
+	type BondedPoolPalletId = BondedPoolPalletId;
+	type RewardCampaignDeposit = RewardCampaignDeposit;
+	type MissionRewardOrigin = EnsureRootOrMetaverseTreasury;
+	type MissionPotPalletId = MissionPotPalletId;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type EconomySpendOrigin = EnsureRootOrMetaverseTreasury;
+	type EconomySpendDeposit = EconomySpendDeposit;
+	type MaxOpenEconomySpends = MaxOpenEconomySpends;
+	type SteppedMigration = (
+		economy::migrations::v3::MigrateToV3<Runtime>,
+		economy::migrations::v4::MigrateToV4<Runtime>,
+		economy::migrations::SyncWeightedEstateStakes<Runtime>,
+		economy::migrations::MigratePowerBalanceToTokens<Runtime>,
+	);
+	type MinimumIncomeStake = MinimumIncomeStake;
+	type ExistentialStake = ExistentialStake;
+	type StakeFeeHeadroom = StakeFeeHeadroom;
+	type OffenceJudge = EnsureRootOrMetaverseTreasury;
+	type OffenceReportDeposit = OffenceReportDeposit;
+	type OffenceReporterReward = OffenceReporterReward;
+	type MinimumProjectDeposit = MinimumProjectDeposit;
+	type TopInnovationProjectsCount = TopInnovationProjectsCount;
+	type OnInnovationProjectsSelected = ();
+	type MatchingEligibility = ();
+	type LendingPotPalletId = LendingPotPalletId;
+	type CrossChainTransfer = XTokensRewardTransfer;
+	type MaxCurrenciesPerClaim = MaxCurrenciesPerClaim;
+	type PowerCurrencyId = PowerCurrencyId;
+	type MaxQueuedExitsPerAccount = MaxQueuedExitsPerAccount;
+	type Randomness = RandomnessCollectiveFlip;
+	type RewardPoolDrainGracePeriod = RewardPoolDrainGracePeriod;
+	type OnEraChange = ();
+	type MaxStakeTiers = MaxStakeTiers;
 }
 
 impl emergency::Config for Runtime {
@@ -1970,6 +2055,35 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl economy_rpc_runtime_api::EconomyApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn quote_power_purchase(
+			power_amount: primitives::PowerAmount,
+			domain_id: primitives::DomainId,
+		) -> Result<economy_rpc_runtime_api::PowerPurchaseQuote<Balance, BlockNumber>, sp_runtime::DispatchError> {
+			Economy::quote_power_purchase(power_amount, domain_id)
+		}
+
+		fn total_economic_weight(who: AccountId) -> Balance {
+			Economy::total_economic_weight(&who)
+		}
+
+		fn position_memo(who: AccountId) -> Option<Vec<u8>> {
+			Economy::position_memo(who).map(|memo| memo.into_inner())
+		}
+
+		fn top_stakers() -> Vec<(AccountId, Balance)> {
+			Economy::top_stakers()
+		}
+
+		fn reward_ledger_page(
+			who: AccountId,
+			start: u32,
+			limit: u32,
+		) -> Vec<economy_rpc_runtime_api::RewardLedgerEntry<Balance, BlockNumber>> {
+			Economy::reward_ledger_page(&who, start, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
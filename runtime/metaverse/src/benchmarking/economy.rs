@@ -4,7 +4,17 @@ use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
 use frame_system::RawOrigin;
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::{AccountIdConversion, Lookup, One, StaticLookup, UniqueSaturatedInto};
+use sp_io::crypto::{sr25519_generate, sr25519_sign};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Lookup, One, StaticLookup, UniqueSaturatedInto};
+use sp_runtime::{FixedU128, MultiSignature, MultiSigner, Perbill};
+use xcm::v3::{Junction, Junctions, MultiLocation};
+
+use economy::{
+	AccountPreference, CampaignEligibility, CampaignId, ConversionRounding, CreditLineConfig,
+	CrossChainRewardDestination, EconomySpendTarget, EstateBoostTier, EstateTraitKind, InnovationConviction,
+	MatchingMode, PenaltySplit, PoolKind, PoolState, PowerAdvanceConfig, ProjectMatchingConfig, RewardDistributionMode,
+	StakeTier, StakeWeight,
+};
 
 use core_primitives::RoundTrait;
 use primitives::estate::{EstateInfo, OwnerId};
@@ -12,8 +22,8 @@ use primitives::staking::RoundInfo;
 use primitives::{AccountId, Balance, ClassId, EstateId, FungibleTokenId, GroupCollectionId, MetaverseId, TokenId};
 
 use crate::{
-	Currencies, Economy, EconomyTreasury, Estate, Metaverse, MinimumStake, Mining, Runtime, RuntimeCall, RuntimeEvent,
-	System,
+	Currencies, Economy, EconomyTreasury, Estate, ExistentialStake, MaximumEstateStake, Metaverse, MinimumStake,
+	Mining, PowerCurrencyId, RewardPoolDrainGracePeriod, Runtime, RuntimeCall, RuntimeEvent, System,
 };
 
 use super::utils::{create_nft_group, dollar, mint_NFT, set_balance, set_metaverse_treasury_initial_balance};
@@ -112,7 +122,7 @@ runtime_benchmarks! {
 		assert_eq!(staking_balance, min_stake + dollar(90));
 
 		assert_eq!(
-			Economy::staking_exit_queue(caller.clone(), next_round),
+			Economy::staking_exit_queue(caller.clone(), next_round).map(|queued_exit| queued_exit.amount),
 			Some(dollar(10))
 		);
 	}
@@ -196,7 +206,7 @@ runtime_benchmarks! {
 	}: _(RawOrigin::Signed(caller.clone()), next_round)
 	verify {
 		assert_eq!(
-			Economy::staking_exit_queue(caller.clone(), next_round),
+			Economy::staking_exit_queue(caller.clone(), next_round).map(|queued_exit| queued_exit.amount),
 			None
 		);
 	}
@@ -213,8 +223,937 @@ runtime_benchmarks! {
 		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), stake_amount);
 
 		run_to_block(100);
+		// Worst case claim iterating the reward map of several currencies
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
 	}: _(RawOrigin::Signed(caller.clone()))
 
+	// era rollover in on_initialize
+	update_current_era{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		let min_stake = MinimumStake::get();
+		let stake_amount = min_stake + dollar(100);
+
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), stake_amount);
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
+	}: {
+		Economy::update_current_era(1)?;
+	}
+	verify {
+		assert_eq!(Economy::current_era(), 1);
+	}
+
+	// root era configuration setter
+	set_config{
+	}: update_era_config(RawOrigin::Root, None, Some(100u32.into()), None, None)
+
+	set_stake_tiers{
+		let n in 0 .. 20;
+		let tiers: Vec<StakeTier<Balance>> = (0..n).map(|i| StakeTier {
+			threshold: dollar(i + 1),
+			reward_bonus: Perbill::from_percent(1),
+			extra_voting_points: i,
+		}).collect();
+	}: _(RawOrigin::Root, tiers.try_into().unwrap())
+
+	set_estate_boost_tiers{
+		let n in 0 .. 20;
+		let tiers: Vec<EstateBoostTier> = (0..n).map(|i| EstateBoostTier {
+			min_land_units: (i + 1).into(),
+			boost: FixedU128::one(),
+		}).collect();
+	}: _(RawOrigin::Root, tiers.try_into().unwrap())
+
+	stake_batch{
+		let n in 1 .. 50;
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get(), Some(ESTATE_ID));
+		let stakes: Vec<(EstateId, Balance)> = (0..n).map(|_| (ESTATE_ID, dollar(10))).collect();
+	}: _(RawOrigin::Signed(caller.clone()), stakes.try_into().unwrap())
+
+	merge_positions{
+		let n in 2 .. 20;
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		mint_NFT(&caller, CLASS_ID);
+		Economy::set_position_receipt_class(RawOrigin::Root.into(), CLASS_ID);
+		Economy::set_preferences(
+			RawOrigin::Signed(caller.clone()).into(),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			},
+		);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), dollar(100));
+		for _ in 1..n {
+			Economy::split_position(RawOrigin::Signed(caller.clone()).into(), (CLASS_ID, 1), dollar(1));
+		}
+		let positions: Vec<(ClassId, TokenId)> = (1..=n).map(|token_id| (CLASS_ID, token_id.into())).collect();
+	}: _(RawOrigin::Signed(caller.clone()), positions.try_into().unwrap())
+
+	force_flush_small_claims{
+		let n in 1 .. 50;
+		let accounts: Vec<AccountId> = (0..n).map(|i| {
+			let staker: AccountId = account("staker", i, SEED);
+			set_balance(CURRENCY_ID, &staker, dollar(1000));
+			Economy::stake_on_innovation(RawOrigin::Signed(staker.clone()).into(), MinimumStake::get());
+			staker
+		}).collect();
+		Economy::accumulate_reward(CURRENCY_ID, dollar(1));
+	}: _(RawOrigin::Root, accounts.try_into().unwrap())
+
+	initialize_reward_pool{
+		let n in 1 .. 20;
+		let currencies: Vec<FungibleTokenId> = (0..n).map(|token_id| FungibleTokenId::FungibleToken(token_id.into())).collect();
+	}: _(RawOrigin::Root, currencies.try_into().unwrap())
+
+	force_unstake_batch{
+		let n in 1 .. 500;
+		let entries: Vec<(AccountId, Balance, Option<EstateId>)> = (0..n).map(|i| {
+			let staker: AccountId = account("staker", i, SEED);
+			set_balance(CURRENCY_ID, &staker, dollar(1000));
+			Economy::stake(RawOrigin::Signed(staker.clone()).into(), MinimumStake::get() + dollar(100), None);
+			(staker, dollar(10), None)
+		}).collect();
+	}: _(RawOrigin::Root, entries.try_into().unwrap())
+
+	keeper_restake_matured_exits{
+		let n in 1 .. 50;
+		let caller: AccountId = whitelisted_caller();
+		Economy::set_keeper(RawOrigin::Root.into(), caller.clone(), true);
+		let accounts: Vec<AccountId> = (0..n).map(|i| {
+			let staker: AccountId = account("staker", i, SEED);
+			set_balance(CURRENCY_ID, &staker, dollar(1000));
+			Economy::stake(RawOrigin::Signed(staker.clone()).into(), MinimumStake::get() + dollar(100), None);
+			Economy::unstake(RawOrigin::Signed(staker.clone()).into(), dollar(10), None);
+			staker
+		}).collect();
+		run_to_block(100);
+	}: _(RawOrigin::Signed(caller.clone()), accounts.try_into().unwrap())
+
+	keeper_refund_expired_campaigns{
+		let n in 1 .. 50;
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::set_keeper(RawOrigin::Root.into(), caller.clone(), true);
+		let campaign_ids: Vec<CampaignId> = (0..n).map(|campaign_id| {
+			Economy::create_reward_campaign(
+				RawOrigin::Signed(caller.clone()).into(),
+				CURRENCY_ID,
+				dollar(1),
+				1u32.into(),
+				10u32.into(),
+				CampaignEligibility::AllInnovationStakers,
+			);
+			campaign_id.into()
+		}).collect();
+		run_to_block(10);
+	}: _(RawOrigin::Signed(caller.clone()), campaign_ids.try_into().unwrap())
+
+	create_pool{
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), Perbill::zero())
+
+	join_pool{
+		let operator: AccountId = account("operator", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::create_pool(RawOrigin::Signed(operator).into(), Perbill::zero());
+	}: _(RawOrigin::Signed(caller.clone()), 0, MinimumStake::get() + dollar(100))
+
+	unbond_from_pool{
+		let operator: AccountId = account("operator", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::create_pool(RawOrigin::Signed(operator).into(), Perbill::zero());
+		Economy::join_pool(RawOrigin::Signed(caller.clone()).into(), 0, MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), dollar(10))
+
+	create_reward_campaign{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+	}: _(RawOrigin::Signed(caller.clone()), CURRENCY_ID, dollar(100), 1u32.into(), 100u32.into(), CampaignEligibility::AllInnovationStakers)
+
+	refund_reward_campaign{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::create_reward_campaign(
+			RawOrigin::Signed(caller.clone()).into(),
+			CURRENCY_ID,
+			dollar(100),
+			1u32.into(),
+			10u32.into(),
+			CampaignEligibility::AllInnovationStakers,
+		);
+		run_to_block(10);
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+
+	register_innovation_project{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let metadata_cid = vec![1u8; 128];
+	}: _(RawOrigin::Signed(caller.clone()), metadata_cid.try_into().unwrap(), dollar(100))
+
+	direct_innovation_stake{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::register_innovation_project(RawOrigin::Signed(caller.clone()).into(), vec![1u8].try_into().unwrap(), dollar(100));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), 0, MinimumStake::get())
+
+	propose_economy_spend{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+	}: _(RawOrigin::Signed(caller.clone()), CURRENCY_ID, dollar(10), EconomySpendTarget::Beneficiary(caller.clone()))
+
+	approve_economy_spend{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		set_balance(CURRENCY_ID, &Economy::economy_pallet_account_id(), dollar(1000));
+		Economy::propose_economy_spend(
+			RawOrigin::Signed(caller.clone()).into(),
+			CURRENCY_ID,
+			dollar(10),
+			EconomySpendTarget::Beneficiary(caller.clone()),
+		);
+	}: _(RawOrigin::Root, 0)
+
+	borrow_against_stake{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		set_balance(FungibleTokenId::MiningResource(0), &Economy::lending_pot_account_id(), dollar(1000));
+		Economy::set_credit_line_config(RawOrigin::Root.into(), Some(CreditLineConfig {
+			ltv: Perbill::from_percent(50),
+			bit_per_native: FixedU128::one(),
+			interest_per_era: Perbill::from_percent(1),
+			term_eras: 10,
+		}));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), dollar(10))
+
+	repay_credit{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		set_balance(FungibleTokenId::MiningResource(0), &Economy::lending_pot_account_id(), dollar(1000));
+		Economy::set_credit_line_config(RawOrigin::Root.into(), Some(CreditLineConfig {
+			ltv: Perbill::from_percent(50),
+			bit_per_native: FixedU128::one(),
+			interest_per_era: Perbill::from_percent(1),
+			term_eras: 10,
+		}));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		Economy::borrow_against_stake(RawOrigin::Signed(caller.clone()).into(), dollar(10));
+	}: _(RawOrigin::Signed(caller.clone()), dollar(10))
+
+	prune_my_history{
+		let n in 0 .. 1000;
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::set_preferences(
+			RawOrigin::Signed(caller.clone()).into(),
+			AccountPreference {
+				record_reward_ledger: true,
+				..Default::default()
+			},
+		);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		for _ in 0..n {
+			Economy::accumulate_reward(CURRENCY_ID, dollar(1));
+			Economy::claim_reward(RawOrigin::Signed(caller.clone()).into());
+		}
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert!(Economy::account_reward_ledger(&caller).is_empty());
+	}
+
+	fund_estate_rewards{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get(), Some(ESTATE_ID));
+		set_balance(CURRENCY_ID, &Economy::economy_pallet_account_id(), dollar(1000));
+	}: _(RawOrigin::Root, dollar(100))
+
+	claim_estate_reward{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get(), Some(ESTATE_ID));
+		set_balance(CURRENCY_ID, &Economy::economy_pallet_account_id(), dollar(1000));
+		Economy::fund_estate_rewards(RawOrigin::Root.into(), dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, caller.clone())
+
+	offer_estate_reward_lease{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get(), Some(ESTATE_ID));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, 10, dollar(10))
+
+	accept_estate_reward_lease{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get(), Some(ESTATE_ID));
+		let lessee: AccountId = account("lessee", 0, SEED);
+		set_balance(CURRENCY_ID, &lessee, dollar(1000));
+		Economy::offer_estate_reward_lease(RawOrigin::Signed(caller.clone()).into(), ESTATE_ID, 10, dollar(10));
+	}: _(RawOrigin::Signed(lessee.clone()), ESTATE_ID, caller.clone(), dollar(10))
+
+	distribute_power{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		set_balance(FungibleTokenId::MiningResource(0), &caller, dollar(1000));
+
+		create_nft_group();
+		mint_NFT(&caller, CLASS_ID);
+		Economy::set_distributor_power_quota(RawOrigin::Root.into(), CLASS_ID, Some(1000));
+	}: _(RawOrigin::Signed(caller.clone()), (CLASS_ID, 0), caller.clone(), 100)
+
+	// switch stake out of innovation staking into a new switch lock tranche
+	switch_stake{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), dollar(10), PoolKind::Innovation, PoolKind::Economy101)
+	verify {
+		assert!(!Economy::switched_stake_locks(caller.clone()).is_empty());
+	}
+
+	stake_with_lock{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+	}: _(RawOrigin::Signed(caller.clone()), MinimumStake::get() + dollar(100))
+
+	sponsored_stake_on_innovation{
+		let caller: AccountId = whitelisted_caller();
+		let staker_public = sr25519_generate(0.into(), None);
+		let staker: AccountId = MultiSigner::Sr25519(staker_public).into_account();
+		set_balance(CURRENCY_ID, &staker, dollar(1000));
+
+		Economy::set_stake_sponsor(RawOrigin::Root.into(), caller.clone(), Some(10));
+		let stake_amount = MinimumStake::get() + dollar(100);
+		let payload = Economy::sponsored_stake_payload(&staker, &caller, stake_amount);
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &staker_public, &payload).unwrap());
+	}: _(RawOrigin::Signed(caller.clone()), staker.clone(), stake_amount, signature)
+
+	set_innovation_conviction{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), InnovationConviction::Locked8x)
+
+	reap_dust_position{
+		let caller: AccountId = whitelisted_caller();
+		let staker: AccountId = account("staker", 0, SEED);
+		set_balance(CURRENCY_ID, &staker, dollar(1000));
+		Economy::stake_on_innovation(RawOrigin::Signed(staker.clone()).into(), MinimumStake::get() + dollar(100));
+		// Leave a position below the existential stake behind
+		economy::InnovationStakingInfo::<Runtime>::insert(&staker, ExistentialStake::get() - 1);
+	}: _(RawOrigin::Signed(caller.clone()), staker.clone())
+
+	claim_with_signature{
+		let caller: AccountId = whitelisted_caller();
+		let owner_public = sr25519_generate(0.into(), None);
+		let owner: AccountId = MultiSigner::Sr25519(owner_public).into_account();
+		set_balance(CURRENCY_ID, &owner, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(owner.clone()).into(), MinimumStake::get() + dollar(100));
+		run_to_block(100);
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
+		let payload = Economy::claim_authorization_payload(&owner, 0);
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &owner_public, &payload).unwrap());
+	}: _(RawOrigin::Signed(caller.clone()), owner.clone(), 0, signature)
+
+	claim_reward_to{
+		let caller: AccountId = whitelisted_caller();
+		let beneficiary: AccountId = account("beneficiary", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		run_to_block(100);
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
+	}: _(RawOrigin::Signed(caller.clone()), beneficiary.clone())
+
+	// The runtime does not enable foreign beneficiaries, so the call is measured up to the
+	// feature check
+	claim_reward_to_location{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		let chain = MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000)));
+		Economy::set_cross_chain_reward_chain(RawOrigin::Root.into(), chain, true);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		run_to_block(100);
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
+		let beneficiary = MultiLocation::new(
+			1,
+			Junctions::X2(Junction::Parachain(1000), Junction::AccountId32 { network: None, id: [1u8; 32] }),
+		);
+	}: {
+		let _ = Economy::claim_reward_to_location(RawOrigin::Signed(caller.clone()).into(), beneficiary);
+	}
+
+	claim_reward_partial{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		run_to_block(100);
+		Economy::accumulate_reward(FungibleTokenId::FungibleToken(1), dollar(10));
+	}: _(RawOrigin::Signed(caller.clone()), FungibleTokenId::FungibleToken(1), dollar(1))
+
+	// The runtime has no reward swapper, so the call is measured up to the swap
+	claim_reward_swapped{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		run_to_block(100);
+		Economy::accumulate_reward(FungibleTokenId::FungibleToken(1), dollar(10));
+	}: {
+		let _ = Economy::claim_reward_swapped(
+			RawOrigin::Signed(caller.clone()).into(),
+			FungibleTokenId::FungibleToken(1),
+			CURRENCY_ID,
+			0,
+		);
+	}
+
+	set_estate_staking_operator{
+		let caller: AccountId = whitelisted_caller();
+		let operator: AccountId = account("operator", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, Some(operator.clone()))
+
+	operator_stake{
+		let owner: AccountId = account("owner", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &owner, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(owner.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), owner.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::set_estate_staking_operator(RawOrigin::Signed(owner.clone()).into(), ESTATE_ID, Some(caller.clone()));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, MinimumStake::get() + dollar(100))
+
+	operator_unstake{
+		let owner: AccountId = account("owner", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &owner, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(owner.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), owner.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(owner.clone()).into(), MinimumStake::get() + dollar(100), Some(ESTATE_ID));
+		Economy::set_estate_staking_operator(RawOrigin::Signed(owner.clone()).into(), ESTATE_ID, Some(caller.clone()));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, dollar(10))
+
+	rebalance_estate_stake{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100), Some(ESTATE_ID));
+		// Push the estate stake above the allowance of its land units
+		economy::EstateStakingInfo::<Runtime>::mutate(ESTATE_ID, |maybe_bond| {
+			if let Some(bond) = maybe_bond {
+				bond.amount = bond.amount.saturating_add(MaximumEstateStake::get());
+			}
+		});
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID)
+
+	withdraw_innovation_unreserved{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		Economy::unstake_on_innovation(RawOrigin::Signed(caller.clone()).into(), dollar(10));
+		// Mature the exit queue entry without running through the innovation unbonding rounds
+		let current_round = Mining::get_current_round_info().current;
+		let (unlock_round, queued_exit) =
+			economy::InnovationStakingExitQueue::<Runtime>::iter_prefix(&caller).next().unwrap();
+		economy::InnovationStakingExitQueue::<Runtime>::remove(&caller, unlock_round);
+		economy::InnovationStakingExitQueue::<Runtime>::insert(&caller, current_round, queued_exit);
+	}: _(RawOrigin::Signed(caller.clone()), current_round)
+
+	force_transfer_stake{
+		let from: AccountId = account("staker", 0, SEED);
+		let to: AccountId = account("target", 0, SEED);
+		set_balance(CURRENCY_ID, &from, dollar(1000));
+
+		Economy::stake_on_innovation(RawOrigin::Signed(from.clone()).into(), MinimumStake::get() + dollar(100));
+		Economy::unstake_on_innovation(RawOrigin::Signed(from.clone()).into(), dollar(10));
+	}: _(RawOrigin::Root, from.clone(), to.clone(), PoolKind::Innovation)
+
+	claim_staking_power{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		Economy::set_self_staking_power_per_round(RawOrigin::Root.into(), 1000);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100), None);
+		run_to_block(100);
+	}: _(RawOrigin::Signed(caller.clone()))
+
+	transfer_power{
+		let caller: AccountId = whitelisted_caller();
+		let to: AccountId = account("target", 0, SEED);
+		set_balance(PowerCurrencyId::get(), &caller, 1000);
+	}: _(RawOrigin::Signed(caller.clone()), to.clone(), 100)
+
+	approve_power{
+		let caller: AccountId = whitelisted_caller();
+		let spender: AccountId = account("spender", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), spender.clone(), 100)
+
+	transfer_power_from{
+		let owner: AccountId = account("owner", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		let to: AccountId = account("target", 0, SEED);
+		set_balance(PowerCurrencyId::get(), &owner, 1000);
+		Economy::approve_power(RawOrigin::Signed(owner.clone()).into(), caller.clone(), 100);
+	}: _(RawOrigin::Signed(caller.clone()), owner.clone(), to.clone(), 100)
+
+	set_self_staking_power_per_round{
+	}: _(RawOrigin::Root, 1000)
+
+	set_preferences{
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), AccountPreference {
+		auto_compound: true,
+		auto_restake_exits: true,
+		..Default::default()
+	})
+
+	set_reward_distribution_mode{
+	}: _(RawOrigin::Root, RewardDistributionMode::PerBlock)
+
+	set_pool_commission{
+		let caller: AccountId = whitelisted_caller();
+		Economy::set_max_pool_commission(RawOrigin::Root.into(), Perbill::from_percent(50));
+		Economy::create_pool(RawOrigin::Signed(caller.clone()).into(), Perbill::zero());
+	}: _(RawOrigin::Signed(caller.clone()), 0, Perbill::from_percent(10))
+
+	nominate_pool_operator{
+		let caller: AccountId = whitelisted_caller();
+		let new_operator: AccountId = account("operator", 0, SEED);
+		Economy::create_pool(RawOrigin::Signed(caller.clone()).into(), Perbill::zero());
+	}: _(RawOrigin::Signed(caller.clone()), 0, new_operator.clone())
+
+	set_pool_state{
+		let caller: AccountId = whitelisted_caller();
+		Economy::create_pool(RawOrigin::Signed(caller.clone()).into(), Perbill::zero());
+	}: _(RawOrigin::Signed(caller.clone()), 0, PoolState::Blocked)
+
+	set_max_pool_commission{
+	}: _(RawOrigin::Root, Perbill::from_percent(50))
+
+	set_stake_weights{
+	}: _(RawOrigin::Root, StakeWeight {
+		self_stake: Perbill::from_percent(50),
+		estate_stake: Perbill::from_percent(30),
+		innovation_stake: Perbill::from_percent(20),
+	})
+
+	set_estate_trait_reward_weight{
+	}: _(RawOrigin::Root, EstateTraitKind::Rarity, 1, Some(FixedU128::one()))
+
+	refresh_weighted_estate_stake{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100), Some(ESTATE_ID));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, caller.clone())
+
+	approve_estate_co_staker{
+		let caller: AccountId = whitelisted_caller();
+		let co_staker: AccountId = account("co_staker", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, co_staker.clone(), dollar(100))
+
+	revoke_estate_co_staker{
+		let caller: AccountId = whitelisted_caller();
+		let co_staker: AccountId = account("co_staker", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::approve_estate_co_staker(RawOrigin::Signed(caller.clone()).into(), ESTATE_ID, co_staker.clone(), dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID, co_staker.clone())
+
+	set_estate_stake_limits{
+	}: _(RawOrigin::Root, Some(dollar(1000)), Some(dollar(100000)))
+
+	set_bit_power_exchange_rate{
+	}: _(RawOrigin::Root, FixedU128::one(), ConversionRounding::Nearest)
+
+	set_fee_share_ratio{
+	}: _(RawOrigin::Root, Perbill::from_percent(10))
+
+	set_protocol_commission_share{
+	}: _(RawOrigin::Root, Perbill::from_percent(10))
+
+	set_penalty_split{
+	}: _(RawOrigin::Root, PenaltySplit {
+		reward_pool: Perbill::from_percent(50),
+		treasury: Perbill::from_percent(50),
+	})
+
+	reject_economy_spend{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::propose_economy_spend(
+			RawOrigin::Signed(caller.clone()).into(),
+			CURRENCY_ID,
+			dollar(10),
+			EconomySpendTarget::Beneficiary(caller.clone()),
+		);
+	}: _(RawOrigin::Root, 0)
+
+	report_offence{
+		let caller: AccountId = whitelisted_caller();
+		let offender: AccountId = account("offender", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		let proof = vec![1u8; 4096];
+	}: _(RawOrigin::Signed(caller.clone()), offender.clone(), proof.try_into().unwrap())
+
+	confirm_offence{
+		let caller: AccountId = whitelisted_caller();
+		let offender: AccountId = account("offender", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		set_balance(CURRENCY_ID, &offender, dollar(1000));
+
+		// Slash through the self stake, the innovation stake and the exit queue
+		Economy::stake(RawOrigin::Signed(offender.clone()).into(), MinimumStake::get(), None);
+		Economy::stake_on_innovation(RawOrigin::Signed(offender.clone()).into(), MinimumStake::get() + dollar(10));
+		Economy::unstake_on_innovation(RawOrigin::Signed(offender.clone()).into(), dollar(10));
+		Economy::report_offence(RawOrigin::Signed(caller.clone()).into(), offender.clone(), vec![1u8].try_into().unwrap());
+	}: _(RawOrigin::Root, 0, MinimumStake::get() * 3)
+
+	reject_offence{
+		let caller: AccountId = whitelisted_caller();
+		let offender: AccountId = account("offender", 0, SEED);
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::report_offence(RawOrigin::Signed(caller.clone()).into(), offender.clone(), vec![1u8].try_into().unwrap());
+	}: _(RawOrigin::Root, 0)
+
+	distribute_mission_reward{
+		let player: AccountId = account("player", 0, SEED);
+		set_balance(CURRENCY_ID, &Economy::mission_pot_account_id(), dollar(1000));
+	}: _(RawOrigin::Root, player.clone(), CURRENCY_ID, dollar(10), 1)
+
+	set_position_receipt_class{
+	}: _(RawOrigin::Root, CLASS_ID)
+
+	claim_position_reward{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		mint_NFT(&caller, CLASS_ID);
+		Economy::set_position_receipt_class(RawOrigin::Root.into(), CLASS_ID);
+		Economy::set_preferences(
+			RawOrigin::Signed(caller.clone()).into(),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			},
+		);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		run_to_block(100);
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
+	}: _(RawOrigin::Signed(caller.clone()), (CLASS_ID, 1))
+
+	unstake_position{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		mint_NFT(&caller, CLASS_ID);
+		Economy::set_position_receipt_class(RawOrigin::Root.into(), CLASS_ID);
+		Economy::set_preferences(
+			RawOrigin::Signed(caller.clone()).into(),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			},
+		);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), (CLASS_ID, 1))
+
+	split_position{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		mint_NFT(&caller, CLASS_ID);
+		Economy::set_position_receipt_class(RawOrigin::Root.into(), CLASS_ID);
+		Economy::set_preferences(
+			RawOrigin::Signed(caller.clone()).into(),
+			AccountPreference {
+				mint_position_receipt: true,
+				..Default::default()
+			},
+		);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() * 3);
+	}: _(RawOrigin::Signed(caller.clone()), (CLASS_ID, 1), MinimumStake::get())
+
+	compensate_from_insurance_fund{
+		let who: AccountId = account("victim", 0, SEED);
+		set_balance(CURRENCY_ID, &Economy::insurance_fund_account_id(), dollar(1000));
+	}: _(RawOrigin::Root, who.clone(), dollar(10))
+
+	deregister_innovation_project{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::register_innovation_project(RawOrigin::Signed(caller.clone()).into(), vec![1u8].try_into().unwrap(), dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+
+	withdraw_directed_stake{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::register_innovation_project(RawOrigin::Signed(caller.clone()).into(), vec![1u8].try_into().unwrap(), dollar(100));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		Economy::direct_innovation_stake(RawOrigin::Signed(caller.clone()).into(), 0, MinimumStake::get());
+	}: _(RawOrigin::Signed(caller.clone()), 0, MinimumStake::get())
+
+	set_credit_line_config{
+	}: _(RawOrigin::Root, Some(CreditLineConfig {
+		ltv: Perbill::from_percent(50),
+		bit_per_native: FixedU128::one(),
+		interest_per_era: Perbill::from_percent(1),
+		term_eras: 10,
+	}))
+
+	set_power_advance_config{
+	}: _(RawOrigin::Root, Some(PowerAdvanceConfig {
+		fee: Perbill::from_percent(1),
+		repayment_rounds: 10,
+	}))
+
+	request_power_advance{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(FungibleTokenId::MiningResource(0), &caller, dollar(1000));
+		Economy::set_power_advance_config(RawOrigin::Root.into(), Some(PowerAdvanceConfig {
+			fee: Perbill::from_percent(1),
+			repayment_rounds: 10,
+		}));
+	}: _(RawOrigin::Signed(caller.clone()), 1000)
+
+	settle_power_advance{
+		let caller: AccountId = whitelisted_caller();
+		let borrower: AccountId = account("borrower", 0, SEED);
+		set_balance(FungibleTokenId::MiningResource(0), &borrower, dollar(1000));
+		Economy::set_power_advance_config(RawOrigin::Root.into(), Some(PowerAdvanceConfig {
+			fee: Perbill::from_percent(1),
+			repayment_rounds: 0,
+		}));
+		Economy::request_power_advance(RawOrigin::Signed(borrower.clone()).into(), 1000);
+		run_to_block(100);
+	}: _(RawOrigin::Signed(caller.clone()), borrower.clone())
+
+	set_cross_chain_reward_chain{
+	}: _(RawOrigin::Root, MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000))), true)
+
+	set_cross_chain_reward_destination{
+		let caller: AccountId = whitelisted_caller();
+		let chain = MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000)));
+		Economy::set_cross_chain_reward_chain(RawOrigin::Root.into(), chain, true);
+	}: _(RawOrigin::Signed(caller.clone()), Some(CrossChainRewardDestination {
+		chain,
+		beneficiary: [1u8; 32],
+	}))
+
+	set_stake_sponsor{
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+	}: _(RawOrigin::Root, sponsor.clone(), Some(10))
+
+	set_reward_ledger_retention{
+	}: _(RawOrigin::Root, Some(100))
+
+	set_nft_reward_boost{
+	}: _(RawOrigin::Root, CLASS_ID, Some(Perbill::from_percent(10)))
+
+	fund_reward_pot{
+		set_balance(CURRENCY_ID, &Economy::economy_pallet_account_id(), dollar(1000));
+	}: _(RawOrigin::Root, dollar(100))
+
+	freeze_reward_pool{
+	}: _(RawOrigin::Root)
+
+	drain_reward_pool{
+		set_balance(CURRENCY_ID, &Economy::get_reward_payout_account_id(), dollar(1000));
+		Economy::freeze_reward_pool(RawOrigin::Root.into());
+		System::set_block_number(System::block_number() + RewardPoolDrainGracePeriod::get());
+	}: _(RawOrigin::Root)
+
+	sweep_reward_pot{
+		set_balance(CURRENCY_ID, &Economy::get_reward_payout_account_id(), dollar(1000));
+	}: _(RawOrigin::Root, dollar(10))
+
+	// Worst case rotation moving the balances of every reward pool currency
+	rotate_reward_pot{
+		let new_account: AccountId = account("pot", 0, SEED);
+		let currencies: Vec<FungibleTokenId> = (0..20).map(|token_id| FungibleTokenId::FungibleToken(token_id)).collect();
+		Economy::initialize_reward_pool(RawOrigin::Root.into(), currencies.clone().try_into().unwrap());
+		set_balance(CURRENCY_ID, &Economy::get_reward_payout_account_id(), dollar(1000));
+		for currency_id in currencies {
+			set_balance(currency_id, &Economy::get_reward_payout_account_id(), dollar(1000));
+		}
+	}: _(RawOrigin::Root, new_account.clone())
+
+	set_reward_pot_low_watermark{
+	}: _(RawOrigin::Root, dollar(10))
+
+	set_voting_season_length{
+	}: _(RawOrigin::Root, 10)
+
+	verify_account{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100), None);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone())
+
+	retry_failed_payout{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &Economy::get_reward_payout_account_id(), dollar(1000));
+		economy::FailedPayouts::<Runtime>::insert(&caller, CURRENCY_ID, dollar(10));
+	}: _(RawOrigin::Signed(caller.clone()), CURRENCY_ID)
+
+	set_domain_power_commission{
+	}: _(RawOrigin::Root, 0, Perbill::from_percent(5))
+
+	set_position_memo{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		let memo = vec![1u8; 128];
+	}: _(RawOrigin::Signed(caller.clone()), Some(memo.try_into().unwrap()))
+
+	set_max_innovation_stakers{
+	}: _(RawOrigin::Root, Some(100))
+
+	leave_innovation_waitlist{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::set_max_innovation_stakers(RawOrigin::Root.into(), Some(0));
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+	}: _(RawOrigin::Signed(caller.clone()))
+
+	set_keeper{
+		let keeper: AccountId = account("keeper", 0, SEED);
+	}: _(RawOrigin::Root, keeper.clone(), true)
+
+	keeper_update_era{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::set_keeper(RawOrigin::Root.into(), caller.clone(), true);
+		Economy::update_era_config(RawOrigin::Root.into(), None, Some(10u32.into()), None, None);
+		Economy::stake_on_innovation(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get() + dollar(100));
+		for token_id in 1..10 {
+			Economy::accumulate_reward(FungibleTokenId::FungibleToken(token_id), dollar(1));
+		}
+		// Skip the era rollover of on_initialize so the keeper finds an era update due
+		System::set_block_number(100u32.into());
+	}: _(RawOrigin::Signed(caller.clone()))
+
+	set_unstake_cooldown{
+	}: _(RawOrigin::Root, 10u32.into())
+
+	set_minimum_claim_amount{
+	}: _(RawOrigin::Root, CURRENCY_ID, dollar(1))
+
+	set_project_matching{
+	}: _(RawOrigin::Root, Some(ProjectMatchingConfig {
+		mode: MatchingMode::Quadratic,
+		era_cap: dollar(100),
+		project_cap: dollar(10),
+	}))
+
+	claim_project_matching{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+		Economy::register_innovation_project(RawOrigin::Signed(caller.clone()).into(), vec![1u8].try_into().unwrap(), dollar(100));
+		set_balance(CURRENCY_ID, &Economy::project_pool_account_id(0), dollar(100));
+		economy::ProjectMatchingPools::<Runtime>::insert(0, dollar(10));
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+
+	cancel_estate_reward_lease_offer{
+		let caller: AccountId = whitelisted_caller();
+		set_balance(CURRENCY_ID, &caller, dollar(1000));
+
+		create_nft_group();
+		set_metaverse_treasury_initial_balance();
+		Metaverse::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1u8]);
+		Estate::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1]);
+		Economy::stake(RawOrigin::Signed(caller.clone()).into(), MinimumStake::get(), Some(ESTATE_ID));
+		Economy::offer_estate_reward_lease(RawOrigin::Signed(caller.clone()).into(), ESTATE_ID, 10, dollar(10));
+	}: _(RawOrigin::Signed(caller.clone()), ESTATE_ID)
+
+	set_distributor_power_quota{
+	}: _(RawOrigin::Root, CLASS_ID, Some(1000))
+
 }
 
 #[cfg(test)]
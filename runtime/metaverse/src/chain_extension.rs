@@ -0,0 +1,89 @@
+// This file is part of Metaverse.Network & Bit.Country.
+
+// Copyright (C) 2020-2022 Metaverse.Network & Bit.Country .
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chain extension exposing the economy primitives to ink! smart contracts.
+
+use codec::Encode;
+use frame_support::traits::Get;
+use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
+use sp_runtime::DispatchError;
+
+use economy::WeightInfo;
+use primitives::{Balance, PowerAmount};
+
+use crate::{AccountId, Economy, Runtime, RuntimeOrigin};
+
+/// Stake the given amount of the contract balance on innovation staking
+pub const STAKE_ON_INNOVATION_FUNC_ID: u16 = 1;
+/// Read the power balance of the given account
+pub const POWER_BALANCE_FUNC_ID: u16 = 2;
+/// Read the pending innovation staking rewards of the given account
+pub const PENDING_REWARDS_FUNC_ID: u16 = 3;
+/// Spend the given amount of the contract power balance
+pub const SPEND_POWER_FUNC_ID: u16 = 4;
+
+/// Economy chain extension. Staking and spending power act on the account of the calling contract
+#[derive(Default)]
+pub struct EconomyChainExtension;
+
+impl ChainExtension<Runtime> for EconomyChainExtension {
+	fn call<E: Ext<T = Runtime>>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+
+		match func_id {
+			STAKE_ON_INNOVATION_FUNC_ID => {
+				let amount: Balance = env.read_as()?;
+				env.charge_weight(<Runtime as economy::Config>::WeightInfo::stake_on_innovation())?;
+
+				let contract = env.ext().address().clone();
+				Economy::stake_on_innovation(RuntimeOrigin::signed(contract), amount)?;
+			}
+			POWER_BALANCE_FUNC_ID => {
+				let who: AccountId = env.read_as()?;
+				env.charge_weight(<Runtime as frame_system::Config>::DbWeight::get().reads(1))?;
+
+				env.write(&Economy::get_power_balance(&who).encode(), false, None)?;
+			}
+			PENDING_REWARDS_FUNC_ID => {
+				let who: AccountId = env.read_as()?;
+				let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+				// The reward currencies and the boosted NFT classes size the rest of the reads
+				env.charge_weight(db_weight.reads(2))?;
+				let reward_currencies = Economy::staking_reward_pool_info().rewards.len() as u64;
+				let boosted_classes = Economy::nft_reward_boosts().len() as u64;
+				// Pending and withdrawn rewards, loyalty and stake tier bonus, one pass per reward
+				// currency and one holding check per boosted class
+				env.charge_weight(
+					db_weight.reads(8u64.saturating_add(reward_currencies).saturating_add(boosted_classes)),
+				)?;
+
+				env.write(&Economy::pending_rewards(&who).encode(), false, None)?;
+			}
+			SPEND_POWER_FUNC_ID => {
+				let amount: PowerAmount = env.read_as()?;
+				env.charge_weight(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 2))?;
+
+				let contract = env.ext().address().clone();
+				Economy::spend_power(&contract, amount)?;
+			}
+			_ => return Err(DispatchError::Other("Unimplemented economy chain extension function")),
+		}
+
+		Ok(RetVal::Converging(0))
+	}
+}
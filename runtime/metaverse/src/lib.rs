@@ -119,6 +119,7 @@ pub fn wasm_binary_unwrap() -> &'static [u8] {
 }
 
 mod benchmarking;
+mod chain_extension;
 mod weights;
 
 /// Constant values used within the runtime.
@@ -184,10 +185,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 106,
+	spec_version: 107,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 0,
 };
 
@@ -449,7 +450,7 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = CurrencyAdapter<Balances, economy::FeeSharing<Runtime, ()>>;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = IdentityFee<Balance>;
@@ -799,6 +800,7 @@ impl mining::Config for Runtime {
 	type NetworkTreasuryAccount = TreasuryModuleAccount;
 	type StorageDepositFee = MiningStorageDeposit;
 	type Currency = Balances;
+	type RoundChangeHandler = Economy;
 	type WeightInfo = weights::module_mining::WeightInfo<Runtime>;
 }
 
@@ -1087,6 +1089,7 @@ impl governance::Config for Runtime {
 	type ProposalType = ProposalType;
 	type NetworkTreasury = TreasuryModuleAccount;
 	type StorageDepositFee = GovernanceStorageFee;
+	type VotingPointsSnapshot = Economy;
 }
 
 impl crowdloan::Config for Runtime {
@@ -1099,6 +1102,26 @@ impl crowdloan::Config for Runtime {
 parameter_types! {
 	pub const MiningCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
 	pub const PowerAmountPerBlock: u32 = 100;
+	pub const RewardPoolDrainGracePeriod: BlockNumber = 30 * DAYS;
+	pub const MaxQueuedExitsPerAccount: u32 = 32;
+	pub const PowerCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(1);
+	pub const MaxCurrenciesPerClaim: u32 = 16;
+	pub const LendingPotPalletId: PalletId = PalletId(*b"bit/lend");
+	pub const TopInnovationProjectsCount: u32 = 10;
+	pub const MinimumProjectDeposit: Balance = 100 * DOLLARS;
+	pub const OffenceReporterReward: Perbill = Perbill::from_percent(10);
+	pub const OffenceReportDeposit: Balance = 100 * DOLLARS;
+	pub const EconomySpendDeposit: Balance = 100 * DOLLARS;
+	pub const MaxOpenEconomySpends: u32 = 50;
+	pub const StakeFeeHeadroom: Balance = DOLLARS;
+	pub const ExistentialStake: Balance = DOLLARS;
+	pub const MinimumIncomeStake: Balance = 10 * DOLLARS;
+	pub const MissionPotPalletId: PalletId = PalletId(*b"bit/msnp");
+	pub const RewardCampaignDeposit: Balance = 100 * DOLLARS;
+	pub const BondedPoolPalletId: PalletId = PalletId(*b"bit/bdpl");
+	pub const InsuranceFundPalletId: PalletId = PalletId(*b"bit/insr");
+	pub const MaxEstateStakeBatch: u32 = 50;
+	pub const MaxStakeTiers: u32 = 20;
 }
 
 impl economy::Config for Runtime {
@@ -1115,6 +1138,43 @@ impl economy::Config for Runtime {
 	type WeightInfo = weights::module_economy::WeightInfo<Runtime>;
 	type MaximumEstateStake = MaximumEstateStake;
 	type RewardPayoutAccount = InnovationStakingRewardPayoutAccountPalletId;
+	type MaxEstateStakeBatch = MaxEstateStakeBatch;
+	type RewardSwapper = ();
+	type InsuranceFundAccount = InsuranceFundPalletId;
+	type BondedPoolPalletId = BondedPoolPalletId;
+	type RewardCampaignDeposit = RewardCampaignDeposit;
+	type MissionRewardOrigin = EnsureRootOrMetaverseTreasury;
+	type MissionPotPalletId = MissionPotPalletId;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type EconomySpendOrigin = EnsureRootOrMetaverseTreasury;
+	type EconomySpendDeposit = EconomySpendDeposit;
+	type MaxOpenEconomySpends = MaxOpenEconomySpends;
+	type SteppedMigration = (
+		economy::migrations::v3::MigrateToV3<Runtime>,
+		economy::migrations::v4::MigrateToV4<Runtime>,
+		economy::migrations::SyncWeightedEstateStakes<Runtime>,
+		economy::migrations::MigratePowerBalanceToTokens<Runtime>,
+	);
+	type MinimumIncomeStake = MinimumIncomeStake;
+	type ExistentialStake = ExistentialStake;
+	type StakeFeeHeadroom = StakeFeeHeadroom;
+	type OffenceJudge = EnsureRootOrMetaverseTreasury;
+	type OffenceReportDeposit = OffenceReportDeposit;
+	type OffenceReporterReward = OffenceReporterReward;
+	type MinimumProjectDeposit = MinimumProjectDeposit;
+	type TopInnovationProjectsCount = TopInnovationProjectsCount;
+	type OnInnovationProjectsSelected = ();
+	type MatchingEligibility = ();
+	type LendingPotPalletId = LendingPotPalletId;
+	type CrossChainTransfer = ();
+	type MaxCurrenciesPerClaim = MaxCurrenciesPerClaim;
+	type PowerCurrencyId = PowerCurrencyId;
+	type MaxQueuedExitsPerAccount = MaxQueuedExitsPerAccount;
+	type Randomness = RandomnessCollectiveFlip;
+	type RewardPoolDrainGracePeriod = RewardPoolDrainGracePeriod;
+	type OnEraChange = ();
+	type MaxStakeTiers = MaxStakeTiers;
 }
 
 impl emergency::Config for Runtime {
@@ -1355,7 +1415,7 @@ impl pallet_contracts::Config for Runtime {
 	type DefaultDepositLimit = DefaultDepositLimit;
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = ();
+	type ChainExtension = chain_extension::EconomyChainExtension;
 	type Schedule = Schedule;
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
@@ -1641,8 +1701,21 @@ pub type UncheckedExtrinsic = fp_self_contained::UncheckedExtrinsic<Address, Run
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
+
+/// Pending storage migrations
+pub type Migrations = (
+	economy::migrations::v1::MigrateToV1<Runtime>,
+	economy::migrations::v2::MigrateToV2<Runtime>,
+	economy::migrations::v4::MigrateToV4<Runtime>,
+);
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
 	type SignedInfo = H160;
@@ -2072,6 +2145,35 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl economy_rpc_runtime_api::EconomyApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn quote_power_purchase(
+			power_amount: primitives::PowerAmount,
+			domain_id: primitives::DomainId,
+		) -> Result<economy_rpc_runtime_api::PowerPurchaseQuote<Balance, BlockNumber>, sp_runtime::DispatchError> {
+			Economy::quote_power_purchase(power_amount, domain_id)
+		}
+
+		fn total_economic_weight(who: AccountId) -> Balance {
+			Economy::total_economic_weight(&who)
+		}
+
+		fn position_memo(who: AccountId) -> Option<Vec<u8>> {
+			Economy::position_memo(who).map(|memo| memo.into_inner())
+		}
+
+		fn top_stakers() -> Vec<(AccountId, Balance)> {
+			Economy::top_stakers()
+		}
+
+		fn reward_ledger_page(
+			who: AccountId,
+			start: u32,
+			limit: u32,
+		) -> Vec<economy_rpc_runtime_api::RewardLedgerEntry<Balance, BlockNumber>> {
+			Economy::reward_ledger_page(&who, start, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
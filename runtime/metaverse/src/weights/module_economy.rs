@@ -56,4 +56,537 @@ impl<T: frame_system::Config> economy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	fn update_current_era() -> Weight {
+		Weight::from_parts(183_417_000, 9204)
+			.saturating_add(T::DbWeight::get().reads(22))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	fn set_config() -> Weight {
+		Weight::from_parts(14_210_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_stake_tiers(n: u32) -> Weight {
+		Weight::from_parts(15_032_000, 1489)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn set_estate_boost_tiers(n: u32) -> Weight {
+		Weight::from_parts(14_871_000, 1489)
+			.saturating_add(Weight::from_parts(398_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn stake_batch(n: u32) -> Weight {
+		Weight::from_parts(21_406_000, 3593)
+			.saturating_add(Weight::from_parts(96_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2616).saturating_mul(n.into()))
+	}
+	fn merge_positions(n: u32) -> Weight {
+		Weight::from_parts(38_512_000, 4811)
+			.saturating_add(Weight::from_parts(128_904_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3127).saturating_mul(n.into()))
+	}
+	fn force_flush_small_claims(n: u32) -> Weight {
+		Weight::from_parts(9_874_000, 1489)
+			.saturating_add(Weight::from_parts(27_113_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
+	}
+	fn initialize_reward_pool(n: u32) -> Weight {
+		Weight::from_parts(13_209_000, 1489)
+			.saturating_add(Weight::from_parts(1_137_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 48).saturating_mul(n.into()))
+	}
+	fn force_unstake_batch(n: u32) -> Weight {
+		Weight::from_parts(11_652_000, 1489)
+			.saturating_add(Weight::from_parts(92_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3432).saturating_mul(n.into()))
+	}
+	fn keeper_restake_matured_exits(n: u32) -> Weight {
+		Weight::from_parts(19_044_000, 3593)
+			.saturating_add(Weight::from_parts(78_562_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3145).saturating_mul(n.into()))
+	}
+	fn keeper_refund_expired_campaigns(n: u32) -> Weight {
+		Weight::from_parts(19_044_000, 3593)
+			.saturating_add(Weight::from_parts(64_907_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3297).saturating_mul(n.into()))
+	}
+	fn create_pool() -> Weight {
+		Weight::from_parts(41_820_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn join_pool() -> Weight {
+		Weight::from_parts(112_468_000, 5147)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	fn unbond_from_pool() -> Weight {
+		Weight::from_parts(131_772_000, 5224)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	fn create_reward_campaign() -> Weight {
+		Weight::from_parts(86_153_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn refund_reward_campaign() -> Weight {
+		Weight::from_parts(79_541_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn register_innovation_project() -> Weight {
+		Weight::from_parts(42_775_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn direct_innovation_stake() -> Weight {
+		Weight::from_parts(38_906_000, 4017)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn propose_economy_spend() -> Weight {
+		Weight::from_parts(40_118_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn approve_economy_spend() -> Weight {
+		Weight::from_parts(71_394_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn borrow_against_stake() -> Weight {
+		Weight::from_parts(76_082_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn repay_credit() -> Weight {
+		Weight::from_parts(71_530_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn prune_my_history(n: u32) -> Weight {
+		Weight::from_parts(12_406_000, 3593)
+			.saturating_add(Weight::from_parts(3_118_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn fund_estate_rewards() -> Weight {
+		Weight::from_parts(43_612_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn claim_estate_reward() -> Weight {
+		Weight::from_parts(58_904_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn offer_estate_reward_lease() -> Weight {
+		Weight::from_parts(27_335_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn accept_estate_reward_lease() -> Weight {
+		Weight::from_parts(66_271_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn distribute_power() -> Weight {
+		Weight::from_parts(52_108_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn switch_stake() -> Weight {
+		Weight::from_parts(101_824_000, 5224)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	fn stake_with_lock() -> Weight {
+		Weight::from_parts(62_418_000, 4929)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn sponsored_stake_on_innovation() -> Weight {
+		Weight::from_parts(118_306_000, 4929)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn set_innovation_conviction() -> Weight {
+		Weight::from_parts(31_772_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn reap_dust_position() -> Weight {
+		Weight::from_parts(48_915_000, 4811)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn claim_with_signature() -> Weight {
+		Weight::from_parts(79_604_000, 4410)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn claim_reward_to() -> Weight {
+		Weight::from_parts(26_417_000, 4410)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn claim_reward_to_location() -> Weight {
+		Weight::from_parts(47_209_000, 4410)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn claim_reward_partial() -> Weight {
+		Weight::from_parts(28_150_000, 4410)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn claim_reward_swapped() -> Weight {
+		Weight::from_parts(43_862_000, 4410)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn set_estate_staking_operator() -> Weight {
+		Weight::from_parts(24_530_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn operator_stake() -> Weight {
+		Weight::from_parts(121_044_000, 5545)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn operator_unstake() -> Weight {
+		Weight::from_parts(94_377_000, 4921)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn rebalance_estate_stake() -> Weight {
+		Weight::from_parts(88_612_000, 4921)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn withdraw_innovation_unreserved() -> Weight {
+		Weight::from_parts(38_207_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn force_transfer_stake() -> Weight {
+		Weight::from_parts(133_925_000, 5314)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	fn claim_staking_power() -> Weight {
+		Weight::from_parts(29_408_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn transfer_power() -> Weight {
+		Weight::from_parts(46_317_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn approve_power() -> Weight {
+		Weight::from_parts(15_903_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn transfer_power_from() -> Weight {
+		Weight::from_parts(51_260_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn set_self_staking_power_per_round() -> Weight {
+		Weight::from_parts(9_812_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_preferences() -> Weight {
+		Weight::from_parts(13_564_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn set_reward_distribution_mode() -> Weight {
+		Weight::from_parts(10_127_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_pool_commission() -> Weight {
+		Weight::from_parts(27_018_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn nominate_pool_operator() -> Weight {
+		Weight::from_parts(18_771_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_pool_state() -> Weight {
+		Weight::from_parts(19_245_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_max_pool_commission() -> Weight {
+		Weight::from_parts(9_604_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_stake_weights() -> Weight {
+		Weight::from_parts(9_930_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_estate_trait_reward_weight() -> Weight {
+		Weight::from_parts(10_482_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn refresh_weighted_estate_stake() -> Weight {
+		Weight::from_parts(33_716_000, 4017)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn approve_estate_co_staker() -> Weight {
+		Weight::from_parts(21_389_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn revoke_estate_co_staker() -> Weight {
+		Weight::from_parts(20_917_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_estate_stake_limits() -> Weight {
+		Weight::from_parts(10_211_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn set_bit_power_exchange_rate() -> Weight {
+		Weight::from_parts(10_034_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_fee_share_ratio() -> Weight {
+		Weight::from_parts(9_687_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_protocol_commission_share() -> Weight {
+		Weight::from_parts(9_745_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_penalty_split() -> Weight {
+		Weight::from_parts(9_866_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn reject_economy_spend() -> Weight {
+		Weight::from_parts(52_440_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn report_offence() -> Weight {
+		Weight::from_parts(41_583_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn confirm_offence() -> Weight {
+		Weight::from_parts(128_906_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	fn reject_offence() -> Weight {
+		Weight::from_parts(49_370_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn distribute_mission_reward() -> Weight {
+		Weight::from_parts(44_758_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn set_position_receipt_class() -> Weight {
+		Weight::from_parts(9_573_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn claim_position_reward() -> Weight {
+		Weight::from_parts(41_926_000, 4410)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn unstake_position() -> Weight {
+		Weight::from_parts(97_331_000, 4811)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	fn split_position() -> Weight {
+		Weight::from_parts(112_640_000, 4811)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	fn compensate_from_insurance_fund() -> Weight {
+		Weight::from_parts(47_118_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn deregister_innovation_project() -> Weight {
+		Weight::from_parts(57_302_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn withdraw_directed_stake() -> Weight {
+		Weight::from_parts(36_774_000, 4017)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn set_credit_line_config() -> Weight {
+		Weight::from_parts(10_356_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_power_advance_config() -> Weight {
+		Weight::from_parts(10_098_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn request_power_advance() -> Weight {
+		Weight::from_parts(58_627_000, 4929)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn settle_power_advance() -> Weight {
+		Weight::from_parts(54_913_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn set_cross_chain_reward_chain() -> Weight {
+		Weight::from_parts(11_409_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_cross_chain_reward_destination() -> Weight {
+		Weight::from_parts(16_238_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_stake_sponsor() -> Weight {
+		Weight::from_parts(10_675_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_reward_ledger_retention() -> Weight {
+		Weight::from_parts(9_714_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_nft_reward_boost() -> Weight {
+		Weight::from_parts(10_259_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn fund_reward_pot() -> Weight {
+		Weight::from_parts(42_537_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn freeze_reward_pool() -> Weight {
+		Weight::from_parts(12_884_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn drain_reward_pool() -> Weight {
+		Weight::from_parts(49_161_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn sweep_reward_pot() -> Weight {
+		Weight::from_parts(46_072_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn rotate_reward_pot() -> Weight {
+		Weight::from_parts(318_740_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(43))
+			.saturating_add(T::DbWeight::get().writes(42))
+	}
+	fn set_reward_pot_low_watermark() -> Weight {
+		Weight::from_parts(9_652_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_voting_season_length() -> Weight {
+		Weight::from_parts(9_801_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn verify_account() -> Weight {
+		Weight::from_parts(64_208_000, 5001)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn retry_failed_payout() -> Weight {
+		Weight::from_parts(45_927_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn set_domain_power_commission() -> Weight {
+		Weight::from_parts(10_144_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_position_memo() -> Weight {
+		Weight::from_parts(15_476_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_max_innovation_stakers() -> Weight {
+		Weight::from_parts(9_588_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn leave_innovation_waitlist() -> Weight {
+		Weight::from_parts(31_093_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn set_keeper() -> Weight {
+		Weight::from_parts(10_962_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn keeper_update_era() -> Weight {
+		Weight::from_parts(189_502_000, 9204)
+			.saturating_add(T::DbWeight::get().reads(24))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+	fn set_unstake_cooldown() -> Weight {
+		Weight::from_parts(9_513_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_minimum_claim_amount() -> Weight {
+		Weight::from_parts(10_047_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_project_matching() -> Weight {
+		Weight::from_parts(10_306_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn claim_project_matching() -> Weight {
+		Weight::from_parts(45_815_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn cancel_estate_reward_lease_offer() -> Weight {
+		Weight::from_parts(17_629_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_distributor_power_quota() -> Weight {
+		Weight::from_parts(10_388_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::weights::Weight;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::{DispatchError, DispatchResult, Perbill, RuntimeDebug};
@@ -8,7 +9,8 @@ use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec::Vec};
 
 use primitives::staking::RoundInfo;
 use primitives::{
-	ClassId, FungibleTokenId, GroupCollectionId, MetaverseId, UndeployedLandBlockId, UndeployedLandBlockType,
+	ClassId, EraIndex, EstateId, FungibleTokenId, GroupCollectionId, MetaverseId, ProposalId, RoundIndex,
+	UndeployedLandBlockId, UndeployedLandBlockType,
 };
 
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
@@ -307,6 +309,8 @@ pub trait NFTTrait<AccountId, Balance> {
 	fn get_asset_owner(asset_id: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError>;
 	/// Get stackable nft balance
 	fn get_free_stackable_nft_balance(who: &AccountId, asset_id: &(Self::ClassId, Self::TokenId)) -> Balance;
+	/// Check if account holds any token of the class
+	fn holds_class_token(who: &AccountId, class_id: Self::ClassId) -> bool;
 	/// Reserve stackable nft balance
 	fn reserve_stackable_nft_balance(
 		who: &AccountId,
@@ -332,6 +336,222 @@ pub trait RoundTrait<BlockNumber> {
 	fn get_current_round_info() -> RoundInfo<BlockNumber>;
 }
 
+/// Handler notified by the round provider whenever a new round starts
+pub trait RoundChangeHandler {
+	/// Called once when the round advances from `previous_round` to `new_round`
+	fn on_round_change(previous_round: RoundIndex, new_round: RoundIndex) -> Weight;
+}
+
+impl RoundChangeHandler for () {
+	fn on_round_change(_previous_round: RoundIndex, _new_round: RoundIndex) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Provides the economic stake of accounts, e.g. for weighting governance votes
+pub trait StakeWeightProvider<AccountId, Balance> {
+	/// Combined weighted self, estate and innovation stake of `who`
+	fn stake_weight(who: &AccountId) -> Balance;
+}
+
+impl<AccountId, Balance: Default> StakeWeightProvider<AccountId, Balance> for () {
+	fn stake_weight(_who: &AccountId) -> Balance {
+		Default::default()
+	}
+}
+
+/// Samples stakers proportionally to their stake, e.g. to pick quest or airdrop recipients
+pub trait WeightedStakerSelector<AccountId> {
+	/// Select up to `n` distinct stakers using the runtime randomness for `seed`
+	fn select_weighted_stakers(n: u32, seed: &[u8]) -> Vec<AccountId>;
+}
+
+impl<AccountId> WeightedStakerSelector<AccountId> for () {
+	fn select_weighted_stakers(_n: u32, _seed: &[u8]) -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
+/// Share based reward pool in the style of orml-rewards, so pallets built around orml incentives
+/// can fund and inspect a reward pool. Shares follow the stake of the pool and cannot be changed
+/// through this interface
+pub trait RewardPoolHandler<AccountId, Balance, CurrencyId> {
+	/// Add `reward_increment` of `reward_currency`, already held by the reward payout account, to
+	/// the pool
+	fn accumulate_reward(reward_currency: CurrencyId, reward_increment: Balance) -> DispatchResult;
+	/// Move the rewards accrued to the shares of `who` to its pending rewards
+	fn claim_rewards(who: &AccountId);
+	/// Shares of `who` and the rewards withdrawn by them, per currency
+	fn shares_and_withdrawn_rewards(who: &AccountId) -> (Balance, BTreeMap<CurrencyId, Balance>);
+	/// Total shares and, per currency, the total and withdrawn rewards of the pool
+	fn pool_info() -> (Balance, BTreeMap<CurrencyId, (Balance, Balance)>);
+	/// Rewards of `who` claimable right now, per currency
+	fn pending_rewards(who: &AccountId) -> Vec<(CurrencyId, Balance)>;
+}
+
+impl<AccountId, Balance: Default, CurrencyId> RewardPoolHandler<AccountId, Balance, CurrencyId> for () {
+	fn accumulate_reward(_reward_currency: CurrencyId, _reward_increment: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn claim_rewards(_who: &AccountId) {}
+
+	fn shares_and_withdrawn_rewards(_who: &AccountId) -> (Balance, BTreeMap<CurrencyId, Balance>) {
+		(Default::default(), BTreeMap::new())
+	}
+
+	fn pool_info() -> (Balance, BTreeMap<CurrencyId, (Balance, Balance)>) {
+		(Default::default(), BTreeMap::new())
+	}
+
+	fn pending_rewards(_who: &AccountId) -> Vec<(CurrencyId, Balance)> {
+		Vec::new()
+	}
+}
+
+/// Freezes staker voting points when a governance proposal is created
+pub trait VotingPointsSnapshotProvider<AccountId, Balance> {
+	/// Snapshot the voting points of every staker for `proposal_id`
+	fn snapshot_voting_points(proposal_id: ProposalId) -> DispatchResult;
+	/// Voting points of `who` frozen for `proposal_id`
+	fn voting_points_at(proposal_id: ProposalId, who: &AccountId) -> Balance;
+	/// Record the voting points of `who` frozen for `proposal_id` when it votes
+	fn record_voting_points(proposal_id: ProposalId, who: &AccountId) -> Balance;
+	/// Remove the snapshot of `proposal_id` once the proposal is finished
+	fn clear_voting_points_snapshot(proposal_id: ProposalId);
+}
+
+impl<AccountId, Balance: Default> VotingPointsSnapshotProvider<AccountId, Balance> for () {
+	fn snapshot_voting_points(_proposal_id: ProposalId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn voting_points_at(_proposal_id: ProposalId, _who: &AccountId) -> Balance {
+		Default::default()
+	}
+
+	fn record_voting_points(_proposal_id: ProposalId, _who: &AccountId) -> Balance {
+		Default::default()
+	}
+
+	fn clear_voting_points_snapshot(_proposal_id: ProposalId) {}
+}
+
+/// Prices power in BIT for pallets selling or distributing power
+pub trait PowerConversion<Balance> {
+	/// BIT required for `power_amount` including `commission`, and the commission part of it
+	fn convert_power_to_bit(power_amount: Balance, commission: Perbill) -> Result<(Balance, Balance), DispatchError>;
+}
+
+impl<Balance> PowerConversion<Balance> for () {
+	fn convert_power_to_bit(_power_amount: Balance, _commission: Perbill) -> Result<(Balance, Balance), DispatchError> {
+		Err(DispatchError::Other("Power conversion is not supported"))
+	}
+}
+
+/// Handles native currency income paid out by other pallets, e.g. marketplace sale proceeds or
+/// rental income
+pub trait OnIncome<AccountId, Balance> {
+	/// `who` received `amount`, already credited to its free balance
+	fn on_income(who: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance> OnIncome<AccountId, Balance> for () {
+	fn on_income(_who: &AccountId, _amount: Balance) {}
+}
+
+/// Handles the innovation projects selected by directed stake at an era rollover, e.g. to fund
+/// grants or spotlight projects
+pub trait OnInnovationProjectsSelected<ProjectId, Balance> {
+	/// `projects` were selected at `era`, ranked by directed stake
+	fn on_projects_selected(era: EraIndex, projects: &[(ProjectId, Balance)]);
+}
+
+impl<ProjectId, Balance> OnInnovationProjectsSelected<ProjectId, Balance> for () {
+	fn on_projects_selected(_era: EraIndex, _projects: &[(ProjectId, Balance)]) {}
+}
+
+/// Handles the start of a new innovation staking era, e.g. to let reputation, quest or analytics
+/// pallets react without tracking eras themselves
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnEraChange<Balance> {
+	/// `new_era` started, with `distributed_rewards` added to the reward pool per currency during
+	/// the ended era
+	fn on_era_change(new_era: EraIndex, distributed_rewards: &[(FungibleTokenId, Balance)]);
+}
+
+/// Decides whether a staker counts towards the breadth of a project for reward matching, e.g.
+/// by requiring a verified identity
+pub trait MatchingEligibility<AccountId> {
+	/// Whether `who` counts as a distinct staker
+	fn is_eligible(who: &AccountId) -> bool;
+}
+
+impl<AccountId> MatchingEligibility<AccountId> for () {
+	fn is_eligible(_who: &AccountId) -> bool {
+		true
+	}
+}
+
+/// Transfers assets to an account on another chain, usually through an XCM reserve transfer
+pub trait CrossChainTransfer<AccountId, Balance, Location> {
+	/// Transfer `amount` of `currency_id` held by `who` to `dest`
+	fn transfer_to_chain(
+		who: &AccountId,
+		currency_id: FungibleTokenId,
+		amount: Balance,
+		dest: Location,
+	) -> DispatchResult;
+}
+
+impl<AccountId, Balance, Location> CrossChainTransfer<AccountId, Balance, Location> for () {
+	fn transfer_to_chain(
+		_who: &AccountId,
+		_currency_id: FungibleTokenId,
+		_amount: Balance,
+		_dest: Location,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Cross-chain transfer is not supported"))
+	}
+}
+
+/// Handles an estate split into child estates, e.g. to move state keyed by the parent estate id
+pub trait OnEstateSubdivided {
+	/// `parent` was split into `children`, each with its number of land units
+	fn on_estate_subdivided(parent: EstateId, children: &[(EstateId, u64)]) -> DispatchResult;
+}
+
+impl OnEstateSubdivided for () {
+	fn on_estate_subdivided(_parent: EstateId, _children: &[(EstateId, u64)]) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Converts claimed rewards into another currency, usually through a DEX
+pub trait RewardSwapper<AccountId, Balance> {
+	/// Swap `amount_in` of `currency_in` held by `who` into `currency_out`, failing if less than
+	/// `min_amount_out` is received. Returns the received amount
+	fn swap_reward(
+		who: &AccountId,
+		currency_in: FungibleTokenId,
+		currency_out: FungibleTokenId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError>;
+}
+
+impl<AccountId, Balance> RewardSwapper<AccountId, Balance> for () {
+	fn swap_reward(
+		_who: &AccountId,
+		_currency_in: FungibleTokenId,
+		_currency_out: FungibleTokenId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("Reward swap is not supported"))
+	}
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
 pub struct MiningRange<T> {